@@ -0,0 +1,80 @@
+//! Static validation of `.scm` query files.
+//!
+//! Every file is parsed and checked against the grammar's node types: unknown node kinds,
+//! anonymous nodes, fields and supertypes, nodes that cannot appear under their parent, and
+//! undefined captures are reported with the line and column they start at.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::validate::{self, Diagnostic, Location};
+
+/// The diagnostics for one query file.
+///
+/// Displays as one `path:line:column: message` line per diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The file that was checked.
+    pub path: PathBuf,
+    /// Each problem found, with its position in the file.
+    pub diagnostics: Vec<(Location, Diagnostic)>,
+}
+
+impl Report {
+    /// Whether the file has no problems.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (location, diagnostic) in &self.diagnostics {
+            writeln!(
+                f,
+                "{}:{location}: {}",
+                self.path.display(),
+                diagnostic.message
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Check query source that was read from `path`.
+#[must_use]
+pub fn check_source(grammar: &Grammar, path: impl Into<PathBuf>, source: &str) -> Report {
+    let diagnostics = validate::check(grammar, source)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.location(source), diagnostic))
+        .collect();
+    Report {
+        path: path.into(),
+        diagnostics,
+    }
+}
+
+/// Read and check a query file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read.
+pub fn check_file(grammar: &Grammar, path: &Path) -> io::Result<Report> {
+    let source = fs::read_to_string(path)?;
+    Ok(check_source(grammar, path, &source))
+}
+
+/// Expand `paths` into the query files to check.
+///
+/// Files are kept as given, whatever their extension. Directories are searched recursively for
+/// `.scm` files, in sorted order.
+///
+/// # Errors
+///
+/// Returns an error if a path does not exist or a directory cannot be read.
+pub fn query_files(paths: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    crate::files::collect(paths, "scm")
+}
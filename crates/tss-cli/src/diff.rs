@@ -0,0 +1,106 @@
+//! Comparing grammar versions from the command line.
+//!
+//! Each side of a comparison is either a bundled [`Language`] (by name) or a path to a
+//! `node-types.json` file, so `tss diff rust path/to/node-types.json` compares the bundled
+//! tree-sitter-rust grammar with a newer checkout. The changes themselves come from
+//! [`tree_sitter_symbols_schema::diff`], with nodes named as the bundled language's generated
+//! crate names them (see [`overrides`]).
+//!
+//! ```
+//! use tree_sitter_symbols_cli::diff::{load, overrides, report};
+//! use tree_sitter_symbols_schema::diff::diff;
+//!
+//! let grammar = load("rust")?;
+//! let changes = diff(&grammar, &grammar, &overrides("rust", "rust"))?;
+//! assert_eq!(report(&changes, false), "no changes\n");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::language::Language;
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use tree_sitter_symbols_schema::diff::Change;
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::Overrides;
+
+/// An error loading one side of a comparison.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io(PathBuf, io::Error),
+    /// The file is not a valid `node-types.json`.
+    Schema(PathBuf, String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            Self::Schema(path, message) => {
+                write!(
+                    f,
+                    "{}: not a node-types.json file: {message}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Load a grammar from a bundled language name (such as `rust`) or a `node-types.json` path.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or does not match the `node-types.json` schema.
+pub fn load(spec: &str) -> Result<Grammar, LoadError> {
+    if let Ok(language) = spec.parse::<Language>() {
+        return Ok(language.grammar());
+    }
+    let path = PathBuf::from(spec);
+    let json = fs::read_to_string(&path).map_err(|err| LoadError::Io(path.clone(), err))?;
+    Grammar::from_json(&json).map_err(|err| LoadError::Schema(path, err.to_string()))
+}
+
+/// The naming overrides to compare `old` and `new` with: those of the generated crate of the
+/// first side that is a bundled language, or none if both are files.
+#[must_use]
+pub fn overrides(old: &str, new: &str) -> Overrides {
+    [old, new]
+        .into_iter()
+        .find_map(|spec| spec.parse::<Language>().ok())
+        .map(Language::overrides)
+        .unwrap_or_default()
+}
+
+/// One line per change, then a count of the changes and how many are breaking.
+///
+/// With `breaking_only`, changes that cannot break existing code (such as new node kinds) are
+/// left out.
+#[must_use]
+pub fn report(changes: &[Change], breaking_only: bool) -> String {
+    let mut out = String::new();
+    let shown: Vec<&Change> = changes
+        .iter()
+        .filter(|change| !breaking_only || change.is_breaking())
+        .collect();
+    for change in &shown {
+        let _ = writeln!(out, "{change}");
+    }
+    let breaking = changes.iter().filter(|change| change.is_breaking()).count();
+    match (changes.len(), breaking_only) {
+        (0, _) => out.push_str("no changes\n"),
+        (_, true) if breaking == 0 => out.push_str("no breaking changes\n"),
+        (total, _) => {
+            let _ = writeln!(
+                out,
+                "{total} change{} ({breaking} breaking)",
+                if total == 1 { "" } else { "s" }
+            );
+        }
+    }
+    out
+}
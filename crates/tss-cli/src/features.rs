@@ -0,0 +1,473 @@
+//! Working out the minimal set of `tss-rust` node features a crate needs.
+//!
+//! Every `NodeType` variant sits behind its own Cargo feature, so a crate only pays for the node
+//! kinds it names. [`scan_paths`] finds those names in Rust source: `NodeType::FunctionItem`
+//! paths (also through the `NodeTypeRust` re-export of `tss`) and string literals parsed as a
+//! `NodeType`, by `NodeType::from_str("function_item")` or `"function_item".parse::<NodeType>()`.
+//! Deprecated aliases count as their replacement, and associated constants such as
+//! `NodeType::ALL` are skipped. [`rewrite_manifest`] then writes the features into the dependent
+//! crate's `Cargo.toml`.
+//!
+//! ```
+//! use tree_sitter_symbols_cli::features::{features_array, Index, Usage};
+//! use tree_sitter_symbols_cli::language::Language;
+//!
+//! let index = Index::new(&Language::Rust.names(), &Language::Rust.aliases());
+//! let mut usage = Usage::default();
+//! usage.scan_source(
+//!     &index,
+//!     r#"if kind == NodeType::FunctionItem || NodeType::from_str("::").is_ok() {}"#,
+//! );
+//! assert_eq!(features_array(&usage.features), r#"["function_item"]"#);
+//! assert!(usage.unknown.contains("\"::\""));
+//! ```
+
+use ropey::Rope;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use textum::{Patch, PatchError};
+use tree_sitter_symbols_schema::naming::{Alias, Names};
+
+/// The type names a `NodeType` is referred to by.
+const TYPE_NAMES: &[&str] = &["NodeType", "NodeTypeRust"];
+
+/// The features of a grammar's node kinds, by variant and by kind, built once for a scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Index {
+    by_variant: HashMap<String, String>,
+    /// `FromStr` only parses named kinds (and named deprecated kinds)
+    by_kind: HashMap<String, String>,
+}
+
+impl Index {
+    /// Index `names` and the deprecated `aliases` of the generated crate, each alias standing for
+    /// its replacement's feature.
+    #[must_use]
+    pub fn new(names: &[Names], aliases: &[Alias]) -> Self {
+        let mut index = Self::default();
+        for names in names {
+            index
+                .by_variant
+                .insert(names.variant.clone(), names.feature.clone());
+            if names.named {
+                index
+                    .by_kind
+                    .insert(names.kind.clone(), names.feature.clone());
+            }
+        }
+        // Dropped kinds stand for nothing, so they stay unknown
+        for alias in aliases {
+            let Some(feature) = alias.replacement.as_ref().map(|names| &names.feature) else {
+                continue;
+            };
+            index
+                .by_variant
+                .insert(alias.variant.clone(), feature.clone());
+            if alias.named {
+                index.by_kind.insert(alias.kind.clone(), feature.clone());
+            }
+        }
+        index
+    }
+}
+
+/// The node features found in Rust source.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Features of the node kinds that are used.
+    pub features: BTreeSet<String>,
+    /// Variants and parsed literals that match no node kind, shown as written (literals
+    /// quoted).
+    pub unknown: BTreeSet<String>,
+}
+
+impl Usage {
+    /// Record the node kinds in `index` named in one Rust source file.
+    pub fn scan_source(&mut self, index: &Index, source: &str) {
+        for variant in variant_paths(source) {
+            // Lowercase segments are associated functions such as `NodeType::from_str`, and
+            // uppercase ones associated constants such as `NodeType::ALL`
+            let is_variant = variant.starts_with(|c: char| c.is_ascii_uppercase())
+                && variant.contains(|c: char| c.is_ascii_lowercase());
+            if !is_variant {
+                continue;
+            }
+            match index.by_variant.get(variant) {
+                Some(feature) => self.features.insert(feature.clone()),
+                None => self.unknown.insert(variant.to_string()),
+            };
+        }
+        for kind in parsed_literals(source) {
+            match index.by_kind.get(kind) {
+                Some(feature) => self.features.insert(feature.clone()),
+                None => self.unknown.insert(format!("{kind:?}")),
+            };
+        }
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Where each mention of a `NodeType` type name ends.
+fn type_name_ends(source: &str) -> impl Iterator<Item = usize> + '_ {
+    TYPE_NAMES.iter().flat_map(move |type_name| {
+        source
+            .match_indices(type_name)
+            .map(|(i, _)| (i, i + type_name.len()))
+            .filter(|(i, end)| {
+                !source[..*i].ends_with(is_ident_char) && !source[*end..].starts_with(is_ident_char)
+            })
+            .map(|(_, end)| end)
+    })
+}
+
+/// The identifiers following `NodeType::` (or `NodeTypeRust::`).
+fn variant_paths(source: &str) -> impl Iterator<Item = &str> {
+    type_name_ends(source).filter_map(move |end| {
+        let rest = source[end..].strip_prefix("::")?;
+        let variant = &rest[..rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len())];
+        (!variant.is_empty()).then_some(variant)
+    })
+}
+
+/// The contents of a string literal without escapes at the start of `source`.
+fn leading_literal(source: &str) -> Option<&str> {
+    let rest = source.strip_prefix('"')?;
+    let end = rest.find(['"', '\\'])?;
+    rest[end..].starts_with('"').then(|| &rest[..end])
+}
+
+/// The string literals parsed as a `NodeType`: passed directly to `NodeType::from_str(...)`, or
+/// followed by `.parse::<NodeType>()`.
+fn parsed_literals(source: &str) -> impl Iterator<Item = &str> {
+    let from_str = type_name_ends(source).filter_map(move |end| {
+        let rest = source[end..].strip_prefix("::from_str(")?;
+        leading_literal(rest.trim_start())
+    });
+    let parse = source
+        .match_indices(".parse::<")
+        .filter_map(move |(i, call)| {
+            let rest = source[i + call.len()..].trim_start();
+            let parses_node_type = TYPE_NAMES.iter().any(|type_name| {
+                rest.strip_prefix(type_name)
+                    .is_some_and(|rest| rest.trim_start().starts_with('>'))
+            });
+            // The literal ends right before `.parse`; find where it starts, giving up on escapes
+            let before = source[..i].strip_suffix('"').filter(|_| parses_node_type)?;
+            let start = before.rfind('"')?;
+            if before[..start].ends_with('\\') {
+                return None;
+            }
+            leading_literal(&source[start..i])
+        });
+    from_str.chain(parse)
+}
+
+/// Scan `.rs` files under `paths` (files, or directories searched recursively, skipping
+/// `target` and hidden directories).
+///
+/// # Errors
+///
+/// Returns an error if a path does not exist or a file cannot be read.
+pub fn scan_paths(index: &Index, paths: &[PathBuf]) -> io::Result<Usage> {
+    let mut usage = Usage::default();
+    for path in crate::files::collect(paths, "rs")? {
+        usage.scan_source(index, &fs::read_to_string(&path)?);
+    }
+    Ok(usage)
+}
+
+/// Format features as a TOML array, e.g. `["block", "function_item"]`.
+#[must_use]
+pub fn features_array<'a>(features: impl IntoIterator<Item = &'a String>) -> String {
+    let quoted: Vec<String> = features
+        .into_iter()
+        .map(|feature| format!("{feature:?}"))
+        .collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+/// An error rewriting a dependent crate's `Cargo.toml`.
+#[derive(Debug)]
+pub enum RewriteError {
+    /// The manifest could not be read or written.
+    Io(io::Error),
+    /// The textum patch could not be applied.
+    Patch(PatchError),
+    /// The manifest has no entry for the dependency.
+    MissingDependency(String),
+    /// The dependency is declared in a form this tool cannot rewrite, such as an inline table
+    /// spanning several lines.
+    Unsupported(String),
+}
+
+impl fmt::Display for RewriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Patch(err) => write!(f, "failed to patch manifest: {err}"),
+            Self::MissingDependency(name) => write!(f, "no `{name}` dependency found"),
+            Self::Unsupported(line) => write!(f, "cannot rewrite dependency declared as `{line}`"),
+        }
+    }
+}
+
+impl std::error::Error for RewriteError {}
+
+impl From<io::Error> for RewriteError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<PatchError> for RewriteError {
+    fn from(err: PatchError) -> Self {
+        Self::Patch(err)
+    }
+}
+
+/// Set the node features of `dependency` in the `Cargo.toml` at `manifest`.
+///
+/// Features in the existing list that are not node features (such as `meta_fields` or
+/// `tree-sitter`) are kept; node features are replaced by `features`. The dependency can be
+/// written as `name = "version"`, a single-line inline table, or a `[dependencies.name]` table,
+/// with the name quoted or not; trailing comments are kept. Returns whether the file changed.
+///
+/// # Errors
+///
+/// Returns an error if the manifest cannot be read or written, or if the dependency is missing
+/// or declared in a form that cannot be rewritten.
+pub fn rewrite_manifest(
+    names: &[Names],
+    manifest: &Path,
+    dependency: &str,
+    features: &BTreeSet<String>,
+) -> Result<bool, RewriteError> {
+    let content = fs::read_to_string(manifest)?;
+    let node_features: BTreeSet<&String> = names.iter().map(|names| &names.feature).collect();
+    let merge = |existing: &[String]| -> BTreeSet<String> {
+        existing
+            .iter()
+            .filter(|feature| !node_features.contains(feature))
+            .chain(features)
+            .cloned()
+            .collect()
+    };
+
+    let (line_idx, replacement) = dependency_edit(&content, dependency, merge)?;
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    if lines[line_idx] == replacement {
+        return Ok(false);
+    }
+
+    let mut rope = Rope::from_str(&content);
+    let patch = Patch::from_line_range(
+        manifest.display().to_string(),
+        line_idx,
+        line_idx + 1,
+        &replacement,
+    );
+    patch.apply(&mut rope)?;
+    fs::write(manifest, rope.to_string())?;
+    Ok(true)
+}
+
+/// Find the line to replace for `dependency` and what to replace it with.
+fn dependency_edit(
+    content: &str,
+    dependency: &str,
+    merge: impl Fn(&[String]) -> BTreeSet<String>,
+) -> Result<(usize, String), RewriteError> {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let mut section = "";
+    for (i, raw) in lines.iter().enumerate() {
+        let line = raw.trim();
+        if line.starts_with('[') {
+            section = line.split('#').next().unwrap_or(line).trim();
+            if is_dependency_table(section, dependency) {
+                return table_edit(&lines, i, merge);
+            }
+            continue;
+        }
+        if !is_dependency_section(section) {
+            continue;
+        }
+        let unsupported = || RewriteError::Unsupported(line.to_string());
+        let Some(entry) = KeyValue::parse(raw) else {
+            // A value spanning several lines does not parse on its own
+            let key = line
+                .split_once('=')
+                .map(|(key, _)| key.trim().trim_matches('"'));
+            if key == Some(dependency) {
+                return Err(unsupported());
+            }
+            continue;
+        };
+        let Some(value) = entry.parsed.get(dependency) else {
+            continue;
+        };
+        let table = match value {
+            toml::Value::String(_) => {
+                let features = features_array(&merge(&[]));
+                format!("{{ version = {}, features = {features} }}", entry.value)
+            }
+            toml::Value::Table(_) if entry.value.starts_with('{') => {
+                replace_features(entry.value, &merge).ok_or_else(unsupported)?
+            }
+            _ => return Err(unsupported()),
+        };
+        let rewritten = format!("{}= {table}{}", entry.key, entry.rest);
+        // Refuse to write a line that no longer parses rather than corrupt the manifest
+        if KeyValue::parse(&rewritten).is_none() {
+            return Err(unsupported());
+        }
+        return Ok((i, rewritten));
+    }
+    Err(RewriteError::MissingDependency(dependency.to_string()))
+}
+
+/// A single-line `key = value` entry of a manifest.
+struct KeyValue<'a> {
+    /// The key as written, with any indentation and the space before `=`.
+    key: &'a str,
+    /// The value as written.
+    value: &'a str,
+    /// Everything after the value: spacing, any `#` comment and the line ending.
+    rest: &'a str,
+    /// The entry parsed as TOML.
+    parsed: toml::Table,
+}
+
+impl<'a> KeyValue<'a> {
+    /// Split `line` into its key, value and trailing comment, if it is a complete entry.
+    fn parse(line: &'a str) -> Option<Self> {
+        let parsed: toml::Table = line.parse().ok()?;
+        let parses_to = |text: &str, expected: &toml::Table| {
+            text.parse::<toml::Table>()
+                .is_ok_and(|table| table == *expected)
+        };
+        // The `=` after the key is the first one where the key parses on its own, and the
+        // comment starts at the first `#` after which the entry is unchanged
+        let equals = line
+            .match_indices('=')
+            .map(|(i, _)| i)
+            .find(|&i| format!("{} = 0", &line[..i]).parse::<toml::Table>().is_ok())?;
+        let end = line
+            .match_indices('#')
+            .map(|(i, _)| i)
+            .find(|&i| i > equals && parses_to(&line[..i], &parsed))
+            .unwrap_or(line.len());
+        let value = line[equals + 1..end].trim();
+        let value_start = equals + 1 + line[equals + 1..].find(value)?;
+        Some(Self {
+            key: &line[..equals],
+            value,
+            rest: &line[value_start + value.len()..],
+            parsed,
+        })
+    }
+}
+
+/// Whether `header` opens the `[dependencies.name]` table of `dependency`, quoted or not.
+fn is_dependency_table(header: &str, dependency: &str) -> bool {
+    header.parse::<toml::Table>().is_ok_and(|table| {
+        table
+            .get("dependencies")
+            .and_then(toml::Value::as_table)
+            .is_some_and(|dependencies| {
+                dependencies.len() == 1 && dependencies.contains_key(dependency)
+            })
+    })
+}
+
+fn is_dependency_section(section: &str) -> bool {
+    let name = section.trim_matches(['[', ']']);
+    name == "dependencies"
+        || name == "dev-dependencies"
+        || name == "build-dependencies"
+        || (name.starts_with("target.") && name.ends_with("dependencies"))
+}
+
+/// Rewrite the `features` line of a `[dependencies.name]` table, or add one after the header.
+fn table_edit(
+    lines: &[&str],
+    header: usize,
+    merge: impl Fn(&[String]) -> BTreeSet<String>,
+) -> Result<(usize, String), RewriteError> {
+    for (i, raw) in lines.iter().enumerate().skip(header + 1) {
+        let line = raw.trim();
+        if line.starts_with('[') {
+            break;
+        }
+        let unsupported = || RewriteError::Unsupported(line.to_string());
+        let Some(entry) = KeyValue::parse(raw) else {
+            if line
+                .split_once('=')
+                .is_some_and(|(key, _)| key.trim() == "features")
+            {
+                return Err(unsupported());
+            }
+            continue;
+        };
+        if let Some(value) = entry.parsed.get("features") {
+            let existing = value
+                .as_array()
+                .and_then(|array| {
+                    array
+                        .iter()
+                        .map(|feature| feature.as_str().map(str::to_string))
+                        .collect::<Option<Vec<_>>>()
+                })
+                .ok_or_else(unsupported)?;
+            let features = features_array(&merge(&existing));
+            return Ok((i, format!("{}= {features}{}", entry.key, entry.rest)));
+        }
+    }
+    let features = features_array(&merge(&[]));
+    let header_line = lines[header].trim_end_matches('\n');
+    Ok((header, format!("{header_line}\nfeatures = {features}\n")))
+}
+
+/// Replace (or add) the `features` key of a single-line inline table.
+fn replace_features(table: &str, merge: &impl Fn(&[String]) -> BTreeSet<String>) -> Option<String> {
+    let inner = table.strip_prefix('{')?.strip_suffix('}')?.trim();
+    // Skip keys that merely end in `features`, such as `default-features`
+    let key = inner.match_indices("features").find(|(i, key)| {
+        !inner[..*i].ends_with(|c: char| is_ident_char(c) || c == '-')
+            && inner[i + key.len()..].trim_start().starts_with('=')
+    });
+    let Some((start, _)) = key else {
+        let features = features_array(&merge(&[]));
+        let separator = if inner.is_empty() { "" } else { ", " };
+        return Some(format!("{{ {inner}{separator}features = {features} }}"));
+    };
+    let open = start + inner[start..].find('[')?;
+    let close = open + inner[open..].find(']')?;
+    let existing = parse_array(&inner[open..=close])?;
+    let features = features_array(&merge(&existing));
+    Some(format!(
+        "{{ {}{features}{} }}",
+        &inner[..open],
+        &inner[close + 1..]
+    ))
+}
+
+/// Parse a single-line array of plain strings.
+fn parse_array(array: &str) -> Option<Vec<String>> {
+    let inner = array.strip_prefix('[')?.strip_suffix(']')?;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            item.strip_prefix('"')
+                .and_then(|item| item.strip_suffix('"'))
+                .map(str::to_string)
+        })
+        .collect()
+}
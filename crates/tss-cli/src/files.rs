@@ -0,0 +1,42 @@
+//! Expanding command line paths into the files to process.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Expand `paths` into files: files are kept as given, whatever their extension, and directories
+/// are searched recursively (in sorted order) for files ending in `.{extension}`. `target` and
+/// hidden directories are skipped.
+pub(crate) fn collect(paths: &[PathBuf], extension: &str) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            walk(path, extension, &mut files)?;
+        } else {
+            fs::metadata(path)?;
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn walk(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            let skip = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name == "target" || name.starts_with('.'));
+            if !skip {
+                walk(&path, extension, files)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
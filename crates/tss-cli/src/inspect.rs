@@ -0,0 +1,233 @@
+//! Inspecting a grammar's node types and how they are named in generated code.
+//!
+//! Each function renders plain text for the matching `tss` subcommand. Unnamed nodes are shown
+//! quoted, as they are written in queries (`"fn"`), and named nodes bare (`function_item`).
+//! Variants and features are looked up in the names the generated crate uses, from
+//! [`Language::names`](crate::language::Language::names).
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use tree_sitter_symbols_schema::diff::Cardinality;
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::Names;
+use tree_sitter_symbols_schema::schema::NodeType;
+
+/// Which node types to list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Filter {
+    /// Named and unnamed nodes.
+    #[default]
+    All,
+    /// Only named nodes.
+    Named,
+    /// Only unnamed (anonymous) nodes.
+    Anonymous,
+    /// Only supertypes.
+    Supertypes,
+}
+
+impl Filter {
+    fn matches(self, node_type: &NodeType) -> bool {
+        match self {
+            Self::All => true,
+            Self::Named => node_type.named,
+            Self::Anonymous => !node_type.named,
+            Self::Supertypes => node_type.subtypes.is_some(),
+        }
+    }
+}
+
+/// The names matching `name` as a kind, a variant or a feature.
+///
+/// A kind can be given quoted (`"block"`) to select only the unnamed node.
+#[must_use]
+pub fn lookup<'n>(names: &'n [Names], name: &str) -> Vec<&'n Names> {
+    let quoted = name
+        .strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'))
+        .filter(|name| !name.is_empty());
+    names
+        .iter()
+        .filter(|names| match quoted {
+            Some(kind) => !names.named && names.kind == kind,
+            None => names.kind == name || names.variant == name || names.feature == name,
+        })
+        .collect()
+}
+
+fn display_kind(kind: &str, named: bool) -> String {
+    if named {
+        kind.to_string()
+    } else {
+        format!("{kind:?}")
+    }
+}
+
+/// One node kind per line, in `node-types.json` order.
+#[must_use]
+pub fn list(grammar: &Grammar, filter: Filter) -> String {
+    let mut out = String::new();
+    for node_type in grammar.node_types() {
+        if filter.matches(node_type) {
+            let _ = writeln!(
+                out,
+                "{}",
+                display_kind(&node_type.node_type_name, node_type.named)
+            );
+        }
+    }
+    out
+}
+
+/// The supertypes `kind` is a direct subtype of.
+fn supertypes_of<'g>(grammar: &'g Grammar, kind: &str, named: bool) -> Vec<&'g str> {
+    grammar
+        .node_types()
+        .iter()
+        .filter(|node_type| {
+            node_type.subtypes.as_ref().is_some_and(|subtypes| {
+                subtypes
+                    .iter()
+                    .any(|subtype| subtype.subchild_type_name == kind && subtype.named == named)
+            })
+        })
+        .map(|node_type| node_type.node_type_name.as_str())
+        .collect()
+}
+
+fn type_list<'a>(types: impl Iterator<Item = (&'a str, bool)>) -> String {
+    types
+        .map(|(kind, named)| display_kind(kind, named))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+fn cardinality(multiple: bool, required: bool) -> Cardinality {
+    Cardinality { multiple, required }
+}
+
+/// Describe every node type of `grammar` matching `name` in `names` (see [`lookup`]): its names,
+/// the supertypes it belongs to, and its fields, children and subtypes.
+///
+/// Returns `None` if nothing matches.
+#[must_use]
+pub fn show(grammar: &Grammar, names: &[Names], name: &str) -> Option<String> {
+    let matches = lookup(names, name);
+    if matches.is_empty() {
+        return None;
+    }
+    let mut out = String::new();
+    for (i, names) in matches.iter().enumerate() {
+        let Some(node_type) = grammar.get(&names.kind, names.named) else {
+            continue;
+        };
+        if i > 0 {
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "{names} ({})",
+            if names.named { "named" } else { "anonymous" }
+        );
+        let _ = writeln!(out, "  variant: {}", names.variant);
+        let _ = writeln!(out, "  feature: {}", names.feature);
+        let supertypes = supertypes_of(grammar, &names.kind, names.named);
+        if !supertypes.is_empty() {
+            let _ = writeln!(out, "  supertypes: {}", supertypes.join(", "));
+        }
+        if let Some(fields) = node_type
+            .fields
+            .as_ref()
+            .filter(|fields| !fields.is_empty())
+        {
+            let _ = writeln!(out, "  fields:");
+            let sorted: BTreeSet<&String> = fields.keys().collect();
+            for field_name in sorted {
+                let field = &fields[field_name];
+                let types = field
+                    .types
+                    .iter()
+                    .map(|t| (t.field_type_name.as_str(), t.named));
+                let _ = writeln!(
+                    out,
+                    "    {field_name}: {} ({})",
+                    type_list(types),
+                    cardinality(field.multiple, field.required)
+                );
+            }
+        }
+        if let Some(children) = &node_type.children {
+            let types = children
+                .types
+                .iter()
+                .map(|t| (t.child_type_name.as_str(), t.named));
+            let _ = writeln!(
+                out,
+                "  children: {} ({})",
+                type_list(types),
+                cardinality(children.multiple, children.required)
+            );
+        }
+        if let Some(subtypes) = &node_type.subtypes {
+            let types = subtypes
+                .iter()
+                .map(|t| (t.subchild_type_name.as_str(), t.named));
+            let _ = writeln!(out, "  subtypes: {}", type_list(types));
+        }
+    }
+    Some(out)
+}
+
+/// The supertype hierarchy as an indented tree.
+///
+/// Supertypes that are not themselves subtypes of another supertype are the roots, and nested
+/// supertypes (such as `_literal` under `_expression`) are expanded in place.
+#[must_use]
+pub fn supertype_tree(grammar: &Grammar) -> String {
+    let mut out = String::new();
+    for node_type in grammar.node_types() {
+        let kind = &node_type.node_type_name;
+        if node_type.subtypes.is_some() && supertypes_of(grammar, kind, true).is_empty() {
+            write_subtree(grammar, kind, true, 0, &mut out);
+        }
+    }
+    out
+}
+
+fn write_subtree(grammar: &Grammar, kind: &str, named: bool, depth: usize, out: &mut String) {
+    let _ = writeln!(
+        out,
+        "{:indent$}{}",
+        "",
+        display_kind(kind, named),
+        indent = depth * 2
+    );
+    let subtypes = grammar
+        .get(kind, named)
+        .and_then(|node_type| node_type.subtypes.as_ref());
+    for subtype in subtypes.into_iter().flatten() {
+        write_subtree(
+            grammar,
+            &subtype.subchild_type_name,
+            subtype.named,
+            depth + 1,
+            out,
+        );
+    }
+}
+
+/// A tab-separated `kind`, `variant`, `feature` line for every node matching `name` in `names`.
+///
+/// Returns `None` if nothing matches.
+#[must_use]
+pub fn name(names: &[Names], name: &str) -> Option<String> {
+    let matches = lookup(names, name);
+    if matches.is_empty() {
+        return None;
+    }
+    let mut out = String::new();
+    for names in matches {
+        let _ = writeln!(out, "{names}\t{}\t{}", names.variant, names.feature);
+    }
+    Some(out)
+}
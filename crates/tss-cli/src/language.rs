@@ -0,0 +1,139 @@
+//! The grammars `tss` knows about.
+
+use std::fmt;
+use std::str::FromStr;
+use tree_sitter_symbols_rust::names::{DeprecatedKind, KindNames};
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::{
+    feature_name, to_pascal_case, Alias, Names, Override, Overrides,
+};
+
+/// A tree-sitter grammar bundled with `tss`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Language {
+    /// tree-sitter-rust
+    Rust,
+}
+
+impl Language {
+    /// Every supported language.
+    pub const ALL: &'static [Self] = &[Self::Rust];
+
+    /// The language name, as accepted on the command line.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+        }
+    }
+
+    /// The contents of the grammar's `node-types.json`.
+    #[must_use]
+    pub const fn node_types(self) -> &'static str {
+        match self {
+            Self::Rust => tree_sitter_rust::NODE_TYPES,
+        }
+    }
+
+    /// The grammar's node types, indexed for lookups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled `node-types.json` does not match the schema, which would be a bug in
+    /// the grammar crate.
+    #[must_use]
+    pub fn grammar(self) -> Grammar {
+        Grammar::from_json(self.node_types())
+            .unwrap_or_else(|err| panic!("{self} node types should match the schema: {err}"))
+    }
+
+    /// The generated crate's name tables: every node kind's names, and the deprecated variants.
+    const fn tables(self) -> (&'static [KindNames], &'static [DeprecatedKind]) {
+        match self {
+            Self::Rust => (
+                tree_sitter_symbols_rust::NodeType::NAMES,
+                tree_sitter_symbols_rust::NodeType::DEPRECATED,
+            ),
+        }
+    }
+
+    /// Every node kind's variant and feature, as the generated crate names them, in
+    /// `node-types.json` order.
+    #[must_use]
+    pub fn names(self) -> Vec<Names> {
+        self.tables()
+            .0
+            .iter()
+            .map(|names| Names {
+                kind: names.kind.to_string(),
+                named: names.named,
+                variant: names.variant.to_string(),
+                feature: names.feature.to_string(),
+            })
+            .collect()
+    }
+
+    /// The names the generated crate chose instead of the derived ones, keyed like the
+    /// `[package.metadata.tss.naming]` table they came from.
+    #[must_use]
+    pub fn overrides(self) -> Overrides {
+        let mut overrides = Overrides::default();
+        for names in self.tables().0 {
+            let mut variant = to_pascal_case(names.kind);
+            if !names.named {
+                variant.push_str("Token");
+            }
+            let chosen = Override {
+                variant: (names.variant != variant).then(|| names.variant.to_string()),
+                feature: (names.feature != feature_name(names.kind, names.named))
+                    .then(|| names.feature.to_string()),
+            };
+            if chosen != Override::default() {
+                let key = if names.named {
+                    names.kind.to_string()
+                } else {
+                    format!("\"{}\"", names.kind)
+                };
+                overrides.0.insert(key, chosen);
+            }
+        }
+        overrides
+    }
+
+    /// The deprecated variants the generated crate keeps for kinds earlier releases had.
+    #[must_use]
+    pub fn aliases(self) -> Vec<Alias> {
+        let names = self.names();
+        self.tables()
+            .1
+            .iter()
+            .map(|deprecated| Alias {
+                kind: deprecated.kind.to_string(),
+                named: deprecated.named,
+                variant: deprecated.variant.to_string(),
+                replacement: deprecated.replacement.and_then(|variant| {
+                    names.iter().find(|names| names.variant == variant).cloned()
+                }),
+                note: deprecated.note.to_string(),
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|language| language.name() == s)
+            .ok_or_else(|| format!("Unknown language: {s}"))
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
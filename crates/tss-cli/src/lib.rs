@@ -0,0 +1,34 @@
+//! Command line tools for tree-sitter grammars and queries.
+//!
+//! This crate backs the `tss` binary. Its modules are usable as a library too:
+//!
+//! - [`language`] - the grammars `tss` knows about and their node types
+//! - [`check`] - static validation of `.scm` query files against a grammar
+//! - [`diff`] - comparing two versions of a grammar's node types
+//! - [`features`] - the minimal set of `tss-rust` node features a crate uses
+//! - [`inspect`] - listing node types and mapping between kinds, variants and feature names
+//! - [`scip`] - a SCIP code navigation index of a Cargo workspace, from syntax alone
+//!
+//! ```
+//! use tree_sitter_symbols_cli::check::check_source;
+//! use tree_sitter_symbols_cli::language::Language;
+//!
+//! let grammar = Language::Rust.grammar();
+//! let report = check_source(&grammar, "highlights.scm", "(function_item nmae: (identifier))");
+//! assert_eq!(
+//!     report.to_string(),
+//!     "highlights.scm:1:16: `function_item` has no field `nmae`\n"
+//! );
+//! ```
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+pub mod check;
+pub mod diff;
+pub mod features;
+mod files;
+pub mod inspect;
+pub mod language;
+mod protobuf;
+pub mod scip;
@@ -0,0 +1,273 @@
+//! The `tss` command line tool.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use tree_sitter_symbols_cli::check;
+use tree_sitter_symbols_cli::diff;
+use tree_sitter_symbols_cli::features::{self, features_array};
+use tree_sitter_symbols_cli::inspect::{self, Filter};
+use tree_sitter_symbols_cli::language::Language;
+use tree_sitter_symbols_cli::scip;
+use tree_sitter_symbols_schema::diff::{self as schema_diff, Change};
+
+/// Tools for tree-sitter grammars and queries.
+#[derive(Parser)]
+#[command(name = "tss", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Check `.scm` query files against a grammar's node types.
+    Check {
+        /// The grammar the queries target.
+        language: Language,
+        /// Query files, or directories to search for `.scm` files.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Compare two grammar versions, reporting changes in terms of variants and features.
+    Diff {
+        /// The old grammar: a language name or a `node-types.json` path.
+        old: String,
+        /// The new grammar: a language name or a `node-types.json` path.
+        new: String,
+        /// Only report changes that can break existing code, failing if there are any.
+        #[arg(long)]
+        breaking: bool,
+    },
+    /// Print the node features a crate uses, optionally writing them into its `Cargo.toml`.
+    Features {
+        /// The grammar the crate uses.
+        language: Language,
+        /// Rust files, or directories to search for `.rs` files.
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+        /// Rewrite the dependency's features in this `Cargo.toml`.
+        #[arg(long, value_name = "CARGO_TOML")]
+        write: Option<PathBuf>,
+        /// The dependency to rewrite.
+        #[arg(long, default_value = "tss-rust", requires = "write")]
+        dependency: String,
+    },
+    /// List node kinds, quoting anonymous ones.
+    List {
+        /// The grammar to list.
+        language: Language,
+        /// Only list named nodes.
+        #[arg(long, group = "filter")]
+        named: bool,
+        /// Only list anonymous nodes.
+        #[arg(long, group = "filter")]
+        anonymous: bool,
+        /// Only list supertypes.
+        #[arg(long, group = "filter")]
+        supertypes: bool,
+    },
+    /// Show a node's names, fields, children and subtypes.
+    Show {
+        /// The grammar the node belongs to.
+        language: Language,
+        /// A kind (quoted for anonymous nodes, e.g. '"fn"'), variant or feature name.
+        name: String,
+    },
+    /// Print the supertype hierarchy.
+    Supertypes {
+        /// The grammar to inspect.
+        language: Language,
+    },
+    /// Map between kind strings, `NodeType` variants and Cargo feature names.
+    Name {
+        /// The grammar the node belongs to.
+        language: Language,
+        /// A kind (quoted for anonymous nodes, e.g. '"fn"'), variant or feature name.
+        name: String,
+    },
+    /// Write a SCIP index of a Cargo workspace's definitions and references, without compiling it.
+    Scip {
+        /// The workspace root, searched for packages.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+        /// Where to write the index.
+        #[arg(long, short, default_value = "index.scip")]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Check { language, paths } => run_check(language, &paths),
+        Command::Diff { old, new, breaking } => run_diff(&old, &new, breaking),
+        Command::Features {
+            language,
+            paths,
+            write,
+            dependency,
+        } => run_features(language, &paths, write.as_deref(), &dependency),
+        Command::List {
+            language,
+            named,
+            anonymous,
+            supertypes,
+        } => {
+            let filter = if named {
+                Filter::Named
+            } else if anonymous {
+                Filter::Anonymous
+            } else if supertypes {
+                Filter::Supertypes
+            } else {
+                Filter::All
+            };
+            print!("{}", inspect::list(&language.grammar(), filter));
+            ExitCode::SUCCESS
+        }
+        Command::Show { language, name } => found(
+            inspect::show(&language.grammar(), &language.names(), &name),
+            language,
+            &name,
+        ),
+        Command::Supertypes { language } => {
+            print!("{}", inspect::supertype_tree(&language.grammar()));
+            ExitCode::SUCCESS
+        }
+        Command::Name { language, name } => {
+            found(inspect::name(&language.names(), &name), language, &name)
+        }
+        Command::Scip { root, output } => run_scip(&root, &output),
+    }
+}
+
+fn run_scip(root: &Path, output: &Path) -> ExitCode {
+    let index = match scip::index_workspace(root) {
+        Ok(index) => index,
+        Err(err) => {
+            eprintln!("error: {}: {err}", root.display());
+            return ExitCode::from(2);
+        }
+    };
+    if let Err(err) = std::fs::write(output, index.encode()) {
+        eprintln!("error: {}: {err}", output.display());
+        return ExitCode::from(2);
+    }
+    let symbols: usize = index
+        .documents
+        .iter()
+        .map(|document| document.symbols.len())
+        .sum();
+    eprintln!(
+        "wrote {}: {symbols} symbols in {} documents",
+        output.display(),
+        index.documents.len()
+    );
+    ExitCode::SUCCESS
+}
+
+fn run_features(
+    language: Language,
+    paths: &[PathBuf],
+    write: Option<&Path>,
+    dependency: &str,
+) -> ExitCode {
+    let names = language.names();
+    let index = features::Index::new(&names, &language.aliases());
+    let usage = match features::scan_paths(&index, paths) {
+        Ok(usage) => usage,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    for unknown in &usage.unknown {
+        eprintln!("warning: `{unknown}` is not a {language} node kind");
+    }
+    println!("features = {}", features_array(&usage.features));
+    if let Some(manifest) = write {
+        match features::rewrite_manifest(&names, manifest, dependency, &usage.features) {
+            Ok(true) => eprintln!("updated {}", manifest.display()),
+            Ok(false) => eprintln!("{} is up to date", manifest.display()),
+            Err(err) => {
+                eprintln!("error: {}: {err}", manifest.display());
+                return ExitCode::from(2);
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn run_diff(old: &str, new: &str, breaking: bool) -> ExitCode {
+    let overrides = diff::overrides(old, new);
+    let (old, new) = match (diff::load(old), diff::load(new)) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    let changes = match schema_diff::diff(&old, &new, &overrides) {
+        Ok(changes) => changes,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    print!("{}", diff::report(&changes, breaking));
+    // Reporting changes is a success; only `--breaking` fails, and only on breaking changes
+    if breaking && changes.iter().any(Change::is_breaking) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn found(output: Option<String>, language: Language, name: &str) -> ExitCode {
+    if let Some(output) = output {
+        print!("{output}");
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("error: no {language} node kind, variant or feature named `{name}`");
+        ExitCode::FAILURE
+    }
+}
+
+fn run_check(language: Language, paths: &[PathBuf]) -> ExitCode {
+    let files = match check::query_files(paths) {
+        Ok(files) => files,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(2);
+        }
+    };
+    let grammar = language.grammar();
+    let mut problems = 0;
+    for path in &files {
+        match check::check_file(&grammar, path) {
+            Ok(report) => {
+                problems += report.diagnostics.len();
+                print!("{report}");
+            }
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::from(2);
+            }
+        }
+    }
+    if problems == 0 {
+        ExitCode::SUCCESS
+    } else {
+        eprintln!(
+            "{problems} problem{} in {} file{}",
+            if problems == 1 { "" } else { "s" },
+            files.len(),
+            if files.len() == 1 { "" } else { "s" }
+        );
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,87 @@
+//! Just enough protobuf encoding to write SCIP indexes.
+//!
+//! Fields are written in the order they are added. As in proto3, empty strings, zero integers
+//! and empty repeated fields are left out.
+
+/// The wire type of varint fields.
+const VARINT: u64 = 0;
+/// The wire type of strings, nested messages and packed repeated fields.
+const LENGTH_DELIMITED: u64 = 2;
+
+/// An encoded message, built up field by field.
+#[derive(Debug, Default)]
+pub(crate) struct Message(Vec<u8>);
+
+impl Message {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// The encoded bytes.
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    fn key(&mut self, field: u32, wire_type: u64) {
+        self.varint((u64::from(field) << 3) | wire_type);
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            // Truncation keeps the low seven bits, which is the point
+            #[allow(clippy::cast_possible_truncation)]
+            self.0.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        self.0.push(value as u8);
+    }
+
+    fn bytes(&mut self, field: u32, bytes: &[u8]) {
+        self.key(field, LENGTH_DELIMITED);
+        self.varint(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+    }
+
+    /// A `string` field.
+    pub(crate) fn string(&mut self, field: u32, value: &str) {
+        if !value.is_empty() {
+            self.bytes(field, value.as_bytes());
+        }
+    }
+
+    /// A `repeated string` field.
+    pub(crate) fn strings(&mut self, field: u32, values: &[String]) {
+        for value in values {
+            self.bytes(field, value.as_bytes());
+        }
+    }
+
+    /// An `int32` or enum field.
+    pub(crate) fn int32(&mut self, field: u32, value: i32) {
+        if value != 0 {
+            self.key(field, VARINT);
+            // Negative values are sign-extended to ten bytes
+            #[allow(clippy::cast_sign_loss)]
+            self.varint(i64::from(value) as u64);
+        }
+    }
+
+    /// A packed `repeated int32` field.
+    pub(crate) fn packed_int32(&mut self, field: u32, values: &[i32]) {
+        if values.is_empty() {
+            return;
+        }
+        let mut packed = Self::new();
+        for &value in values {
+            #[allow(clippy::cast_sign_loss)]
+            packed.varint(i64::from(value) as u64);
+        }
+        self.bytes(field, &packed.0);
+    }
+
+    /// A nested message field, written even when empty.
+    pub(crate) fn message(&mut self, field: u32, message: &Self) {
+        self.bytes(field, &message.0);
+    }
+}
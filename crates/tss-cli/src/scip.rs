@@ -0,0 +1,780 @@
+//! Writing a SCIP index of a Cargo workspace from syntax alone.
+//!
+//! [`index_workspace`] reads the workspace members from the root `Cargo.toml`, parses the `.rs`
+//! files in their `src` directories with tree-sitter-rust and collects their definitions and
+//! references with `tss-rust`'s [`symbols`]. Nothing is compiled or type-checked, so a workspace that
+//! rust-analyzer cannot load can still be indexed.
+//!
+//! Each definition gets a global SCIP symbol such as
+//! `tss cargo shapes 0.1.0 shapes/geometry/Circle#area().`: the package, then the crate,
+//! which for a binary such as `src/main.rs` or `src/bin/report.rs` is named after the binary,
+//! the module path from the file location and any inline `mod` items, the enclosing type, trait
+//! or function, and the definition itself. Methods in an `impl` block are placed under the
+//! `impl`'s type without its generic arguments or path, so `fmt` in
+//! `impl fmt::Display for Wrap<T>` is `Wrap#fmt().`. The trait is only a relationship: a method
+//! of a trait `impl` implements the trait's method of the same name, when both are found in the
+//! workspace. A call, macro invocation or `impl` block refers to a definition by name when
+//! only one definition of that name could be meant: one in the same file is preferred, then one
+//! in the same package, then one anywhere in the workspace. Ambiguous and unknown names are left
+//! out rather than guessed.
+//!
+//! [`Index::encode`] writes the protobuf bytes of an `index.scip` file.
+//!
+//! [`symbols`]: tree_sitter_symbols_rust::symbols::symbols
+
+use crate::protobuf::Message;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use tree_sitter_symbols_rust::symbols::{symbols, Role, Symbol};
+use tree_sitter_symbols_rust::NodeType;
+
+/// The scheme of every symbol in the index, naming the indexer.
+const SCHEME: &str = "tss";
+/// SCIP's `SymbolRole.Definition` bit.
+const DEFINITION: i32 = 1;
+/// SCIP's `TextEncoding.UTF8` and `PositionEncoding.UTF8CodeUnitOffsetFromLineStart`, matching
+/// tree-sitter's byte columns.
+const UTF8: i32 = 1;
+
+/// A package of the workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    /// The package name from `[package]`.
+    pub name: String,
+    /// The package version, or `.` if it has none.
+    pub version: String,
+    /// The library and binary crates of the package, library first.
+    pub crates: Vec<Crate>,
+    /// The directory holding the package's `Cargo.toml`.
+    pub dir: PathBuf,
+}
+
+/// A library or binary crate of a package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crate {
+    /// The crate name: `[lib] name` or the package name for the library, and the binary's name
+    /// for a binary, with `-` replaced by `_`.
+    pub name: String,
+    /// The crate root, such as `src/lib.rs` or `src/bin/report.rs`.
+    pub root: PathBuf,
+    /// Whether the crate is a binary.
+    pub bin: bool,
+}
+
+/// A SCIP index: one document per source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Index {
+    /// The `file://` URI of the workspace root.
+    pub project_root: String,
+    /// The indexed files, in path order.
+    pub documents: Vec<Document>,
+}
+
+/// The occurrences and definitions in one source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    /// The file's path relative to the workspace root, with `/` separators.
+    pub relative_path: String,
+    /// Every definition and resolved reference, in source order.
+    pub occurrences: Vec<Occurrence>,
+    /// The symbols defined in this file.
+    pub symbols: Vec<SymbolInformation>,
+}
+
+/// A name in a source file that defines or refers to a symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    /// The name's start line, start column, end line and end column, zero-based with columns in
+    /// bytes.
+    pub range: [usize; 4],
+    /// The symbol defined or referred to.
+    pub symbol: String,
+    /// Whether the name is a definition.
+    pub definition: bool,
+    /// For definitions, the range of the whole item.
+    pub enclosing_range: Option<[usize; 4]>,
+}
+
+/// A symbol defined in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolInformation {
+    /// The global symbol.
+    pub symbol: String,
+    /// The name as written.
+    pub display_name: String,
+    /// Markdown documentation: the signature of functions.
+    pub documentation: Vec<String>,
+    /// For methods of a trait `impl`, the trait method implemented.
+    pub implements: Option<String>,
+}
+
+impl Index {
+    /// The index as the protobuf bytes of an `index.scip` file.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut tool_info = Message::new();
+        tool_info.string(1, "tss");
+        tool_info.string(2, env!("CARGO_PKG_VERSION"));
+        let mut metadata = Message::new();
+        metadata.message(2, &tool_info);
+        metadata.string(3, &self.project_root);
+        metadata.int32(4, UTF8);
+
+        let mut index = Message::new();
+        index.message(1, &metadata);
+        for document in &self.documents {
+            index.message(2, &document.encode());
+        }
+        index.into_bytes()
+    }
+}
+
+impl Document {
+    fn encode(&self) -> Message {
+        let mut document = Message::new();
+        document.string(1, &self.relative_path);
+        for occurrence in &self.occurrences {
+            let mut message = Message::new();
+            message.packed_int32(1, &scip_range(occurrence.range));
+            message.string(2, &occurrence.symbol);
+            message.int32(3, if occurrence.definition { DEFINITION } else { 0 });
+            if let Some(range) = occurrence.enclosing_range {
+                message.packed_int32(7, &scip_range(range));
+            }
+            document.message(2, &message);
+        }
+        for symbol in &self.symbols {
+            let mut message = Message::new();
+            message.string(1, &symbol.symbol);
+            message.strings(3, &symbol.documentation);
+            if let Some(implemented) = &symbol.implements {
+                let mut relationship = Message::new();
+                relationship.string(1, implemented);
+                relationship.int32(3, 1);
+                message.message(4, &relationship);
+            }
+            message.string(6, &symbol.display_name);
+            document.message(3, &message);
+        }
+        document.string(4, "rust");
+        document.int32(6, UTF8);
+        document
+    }
+}
+
+/// A range as SCIP stores it, with three elements when it starts and ends on the same line.
+fn scip_range([start_line, start_column, end_line, end_column]: [usize; 4]) -> Vec<i32> {
+    let int = |n: usize| i32::try_from(n).unwrap_or(i32::MAX);
+    if start_line == end_line {
+        vec![int(start_line), int(start_column), int(end_column)]
+    } else {
+        vec![
+            int(start_line),
+            int(start_column),
+            int(end_line),
+            int(end_column),
+        ]
+    }
+}
+
+/// The packages of the workspace at `root`, in path order: the root package, if any, and the
+/// `[workspace] members` not matched by `exclude`. Members may use `*` and `?` wildcards.
+///
+/// # Errors
+///
+/// Returns an error if a directory or manifest cannot be read, or a manifest is not valid TOML.
+pub fn packages(root: &Path) -> io::Result<Vec<Package>> {
+    let manifest = read_manifest(&root.join("Cargo.toml"))?;
+    let workspace = manifest.get("workspace").and_then(toml::Value::as_table);
+    let workspace_version = workspace
+        .and_then(|workspace| workspace.get("package")?.get("version")?.as_str())
+        .map(str::to_string);
+
+    let mut dirs = Vec::new();
+    if manifest.contains_key("package") {
+        dirs.push(root.to_path_buf());
+    }
+    if let Some(workspace) = workspace {
+        let exclude: Vec<PathBuf> = strings(workspace, "exclude")
+            .map(|path| root.join(path))
+            .collect();
+        for member in strings(workspace, "members") {
+            for dir in expand(root, member)? {
+                if dir.join("Cargo.toml").is_file()
+                    && !exclude.iter().any(|excluded| dir.starts_with(excluded))
+                    && !dirs.contains(&dir)
+                {
+                    dirs.push(dir);
+                }
+            }
+        }
+    }
+    dirs.sort();
+
+    let mut packages = Vec::new();
+    for dir in dirs {
+        if let Some(package) = package(dir, workspace_version.as_deref())? {
+            packages.push(package);
+        }
+    }
+    Ok(packages)
+}
+
+/// The package whose manifest is in `dir`, if it has a `[package]` table with a name.
+fn package(dir: PathBuf, workspace_version: Option<&str>) -> io::Result<Option<Package>> {
+    let manifest = read_manifest(&dir.join("Cargo.toml"))?;
+    let Some(package) = manifest.get("package").and_then(toml::Value::as_table) else {
+        return Ok(None);
+    };
+    let Some(name) = package.get("name").and_then(toml::Value::as_str) else {
+        return Ok(None);
+    };
+    let version = match package.get("version") {
+        Some(toml::Value::String(version)) => Some(version.as_str()),
+        Some(version) if version.get("workspace") == Some(&toml::Value::Boolean(true)) => {
+            workspace_version
+        }
+        _ => None,
+    };
+
+    let mut crates = Vec::new();
+    let lib = manifest.get("lib");
+    let lib_root = lib
+        .and_then(|lib| lib.get("path")?.as_str())
+        .map_or_else(|| dir.join("src/lib.rs"), |path| dir.join(path));
+    if lib_root.is_file() {
+        let lib_name = lib
+            .and_then(|lib| lib.get("name")?.as_str())
+            .unwrap_or(name);
+        crates.push(Crate {
+            name: lib_name.replace('-', "_"),
+            root: lib_root,
+            bin: false,
+        });
+    }
+    let bins = manifest
+        .get("bin")
+        .and_then(toml::Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    for bin in bins {
+        let Some(bin_name) = bin.get("name").and_then(toml::Value::as_str) else {
+            continue;
+        };
+        let mut candidates = vec![
+            dir.join(format!("src/bin/{bin_name}.rs")),
+            dir.join(format!("src/bin/{bin_name}/main.rs")),
+        ];
+        if bin_name == name {
+            candidates.push(dir.join("src/main.rs"));
+        }
+        if let Some(path) = bin.get("path").and_then(toml::Value::as_str) {
+            candidates = vec![dir.join(path)];
+        }
+        let Some(root) = candidates.into_iter().find(|root| root.is_file()) else {
+            continue;
+        };
+        crates.push(Crate {
+            name: bin_name.replace('-', "_"),
+            root,
+            bin: true,
+        });
+    }
+    if package.get("autobins") != Some(&toml::Value::Boolean(false)) {
+        for (bin_name, root) in discovered_bins(&dir, name)? {
+            let bin_name = bin_name.replace('-', "_");
+            if !crates
+                .iter()
+                .any(|known| known.root == root || (known.bin && known.name == bin_name))
+            {
+                crates.push(Crate {
+                    name: bin_name,
+                    root,
+                    bin: true,
+                });
+            }
+        }
+    }
+
+    Ok(Some(Package {
+        name: name.to_string(),
+        version: version.unwrap_or(".").to_string(),
+        crates,
+        dir,
+    }))
+}
+
+/// The binaries Cargo finds without a `[[bin]]` table: `src/main.rs`, named after the package,
+/// and `src/bin/*.rs` and `src/bin/*/main.rs`, named after the file or directory.
+fn discovered_bins(dir: &Path, name: &str) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut bins = Vec::new();
+    let main = dir.join("src/main.rs");
+    if main.is_file() {
+        bins.push((name.to_string(), main));
+    }
+    let bin_dir = dir.join("src/bin");
+    if bin_dir.is_dir() {
+        let mut entries = fs::read_dir(&bin_dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        for path in entries {
+            let root = if path.is_dir() {
+                path.join("main.rs")
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                path.clone()
+            } else {
+                continue;
+            };
+            let stem = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned());
+            if let (true, Some(stem)) = (root.is_file(), stem) {
+                bins.push((stem, root));
+            }
+        }
+    }
+    Ok(bins)
+}
+
+/// A parsed `Cargo.toml`.
+fn read_manifest(path: &Path) -> io::Result<toml::Table> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+    content.parse().map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {err}", path.display()),
+        )
+    })
+}
+
+/// The strings in the array `key` of `table`.
+fn strings<'t>(table: &'t toml::Table, key: &str) -> impl Iterator<Item = &'t str> {
+    table
+        .get(key)
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_str)
+}
+
+/// The directories under `root` matching a member `pattern` such as `crates/*`, in path order.
+fn expand(root: &Path, pattern: &str) -> io::Result<Vec<PathBuf>> {
+    let mut dirs = vec![root.to_path_buf()];
+    for part in pattern
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+    {
+        if !part.contains(['*', '?']) {
+            for dir in &mut dirs {
+                dir.push(part);
+            }
+            continue;
+        }
+        let mut matched = Vec::new();
+        for dir in dirs.iter().filter(|dir| dir.is_dir()) {
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                let name = path.file_name().and_then(|name| name.to_str());
+                if path.is_dir() && name.is_some_and(|name| wildcard_match(part, name)) {
+                    matched.push(path);
+                }
+            }
+        }
+        matched.sort();
+        dirs = matched;
+    }
+    Ok(dirs)
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any run of characters and `?` any one.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let mut chars = pattern.chars();
+    match chars.next() {
+        None => name.is_empty(),
+        Some('*') => name
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([name.len()])
+            .any(|i| wildcard_match(chars.as_str(), &name[i..])),
+        Some(expected) => {
+            let mut rest = name.chars();
+            rest.next()
+                .is_some_and(|c| expected == '?' || expected == c)
+                && wildcard_match(chars.as_str(), rest.as_str())
+        }
+    }
+}
+
+/// The crate of `package` that a source file belongs to, and the modules the file defines. A
+/// file belongs to the crate it is the root of, else to the crate whose root is in the nearest
+/// directory above it, preferring the library.
+fn crate_of<'p>(package: &'p Package, path: &Path) -> Option<(&'p Crate, Vec<String>)> {
+    if let Some(root) = package.crates.iter().find(|krate| krate.root == path) {
+        return Some((root, Vec::new()));
+    }
+    package
+        .crates
+        .iter()
+        .filter_map(|krate| Some((krate, path.strip_prefix(krate.root.parent()?).ok()?)))
+        .min_by_key(|(krate, relative)| (relative.components().count(), krate.bin))
+        .map(|(krate, relative)| (krate, module_path(relative)))
+}
+
+/// A parsed source file.
+struct Source {
+    package: usize,
+    relative_path: String,
+    /// The package, crate and module the file defines, as a symbol prefix such as
+    /// `tss cargo shapes 0.1.0 shapes/geometry/`.
+    module: String,
+    symbols: Vec<Symbol>,
+    /// The global symbols of the definitions, by symbol index.
+    descriptors: Vec<Option<String>>,
+}
+
+/// What a name refers to, for matching references with definitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Category {
+    Function,
+    Macro,
+    Type,
+}
+
+fn category(kind: NodeType) -> Option<Category> {
+    match kind {
+        NodeType::FunctionItem | NodeType::FunctionSignatureItem | NodeType::CallExpression => {
+            Some(Category::Function)
+        }
+        NodeType::MacroDefinition | NodeType::MacroInvocation => Some(Category::Macro),
+        NodeType::EnumItem
+        | NodeType::ImplItem
+        | NodeType::StructItem
+        | NodeType::TraitItem
+        | NodeType::TypeItem
+        | NodeType::UnionItem => Some(Category::Type),
+        _ => None,
+    }
+}
+
+/// Definitions by name and category, with the package and file they are in.
+type Definitions = HashMap<(String, Category), Vec<(usize, usize, String)>>;
+
+/// The one definition `file` in `package` can mean by a name: the only one in the same file,
+/// else in the same package, else in the workspace.
+fn resolve(candidates: &[(usize, usize, String)], package: usize, file: usize) -> Option<&str> {
+    let only = |filter: &dyn Fn(&(usize, usize, String)) -> bool| {
+        let mut matching = candidates.iter().filter(|candidate| filter(candidate));
+        match (matching.next(), matching.next()) {
+            (Some((_, _, symbol)), None) => Some(symbol.as_str()),
+            _ => None,
+        }
+    };
+    only(&|(_, f, _)| *f == file)
+        .or_else(|| only(&|(p, _, _)| *p == package))
+        .or_else(|| only(&|_| true))
+}
+
+/// Index the packages under `root`, as described in the [module docs](self).
+///
+/// # Errors
+///
+/// Returns an error if the root, a directory or a file cannot be read, or if tree-sitter cannot
+/// load the tree-sitter-rust language or its tags query.
+pub fn index_workspace(root: &Path) -> io::Result<Index> {
+    let root = fs::canonicalize(root)?;
+    let packages = packages(&root)?;
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .map_err(io::Error::other)?;
+
+    let mut sources = Vec::new();
+    for (package_index, package) in packages.iter().enumerate() {
+        let src = package.dir.join("src");
+        let mut paths = if src.is_dir() {
+            crate::files::collect(&[src], "rs")?
+        } else {
+            Vec::new()
+        };
+        paths.extend(package.crates.iter().map(|krate| krate.root.clone()));
+        paths.sort();
+        paths.dedup();
+        for path in paths {
+            let Some((krate, modules)) = crate_of(package, &path) else {
+                continue;
+            };
+            let text = fs::read(&path)?;
+            let Some(tree) = parser.parse(&text, None) else {
+                continue;
+            };
+            let mut module = format!(
+                "{SCHEME} cargo {} {} {}/",
+                escape_package(&package.name),
+                escape_package(&package.version),
+                escape(&krate.name)
+            );
+            for part in modules {
+                module.push_str(&escape(&part));
+                module.push('/');
+            }
+            let symbols = symbols(&tree, &text).map_err(io::Error::other)?;
+            sources.push(Source {
+                package: package_index,
+                relative_path: slash_path(path.strip_prefix(&root).unwrap_or(&path)),
+                module,
+                descriptors: vec![None; symbols.len()],
+                symbols,
+            });
+        }
+    }
+
+    // Types first, so that the methods of an `impl` can be placed under the type it implements
+    let no_types = Definitions::new();
+    for source in &mut sources {
+        source.descriptors = descriptors(source, &no_types, usize::MAX);
+    }
+    let types = definitions(&sources, Some(Category::Type));
+    for (file, source) in sources.iter_mut().enumerate() {
+        source.descriptors = descriptors(source, &types, file);
+    }
+    let definitions = definitions(&sources, None);
+
+    let documents = sources
+        .iter()
+        .enumerate()
+        .map(|(file, source)| document(source, file, &definitions))
+        .collect();
+    Ok(Index {
+        project_root: file_url(&root),
+        documents,
+    })
+}
+
+/// The definitions in `sources` by name, optionally of one category only.
+fn definitions(sources: &[Source], only: Option<Category>) -> Definitions {
+    let mut definitions = Definitions::new();
+    for (file, source) in sources.iter().enumerate() {
+        for (symbol, descriptor) in source.symbols.iter().zip(&source.descriptors) {
+            let (Some(descriptor), Some(category)) = (descriptor, category(symbol.kind)) else {
+                continue;
+            };
+            if only.is_some_and(|only| only != category) {
+                continue;
+            }
+            definitions
+                .entry((symbol.name.clone(), category))
+                .or_default()
+                .push((source.package, file, descriptor.clone()));
+        }
+    }
+    definitions
+}
+
+/// The descriptors of the definitions in `source`, placing `impl` members under the types in
+/// `types` where one can be resolved.
+fn descriptors(source: &Source, types: &Definitions, file: usize) -> Vec<Option<String>> {
+    let mut descriptors: Vec<Option<String>> = Vec::with_capacity(source.symbols.len());
+    for symbol in &source.symbols {
+        if symbol.role != Role::Definition {
+            descriptors.push(None);
+            continue;
+        }
+        // Parents come before their children, so their descriptors are known
+        let scope = match symbol.parent {
+            None => source.module.clone(),
+            Some(parent) => {
+                let parent_symbol = &source.symbols[parent];
+                if parent_symbol.kind == NodeType::ImplItem {
+                    let key = (parent_symbol.name.clone(), Category::Type);
+                    types
+                        .get(&key)
+                        .and_then(|candidates| resolve(candidates, source.package, file))
+                        .map_or_else(
+                            || {
+                                let outer = parent_symbol
+                                    .parent
+                                    .and_then(|outer| descriptors[outer].clone())
+                                    .unwrap_or_else(|| source.module.clone());
+                                format!("{outer}{}#", escape(&parent_symbol.name))
+                            },
+                            str::to_string,
+                        )
+                } else {
+                    descriptors[parent]
+                        .clone()
+                        .unwrap_or_else(|| source.module.clone())
+                }
+            }
+        };
+        let name = escape(&symbol.name);
+        let descriptor = match symbol.kind {
+            NodeType::ModItem => format!("{scope}{name}/"),
+            NodeType::EnumItem
+            | NodeType::StructItem
+            | NodeType::TraitItem
+            | NodeType::TypeItem
+            | NodeType::UnionItem => format!("{scope}{name}#"),
+            NodeType::FunctionItem | NodeType::FunctionSignatureItem => {
+                format!("{scope}{name}().")
+            }
+            NodeType::MacroDefinition => format!("{scope}{name}!"),
+            _ => format!("{scope}{name}."),
+        };
+        descriptors.push(Some(descriptor));
+    }
+    descriptors
+}
+
+fn document(source: &Source, file: usize, definitions: &Definitions) -> Document {
+    let mut occurrences = Vec::new();
+    let mut symbols = Vec::new();
+    for (symbol, descriptor) in source.symbols.iter().zip(&source.descriptors) {
+        if let Some(descriptor) = descriptor {
+            occurrences.push(Occurrence {
+                range: range(symbol.name_range),
+                symbol: descriptor.clone(),
+                definition: true,
+                enclosing_range: Some(range(symbol.range)),
+            });
+            let documentation = symbol
+                .signature
+                .iter()
+                .map(|signature| format!("```rust\nfn {}{signature}\n```", symbol.name))
+                .collect();
+            let implements = symbol
+                .parent
+                .map(|parent| &source.symbols[parent])
+                .filter(|_| symbol.kind == NodeType::FunctionItem)
+                .filter(|parent| parent.kind == NodeType::ImplItem)
+                .and_then(|parent| parent.implements.clone())
+                .and_then(|name| definitions.get(&(name, Category::Type)))
+                .and_then(|candidates| resolve(candidates, source.package, file))
+                .map(|implemented| format!("{implemented}{}().", escape(&symbol.name)))
+                .filter(|implemented| {
+                    definitions
+                        .get(&(symbol.name.clone(), Category::Function))
+                        .is_some_and(|candidates| {
+                            candidates
+                                .iter()
+                                .any(|(_, _, symbol)| symbol == implemented)
+                        })
+                });
+            symbols.push(SymbolInformation {
+                symbol: descriptor.clone(),
+                display_name: symbol.name.clone(),
+                documentation,
+                implements,
+            });
+            continue;
+        }
+        let Some(category) = category(symbol.kind) else {
+            continue;
+        };
+        let resolved = definitions
+            .get(&(symbol.name.clone(), category))
+            .and_then(|candidates| resolve(candidates, source.package, file));
+        if let Some(descriptor) = resolved {
+            occurrences.push(Occurrence {
+                range: range(symbol.name_range),
+                symbol: descriptor.to_string(),
+                definition: false,
+                enclosing_range: None,
+            });
+        }
+    }
+    occurrences.sort_by_key(|occurrence| occurrence.range);
+    Document {
+        relative_path: source.relative_path.clone(),
+        occurrences,
+        symbols,
+    }
+}
+
+fn range(range: tree_sitter::Range) -> [usize; 4] {
+    [
+        range.start_point.row,
+        range.start_point.column,
+        range.end_point.row,
+        range.end_point.column,
+    ]
+}
+
+/// The modules a file defines, from its path relative to the crate root's directory: `a/mod.rs`
+/// and `a.rs` are module `a`, and `a/b.rs` is module `a::b`.
+fn module_path(relative: &Path) -> Vec<String> {
+    let mut parts: Vec<String> = relative
+        .with_extension("")
+        .iter()
+        .map(|part| part.to_string_lossy().into_owned())
+        .collect();
+    if parts.last().is_some_and(|last| last == "mod") {
+        parts.pop();
+    }
+    parts
+}
+
+/// `path` with `/` between its parts, as SCIP paths use on every platform.
+///
+/// A Windows prefix keeps its own form with `/` for `\`, so `C:\src` becomes `C:/src` and
+/// `\\server\share\src` becomes `//server/share/src`.
+fn slash_path(path: &Path) -> String {
+    let mut slashed = String::new();
+    let mut separate = false;
+    for component in path.components() {
+        let part = match component {
+            Component::Prefix(prefix) => {
+                slashed.push_str(&prefix.as_os_str().to_string_lossy().replace('\\', "/"));
+                continue;
+            }
+            Component::RootDir => {
+                slashed.push('/');
+                separate = false;
+                continue;
+            }
+            Component::CurDir => ".".into(),
+            Component::ParentDir => "..".into(),
+            Component::Normal(part) => part.to_string_lossy(),
+        };
+        if separate {
+            slashed.push('/');
+        }
+        slashed.push_str(&part);
+        separate = true;
+    }
+    slashed
+}
+
+/// A `file://` URL for the absolute `path`, with a third `/` before a Windows drive.
+fn file_url(path: &Path) -> String {
+    let path = slash_path(path);
+    if path.starts_with('/') {
+        format!("file://{path}")
+    } else {
+        format!("file:///{path}")
+    }
+}
+
+/// A descriptor name, in backticks unless it is a plain identifier.
+fn escape(name: &str) -> String {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-' | '$'))
+    {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+/// A package name or version, with spaces doubled and empty values as `.`.
+fn escape_package(value: &str) -> String {
+    if value.is_empty() {
+        ".".to_string()
+    } else {
+        value.replace(' ', "  ")
+    }
+}
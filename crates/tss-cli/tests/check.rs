@@ -0,0 +1,71 @@
+//! Integration tests for `tss check`.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tree_sitter_symbols_cli::check::{check_file, query_files};
+use tree_sitter_symbols_cli::language::Language;
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+#[test]
+fn finds_scm_files_in_directories() {
+    let files = query_files(&[fixtures()]).unwrap();
+    assert_eq!(
+        files,
+        [
+            fixtures().join("invalid/highlights.scm"),
+            fixtures().join("valid.scm")
+        ]
+    );
+    assert!(query_files(&[fixtures().join("missing.scm")]).is_err());
+}
+
+#[test]
+fn reports_lines_and_columns() {
+    let grammar = Language::Rust.grammar();
+    assert!(check_file(&grammar, &fixtures().join("valid.scm"))
+        .unwrap()
+        .is_clean());
+
+    let report = check_file(&grammar, &fixtures().join("invalid/highlights.scm")).unwrap();
+    let lines: Vec<String> = report
+        .diagnostics
+        .iter()
+        .map(|(location, diagnostic)| format!("{location}: {}", diagnostic.message))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "3:3: `function_item` has no field `nmae`",
+            "5:14: `identifier` cannot have `block` as a child",
+            "7:14: `type_identifier` is not a subtype of `_expression`",
+        ]
+    );
+}
+
+#[test]
+fn exit_code_reflects_problems() {
+    let tss = env!("CARGO_BIN_EXE_tss");
+    let clean = Command::new(tss)
+        .args(["check", "rust"])
+        .arg(fixtures().join("valid.scm"))
+        .output()
+        .unwrap();
+    assert!(clean.status.success());
+    assert!(clean.stdout.is_empty());
+
+    let dirty = Command::new(tss)
+        .args(["check", "rust"])
+        .arg(fixtures())
+        .output()
+        .unwrap();
+    assert_eq!(dirty.status.code(), Some(1));
+    let stdout = String::from_utf8(dirty.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout.contains("highlights.scm:3:3: `function_item` has no field `nmae`"));
+}
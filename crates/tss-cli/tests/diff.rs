@@ -0,0 +1,118 @@
+//! Integration tests for `tss diff`.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tree_sitter_symbols_cli::diff::{load, overrides, report, LoadError};
+use tree_sitter_symbols_schema::diff::diff;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/diff")
+        .join(name)
+}
+
+fn load_fixture(name: &str) -> tree_sitter_symbols_schema::grammar::Grammar {
+    load(fixture(name).to_str().unwrap()).unwrap()
+}
+
+#[test]
+fn reports_changes_and_counts() {
+    let changes = diff(
+        &load_fixture("old.json"),
+        &load_fixture("new.json"),
+        &overrides("old.json", "new.json"),
+    )
+    .unwrap();
+    let full = report(&changes, false);
+    assert_eq!(full.lines().count(), changes.len() + 1);
+    assert!(full.ends_with("11 changes (5 breaking)\n"));
+
+    let breaking = report(&changes, true);
+    assert!(!breaking.contains("+ node"));
+    assert!(
+        breaking.contains("- node yield_expression (YieldExpression, feature yield_expression)")
+    );
+    assert!(breaking.ends_with("11 changes (5 breaking)\n"));
+
+    let unchanged = diff(
+        &load_fixture("old.json"),
+        &load_fixture("old.json"),
+        &overrides("old.json", "old.json"),
+    )
+    .unwrap();
+    assert_eq!(report(&unchanged, true), "no changes\n");
+}
+
+#[test]
+fn load_reports_missing_and_invalid_files() {
+    assert!(matches!(
+        load(fixture("missing.json").to_str().unwrap()),
+        Err(LoadError::Io(..))
+    ));
+    let Err(invalid) = load(fixture("../valid.scm").to_str().unwrap()) else {
+        panic!("a query file is not a grammar");
+    };
+    assert!(matches!(invalid, LoadError::Schema(..)));
+    assert!(invalid.to_string().contains("not a node-types.json file"));
+}
+
+#[test]
+fn diff_command_only_fails_on_breaking_changes() {
+    let tss = env!("CARGO_BIN_EXE_tss");
+    let same = Command::new(tss)
+        .args(["diff", "rust", "rust"])
+        .output()
+        .unwrap();
+    assert!(same.status.success());
+    assert_eq!(String::from_utf8(same.stdout).unwrap(), "no changes\n");
+
+    let changed = Command::new(tss)
+        .arg("diff")
+        .arg(fixture("old.json"))
+        .arg(fixture("new.json"))
+        .output()
+        .unwrap();
+    assert!(changed.status.success());
+    let stdout = String::from_utf8(changed.stdout).unwrap();
+    assert!(stdout.ends_with("11 changes (5 breaking)\n"));
+
+    // Adding a node breaks nothing, even with `--breaking`
+    let old = std::fs::read_to_string(fixture("old.json")).unwrap();
+    let grown = std::env::temp_dir().join(format!("tss-cli-diff-{}.json", std::process::id()));
+    let node = r#"{"type": "gen_block", "named": true},"#;
+    std::fs::write(&grown, old.replacen('[', &format!("[{node}"), 1)).unwrap();
+    let added = Command::new(tss)
+        .arg("diff")
+        .arg(fixture("old.json"))
+        .arg(&grown)
+        .arg("--breaking")
+        .output()
+        .unwrap();
+    std::fs::remove_file(grown).unwrap();
+    assert!(added.status.success());
+    assert_eq!(
+        String::from_utf8(added.stdout).unwrap(),
+        "no breaking changes\n"
+    );
+
+    let changed = Command::new(tss)
+        .arg("diff")
+        .arg(fixture("old.json"))
+        .arg(fixture("new.json"))
+        .arg("--breaking")
+        .output()
+        .unwrap();
+    assert_eq!(changed.status.code(), Some(1));
+    let stdout = String::from_utf8(changed.stdout).unwrap();
+    assert!(stdout.contains("~ field function_item.body (FunctionItem): optional -> required"));
+
+    let unreadable = Command::new(tss)
+        .args(["diff", "rust"])
+        .arg(fixture("missing.json"))
+        .output()
+        .unwrap();
+    assert_eq!(unreadable.status.code(), Some(2));
+}
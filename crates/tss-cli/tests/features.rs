@@ -0,0 +1,211 @@
+//! Integration tests for the minimal feature set scanner.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter_symbols_cli::features::{rewrite_manifest, scan_paths, Index, RewriteError, Usage};
+use tree_sitter_symbols_cli::language::Language;
+use tree_sitter_symbols_schema::naming::{resolve_aliases, Deprecation, Deprecations};
+
+fn index() -> Index {
+    Index::new(&Language::Rust.names(), &Language::Rust.aliases())
+}
+
+fn set(items: &[&str]) -> BTreeSet<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+/// Write `content` to a fresh manifest, rewrite it and return the result.
+fn rewrite(name: &str, content: &str, features: &[&str]) -> Result<String, RewriteError> {
+    let dir = std::env::temp_dir().join(format!("tss-cli-features-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{name}.toml"));
+    fs::write(&path, content).unwrap();
+    rewrite_manifest(&Language::Rust.names(), &path, "tss-rust", &set(features))?;
+    Ok(fs::read_to_string(&path).unwrap())
+}
+
+#[test]
+fn scans_variants_and_from_str_literals() {
+    let fixture: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/features");
+    let usage = scan_paths(&index(), &[fixture]).unwrap();
+    assert_eq!(
+        usage.features,
+        set(&["block", "closure_expression", "function_item"])
+    );
+    assert_eq!(usage.unknown, set(&["\"fuction_item\""]));
+}
+
+#[test]
+fn maps_tokens_and_ignores_lookalikes() {
+    let mut usage = Usage::default();
+    usage.scan_source(
+        &index(),
+        "let _ = (NodeTypeRust::PathSepToken, MyNodeType::Block, NodeType::Nope);",
+    );
+    assert_eq!(usage.features, set(&["path_sep_token"]));
+    assert_eq!(usage.unknown, set(&["Nope"]));
+}
+
+#[test]
+fn only_reads_literals_parsed_as_node_types() {
+    let mut usage = Usage::default();
+    usage.scan_source(
+        &index(),
+        r#"
+        let a = NodeType::from_str( "block" );
+        let b = "closure_expression".parse::<NodeTypeRust>();
+        let c = "fuction_item".parse::< NodeType >();
+        let d = Language::from_str("rust");
+        let e = "42".parse::<u8>();
+        let f = u8::from_str("7");
+        "#,
+    );
+    assert_eq!(usage.features, set(&["block", "closure_expression"]));
+    assert_eq!(usage.unknown, set(&["\"fuction_item\""]));
+}
+
+#[test]
+fn skips_constants_and_maps_aliases_to_replacements() {
+    let mut usage = Usage::default();
+    usage.scan_source(
+        &index(),
+        "for kind in NodeType::ALL { println!(\"{}\", NodeType::GRAMMAR_VERSION); }",
+    );
+    assert_eq!(usage, Usage::default());
+
+    let names = Language::Rust.names();
+    let deprecations = Deprecations(
+        [(
+            "macro_call".to_string(),
+            Deprecation {
+                replacement: Some("macro_invocation".to_string()),
+                ..Deprecation::default()
+            },
+        )]
+        .into_iter()
+        .collect(),
+    );
+    let grammar = Language::Rust.grammar();
+    let aliases = resolve_aliases(grammar.node_types(), &names, &deprecations).unwrap();
+    usage.scan_source(
+        &Index::new(&names, &aliases),
+        r#"(NodeType::MacroCall, NodeType::from_str("macro_call"))"#,
+    );
+    assert_eq!(usage.features, set(&["macro_invocation"]));
+    assert!(usage.unknown.is_empty());
+}
+
+#[test]
+fn rewrites_version_strings_and_inline_tables() {
+    assert_eq!(
+        rewrite(
+            "version",
+            "[dependencies]\ntss-rust = \"0.2\"\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies]\ntss-rust = { version = \"0.2\", features = [\"block\"] }\n"
+    );
+    // Node features are replaced, other features and keys are kept
+    assert_eq!(
+        rewrite(
+            "inline",
+            "[dependencies]\ntss-rust = { version = \"0.2\", default-features = false, features = [\"identifier\", \"meta_fields\"] }\nserde = \"1\"\n",
+            &["block", "function_item"]
+        )
+        .unwrap(),
+        "[dependencies]\ntss-rust = { version = \"0.2\", default-features = false, features = [\"block\", \"function_item\", \"meta_fields\"] }\nserde = \"1\"\n"
+    );
+}
+
+#[test]
+fn keeps_comments_and_reads_quoted_keys() {
+    assert_eq!(
+        rewrite(
+            "comment",
+            "[dependencies]\ntss-rust = \"0.2\" # pinned\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies]\ntss-rust = { version = \"0.2\", features = [\"block\"] } # pinned\n"
+    );
+    assert_eq!(
+        rewrite(
+            "comment-inline",
+            "[dependencies]\ntss-rust = { version = \"0.2\", features = [] } # see #12\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies]\ntss-rust = { version = \"0.2\", features = [\"block\"] } # see #12\n"
+    );
+    assert_eq!(
+        rewrite(
+            "quoted",
+            "[dependencies]\n\"tss-rust\" = \"0.2\"\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies]\n\"tss-rust\" = { version = \"0.2\", features = [\"block\"] }\n"
+    );
+    assert_eq!(
+        rewrite(
+            "quoted-table",
+            "[dependencies.\"tss-rust\"]\nfeatures = [\"identifier\"] # trimmed\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies.\"tss-rust\"]\nfeatures = [\"block\"] # trimmed\n"
+    );
+}
+
+#[test]
+fn rewrites_dependency_tables() {
+    assert_eq!(
+        rewrite(
+            "table",
+            "[dependencies.tss-rust]\nversion = \"0.2\"\nfeatures = [\"identifier\"]\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies.tss-rust]\nversion = \"0.2\"\nfeatures = [\"block\"]\n"
+    );
+    assert_eq!(
+        rewrite(
+            "table-new",
+            "[dependencies.tss-rust]\nversion = \"0.2\"\n",
+            &["block"]
+        )
+        .unwrap(),
+        "[dependencies.tss-rust]\nfeatures = [\"block\"]\nversion = \"0.2\"\n"
+    );
+}
+
+#[test]
+fn reports_missing_and_unsupported_dependencies() {
+    assert!(matches!(
+        rewrite("missing", "[dependencies]\nserde = \"1\"\n", &[]),
+        Err(RewriteError::MissingDependency(_))
+    ));
+    // Only dependency sections are searched
+    assert!(matches!(
+        rewrite("feature", "[features]\ntss-rust = []\n", &[]),
+        Err(RewriteError::MissingDependency(_))
+    ));
+    assert!(matches!(
+        rewrite(
+            "multiline",
+            "[dependencies]\ntss-rust = {\n  version = \"0.2\"\n}\n",
+            &[]
+        ),
+        Err(RewriteError::Unsupported(_))
+    ));
+    // Dotted keys are valid TOML but not a form this tool rewrites
+    assert!(matches!(
+        rewrite("dotted", "[dependencies]\ntss-rust.workspace = true\n", &[]),
+        Err(RewriteError::Unsupported(_))
+    ));
+}
@@ -0,0 +1,14 @@
+use std::str::FromStr;
+use tss_rust::NodeType;
+
+pub fn is_function(kind: NodeType) -> bool {
+    matches!(kind, NodeType::FunctionItem | NodeType::ClosureExpression)
+}
+
+pub fn parse_block() -> Option<NodeType> {
+    NodeType::from_str("block").ok()
+}
+
+pub fn typo() -> Option<NodeType> {
+    NodeType::from_str("fuction_item").ok()
+}
@@ -0,0 +1,7 @@
+; Misspelt field, impossible child and an unrelated subtype
+(function_item
+  nmae: (identifier) @name)
+
+(identifier (block))
+
+(_expression/type_identifier) @type
@@ -0,0 +1,5 @@
+fn main() {
+    let circle = shape_kit::geometry::Circle { radius: 2.0 };
+    let area = circle.area();
+    println!("{area}");
+}
@@ -0,0 +1,11 @@
+mod util {
+    pub fn new() -> f64 {
+        1.0
+    }
+}
+
+fn main() {
+    let circle = shape_kit::geometry::Circle { radius: util::new() };
+    let area = circle.area();
+    println!("{area}");
+}
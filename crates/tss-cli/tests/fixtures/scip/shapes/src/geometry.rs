@@ -0,0 +1,9 @@
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn area(&self) -> f64 {
+        square!(self.radius) * 3.14
+    }
+}
@@ -0,0 +1,9 @@
+pub mod geometry;
+pub mod wrap;
+
+#[macro_export]
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+use crate::geometry::Circle;
+
+pub trait Describe {
+    fn describe(&self) -> String {
+        String::from("a shape")
+    }
+}
+
+pub struct Wrap<T>(pub T);
+
+impl<T> Wrap<T> {
+    pub fn new(value: T) -> Self {
+        Wrap(value)
+    }
+}
+
+impl Describe for Circle {
+    fn describe(&self) -> String {
+        format!("circle of radius {}", self.radius)
+    }
+}
+
+impl fmt::Display for Wrap<Circle> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("wrapped circle")
+    }
+}
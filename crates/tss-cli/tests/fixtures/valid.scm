@@ -0,0 +1,6 @@
+; Function names
+(function_item
+  name: (identifier) @name) @definition.function
+
+(binary_expression
+  operator: ["+" "-"] @operator)
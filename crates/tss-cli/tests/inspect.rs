@@ -0,0 +1,68 @@
+//! Integration tests for the `tss` inspection commands.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_cli::inspect::{list, lookup, name, show, supertype_tree, Filter};
+use tree_sitter_symbols_cli::language::Language;
+
+#[test]
+fn lists_node_kinds_by_filter() {
+    let grammar = Language::Rust.grammar();
+    let all = list(&grammar, Filter::All);
+    let named = list(&grammar, Filter::Named);
+    let anonymous = list(&grammar, Filter::Anonymous);
+    assert_eq!(
+        all.lines().count(),
+        named.lines().count() + anonymous.lines().count()
+    );
+    assert!(named.lines().any(|line| line == "function_item"));
+    assert!(anonymous.lines().any(|line| line == "\"fn\""));
+    assert!(!named.contains('"'));
+    assert_eq!(
+        list(&grammar, Filter::Supertypes),
+        "_declaration_statement\n_expression\n_literal\n_literal_pattern\n_pattern\n_type\n"
+    );
+}
+
+#[test]
+fn shows_fields_children_and_supertypes() {
+    let grammar = Language::Rust.grammar();
+    let names = Language::Rust.names();
+    let shown = show(&grammar, &names, "function_item").unwrap();
+    assert!(shown.starts_with("function_item (named)\n  variant: FunctionItem\n"));
+    assert!(shown.contains("  supertypes: _declaration_statement\n"));
+    assert!(shown.contains("    name: identifier | metavariable (required)\n"));
+    assert!(shown.contains("    return_type: _type (optional)\n"));
+
+    let shown = show(&grammar, &names, "_literal").unwrap();
+    assert!(shown.contains("  subtypes: boolean_literal | char_literal"));
+    assert!(show(&grammar, &names, "function_itme").is_none());
+}
+
+#[test]
+fn prints_nested_supertypes() {
+    let tree = supertype_tree(&Language::Rust.grammar());
+    // `_literal` is a subtype of `_expression`, so it is nested rather than a root
+    assert!(tree.contains("\n  _literal\n    boolean_literal\n"));
+    assert!(!tree.lines().any(|line| line == "_literal"));
+    assert!(tree.lines().any(|line| line == "_type"));
+}
+
+#[test]
+fn maps_between_kinds_variants_and_features() {
+    let names = Language::Rust.names();
+    for query in ["::", "PathSepToken", "path_sep_token"] {
+        assert_eq!(
+            name(&names, query).unwrap(),
+            "\"::\"\tPathSepToken\tpath_sep_token\n"
+        );
+    }
+    // `block` is both a named node and an anonymous token
+    assert_eq!(lookup(&names, "block").len(), 2);
+    assert_eq!(
+        name(&names, "\"block\"").unwrap(),
+        "\"block\"\tBlockToken\tblock_token\n"
+    );
+    assert!(name(&names, "nope").is_none());
+}
@@ -0,0 +1,30 @@
+//! Integration tests for the names of the bundled languages.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_cli::language::Language;
+use tree_sitter_symbols_schema::naming::resolve_names;
+
+#[test]
+fn overrides_resolve_to_the_generated_names() {
+    for &language in Language::ALL {
+        let resolved = resolve_names(language.grammar().node_types(), &language.overrides());
+        assert_eq!(resolved.unwrap(), language.names(), "{language}");
+    }
+}
+
+#[test]
+fn names_follow_node_types_order() {
+    let names = Language::Rust.names();
+    let grammar = Language::Rust.grammar();
+    assert_eq!(names.len(), grammar.node_types().len());
+    let block = names
+        .iter()
+        .find(|names| names.kind == "block" && !names.named)
+        .unwrap();
+    assert_eq!(
+        (block.variant.as_str(), block.feature.as_str()),
+        ("BlockToken", "block_token")
+    );
+}
@@ -0,0 +1,350 @@
+//! Integration tests for the syntactic SCIP indexer.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use std::path::{Path, PathBuf};
+use tree_sitter_symbols_cli::scip::{
+    index_workspace, packages, Crate, Document, Index, Occurrence, Package, SymbolInformation,
+};
+
+fn fixture() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/scip")
+}
+
+fn document<'a>(index: &'a Index, path: &str) -> &'a Document {
+    index
+        .documents
+        .iter()
+        .find(|document| document.relative_path == path)
+        .unwrap()
+}
+
+/// The symbols and roles of a document's occurrences, in order.
+fn occurrences(document: &Document) -> Vec<(&str, bool)> {
+    document
+        .occurrences
+        .iter()
+        .map(|occurrence| (occurrence.symbol.as_str(), occurrence.definition))
+        .collect()
+}
+
+#[test]
+fn finds_packages() {
+    let found = packages(&fixture()).unwrap();
+    let names: Vec<_> = found
+        .iter()
+        .map(|package: &Package| {
+            (
+                package.name.as_str(),
+                package.version.as_str(),
+                package
+                    .crates
+                    .iter()
+                    .map(|krate: &Crate| (krate.name.as_str(), krate.bin))
+                    .collect(),
+            )
+        })
+        .collect();
+    // `scratch` is excluded, `app` has two binaries, and `shapes` takes its version from the
+    // workspace and renames its library
+    assert_eq!(
+        names,
+        [
+            ("app", "0.2.0", vec![("app", true), ("report", true)]),
+            ("shapes", "0.1.0", vec![("shape_kit", false)]),
+        ]
+    );
+    assert_eq!(
+        found[0].crates[1].root,
+        fixture().join("app/src/bin/report.rs")
+    );
+}
+
+#[test]
+fn reads_bin_tables_and_member_paths() {
+    let dir = std::env::temp_dir().join(format!("tss-scip-bins-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("tools/cli/src/bin")).unwrap();
+    std::fs::write(
+        dir.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"tools/cli\"]\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("tools/cli/Cargo.toml"),
+        "[package]\nname = \"my-cli\"\nautobins = false\n\n\
+         [[bin]]\nname = \"run-it\"\npath = \"src/main.rs\"\n",
+    )
+    .unwrap();
+    for file in ["lib.rs", "main.rs", "bin/skipped.rs"] {
+        std::fs::write(dir.join("tools/cli/src").join(file), "fn f() {}\n").unwrap();
+    }
+
+    let found = packages(&dir).unwrap();
+    let crates: Vec<(&str, bool)> = found[0]
+        .crates
+        .iter()
+        .map(|krate| (krate.name.as_str(), krate.bin))
+        .collect();
+    assert_eq!(found[0].version, ".");
+    assert_eq!(crates, [("my_cli", false), ("run_it", true)]);
+
+    let index = index_workspace(&dir).unwrap();
+    if cfg!(unix) {
+        let root = std::fs::canonicalize(&dir).unwrap();
+        assert_eq!(index.project_root, format!("file://{}", root.display()));
+    }
+    let paths: Vec<&str> = index
+        .documents
+        .iter()
+        .map(|document| document.relative_path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "tools/cli/src/bin/skipped.rs",
+            "tools/cli/src/lib.rs",
+            "tools/cli/src/main.rs",
+        ]
+    );
+    let symbols: Vec<&str> = index
+        .documents
+        .iter()
+        .flat_map(|document| &document.symbols)
+        .map(|symbol| symbol.symbol.as_str())
+        .collect();
+    // `src/bin/skipped.rs` belongs to no crate once `autobins` is off, so it is in the library
+    assert_eq!(
+        symbols,
+        [
+            "tss cargo my-cli . my_cli/bin/skipped/f().",
+            "tss cargo my-cli . my_cli/f().",
+            "tss cargo my-cli . run_it/f().",
+        ]
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn escapes_non_ascii_names() {
+    let dir = std::env::temp_dir().join(format!("tss-scip-escape-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"menu\"\n").unwrap();
+    std::fs::write(dir.join("src/lib.rs"), "fn café() {}\nfn tea_2() {}\n").unwrap();
+
+    let index = index_workspace(&dir).unwrap();
+    let symbols: Vec<&str> = index.documents[0]
+        .symbols
+        .iter()
+        .map(|symbol| symbol.symbol.as_str())
+        .collect();
+    // SCIP simple identifiers are ASCII letters and digits plus `_+-$`
+    assert_eq!(
+        symbols,
+        [
+            "tss cargo menu . menu/`café`().",
+            "tss cargo menu . menu/tea_2().",
+        ]
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn definitions_and_references() {
+    let index = index_workspace(&fixture()).unwrap();
+    let paths: Vec<&str> = index
+        .documents
+        .iter()
+        .map(|document| document.relative_path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "app/src/bin/report.rs",
+            "app/src/main.rs",
+            "shapes/src/geometry.rs",
+            "shapes/src/lib.rs",
+            "shapes/src/wrap.rs"
+        ]
+    );
+    assert!(index.project_root.starts_with("file:///"));
+
+    let geometry = document(&index, "shapes/src/geometry.rs");
+    assert_eq!(
+        occurrences(geometry),
+        [
+            ("tss cargo shapes 0.1.0 shape_kit/geometry/Circle#", true),
+            ("tss cargo shapes 0.1.0 shape_kit/geometry/Circle#", false),
+            (
+                "tss cargo shapes 0.1.0 shape_kit/geometry/Circle#area().",
+                true
+            ),
+            ("tss cargo shapes 0.1.0 shape_kit/square!", false),
+        ]
+    );
+    assert_eq!(
+        geometry.occurrences[2],
+        Occurrence {
+            range: [5, 11, 5, 15],
+            symbol: "tss cargo shapes 0.1.0 shape_kit/geometry/Circle#area().".to_string(),
+            definition: true,
+            enclosing_range: Some([5, 4, 7, 5]),
+        }
+    );
+    assert_eq!(
+        geometry.symbols[1].documentation,
+        ["```rust\nfn area(&self) -> f64\n```"]
+    );
+
+    // `new` is found in the same file, `area` in another package, and `println!` nowhere
+    let main = document(&index, "app/src/main.rs");
+    assert_eq!(
+        occurrences(main),
+        [
+            ("tss cargo app 0.2.0 app/util/", true),
+            ("tss cargo app 0.2.0 app/util/new().", true),
+            ("tss cargo app 0.2.0 app/main().", true),
+            ("tss cargo app 0.2.0 app/util/new().", false),
+            (
+                "tss cargo shapes 0.1.0 shape_kit/geometry/Circle#area().",
+                false
+            ),
+        ]
+    );
+
+    // The second binary is its own crate
+    let report = document(&index, "app/src/bin/report.rs");
+    assert_eq!(
+        occurrences(report),
+        [
+            ("tss cargo app 0.2.0 report/main().", true),
+            (
+                "tss cargo shapes 0.1.0 shape_kit/geometry/Circle#area().",
+                false
+            ),
+        ]
+    );
+
+    let lib = document(&index, "shapes/src/lib.rs");
+    assert_eq!(
+        lib.symbols
+            .iter()
+            .map(|symbol| symbol.display_name.as_str())
+            .collect::<Vec<_>>(),
+        ["geometry", "wrap", "square"]
+    );
+}
+
+#[test]
+fn methods_sit_under_the_impl_type() {
+    let index = index_workspace(&fixture()).unwrap();
+    let wrap = document(&index, "shapes/src/wrap.rs");
+    assert_eq!(
+        occurrences(wrap),
+        [
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Describe#", true),
+            (
+                "tss cargo shapes 0.1.0 shape_kit/wrap/Describe#describe().",
+                true
+            ),
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Wrap#", true),
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Wrap#", false),
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Wrap#new().", true),
+            ("tss cargo shapes 0.1.0 shape_kit/geometry/Circle#", false),
+            (
+                "tss cargo shapes 0.1.0 shape_kit/geometry/Circle#describe().",
+                true
+            ),
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Wrap#", false),
+            ("tss cargo shapes 0.1.0 shape_kit/wrap/Wrap#fmt().", true),
+        ]
+    );
+
+    // `Display` is not defined in the workspace, so `fmt` implements nothing known
+    let implements: Vec<(&str, Option<&str>)> = wrap
+        .symbols
+        .iter()
+        .map(|symbol| (symbol.display_name.as_str(), symbol.implements.as_deref()))
+        .collect();
+    assert_eq!(
+        implements,
+        [
+            ("Describe", None),
+            ("describe", None),
+            ("Wrap", None),
+            ("new", None),
+            (
+                "describe",
+                Some("tss cargo shapes 0.1.0 shape_kit/wrap/Describe#describe().")
+            ),
+            ("fmt", None),
+        ]
+    );
+}
+
+#[test]
+fn encodes_protobuf() {
+    let index = Index {
+        project_root: "file:///w".to_string(),
+        documents: vec![Document {
+            relative_path: "a.rs".to_string(),
+            occurrences: vec![Occurrence {
+                range: [0, 3, 0, 4],
+                symbol: "s".to_string(),
+                definition: true,
+                enclosing_range: None,
+            }],
+            symbols: Vec::new(),
+        }],
+    };
+    let bytes = index.encode();
+    // Metadata comes first, with the project root and UTF-8 text
+    assert_eq!(bytes[0], 0x0a);
+    assert!(bytes
+        .windows(b"file:///w".len())
+        .any(|window| window == b"file:///w"));
+    #[rustfmt::skip]
+    let document: &[u8] = &[
+        0x12, 26, // documents
+        0x0a, 4, b'a', b'.', b'r', b's', // relative_path
+        0x12, 10, // occurrences
+        0x0a, 3, 0, 3, 4, // range, on one line
+        0x12, 1, b's', // symbol
+        0x18, 1, // symbol_roles: Definition
+        0x22, 4, b'r', b'u', b's', b't', // language
+        0x30, 1, // position_encoding: UTF-8 offsets
+    ];
+    assert!(bytes.ends_with(document));
+}
+
+#[test]
+fn encodes_implementation_relationships() {
+    let index = Index {
+        project_root: "file:///w".to_string(),
+        documents: vec![Document {
+            relative_path: "a.rs".to_string(),
+            occurrences: Vec::new(),
+            symbols: vec![SymbolInformation {
+                symbol: "m".to_string(),
+                display_name: "m".to_string(),
+                documentation: Vec::new(),
+                implements: Some("t".to_string()),
+            }],
+        }],
+    };
+    #[rustfmt::skip]
+    let symbol: &[u8] = &[
+        0x1a, 13, // symbols
+        0x0a, 1, b'm', // symbol
+        0x22, 5, // relationships
+        0x0a, 1, b't', // symbol
+        0x18, 1, // is_implementation
+        0x32, 1, b'm', // display_name
+    ];
+    assert!(index
+        .encode()
+        .windows(symbol.len())
+        .any(|window| window == symbol));
+}
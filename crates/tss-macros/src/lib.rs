@@ -0,0 +1,270 @@
+//! Compile-time checked tree-sitter queries for tree-sitter-rust.
+//!
+//! The [`query!`] macro parses tree-sitter query source while your crate compiles and checks
+//! every node kind, field name and `!field` negation against tree-sitter-rust's `NODE_TYPES`.
+//! Typos become compile errors pointing at the query instead of runtime query errors.
+//!
+//! ```
+//! use tree_sitter_symbols_macros::query;
+//!
+//! query! {
+//!     /// Function definitions and their names.
+//!     pub struct FunctionNames("(function_item name: (identifier) @name) @definition.function");
+//! }
+//!
+//! assert_eq!(FunctionNames::CAPTURE_NAMES, ["name", "definition.function"]);
+//! assert_eq!(FunctionNamesCapture::Name.index(), 0);
+//! assert_eq!(
+//!     FunctionNamesCapture::from_index(1),
+//!     Some(FunctionNamesCapture::DefinitionFunction)
+//! );
+//! ```
+//!
+//! A misspelt kind fails to compile:
+//!
+//! ```compile_fail
+//! use tree_sitter_symbols_macros::query;
+//!
+//! query! {
+//!     struct Broken("(function_itme name: (identifier) @name)");
+//! }
+//! ```
+//!
+//! So does a capture with no letters or digits to name its variant after:
+//!
+//! ```compile_fail
+//! use tree_sitter_symbols_macros::query;
+//!
+//! query! {
+//!     struct Unnamed("(identifier) @_");
+//! }
+//! ```
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, parse_macro_input, Attribute, Ident, LitStr, Token, Visibility};
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::{query, validate};
+
+/// Define a struct holding a tree-sitter query checked against tree-sitter-rust at compile time.
+///
+/// ```text
+/// query! {
+///     /// Optional docs and attributes
+///     pub struct Name("(query source) @capture");
+/// }
+/// ```
+///
+/// This expands to:
+///
+/// - `struct Name` with `Name::SOURCE` (the query source) and `Name::CAPTURE_NAMES` (capture names
+///   ordered by their tree-sitter capture index)
+/// - `enum NameCapture` with one variant per capture (`@definition.function` becomes
+///   `DefinitionFunction`, and `@self` becomes `Self_`, since `Self` is a keyword) and `index()`,
+///   `from_index()` and `name()` to convert to and from the `u32` indices tree-sitter reports in
+///   matches
+///
+/// Unknown node kinds, anonymous nodes, fields, negated fields, supertypes and predicate capture
+/// references are reported as compile errors, as are nodes that cannot appear under their parent.
+#[proc_macro]
+pub fn query(input: TokenStream) -> TokenStream {
+    let def = parse_macro_input!(input as QueryDef);
+    expand(&def)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct QueryDef {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    source: LitStr,
+}
+
+impl Parse for QueryDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let source = content.parse()?;
+        input.parse::<Option<Token![;]>>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            source,
+        })
+    }
+}
+
+fn grammar() -> &'static Grammar {
+    static GRAMMAR: OnceLock<Grammar> = OnceLock::new();
+    GRAMMAR.get_or_init(|| {
+        Grammar::from_json(tree_sitter_rust::NODE_TYPES)
+            .expect("tree-sitter-rust NODE_TYPES should match the node types schema")
+    })
+}
+
+/// Point at a byte range of the query inside the string literal, where the compiler allows it.
+///
+/// Sub-spans are only available on nightly, and only line up when the literal has no escapes,
+/// so this falls back to the span of the whole literal.
+fn span_of(lit: &LitStr, span: query::Span) -> Span {
+    let repr = lit.token().to_string();
+    let (Some(open), Some(close)) = (repr.find('"'), repr.rfind('"')) else {
+        return lit.span();
+    };
+    if repr.get(open + 1..close) != Some(lit.value().as_str()) {
+        return lit.span();
+    }
+    let start = open + 1 + span.start;
+    let end = open + 1 + span.end.max(span.start + 1).min(close - open - 1);
+    lit.token()
+        .subspan(start..end)
+        .unwrap_or_else(|| lit.span())
+}
+
+/// `definition.function` -> `DefinitionFunction`, or `None` if the name has no letters or digits.
+///
+/// A name that becomes a keyword, `@self` -> `Self`, gets a trailing underscore: `Self_`.
+fn capture_variant(name: &str) -> Option<String> {
+    let mut variant: String = name
+        .split(['.', '-', '_'])
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect();
+    if variant.is_empty() {
+        return None;
+    }
+    if variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert(0, '_');
+    }
+    if syn::parse_str::<Ident>(&variant).is_err() {
+        variant.push('_');
+    }
+    Some(variant)
+}
+
+fn expand(def: &QueryDef) -> syn::Result<proc_macro2::TokenStream> {
+    let source = def.source.value();
+    let patterns = query::parse(&source)
+        .map_err(|err| syn::Error::new(span_of(&def.source, err.span), err.message))?;
+
+    let mut errors = validate::validate(grammar(), &patterns)
+        .into_iter()
+        .map(|diagnostic| {
+            syn::Error::new(span_of(&def.source, diagnostic.span), diagnostic.message)
+        });
+    if let Some(mut first) = errors.next() {
+        for err in errors {
+            first.combine(err);
+        }
+        return Err(first);
+    }
+
+    // tree-sitter numbers captures by first appearance in the source
+    let mut capture_names: Vec<String> = Vec::new();
+    let mut capture_spans = Vec::new();
+    let mut all_captures: Vec<&query::Ident> = patterns
+        .iter()
+        .flat_map(query::Pattern::all_captures)
+        .collect();
+    all_captures.sort_by_key(|capture| capture.span.start);
+    for capture in all_captures {
+        if !capture_names.contains(&capture.name) {
+            capture_names.push(capture.name.clone());
+            capture_spans.push(capture.span);
+        }
+    }
+
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    let mut variants = Vec::new();
+    for (name, span) in capture_names.iter().zip(capture_spans) {
+        let Some(variant) = capture_variant(name) else {
+            return Err(syn::Error::new(
+                span_of(&def.source, span),
+                format!("capture `@{name}` has no letters or digits to name its variant after"),
+            ));
+        };
+        if let Some(other) = seen.insert(variant.clone(), name) {
+            return Err(syn::Error::new(
+                def.source.span(),
+                format!("captures `@{other}` and `@{name}` both map to the variant `{variant}`"),
+            ));
+        }
+        variants.push(format_ident!("{variant}"));
+    }
+
+    let QueryDef {
+        attrs, vis, name, ..
+    } = def;
+    let capture_enum = format_ident!("{name}Capture");
+    let enum_doc = format!("Captures of [`{name}`], in tree-sitter capture index order.");
+    let variant_docs = capture_names.iter().map(|name| format!("`@{name}`"));
+    let indices: Vec<u32> = (0..).take(capture_names.len()).collect();
+    let source_lit = &def.source;
+
+    Ok(quote! {
+        #(#attrs)*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis struct #name;
+
+        impl #name {
+            /// The query source, checked against tree-sitter-rust's node types.
+            pub const SOURCE: &'static str = #source_lit;
+            /// The capture names, indexed by tree-sitter capture index.
+            pub const CAPTURE_NAMES: &'static [&'static str] = &[#(#capture_names),*];
+        }
+
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #capture_enum {
+            #(
+                #[doc = #variant_docs]
+                #variants,
+            )*
+        }
+
+        impl #capture_enum {
+            /// The tree-sitter capture index.
+            #[must_use]
+            pub const fn index(self) -> u32 {
+                match self {
+                    #(Self::#variants => #indices,)*
+                }
+            }
+
+            /// The capture with the given tree-sitter capture index.
+            #[must_use]
+            pub const fn from_index(index: u32) -> Option<Self> {
+                match index {
+                    #(#indices => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+
+            /// The capture name, without the leading `@`.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(Self::#variants => #capture_names,)*
+                }
+            }
+        }
+    })
+}
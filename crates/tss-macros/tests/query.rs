@@ -0,0 +1,74 @@
+//! Integration tests for the `query!` macro.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_macros::query;
+
+query! {
+    /// Function definitions, as in tree-sitter-rust's tags query.
+    pub struct FunctionNames("(function_item name: (identifier) @name) @definition.function");
+}
+
+query! {
+    struct Impls(r"
+        ; inherent impls only
+        (impl_item
+            type: (type_identifier) @name
+            !trait) @reference.implementation
+    ");
+}
+
+query! {
+    struct Constants("((identifier) @constant (#match? @constant \"^[A-Z][A-Z\\\\d_]+$\"))");
+}
+
+query! {
+    struct Receivers("(self_parameter (self) @self) @crate");
+}
+
+#[test]
+fn source_is_kept_verbatim() {
+    assert!(FunctionNames::SOURCE.starts_with("(function_item"));
+    assert!(Impls::SOURCE.contains("!trait"));
+}
+
+#[test]
+fn captures_are_numbered_in_source_order() {
+    assert_eq!(
+        FunctionNames::CAPTURE_NAMES,
+        ["name", "definition.function"]
+    );
+    assert_eq!(FunctionNamesCapture::Name.index(), 0);
+    assert_eq!(FunctionNamesCapture::DefinitionFunction.index(), 1);
+    assert_eq!(
+        FunctionNamesCapture::from_index(1),
+        Some(FunctionNamesCapture::DefinitionFunction)
+    );
+    assert_eq!(FunctionNamesCapture::from_index(2), None);
+    assert_eq!(
+        ImplsCapture::ReferenceImplementation.name(),
+        "reference.implementation"
+    );
+    assert_eq!(ConstantsCapture::Constant.index(), 0);
+}
+
+#[test]
+fn keyword_captures_get_an_underscore() {
+    assert_eq!(ReceiversCapture::Self_.name(), "self");
+    assert_eq!(ReceiversCapture::Crate.name(), "crate");
+}
+
+#[test]
+fn capture_indices_match_tree_sitter() {
+    let language = tree_sitter_rust::LANGUAGE.into();
+    for (source, names) in [
+        (FunctionNames::SOURCE, FunctionNames::CAPTURE_NAMES),
+        (Impls::SOURCE, Impls::CAPTURE_NAMES),
+        (Constants::SOURCE, Constants::CAPTURE_NAMES),
+        (Receivers::SOURCE, Receivers::CAPTURE_NAMES),
+    ] {
+        let query = tree_sitter::Query::new(&language, source).unwrap();
+        assert_eq!(query.capture_names(), names);
+    }
+}
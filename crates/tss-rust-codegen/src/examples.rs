@@ -0,0 +1,344 @@
+use super::meta::write_match;
+use super::schema::NodeType;
+use std::io::{self, Write};
+
+/// A minimal Rust source file that contains a node of the given kind.
+///
+/// tree-sitter-rust accepts expression statements at the top level, so expression examples are
+/// written without an enclosing function. Supertypes such as `_expression` never appear in a
+/// tree themselves, so their examples contain one of their subtypes.
+#[allow(clippy::too_many_lines)]
+pub fn example(kind: &str, named: bool) -> Option<&'static str> {
+    if !named {
+        return token_example(kind);
+    }
+    Some(match kind {
+        "source_file" => "fn main() {}",
+        "shebang" => "#!/usr/bin/env run-cargo-script\nfn main() {}",
+
+        // Items
+        "_declaration_statement" | "function_item" => "fn add(a: i32, b: i32) -> i32 { a + b }",
+        "function_signature_item" => "trait Shape { fn area(&self) -> f64; }",
+        "function_modifiers" => "const unsafe fn f() {}",
+        "extern_modifier" => "extern \"C\" fn f() {}",
+        "parameters" | "parameter" => "fn f(x: i32) {}",
+        "self_parameter" => "impl S { fn get(&self) {} }",
+        "variadic_parameter" => "extern \"C\" { fn printf(format: *const u8, ...); }",
+        "struct_item" | "field_declaration_list" | "field_declaration" => {
+            "struct Point { x: i32, y: i32 }"
+        }
+        "ordered_field_declaration_list" => "struct Pair(i32, i32);",
+        "enum_item" | "enum_variant_list" | "enum_variant" => "enum Color { Red, Green }",
+        "union_item" => "union Bits { i: u32, f: f32 }",
+        "impl_item" | "declaration_list" => "impl Point { fn new() {} }",
+        "trait_item" => "trait Shape { fn area(&self) -> f64; }",
+        "associated_type" => "trait Iterator { type Item; }",
+        "type_item" => "type Id = u64;",
+        "const_item" => "const MAX: u32 = 10;",
+        "static_item" => "static NAME: &str = \"x\";",
+        "mod_item" => "mod tests {}",
+        "foreign_mod_item" => "extern \"C\" { fn abs(x: i32) -> i32; }",
+        "extern_crate_declaration" => "extern crate alloc;",
+        "use_declaration" => "use std::fmt;",
+        "use_list" | "scoped_use_list" => "use std::{fmt, io};",
+        "use_as_clause" => "use std::io::Result as IoResult;",
+        "use_wildcard" => "use std::io::*;",
+
+        // Macros
+        "macro_invocation" | "token_tree" => "println!(\"hi\");",
+        "token_repetition" => "macro_rules! m { ($($e:expr),*) => { $($e;)* }; }",
+        "macro_definition" | "macro_rule" | "token_tree_pattern" => {
+            "macro_rules! id { ($e:expr) => { $e }; }"
+        }
+        "token_binding_pattern" | "fragment_specifier" | "metavariable" => {
+            "macro_rules! id { ($e:expr) => { $e }; }"
+        }
+        "token_repetition_pattern" => "macro_rules! all { ($($e:expr),*) => {}; }",
+
+        // Attributes and visibility
+        "attribute_item" | "attribute" => "#[derive(Debug)]\nstruct S;",
+        "inner_attribute_item" => "#![no_std]",
+        "visibility_modifier" => "pub(crate) fn f() {}",
+
+        // Generics and bounds
+        "type_parameters" | "type_parameter" => "fn id<T>(x: T) -> T { x }",
+        "lifetime_parameter" | "lifetime" => "fn first<'a>(x: &'a str) -> &'a str { x }",
+        "const_parameter" => "struct Buf<const N: usize>;",
+        "where_clause" | "where_predicate" => "fn f<T>(x: T) where T: Clone {}",
+        "trait_bounds" => "fn f<T: Clone + Send>(x: T) {}",
+        "bounded_type" => "fn f(x: Box<dyn Send + 'static>) {}",
+        "removed_trait_bound" => "fn f<T: ?Sized>(x: &T) {}",
+        "higher_ranked_trait_bound" => "fn f<F>(f: F) where F: for<'a> Fn(&'a str) {}",
+        "for_lifetimes" => "let f: for<'a> fn(&'a u8) = g;",
+        "use_bounds" => "fn f<'a>(x: &'a str) -> impl Sized + use<'a> { x }",
+        "label" => "'outer: loop { break 'outer; }",
+
+        // Paths and identifiers
+        "scoped_identifier" => "std::mem::swap(a, b);",
+        "generic_function" => "parse::<i32>(s);",
+        "type_arguments" | "generic_type" => "let v: Vec<i32> = x;",
+        "type_binding" => "fn f(x: impl Iterator<Item = u8>) {}",
+        "scoped_type_identifier" => "let x: std::io::Error = e;",
+        "generic_type_with_turbofish" => "Foo::<u8> { x: 1 };",
+        "qualified_type" | "bracketed_type" => "<T as Default>::default();",
+        "crate" => "use crate::Node;",
+        "self" => "use self::Node;",
+        "super" => "use super::Node;",
+        "identifier" => "let x = 1;",
+        "type_identifier" => "struct Point;",
+        "field_identifier" => "p.x;",
+        "shorthand_field_identifier" => "let Point { x } = p;",
+
+        // Types
+        "_type" | "primitive_type" => "let x: u8 = 1;",
+        "array_type" => "let a: [u8; 4] = b;",
+        "tuple_type" => "let t: (i32, bool) = u;",
+        "unit_type" => "fn f() -> () {}",
+        "reference_type" => "fn f(x: &mut i32) {}",
+        "pointer_type" => "let p: *const u8 = q;",
+        "function_type" => "let f: fn(i32) -> i32 = g;",
+        "never_type" => "fn exit() -> ! { loop {} }",
+        "dynamic_type" => "fn f(x: &dyn Display) {}",
+        "abstract_type" => "fn f() -> impl Display { 1 }",
+        "mutable_specifier" => "let mut x = 1;",
+
+        // Statements
+        "let_declaration" => "let x = 1;",
+        "expression_statement" => "f();",
+        "empty_statement" => ";",
+
+        // Expressions
+        "_expression" | "binary_expression" => "a + b;",
+        "unary_expression" => "-x;",
+        "reference_expression" => "&mut x;",
+        "assignment_expression" => "x = 1;",
+        "compound_assignment_expr" => "x += 1;",
+        "type_cast_expression" => "x as u8;",
+        "try_expression" => "f()?;",
+        "call_expression" | "arguments" => "f(1, 2);",
+        "field_expression" => "p.x;",
+        "index_expression" => "a[0];",
+        "array_expression" => "[1, 2, 3];",
+        "tuple_expression" => "(1, true);",
+        "unit_expression" => "();",
+        "parenthesized_expression" => "(a + b) * c;",
+        "struct_expression" | "field_initializer_list" | "field_initializer" => {
+            "Point { x: 1, y: 2 };"
+        }
+        "shorthand_field_initializer" => "Point { x, y };",
+        "base_field_initializer" => "Point { x: 1, ..p };",
+        "closure_expression" | "closure_parameters" => "|a, b| a + b;",
+        "block" => "fn f() { g(); }",
+        "async_block" => "async move { f().await };",
+        "const_block" => "const { 1 + 1 };",
+        "unsafe_block" => "unsafe { f() };",
+        "gen_block" => "gen { yield 1; };",
+        "try_block" => "try { f()? };",
+        "if_expression" => "if x { f() }",
+        "else_clause" => "if x { f() } else { g() }",
+        "let_condition" => "if let Some(x) = y { f(x) }",
+        "let_chain" => "if let Some(x) = y && x > 0 { f(x) }",
+        "match_expression" | "match_block" | "match_arm" | "match_pattern" => {
+            "match x { Some(y) if y > 0 => y, _ => 0 }"
+        }
+        "loop_expression" => "loop { f() }",
+        "while_expression" => "while x > 0 { x -= 1; }",
+        "for_expression" => "for x in xs { f(x) }",
+        "break_expression" => "loop { break; }",
+        "continue_expression" => "loop { continue; }",
+        "return_expression" => "fn f() { return; }",
+        "await_expression" => "f().await;",
+        "range_expression" => "0..10;",
+        "yield_expression" => "gen { yield 1; };",
+
+        // Literals
+        "_literal" | "integer_literal" => "42;",
+        "float_literal" => "1.5;",
+        "string_literal" | "string_content" => "\"hello\";",
+        "raw_string_literal" => "r#\"raw\"#;",
+        "char_literal" => "'c';",
+        "boolean_literal" => "true;",
+        "escape_sequence" => "\"line\\n\";",
+
+        // Patterns
+        "_pattern" | "or_pattern" => "match x { 1 | 2 => {} }",
+        "_literal_pattern" | "negative_literal" => "match x { -1 => {} }",
+        "ref_pattern" => "let ref x = y;",
+        "mut_pattern" => "match x { mut y => {} }",
+        "captured_pattern" => "match x { n @ 1..=9 => {} }",
+        "tuple_pattern" => "let (a, b) = t;",
+        "tuple_struct_pattern" => "let Some(x) = y;",
+        "struct_pattern" | "field_pattern" => "let Point { x: a, y: b } = p;",
+        "remaining_field_pattern" => "let Point { x, .. } = p;",
+        "slice_pattern" => "let [a, b] = s;",
+        "range_pattern" => "match x { 1..=9 => {} }",
+        "reference_pattern" => "let &x = y;",
+        "generic_pattern" => "match x { Foo::<u8> => {} }",
+
+        // Comments
+        "line_comment" => "// note",
+        "block_comment" => "/* note */",
+        "doc_comment" | "outer_doc_comment_marker" => "/// Docs\nfn f() {}",
+        "inner_doc_comment_marker" => "//! Docs",
+
+        _ => return None,
+    })
+}
+
+/// A minimal Rust source file that contains the unnamed token `kind`.
+#[allow(clippy::too_many_lines)]
+fn token_example(kind: &str) -> Option<&'static str> {
+    Some(match kind {
+        // Operators
+        "!" => "!x;",
+        "!=" => "a != b;",
+        "%" => "a % b;",
+        "%=" => "x %= 2;",
+        "&" => "&x;",
+        "&&" => "a && b;",
+        "&=" => "x &= y;",
+        "*" => "a * b;",
+        "*=" => "x *= 2;",
+        "+" => "a + b;",
+        "+=" => "x += 1;",
+        "-" => "a - b;",
+        "-=" => "x -= 1;",
+        ".." => "0..10;",
+        "..." => "extern \"C\" { fn printf(format: *const u8, ...); }",
+        "..=" => "0..=9;",
+        "/" => "a / b;",
+        "/=" => "x /= 2;",
+        "<" => "a < b;",
+        "<<" => "a << 1;",
+        "<<=" => "x <<= 1;",
+        "<=" => "a <= b;",
+        "=" => "let x = 1;",
+        "==" => "a == b;",
+        ">" => "a > b;",
+        ">=" => "a >= b;",
+        ">>" => "a >> 1;",
+        ">>=" => "x >>= 1;",
+        "?" => "f()?;",
+        "^" => "a ^ b;",
+        "^=" => "x ^= y;",
+        "|" => "|x| x;",
+        "|=" => "x |= y;",
+        "||" => "a || b;",
+
+        // Punctuation
+        "\"" => "\"hello\";",
+        "#" => "#[test]\nfn f() {}",
+        "$" => "macro_rules! all { ($($e:expr),*) => {}; }",
+        "tt" => "macro_rules! m { ($t:tt) => {}; }",
+        "'" => "fn f<'a>() {}",
+        "(" | ")" => "f();",
+        "[" | "]" => "a[0];",
+        "{" | "}" => "fn f() {}",
+        "," => "f(a, b);",
+        "->" => "fn f() -> i32 { 1 }",
+        "." => "p.x;",
+        ":" => "let x: u8 = 1;",
+        "::" => "std::mem::swap(a, b);",
+        ";" => "f();",
+        "=>" => "match x { _ => 0 }",
+        "@" => "match x { n @ 1..=9 => {} }",
+        "_" => "let _ = f();",
+
+        // Comments
+        "//" => "// note",
+        "/*" | "*/" => "/* note */",
+
+        // Keywords
+        "as" => "x as u8;",
+        "async" => "async fn f() {}",
+        "await" => "f().await;",
+        "break" => "loop { break; }",
+        "const" => "const MAX: u32 = 10;",
+        "continue" => "loop { continue; }",
+        "default" => "impl A for B { default fn f() {} }",
+        "dyn" => "fn f(x: &dyn Display) {}",
+        "else" => "if x { f() } else { g() }",
+        "enum" => "enum Color { Red }",
+        "extern" => "extern crate alloc;",
+        "false" => "false;",
+        "fn" => "fn f() {}",
+        "for" => "for x in xs { f(x) }",
+        "gen" => "gen { yield 1; };",
+        "if" => "if x { f() }",
+        "impl" => "impl Point {}",
+        "in" => "for x in xs {}",
+        "let" => "let x = 1;",
+        "loop" => "loop {}",
+        "macro_rules!" => "macro_rules! m { () => {}; }",
+        "match" => "match x { _ => 0 }",
+        "mod" => "mod tests {}",
+        "move" => "move || x;",
+        "pub" => "pub fn f() {}",
+        "raw" => "&raw const x;",
+        "ref" => "let ref x = y;",
+        "return" => "fn f() { return; }",
+        "static" => "static N: u8 = 1;",
+        "struct" => "struct Point;",
+        "trait" => "trait Shape {}",
+        "true" => "true;",
+        "try" => "try { f()? };",
+        "type" => "type Id = u64;",
+        "union" => "union Bits { i: u32 }",
+        "unsafe" => "unsafe { f() };",
+        "use" => "use std::fmt;",
+        "where" => "fn f<T>() where T: Clone {}",
+        "while" => "while x {}",
+        "yield" => "gen { yield 1; };",
+
+        // Macro fragment specifiers
+        "block" => "macro_rules! m { ($b:block) => {}; }",
+        "expr" => "macro_rules! m { ($e:expr) => {}; }",
+        "expr_2021" => "macro_rules! m { ($e:expr_2021) => {}; }",
+        "ident" => "macro_rules! m { ($i:ident) => {}; }",
+        "item" => "macro_rules! m { ($i:item) => {}; }",
+        "lifetime" => "macro_rules! m { ($l:lifetime) => {}; }",
+        "literal" => "macro_rules! m { ($l:literal) => {}; }",
+        "meta" => "macro_rules! m { ($m:meta) => {}; }",
+        "pat" => "macro_rules! m { ($p:pat) => {}; }",
+        "pat_param" => "macro_rules! m { ($p:pat_param) => {}; }",
+        "path" => "macro_rules! m { ($p:path) => {}; }",
+        "stmt" => "macro_rules! m { ($s:stmt) => {}; }",
+        "ty" => "macro_rules! m { ($t:ty) => {}; }",
+        "vis" => "macro_rules! m { ($v:vis) => {}; }",
+
+        _ => return None,
+    })
+}
+
+/// Write the `meta_examples` method `example`.
+pub fn generate_examples<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_examples\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    write_match(
+        f,
+        &[
+            "/// A minimal Rust source file whose syntax tree contains this node.",
+            "///",
+            "/// For supertypes such as `_expression`, which never appear in a tree themselves, the",
+            "/// example contains one of their subtypes.",
+        ],
+        "example(self) -> Option<&'static str>",
+        variant_map
+            .iter()
+            .zip(node_types)
+            .zip(gates)
+            .filter_map(|(((original, variant), node_type), gate)| {
+                example(original, node_type.named)
+                    .map(|example| (variant, gate, format!("Some({example:?})")))
+            }),
+        "None",
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
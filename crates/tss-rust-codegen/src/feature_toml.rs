@@ -0,0 +1,99 @@
+use super::generator::Feature;
+use ropey::Rope;
+use std::collections::BTreeMap;
+use textum::{Boundary, BoundaryMode, Patch, PatchError, Snippet, Target};
+
+/// `cargo_toml` with the generated features block replaced by `features`.
+///
+/// The block sits between `# <!-- generated-features-start -->` and
+/// `# <!-- generated-features-end -->`; everything outside it is kept as is.
+pub fn with_features(cargo_toml: &str, features: &[Feature]) -> Result<String, PatchError> {
+    // Generate feature lines
+    let mut feature_lines = Vec::new();
+    for feat in features {
+        let deps: Vec<String> = feat.deps.iter().map(|dep| format!("{dep:?}")).collect();
+        let line = format!("{} = [{}]", feat.name, deps.join(", "));
+        // Wrap long arrays the way taplo formats them, so formatting doesn't make them stale
+        if line.len() <= 80 {
+            feature_lines.push(line);
+        } else {
+            feature_lines.push(format!("{} = [", feat.name));
+            feature_lines.extend(deps.iter().map(|dep| format!("  {dep},")));
+            feature_lines.push("]".to_string());
+        }
+    }
+    let new_features = format!("{}\n", feature_lines.join("\n"));
+
+    let mut rope = Rope::from_str(cargo_toml);
+
+    // textum searches for a literal one rope chunk at a time, so it misses a marker that
+    // straddles two chunks. The markers are whole lines, so target them by line number, falling
+    // back to the literal (and its not-found error) when a marker is missing.
+    let marker_line = |marker: &str| {
+        cargo_toml
+            .lines()
+            .position(|line| line.trim() == marker)
+            .map_or_else(|| Target::Literal(marker.to_string()), Target::Line)
+    };
+
+    // Create the patch using textum
+    let start = Boundary::new(
+        marker_line("# <!-- generated-features-start -->"),
+        BoundaryMode::Exclude,
+    );
+    let end = Boundary::new(
+        marker_line("# <!-- generated-features-end -->"),
+        BoundaryMode::Exclude,
+    );
+    let snippet = Snippet::Between { start, end };
+
+    let patch = Patch {
+        file: "Cargo.toml".to_string(),
+        snippet,
+        replacement: new_features,
+    };
+
+    // Apply the patch
+    patch.apply(&mut rope)?;
+
+    Ok(rope.to_string())
+}
+
+/// The features in the generated block of `cargo_toml`, with their dependencies, or `None` if
+/// it has no such block.
+pub fn features_block(cargo_toml: &str) -> Option<BTreeMap<String, Vec<String>>> {
+    let (_, rest) = cargo_toml.split_once("# <!-- generated-features-start -->")?;
+    let (block, _) = rest.split_once("# <!-- generated-features-end -->")?;
+
+    let mut features = BTreeMap::new();
+    let mut current: Option<(String, String)> = None;
+    for line in block.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        // Multi-line arrays keep accumulating until their closing bracket
+        let (name, mut array) = match current.take() {
+            Some((name, array)) => (name, array),
+            None => match line.split_once('=') {
+                Some((name, _)) => (name.trim().to_string(), String::new()),
+                None => continue,
+            },
+        };
+        array.push_str(line.split_once('=').map_or(line, |(_, value)| value));
+        if array.contains(']') {
+            let deps = array
+                .trim()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .map(|dep| dep.trim().trim_matches('"').to_string())
+                .filter(|dep| !dep.is_empty())
+                .collect();
+            features.insert(name, deps);
+        } else {
+            current = Some((name, array));
+        }
+    }
+    Some(features)
+}
@@ -0,0 +1,672 @@
+use super::examples::example;
+use super::meta::generate_meta;
+use super::reference::{reference_url, section_for};
+use super::schema::NodeType;
+use super::symbol_kinds::generate_symbol_kinds;
+use super::tokens::category;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::{self, Write};
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::{Alias, Names};
+use tree_sitter_symbols_schema::query::Pattern;
+
+/// How node type features are defined in `Cargo.toml`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FeatureMode {
+    /// Each node feature enables only its own variant: `function_item = ["node"]`.
+    Shallow,
+    /// Each node feature also enables the variants reachable through its fields, children and
+    /// subtypes. Cargo rejects cyclic features, so every node gets a `*_shallow` feature that
+    /// gates its variant, and the full feature enables the `*_shallow` features of its closure:
+    /// `function_item = ["function_item_shallow", "block_shallow", ...]`.
+    Closure,
+}
+
+/// A feature definition to write into `Cargo.toml`.
+pub struct Feature {
+    pub name: String,
+    pub deps: Vec<String>,
+}
+
+/// The parsed queries of a release that metadata is derived from.
+pub struct Queries {
+    /// `queries/highlights.scm`, for `meta_highlights`.
+    pub highlights: Vec<Pattern>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn generate<W: Write, M: Write>(
+    f: &mut W,
+    meta: &mut M,
+    grammar: &Grammar,
+    resolved: &[Names],
+    aliases: &[Alias],
+    queries: &Queries,
+    mode: FeatureMode,
+) -> io::Result<Vec<Feature>> {
+    let node_types = grammar.node_types();
+
+    let variant_map: Vec<(String, String)> = resolved
+        .iter()
+        .map(|names| (names.kind.clone(), names.variant.clone()))
+        .collect();
+
+    // The feature each variant is gated on
+    let names: Vec<String> = resolved.iter().map(|names| names.feature.clone()).collect();
+    let gates: Vec<String> = match mode {
+        FeatureMode::Shallow => names.clone(),
+        FeatureMode::Closure => names.iter().map(|name| format!("{name}_shallow")).collect(),
+    };
+
+    // The gate of each alias's replacement, if it has one
+    let alias_gates: Vec<Option<&String>> = aliases
+        .iter()
+        .map(|alias| {
+            let replacement = alias.replacement.as_ref()?;
+            let i = resolved
+                .iter()
+                .position(|names| names == replacement)
+                .expect("aliases are resolved against these names");
+            Some(&gates[i])
+        })
+        .collect();
+
+    generate_enum(f, node_types, &variant_map, &gates)?;
+    generate_from_str(f, node_types, &variant_map, &gates, aliases, &alias_gates)?;
+    generate_display(f, &variant_map, &gates)?;
+    generate_all(f, &variant_map, &gates)?;
+    generate_names(f, resolved, &gates, aliases)?;
+    generate_symbol_kinds(f, node_types, &variant_map, &gates)?;
+    generate_aliases(f, aliases, &alias_gates)?;
+    generate_meta(meta, node_types, &variant_map, &gates, queries)?;
+
+    let mut features = Vec::new();
+    match mode {
+        FeatureMode::Shallow => {
+            for name in &names {
+                features.push(Feature {
+                    name: name.clone(),
+                    deps: vec!["node".to_string()],
+                });
+            }
+        }
+        FeatureMode::Closure => {
+            let gate_of: HashMap<(&str, bool), &String> = node_types
+                .iter()
+                .zip(&gates)
+                .map(|(node_type, gate)| {
+                    ((node_type.node_type_name.as_str(), node_type.named), gate)
+                })
+                .collect();
+            for (i, node_type) in node_types.iter().enumerate() {
+                let mut deps = vec![gates[i].clone()];
+                let mut closure: Vec<String> = grammar
+                    .reachable(&node_type.node_type_name, node_type.named)
+                    .into_iter()
+                    .map(|t| gate_of[&(t.node_type_name.as_str(), t.named)].clone())
+                    .collect();
+                closure.sort();
+                deps.extend(closure);
+                features.push(Feature {
+                    name: names[i].clone(),
+                    deps,
+                });
+                features.push(Feature {
+                    name: gates[i].clone(),
+                    deps: vec!["node".to_string()],
+                });
+            }
+        }
+    }
+    features.extend(group_features(grammar, &names));
+    features.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(features)
+}
+
+/// `group_*` features made of the supertypes listed and all of their subtypes.
+const SUPERTYPE_GROUPS: &[(&str, &[&str])] = &[
+    ("group_expressions", &["_expression"]),
+    ("group_items", &["_declaration_statement"]),
+    ("group_literals", &["_literal", "_literal_pattern"]),
+    ("group_patterns", &["_pattern"]),
+    ("group_types", &["_type"]),
+];
+
+/// Preset features enabling a category of node types at once: the supertype groups above, and
+/// `group_keywords`, `group_operators` and `group_punctuation` with the unnamed tokens of that
+/// [`category`]. Named nodes such as `self` are not tokens, so they stay out of the latter.
+fn group_features(grammar: &Grammar, names: &[String]) -> Vec<Feature> {
+    let node_types = grammar.node_types();
+    let name_of: HashMap<(&str, bool), &String> = node_types
+        .iter()
+        .zip(names)
+        .map(|(node_type, name)| ((node_type.node_type_name.as_str(), node_type.named), name))
+        .collect();
+
+    let mut groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (group, supertypes) in SUPERTYPE_GROUPS {
+        let members = groups.entry((*group).to_string()).or_default();
+        for supertype in *supertypes {
+            members.insert(name_of[&(*supertype, true)].clone());
+            for subtype in grammar.subtypes(supertype) {
+                members.insert(name_of[&(subtype.node_type_name.as_str(), subtype.named)].clone());
+            }
+        }
+    }
+    for (node_type, name) in node_types.iter().zip(names) {
+        if node_type.named {
+            continue;
+        }
+        let group = match category(&node_type.node_type_name, false) {
+            "Keyword" => "group_keywords",
+            "Operator" => "group_operators",
+            "Punctuation" => "group_punctuation",
+            _ => continue,
+        };
+        groups
+            .entry(group.to_string())
+            .or_default()
+            .insert(name.clone());
+    }
+
+    groups
+        .into_iter()
+        .map(|(name, members)| Feature {
+            name,
+            deps: members.into_iter().collect(),
+        })
+        .collect()
+}
+
+fn generate_enum<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "/// Tree-sitter node types for Rust")?;
+    writeln!(f, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(f, "pub enum NodeType {{")?;
+
+    // Build a map to find paired variants
+    let mut name_variants: std::collections::HashMap<&str, Vec<(&str, bool)>> =
+        std::collections::HashMap::new();
+    for (i, (original, variant_name)) in variant_map.iter().enumerate() {
+        name_variants
+            .entry(original.as_str())
+            .or_default()
+            .push((variant_name.as_str(), node_types[i].named));
+    }
+
+    for (i, (original, variant_name)) in variant_map.iter().enumerate() {
+        let named = node_types[i].named;
+        let named_str = if named { "named" } else { "unnamed" };
+        writeln!(f, "    /// `{original}` ({named_str})")?;
+
+        // The same link as `NodeType::reference_url`
+        if let (Some(section), Some(url)) =
+            (section_for(original, named), reference_url(original, named))
+        {
+            writeln!(f, "    ///")?;
+            writeln!(
+                f,
+                "    /// - **Rust reference**: [{}]({url})",
+                section.title
+            )?;
+            if let Some(grammar) = section.grammar {
+                writeln!(f, "    /// - **Grammar**: `{grammar}`")?;
+            }
+        }
+
+        // If there's a paired variant (named vs unnamed), emit cross-ref doc.
+        let variants = &name_variants[original.as_str()];
+        if variants.len() > 1 {
+            let other = variants
+                .iter()
+                .find(|(v, n)| *v != variant_name && *n != node_types[i].named)
+                .map(|(v, _)| v);
+            if let Some(other_variant) = other {
+                writeln!(f, "    ///")?;
+                writeln!(
+                    f,
+                    "    /// - {} variant: [`Self::{}`]",
+                    if node_types[i].named {
+                        "unnamed"
+                    } else {
+                        "named"
+                    },
+                    other_variant
+                )?;
+            }
+        }
+
+        if let Some(example) = example(original, named) {
+            writeln!(f, "    ///")?;
+            writeln!(f, "    /// ```text")?;
+            for line in example.lines() {
+                writeln!(f, "    /// {line}")?;
+            }
+            writeln!(f, "    /// ```")?;
+        }
+
+        // Gate the variant itself on its feature or the global one
+        let feat = &gates[i];
+        writeln!(
+            f,
+            "    #[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]"
+        )?;
+        writeln!(f, "    {variant_name},")?;
+    }
+
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_from_str<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+    aliases: &[Alias],
+    alias_gates: &[Option<&String>],
+) -> io::Result<()> {
+    // build list of all generated feature names (from aliases) + global
+    let mut all_feature_list = Vec::new();
+    for feat in gates {
+        all_feature_list.push(format!("feature = \"{feat}\""));
+    }
+    all_feature_list.push("feature = \"node_full\"".to_string());
+    let any_cfg = format!("any({})", all_feature_list.join(", "));
+
+    // gate the whole impl
+    writeln!(f, "#[cfg({any_cfg})]")?;
+    writeln!(f, "impl std::str::FromStr for NodeType {{")?;
+    writeln!(f, "    type Err = String;")?;
+    writeln!(f)?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(f, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{")?;
+    writeln!(f, "        match s {{")?;
+
+    for (i, (original, variant_name)) in variant_map.iter().enumerate() {
+        if node_types[i].named {
+            let feat = &gates[i];
+            writeln!(
+                f,
+                "            #[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]"
+            )?;
+            writeln!(f, "            {original:?} => Ok(Self::{variant_name}),")?;
+        }
+    }
+
+    // Kinds from earlier grammar releases parse as their replacements, or fail with why they
+    // were dropped
+    for (alias, feat) in aliases.iter().zip(alias_gates) {
+        if !alias.named {
+            continue;
+        }
+        if let (Some(replacement), Some(feat)) = (&alias.replacement, feat) {
+            writeln!(
+                f,
+                "            #[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]"
+            )?;
+            writeln!(
+                f,
+                "            {:?} => Ok(Self::{}),",
+                alias.kind, replacement.variant
+            )?;
+        } else {
+            let message = format!("{} was {}", alias.kind, alias.note);
+            writeln!(
+                f,
+                "            {:?} => Err({message:?}.to_string()),",
+                alias.kind
+            )?;
+        }
+    }
+
+    // A kind whose feature is disabled says which feature would parse it
+    writeln!(
+        f,
+        "            _ => Err(match Self::feature_for_kind(s, true) {{"
+    )?;
+    writeln!(
+        f,
+        "                Some(feature) => format!(\"Node type {{s}} needs the `{{feature}}` feature\"),"
+    )?;
+    writeln!(
+        f,
+        "                None => format!(\"Unknown node type: {{s}}\"),"
+    )?;
+    writeln!(f, "            }}),")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_display<W: Write>(
+    f: &mut W,
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    // Build any(...) cfg with all valid feature names (derived from aliases)
+    let mut feats = Vec::new();
+    for feat_name in gates {
+        feats.push(format!("feature = \"{feat_name}\""));
+    }
+    feats.push("feature = \"node_full\"".to_string());
+    let any_cfg = format!("any({})", feats.join(", "));
+
+    writeln!(f, "#[cfg({any_cfg})]")?;
+    writeln!(f, "impl std::fmt::Display for NodeType {{")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        f,
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )?;
+    writeln!(f, "        match self {{")?;
+
+    for (i, (original, variant_name)) in variant_map.iter().enumerate() {
+        let feat_name = &gates[i];
+
+        writeln!(
+            f,
+            "            #[cfg(any(feature = \"{feat_name}\", feature = \"node_full\"))]"
+        )?;
+
+        // Escape special characters for Rust string literals in generated code
+        let escaped = original
+            .replace('\\', r"\\") // backslash first!
+            .replace('"', r#"\""#) // escape double quotes
+            .replace('{', "{{") // escape opening brace for format strings
+            .replace('}', "}}"); // escape closing brace for format strings
+
+        writeln!(
+            f,
+            "            Self::{variant_name} => write!(f, \"{escaped}\"),"
+        )?;
+    }
+
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+fn generate_all<W: Write>(
+    f: &mut W,
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "impl NodeType {{")?;
+    writeln!(
+        f,
+        "    /// Every node type enabled by the current features, in `node-types.json` order."
+    )?;
+    writeln!(f, "    pub const ALL: &'static [Self] = &[")?;
+    for ((_, variant_name), feat) in variant_map.iter().zip(gates) {
+        writeln!(
+            f,
+            "        #[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]"
+        )?;
+        writeln!(f, "        Self::{variant_name},")?;
+    }
+    writeln!(f, "    ];")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_names<W: Write>(
+    f: &mut W,
+    resolved: &[Names],
+    gates: &[String],
+    aliases: &[Alias],
+) -> io::Result<()> {
+    let cfg = |feat: &str, indent: &str| {
+        format!("{indent}#[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]")
+    };
+
+    writeln!(f, "impl NodeType {{")?;
+    for (method, doc, example) in [
+        ("variant_name", "The name of this variant", "FunctionItem"),
+        (
+            "feature_name",
+            "The Cargo feature that enables this node type",
+            "function_item",
+        ),
+    ] {
+        writeln!(f, "    /// {doc}, e.g. `\"{example}\"`.")?;
+        writeln!(f, "    #[must_use]")?;
+        writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+        writeln!(f, "    pub const fn {method}(self) -> &'static str {{")?;
+        writeln!(f, "        match self {{")?;
+        for (names, feat) in resolved.iter().zip(gates) {
+            let name = if method == "variant_name" {
+                &names.variant
+            } else {
+                &names.feature
+            };
+            writeln!(f, "{}", cfg(feat, "            "))?;
+            writeln!(f, "            Self::{} => {name:?},", names.variant)?;
+        }
+        writeln!(f, "        }}")?;
+        writeln!(f, "    }}")?;
+        writeln!(f)?;
+    }
+
+    for (method, doc) in [
+        (
+            "from_variant_name",
+            "The node type whose variant is called `name`",
+        ),
+        (
+            "from_feature_name",
+            "The node type enabled by the Cargo feature `name`",
+        ),
+    ] {
+        writeln!(f, "    /// {doc}, if its feature is enabled.")?;
+        if method == "from_variant_name" {
+            writeln!(
+                f,
+                "    /// [`Self::feature_for_variant`] names the feature otherwise."
+            )?;
+        }
+        writeln!(f, "    #[must_use]")?;
+        writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+        writeln!(f, "    pub fn {method}(name: &str) -> Option<Self> {{")?;
+        writeln!(f, "        match name {{")?;
+        for (names, feat) in resolved.iter().zip(gates) {
+            let name = if method == "from_variant_name" {
+                &names.variant
+            } else {
+                &names.feature
+            };
+            writeln!(f, "{}", cfg(feat, "            "))?;
+            writeln!(f, "            {name:?} => Some(Self::{}),", names.variant)?;
+        }
+        writeln!(f, "            _ => None,")?;
+        writeln!(f, "        }}")?;
+        writeln!(f, "    }}")?;
+        writeln!(f)?;
+    }
+
+    // Ungated, so tools can name the feature a disabled node type needs
+    let replaced: Vec<(&Alias, &Names)> = aliases
+        .iter()
+        .filter_map(|alias| Some((alias, alias.replacement.as_ref()?)))
+        .collect();
+    writeln!(
+        f,
+        "    /// The Cargo feature that enables the variant called `name`, whether or not it is \
+         enabled,"
+    )?;
+    writeln!(
+        f,
+        "    /// e.g. `\"function_item\"` for `\"FunctionItem\"`. A deprecated variant gives its \
+         replacement's."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        f,
+        "    pub fn feature_for_variant(name: &str) -> Option<&'static str> {{"
+    )?;
+    writeln!(f, "        match name {{")?;
+    for names in resolved {
+        writeln!(
+            f,
+            "            {:?} => Some({:?}),",
+            names.variant, names.feature
+        )?;
+    }
+    for (alias, replacement) in &replaced {
+        writeln!(
+            f,
+            "            {:?} => Some({:?}),",
+            alias.variant, replacement.feature
+        )?;
+    }
+    writeln!(f, "            _ => None,")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    /// The Cargo feature that enables the node of kind `kind` (named or not), whether or \
+         not it"
+    )?;
+    writeln!(
+        f,
+        "    /// is enabled, e.g. `\"path_sep_token\"` for `(\"::\", false)`. A deprecated kind \
+         gives its"
+    )?;
+    writeln!(f, "    /// replacement's.")?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        f,
+        "    pub fn feature_for_kind(kind: &str, named: bool) -> Option<&'static str> {{"
+    )?;
+    writeln!(f, "        match (kind, named) {{")?;
+    for names in resolved {
+        writeln!(
+            f,
+            "            ({:?}, {}) => Some({:?}),",
+            names.kind, names.named, names.feature
+        )?;
+    }
+    for (alias, replacement) in &replaced {
+        writeln!(
+            f,
+            "            ({:?}, {}) => Some({:?}),",
+            alias.kind, alias.named, replacement.feature
+        )?;
+    }
+    writeln!(f, "            _ => None,")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    generate_name_tables(f, resolved, aliases)?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+/// Write `NAMES` and `DEPRECATED`, the resolved names as data, so tools need not resolve them
+/// again from the crate's manifest.
+fn generate_name_tables<W: Write>(
+    f: &mut W,
+    resolved: &[Names],
+    aliases: &[Alias],
+) -> io::Result<()> {
+    writeln!(
+        f,
+        "    /// Every node kind of the grammar with its variant and feature, in `node-types.json` order,"
+    )?;
+    writeln!(f, "    /// whether or not its feature is enabled.")?;
+    writeln!(
+        f,
+        "    pub const NAMES: &'static [crate::names::KindNames] = &["
+    )?;
+    for names in resolved {
+        writeln!(
+            f,
+            "        crate::names::KindNames {{ kind: {:?}, named: {}, variant: {:?}, feature: {:?} }},",
+            names.kind, names.named, names.variant, names.feature
+        )?;
+    }
+    writeln!(f, "    ];")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    /// The deprecated variants kept for kinds earlier grammar releases had, including kinds"
+    )?;
+    writeln!(f, "    /// that were dropped.")?;
+    writeln!(
+        f,
+        "    pub const DEPRECATED: &'static [crate::names::DeprecatedKind] = &["
+    )?;
+    for alias in aliases {
+        let replacement = alias.replacement.as_ref().map_or_else(
+            || "None".to_string(),
+            |names| format!("Some({:?})", names.variant),
+        );
+        writeln!(
+            f,
+            "        crate::names::DeprecatedKind {{ kind: {:?}, named: {}, variant: {:?}, replacement: {replacement}, note: {:?} }},",
+            alias.kind, alias.named, alias.variant, alias.note
+        )?;
+    }
+    writeln!(f, "    ];")?;
+    Ok(())
+}
+
+fn generate_aliases<W: Write>(
+    f: &mut W,
+    aliases: &[Alias],
+    alias_gates: &[Option<&String>],
+) -> io::Result<()> {
+    // Dropped kinds have nothing to stand for
+    let replaced: Vec<(&Alias, &Names, &String)> = aliases
+        .iter()
+        .zip(alias_gates)
+        .filter_map(|(alias, feat)| Some((alias, alias.replacement.as_ref()?, (*feat)?)))
+        .collect();
+    if replaced.is_empty() {
+        return Ok(());
+    }
+    writeln!(f, "impl NodeType {{")?;
+    for (alias, replacement, feat) in replaced {
+        let kind = if alias.named {
+            alias.kind.clone()
+        } else {
+            format!("{:?}", alias.kind)
+        };
+        writeln!(
+            f,
+            "    /// The `{kind}` node of earlier grammar releases, now [`Self::{}`].",
+            replacement.variant
+        )?;
+        writeln!(
+            f,
+            "    #[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]"
+        )?;
+        writeln!(f, "    #[deprecated(note = {:?})]", alias.note)?;
+        writeln!(f, "    #[allow(non_upper_case_globals)]")?;
+        writeln!(
+            f,
+            "    pub const {}: Self = Self::{};",
+            alias.variant, replacement.variant
+        )?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use super::meta::write_match;
+use super::schema::NodeType;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::{self, Write};
+use tree_sitter_symbols_schema::naming::snake_to_pascal;
+use tree_sitter_symbols_schema::query::{Pattern, PatternKind};
+
+/// The variant of a capture name, e.g. `FunctionMethod` for `function.method`.
+fn capture_variant(name: &str) -> String {
+    snake_to_pascal(&name.replace('.', "_"))
+}
+
+/// Every capture name in the query, sorted.
+fn capture_names(patterns: &[Pattern]) -> BTreeSet<String> {
+    patterns
+        .iter()
+        .flat_map(Pattern::all_captures)
+        .map(|capture| capture.name.clone())
+        .collect()
+}
+
+/// The first `Highlight` variant that more than one capture name maps to, with those names, such
+/// as `FunctionMethod` for `@function.method` and `@function_method`.
+pub fn capture_collision(patterns: &[Pattern]) -> Option<(String, Vec<String>)> {
+    let mut by_variant: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for name in capture_names(patterns) {
+        by_variant
+            .entry(capture_variant(&name))
+            .or_default()
+            .push(name);
+    }
+    by_variant.into_iter().find(|(_, names)| names.len() > 1)
+}
+
+/// The captures each node kind gets wherever it appears, in query order.
+///
+/// Only top-level patterns that are a bare node or token (or an alternation of them) count:
+/// captures that depend on a parent, a child or a predicate, such as `@constructor` on
+/// capitalised identifiers, are left to the query engine.
+fn default_captures(patterns: &[Pattern]) -> HashMap<(String, bool), Vec<String>> {
+    fn add(
+        pattern: &Pattern,
+        captures: &[String],
+        defaults: &mut HashMap<(String, bool), Vec<String>>,
+    ) {
+        let key = match &pattern.kind {
+            PatternKind::Named {
+                kind: Some(kind),
+                supertype: None,
+                children,
+            } if children.is_empty() => (kind.name.clone(), true),
+            PatternKind::Anonymous(kind) => (kind.name.clone(), false),
+            PatternKind::Alternation(alternatives) => {
+                for alternative in alternatives {
+                    let mut captures = captures.to_vec();
+                    captures.extend(alternative.captures.iter().map(|c| c.name.clone()));
+                    add(alternative, &captures, defaults);
+                }
+                return;
+            }
+            _ => return,
+        };
+        let entry = defaults.entry(key).or_default();
+        for capture in captures {
+            if !entry.contains(capture) {
+                entry.push(capture.clone());
+            }
+        }
+    }
+
+    let mut defaults = HashMap::new();
+    for pattern in patterns {
+        let captures: Vec<String> = pattern.captures.iter().map(|c| c.name.clone()).collect();
+        add(pattern, &captures, &mut defaults);
+    }
+    defaults
+}
+
+pub fn generate_highlights<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+    highlights: &[Pattern],
+) -> io::Result<()> {
+    let names = capture_names(highlights);
+
+    writeln!(
+        f,
+        "/// A capture name used by tree-sitter-rust's `HIGHLIGHTS_QUERY`"
+    )?;
+    writeln!(f, "#[cfg(feature = \"meta_highlights\")]")?;
+    writeln!(f, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(f, "pub enum Highlight {{")?;
+    for name in &names {
+        writeln!(f, "    /// `@{name}`")?;
+        writeln!(f, "    {},", capture_variant(name))?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_highlights\")]")?;
+    writeln!(f, "impl Highlight {{")?;
+    writeln!(f, "    /// Every capture name in the query, sorted.")?;
+    writeln!(f, "    pub const ALL: &'static [Self] = &[")?;
+    for name in &names {
+        writeln!(f, "        Self::{},", capture_variant(name))?;
+    }
+    writeln!(f, "    ];")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    /// The capture name as it appears in the query, without the `@`."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    pub const fn as_str(self) -> &'static str {{")?;
+    writeln!(f, "        match self {{")?;
+    for name in &names {
+        writeln!(
+            f,
+            "            Self::{} => {name:?},",
+            capture_variant(name)
+        )?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_highlights\")]")?;
+    writeln!(f, "impl std::str::FromStr for Highlight {{")?;
+    writeln!(f, "    type Err = String;")?;
+    writeln!(f)?;
+    writeln!(f, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{")?;
+    writeln!(f, "        match s {{")?;
+    for name in &names {
+        writeln!(
+            f,
+            "            {name:?} => Ok(Self::{}),",
+            capture_variant(name)
+        )?;
+    }
+    writeln!(
+        f,
+        "            _ => Err(format!(\"Unknown highlight capture: {{s}}\")),"
+    )?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_highlights\")]")?;
+    writeln!(f, "impl std::fmt::Display for Highlight {{")?;
+    writeln!(
+        f,
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )?;
+    writeln!(f, "        f.write_str(self.as_str())")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    let defaults = default_captures(highlights);
+    let captures: Vec<Option<&Vec<String>>> = node_types
+        .iter()
+        .map(|node_type| defaults.get(&(node_type.node_type_name.clone(), node_type.named)))
+        .collect();
+
+    writeln!(f, "#[cfg(feature = \"meta_highlights\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    write_match(
+        f,
+        &[
+            "/// The captures `HIGHLIGHTS_QUERY` gives this node wherever it appears, in query order.",
+            "///",
+            "/// Captures that depend on the node's context, such as `@function` on the identifier",
+            "/// of a call or `@constructor` on a capitalised identifier, are not included.",
+        ],
+        "highlight_captures(self) -> &'static [&'static str]",
+        variant_map
+            .iter()
+            .zip(&captures)
+            .zip(gates)
+            .filter_map(|(((_, variant), captures), gate)| {
+                captures.map(|captures| (variant, gate, format!("&{captures:?}")))
+            }),
+        "&[]",
+    )?;
+    write_match(
+        f,
+        &[
+            "/// The first of [`highlight_captures`](Self::highlight_captures), which takes",
+            "/// precedence when a highlighter applies the query.",
+        ],
+        "highlight(self) -> Option<Highlight>",
+        variant_map.iter().zip(&captures).zip(gates).filter_map(
+            |(((_, variant), captures), gate)| {
+                let first = (*captures)?.first()?;
+                Some((
+                    variant,
+                    gate,
+                    format!("Some(Highlight::{})", capture_variant(first)),
+                ))
+            },
+        ),
+        "None",
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
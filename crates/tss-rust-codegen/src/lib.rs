@@ -0,0 +1,572 @@
+//! Code generator for `tss-rust`.
+//!
+//! Reads the `NODE_TYPES` of each supported `tree-sitter-rust` release and renders the
+//! `NodeType` enum and metadata tables into `tss-rust/src/generated/<release>/`, along with the
+//! node type features in `tss-rust/Cargo.toml`. A release whose node types generate the same file
+//! as an earlier one shares that copy instead. The output is committed, so building `tss-rust`
+//! needs neither this crate nor its dependencies.
+//!
+//! ```sh
+//! cargo run -p tss-rust-codegen            # rewrite any stale files, remove obsolete ones
+//! cargo run -p tss-rust-codegen -- --check # list stale and obsolete files, exit 1 if any
+//! ```
+//!
+//! For `Cargo.toml`, [`FeatureDiff`] tells which generated features are missing, extra or have
+//! different dependencies.
+//!
+//! Setting `TSS_RUST_NODE_TYPES_PATH` to the path of a `node-types.json` file generates the
+//! default release's output from that file instead, so a patched or newer grammar can be tried
+//! out before it is released.
+//!
+//! Setting `feature-closure = true` under `[package.metadata.tss]` in `tss-rust/Cargo.toml`
+//! makes each node feature also enable the node types reachable through its fields and children.
+//!
+//! Variant and feature names can be fixed per kind under `[package.metadata.tss.naming]` (see
+//! [`Overrides`]). Generation fails if two kinds would get the same variant or feature and no
+//! override tells them apart, so names never change silently when the grammar is upgraded.
+//!
+//! Kinds that earlier grammar releases had are listed under `[package.metadata.tss.deprecated]`
+//! (see [`Deprecations`]). Each gets a `#[deprecated]` associated constant on `NodeType` standing
+//! for its replacement, and named ones still parse with `FromStr`. A named kind dropped without a
+//! replacement fails to parse with its deprecation note instead.
+
+#![allow(clippy::too_many_lines)]
+#![allow(clippy::match_same_arms)]
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+// Every supported tree-sitter-rust release is a dependency
+#![allow(clippy::multiple_crate_versions)]
+
+mod examples;
+mod feature_toml;
+mod generator;
+mod highlights;
+mod meta;
+mod reference;
+mod rust_docs;
+mod symbol_kinds;
+mod tokens;
+
+use generator::FeatureMode;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::io;
+use std::path::{Path, PathBuf};
+use textum::PatchError;
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::{resolve_aliases, resolve_names, NamingError};
+use tree_sitter_symbols_schema::query::{self, ParseError};
+use tree_sitter_symbols_schema::schema;
+
+pub use tree_sitter_symbols_schema::naming::{Deprecation, Deprecations, Override, Overrides};
+
+/// A `tree-sitter-rust` release `tss-rust` ships generated code for.
+#[derive(Debug, Clone, Copy)]
+pub struct Release {
+    /// The release's major and minor version, e.g. `0.24`.
+    pub version: &'static str,
+    /// The exact release the workspace `Cargo.toml` pins, e.g. `0.24.2`. Patch releases can
+    /// change the node types, so the generated code is only valid for this one.
+    pub pinned: &'static str,
+    /// The `tss-rust` feature that selects it, e.g. `grammar-0-24`.
+    pub feature: &'static str,
+    /// The directory under `src/generated/` its code is written to, unless an earlier release
+    /// generates the same code.
+    pub dir: &'static str,
+    /// The release's `node-types.json`.
+    pub node_types: &'static str,
+    /// The release's `queries/highlights.scm`.
+    pub highlights: &'static str,
+}
+
+/// The supported releases, the default one (used when no `grammar-*` feature is enabled) first.
+pub const RELEASES: &[Release] = &[
+    Release {
+        version: "0.24",
+        pinned: "0.24.2",
+        feature: "grammar-0-24",
+        dir: "grammar_0_24",
+        node_types: tree_sitter_rust::NODE_TYPES,
+        highlights: tree_sitter_rust::HIGHLIGHTS_QUERY,
+    },
+    Release {
+        version: "0.23",
+        pinned: "0.23.3",
+        feature: "grammar-0-23",
+        dir: "grammar_0_23",
+        node_types: tree_sitter_rust_0_23::NODE_TYPES,
+        highlights: tree_sitter_rust_0_23::HIGHLIGHTS_QUERY,
+    },
+];
+
+/// Environment variable naming a `node-types.json` to generate the default release from.
+pub const NODE_TYPES_PATH: &str = "TSS_RUST_NODE_TYPES_PATH";
+
+/// An error generating `tss-rust`'s code.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read or written.
+    Io(PathBuf, io::Error),
+    /// A grammar's node types could not be parsed; the first field names where they came from.
+    Schema(String, serde_json::Error),
+    /// A release's query could not be parsed; the first field names the query.
+    Query(String, ParseError),
+    /// The generated features block could not be found in `Cargo.toml`.
+    Features(PathBuf, PatchError),
+    /// `Cargo.toml` or its `[package.metadata.tss.naming]` or `[package.metadata.tss.deprecated]`
+    /// table could not be read.
+    Manifest(PathBuf, String),
+    /// A grammar's names clash or an override is invalid; the first field names the grammar.
+    Naming(String, NamingError),
+    /// Captures of a highlights query would get the same `Highlight` variant; the fields name
+    /// the query, the variant and the captures.
+    CaptureCollision(String, String, Vec<String>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::Schema(source, e) => {
+                write!(f, "failed to parse {source} as a node-types schema: {e}")
+            }
+            Self::Query(source, e) => write!(f, "failed to parse {source}: {e}"),
+            Self::Features(path, e) => {
+                write!(
+                    f,
+                    "{}: cannot update the generated features: {e}",
+                    path.display()
+                )
+            }
+            Self::Manifest(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::Naming(source, e) => write!(f, "cannot name the nodes of {source}: {e}"),
+            Self::CaptureCollision(source, variant, captures) => {
+                let captures: Vec<String> =
+                    captures.iter().map(|name| format!("@{name}")).collect();
+                write!(
+                    f,
+                    "cannot name the captures of {source}: variant {variant} is given to {}",
+                    captures.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A generated file and its contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    /// Where the file goes.
+    pub path: PathBuf,
+    /// What it should contain.
+    pub contents: String,
+}
+
+/// The node types of `release`, from `TSS_RUST_NODE_TYPES_PATH` for the default release if set,
+/// with a description of where they came from and the version to record.
+fn load(release: &Release) -> Result<(Grammar, String, String), Error> {
+    let path = match std::env::var_os(NODE_TYPES_PATH) {
+        Some(path) if !path.is_empty() && release.dir == RELEASES[0].dir => PathBuf::from(path),
+        _ => {
+            let source = format!("tree-sitter-rust {} NODE_TYPES", release.pinned);
+            let grammar = Grammar::from_json(release.node_types)
+                .map_err(|e| Error::Schema(source.clone(), e))?;
+            return Ok((grammar, source, release.version.to_string()));
+        }
+    };
+    let json = std::fs::read_to_string(&path).map_err(|e| Error::Io(path.clone(), e))?;
+    let source = format!("{} (from {NODE_TYPES_PATH})", path.display());
+    let grammar = Grammar::from_json(&json).map_err(|e| Error::Schema(source.clone(), e))?;
+    Ok((grammar, source, "custom".to_string()))
+}
+
+impl Output {
+    /// How the features block of the file on disk differs from this output's, if this is a
+    /// `Cargo.toml` and both have a block.
+    #[must_use]
+    pub fn feature_diff(&self) -> Option<FeatureDiff> {
+        if !self.path.ends_with("Cargo.toml") {
+            return None;
+        }
+        let current = std::fs::read_to_string(&self.path).ok()?;
+        FeatureDiff::between(&current, &self.contents)
+    }
+}
+
+/// The `key` value (usually a table) under `[package.metadata.tss]` in `cargo_toml`, or its
+/// default if absent.
+fn tss_metadata<T>(cargo_toml: &str, path: &Path, key: &str) -> Result<T, Error>
+where
+    T: Default + for<'de> serde::Deserialize<'de>,
+{
+    let manifest: toml::Table = cargo_toml
+        .parse()
+        .map_err(|e: toml::de::Error| Error::Manifest(path.to_path_buf(), e.to_string()))?;
+    let table = manifest
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("tss"))
+        .and_then(|tss| tss.get(key));
+    match table {
+        None => Ok(T::default()),
+        Some(table) => table.clone().try_into().map_err(|e: toml::de::Error| {
+            Error::Manifest(
+                path.to_path_buf(),
+                format!("invalid [package.metadata.tss.{key}]: {e}"),
+            )
+        }),
+    }
+}
+
+/// The deprecations that apply to `grammar`, an older release than the one they were written
+/// for: kinds it still has are not deprecated yet.
+fn applicable(grammar: &Grammar, deprecations: &Deprecations) -> Deprecations {
+    let present = |key: &str| match key.strip_prefix('"').and_then(|k| k.strip_suffix('"')) {
+        Some(kind) => grammar.get(kind, false).is_some(),
+        None => grammar.get(key, true).is_some() || grammar.get(key, false).is_some(),
+    };
+    Deprecations(
+        deprecations
+            .0
+            .iter()
+            .filter(|(key, _)| !present(key))
+            .map(|(key, deprecation)| (key.clone(), deprecation.clone()))
+            .collect(),
+    )
+}
+
+/// The code generated for one release, before it is written anywhere.
+struct Generated {
+    release: &'static Release,
+    /// Where the node types came from.
+    source: String,
+    /// The version to record in `GRAMMAR_VERSION`.
+    version: String,
+    /// The name and contents of each file, without a header.
+    files: [(&'static str, String); 2],
+}
+
+const HEADER_END: &str = "// Run `cargo run -p tss-rust-codegen` to update it.\n\n";
+
+/// The outputs for the generated code of every release: one copy of each distinct file, and the
+/// `nodes.rs` and `meta.rs` under `dir` that include the selected release's copies.
+fn shared_outputs(dir: &Path, generated: &[Generated]) -> Vec<Output> {
+    let mut outputs = Vec::new();
+    for (i, (name, _)) in generated[0].files.iter().enumerate() {
+        // The earliest release generating each distinct file, with every release that uses it
+        let mut copies: Vec<(&Generated, Vec<&Generated>)> = Vec::new();
+        for release in generated {
+            match copies
+                .iter_mut()
+                .find(|(owner, _)| owner.files[i].1 == release.files[i].1)
+            {
+                Some((_, users)) => users.push(release),
+                None => copies.push((release, vec![release])),
+            }
+        }
+
+        let mut select = format!("// @generated by tss-rust-codegen.\n{HEADER_END}");
+        select.push_str(
+            "// The code of the tree-sitter-rust release the `grammar-*` features select\n",
+        );
+        for (owner, users) in &copies {
+            let also: Vec<&str> = users[1..].iter().map(|user| user.release.pinned).collect();
+            let shared = if also.is_empty() {
+                String::new()
+            } else {
+                format!(" (the same for {})", also.join(", "))
+            };
+            outputs.push(Output {
+                path: dir.join(owner.release.dir).join(name),
+                contents: format!(
+                    "// @generated by tss-rust-codegen from {}{shared}.\n{HEADER_END}{}\n",
+                    owner.source,
+                    owner.files[i].1.trim_end()
+                ),
+            });
+            if copies.len() > 1 {
+                let _ = writeln!(select, "#[cfg({})]", selected(users.iter().copied()));
+            }
+            let _ = writeln!(select, "include!(\"{}/{name}\");", owner.release.dir);
+        }
+
+        if *name == "nodes.rs" {
+            select.push_str("\nimpl NodeType {\n");
+            for release in generated {
+                select.push_str(
+                    "    /// The tree-sitter-rust release the node types were generated from, \
+                     selected by the\n    /// `grammar-*` features, or `\"custom\"` if they came \
+                     from `TSS_RUST_NODE_TYPES_PATH`.\n",
+                );
+                let _ = write!(
+                    select,
+                    "    #[cfg({})]\n    pub const GRAMMAR_VERSION: &'static str = {:?};\n",
+                    selected([release]),
+                    release.version
+                );
+            }
+            select.push_str("}\n");
+        }
+        outputs.push(Output {
+            path: dir.join(name),
+            contents: select,
+        });
+    }
+    outputs
+}
+
+/// The `cfg` predicate that holds when one of `releases` is selected: its `grammar-*` feature
+/// is enabled, or for the default release, none of the others' are.
+fn selected<'a>(releases: impl IntoIterator<Item = &'a Generated>) -> String {
+    let predicates: Vec<String> = releases
+        .into_iter()
+        .map(|generated| {
+            if generated.release.dir == RELEASES[0].dir {
+                let others: Vec<String> = RELEASES[1..]
+                    .iter()
+                    .map(|release| format!("feature = {:?}", release.feature))
+                    .collect();
+                format!("not(any({}))", others.join(", "))
+            } else {
+                format!("feature = {:?}", generated.release.feature)
+            }
+        })
+        .collect();
+    match predicates.as_slice() {
+        [predicate] => predicate.clone(),
+        _ => format!("any({})", predicates.join(", ")),
+    }
+}
+
+/// Parse the highlights query `source`, named `name` in errors, for the `Highlight` enum.
+///
+/// # Errors
+///
+/// Returns an error if the query does not parse, or if two of its capture names would get the
+/// same `Highlight` variant, such as `@function.method` and `@function_method`.
+pub fn highlights_query(name: &str, source: &str) -> Result<Vec<query::Pattern>, Error> {
+    let patterns = query::parse(source).map_err(|e| Error::Query(name.to_string(), e))?;
+    match highlights::capture_collision(&patterns) {
+        Some((variant, captures)) => {
+            Err(Error::CaptureCollision(name.to_string(), variant, captures))
+        }
+        None => Ok(patterns),
+    }
+}
+
+/// Render every file generated for the `tss-rust` crate in `crate_dir`.
+///
+/// Each release gets a `nodes.rs` (the enum with `FromStr`, `Display` and `ALL`) and a `meta.rs`
+/// (the metadata tables) in its directory, unless an earlier release generates the same file,
+/// whose copy it then shares. `src/generated/nodes.rs` and `src/generated/meta.rs` include the
+/// file of the release the `grammar-*` features select, and the former also defines
+/// `NodeType::GRAMMAR_VERSION`. `Cargo.toml` gets the features of every release, with those of
+/// the default release taking precedence.
+///
+/// # Errors
+///
+/// Returns an error if a grammar's node types cannot be parsed, its names clash without an
+/// override to resolve them, a deprecated kind is still present in the default release, or
+/// `Cargo.toml` cannot be read or has no generated features block.
+pub fn render(crate_dir: &Path) -> Result<Vec<Output>, Error> {
+    let cargo_toml_path = crate_dir.join("Cargo.toml");
+    let cargo_toml = std::fs::read_to_string(&cargo_toml_path)
+        .map_err(|e| Error::Io(cargo_toml_path.clone(), e))?;
+    let closure: bool = tss_metadata(&cargo_toml, &cargo_toml_path, "feature-closure")?;
+    let mode = if closure {
+        FeatureMode::Closure
+    } else {
+        FeatureMode::Shallow
+    };
+    let overrides: Overrides = tss_metadata(&cargo_toml, &cargo_toml_path, "naming")?;
+    let deprecations: Deprecations = tss_metadata(&cargo_toml, &cargo_toml_path, "deprecated")?;
+
+    let mut outputs = Vec::new();
+    let mut features = BTreeMap::new();
+    let mut generated = Vec::new();
+    for release in RELEASES {
+        let (grammar, source, version) = load(release)?;
+        let names = resolve_names(grammar.node_types(), &overrides)
+            .map_err(|e| Error::Naming(source.clone(), e))?;
+        let aliases = if release.dir == RELEASES[0].dir {
+            resolve_aliases(grammar.node_types(), &names, &deprecations)
+        } else {
+            resolve_aliases(
+                grammar.node_types(),
+                &names,
+                &applicable(&grammar, &deprecations),
+            )
+        }
+        .map_err(|e| Error::Naming(source.clone(), e))?;
+        let queries = generator::Queries {
+            highlights: highlights_query(
+                &format!("tree-sitter-rust {} HIGHLIGHTS_QUERY", release.pinned),
+                release.highlights,
+            )?,
+        };
+        let mut nodes = Vec::new();
+        let mut meta = Vec::new();
+        let release_features = generator::generate(
+            &mut nodes, &mut meta, &grammar, &names, &aliases, &queries, mode,
+        )
+        .map_err(|e| Error::Io(crate_dir.join("src/generated"), e))?;
+        for feature in release_features {
+            features.entry(feature.name.clone()).or_insert(feature);
+        }
+        generated.push(Generated {
+            release,
+            source,
+            version,
+            files: [
+                ("nodes.rs", String::from_utf8_lossy(&nodes).into_owned()),
+                ("meta.rs", String::from_utf8_lossy(&meta).into_owned()),
+            ],
+        });
+    }
+    outputs.extend(shared_outputs(&crate_dir.join("src/generated"), &generated));
+
+    let features: Vec<_> = features.into_values().collect();
+    let contents = feature_toml::with_features(&cargo_toml, &features)
+        .map_err(|e| Error::Features(cargo_toml_path.clone(), e))?;
+    outputs.push(Output {
+        path: cargo_toml_path,
+        contents,
+    });
+    Ok(outputs)
+}
+
+/// How the generated features block of a `Cargo.toml` differs from the expected one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureDiff {
+    /// Features that should be in the block but are not.
+    pub missing: Vec<String>,
+    /// Features in the block that should not be.
+    pub extra: Vec<String>,
+    /// Features whose dependencies differ, with the current and expected dependencies.
+    pub changed: Vec<(String, Vec<String>, Vec<String>)>,
+}
+
+impl FeatureDiff {
+    /// Compare the generated features block of the `Cargo.toml` contents `current` with that of
+    /// `expected`, or `None` if either has no such block.
+    #[must_use]
+    pub fn between(current: &str, expected: &str) -> Option<Self> {
+        let current = feature_toml::features_block(current)?;
+        let expected = feature_toml::features_block(expected)?;
+        let mut diff = Self::default();
+        for (name, deps) in &expected {
+            match current.get(name) {
+                None => diff.missing.push(name.clone()),
+                Some(current_deps) if current_deps != deps => {
+                    diff.changed
+                        .push((name.clone(), current_deps.clone(), deps.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        diff.extra = current
+            .keys()
+            .filter(|name| !expected.contains_key(*name))
+            .cloned()
+            .collect();
+        Some(diff)
+    }
+
+    /// Whether the blocks hold the same features with the same dependencies.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for FeatureDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for name in &self.missing {
+            writeln!(f, "missing feature {name}")?;
+        }
+        for name in &self.extra {
+            writeln!(f, "extra feature {name}")?;
+        }
+        for (name, current, expected) in &self.changed {
+            writeln!(f, "changed feature {name}: {current:?} -> {expected:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The outputs whose files are missing or differ from what was rendered.
+#[must_use]
+pub fn stale(outputs: &[Output]) -> Vec<&Output> {
+    outputs
+        .iter()
+        .filter(|output| {
+            std::fs::read_to_string(&output.path).ok().as_ref() != Some(&output.contents)
+        })
+        .collect()
+}
+
+/// The files under `src/generated/` in `crate_dir` that none of `outputs` is for, such as a
+/// release's own copy of a file it now shares with an earlier release.
+#[must_use]
+pub fn obsolete(crate_dir: &Path, outputs: &[Output]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![crate_dir.join("src/generated")];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if !outputs.iter().any(|output| output.path == path) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Remove the obsolete files, and any directories they leave empty, returning the paths removed.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be removed.
+pub fn remove_obsolete(crate_dir: &Path, outputs: &[Output]) -> Result<Vec<PathBuf>, Error> {
+    let removed = obsolete(crate_dir, outputs);
+    for path in &removed {
+        std::fs::remove_file(path).map_err(|e| Error::Io(path.clone(), e))?;
+        if let Some(dir) = path.parent() {
+            // Fails, harmlessly, unless the directory is now empty
+            let _ = std::fs::remove_dir(dir);
+        }
+    }
+    Ok(removed)
+}
+
+/// Write the stale outputs, returning the paths written.
+///
+/// # Errors
+///
+/// Returns an error if a file or its directory cannot be written.
+pub fn write(outputs: &[Output]) -> Result<Vec<PathBuf>, Error> {
+    let mut written = Vec::new();
+    for output in stale(outputs) {
+        if let Some(dir) = output.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| Error::Io(dir.to_path_buf(), e))?;
+        }
+        std::fs::write(&output.path, &output.contents)
+            .map_err(|e| Error::Io(output.path.clone(), e))?;
+        written.push(output.path.clone());
+    }
+    Ok(written)
+}
+
+/// The `tss-rust` crate directory in this workspace.
+#[must_use]
+pub fn tss_rust_dir() -> PathBuf {
+    let dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../tss-rust"));
+    dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf())
+}
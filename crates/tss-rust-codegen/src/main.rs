@@ -0,0 +1,82 @@
+//! Regenerate the committed `tss-rust` code, or check that it is up to date with `--check`.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+// Every supported tree-sitter-rust release is a dependency
+#![allow(clippy::multiple_crate_versions)]
+
+use std::process::ExitCode;
+use tree_sitter_symbols_rust_codegen::{
+    obsolete, remove_obsolete, render, stale, tss_rust_dir, write, RELEASES,
+};
+
+fn main() -> ExitCode {
+    let check = match std::env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(arg) => {
+            eprintln!("unexpected argument {arg:?}\nusage: tss-rust-codegen [--check]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let crate_dir = tss_rust_dir();
+    let outputs = match render(&crate_dir) {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if check {
+        let stale = stale(&outputs);
+        for output in &stale {
+            println!("stale: {}", output.path.display());
+            match output.feature_diff() {
+                Some(diff) if diff.is_empty() => {
+                    println!("  the features are the same but formatted differently");
+                }
+                Some(diff) => {
+                    for line in diff.to_string().lines() {
+                        println!("  {line}");
+                    }
+                }
+                None => {}
+            }
+        }
+        let obsolete = obsolete(&crate_dir, &outputs);
+        for path in &obsolete {
+            println!("obsolete: {}", path.display());
+        }
+        if stale.is_empty() && obsolete.is_empty() {
+            ExitCode::SUCCESS
+        } else {
+            let releases: Vec<_> = RELEASES.iter().map(|release| release.pinned).collect();
+            eprintln!(
+                "compared against tree-sitter-rust {}; run `cargo run -p tss-rust-codegen` to \
+                 regenerate",
+                releases.join(", ")
+            );
+            ExitCode::FAILURE
+        }
+    } else {
+        match write(&outputs)
+            .and_then(|written| Ok((written, remove_obsolete(&crate_dir, &outputs)?)))
+        {
+            Ok((written, removed)) => {
+                for path in written {
+                    println!("wrote {}", path.display());
+                }
+                for path in removed {
+                    println!("removed {}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::from(2)
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use super::examples::generate_examples;
+use super::generator::Queries;
+use super::highlights::generate_highlights;
+use super::reference::generate_docs;
+use super::schema::{self, NodeType};
+use super::tokens::generate_tokens;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+use tree_sitter_symbols_schema::naming::snake_to_pascal;
+
+/// Write the metadata tables that back the `meta_*` features.
+///
+/// Field and children types are emitted as kind strings (`TypeRef`) rather than `NodeType`
+/// variants, so the tables stay complete whichever node type features are enabled.
+pub fn generate_meta<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+    queries: &Queries,
+) -> io::Result<()> {
+    let field_names = collect_field_names(node_types);
+
+    generate_field_name(f, &field_names)?;
+    generate_is_named(f, node_types, variant_map, gates)?;
+    generate_is_extra(f, node_types, variant_map, gates)?;
+    generate_fields(f, node_types, variant_map, gates)?;
+    generate_children(f, node_types, variant_map, gates)?;
+    generate_subtypes(f, node_types, variant_map, gates)?;
+    generate_subtypes_of(f, node_types)?;
+    generate_tokens(f, node_types, variant_map, gates)?;
+    generate_docs(f, node_types, variant_map, gates)?;
+    generate_examples(f, node_types, variant_map, gates)?;
+    generate_highlights(f, node_types, variant_map, gates, &queries.highlights)?;
+    Ok(())
+}
+
+fn collect_field_names(node_types: &[NodeType]) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    for node_type in node_types {
+        if let Some(fields) = &node_type.fields {
+            names.extend(fields.keys().cloned());
+        }
+    }
+    names.into_iter().collect()
+}
+
+fn type_ref(kind: &str, named: bool) -> String {
+    format!("TypeRef {{ kind: {kind:?}, named: {named} }}")
+}
+
+fn variant_cfg(feat: &str) -> String {
+    format!("#[cfg(any(feature = \"{feat}\", feature = \"node_full\"))]")
+}
+
+fn generate_field_name<W: Write>(f: &mut W, field_names: &[String]) -> io::Result<()> {
+    writeln!(f, "/// Field names used by tree-sitter-rust nodes")?;
+    writeln!(f, "#[cfg(feature = \"meta_fields\")]")?;
+    writeln!(f, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]")?;
+    writeln!(f, "pub enum FieldName {{")?;
+    for name in field_names {
+        writeln!(f, "    /// `{name}`")?;
+        writeln!(f, "    {},", snake_to_pascal(name))?;
+    }
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_fields\")]")?;
+    writeln!(f, "impl FieldName {{")?;
+    writeln!(f, "    /// The field name as it appears in the grammar.")?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    pub const fn as_str(self) -> &'static str {{")?;
+    writeln!(f, "        match self {{")?;
+    for name in field_names {
+        writeln!(
+            f,
+            "            Self::{} => {name:?},",
+            snake_to_pascal(name)
+        )?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_fields\")]")?;
+    writeln!(f, "impl std::str::FromStr for FieldName {{")?;
+    writeln!(f, "    type Err = String;")?;
+    writeln!(f)?;
+    writeln!(f, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{")?;
+    writeln!(f, "        match s {{")?;
+    for name in field_names {
+        writeln!(
+            f,
+            "            {name:?} => Ok(Self::{}),",
+            snake_to_pascal(name)
+        )?;
+    }
+    writeln!(
+        f,
+        "            _ => Err(format!(\"Unknown field name: {{s}}\")),"
+    )?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    writeln!(f, "#[cfg(feature = \"meta_fields\")]")?;
+    writeln!(f, "impl std::fmt::Display for FieldName {{")?;
+    writeln!(
+        f,
+        "    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )?;
+    writeln!(f, "        f.write_str(self.as_str())")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_is_named<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_named\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    writeln!(
+        f,
+        "    /// Whether this node is named in the grammar (unnamed nodes are literal tokens)."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(f, "    pub const fn is_named(self) -> bool {{")?;
+    writeln!(f, "        match self {{")?;
+    for (i, (_, variant_name)) in variant_map.iter().enumerate() {
+        let named = node_types[i].named;
+        writeln!(f, "            {}", variant_cfg(&gates[i]))?;
+        writeln!(f, "            Self::{variant_name} => {named},")?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+/// `is_extra` is true for the kinds `node-types.json` marks with the `extra` flag. Releases
+/// older than tree-sitter 0.24 do not write the flag, so for them it falls back to
+/// [`schema::FALLBACK_EXTRAS`].
+fn generate_is_extra<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    let marked = schema::marks_extras(node_types);
+    writeln!(f, "#[cfg(feature = \"meta_extra\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    write_match(
+        f,
+        &["/// Whether this node is an extra, which can appear anywhere in the tree (comments)."],
+        "is_extra(self) -> bool",
+        variant_map
+            .iter()
+            .zip(node_types)
+            .zip(gates)
+            .filter(|((_, node_type), _)| node_type.is_extra(marked))
+            .map(|(((_, variant), _), gate)| (variant, gate, "true".to_string())),
+        "false",
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_fields<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_fields\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    writeln!(
+        f,
+        "    /// The named fields this node can have, sorted by field name."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(f, "    pub const fn fields(self) -> &'static [Field] {{")?;
+    writeln!(f, "        match self {{")?;
+    for (i, (_, variant_name)) in variant_map.iter().enumerate() {
+        let node_type = &node_types[i];
+        writeln!(f, "            {}", variant_cfg(&gates[i]))?;
+        let Some(fields) = &node_type.fields else {
+            writeln!(f, "            Self::{variant_name} => &[],")?;
+            continue;
+        };
+        let mut names: Vec<&String> = fields.keys().collect();
+        names.sort();
+        writeln!(f, "            Self::{variant_name} => &[")?;
+        for name in names {
+            let field = &fields[name];
+            let types: Vec<String> = field
+                .types
+                .iter()
+                .map(|t| type_ref(&t.field_type_name, t.named))
+                .collect();
+            writeln!(f, "                Field {{")?;
+            writeln!(
+                f,
+                "                    name: FieldName::{},",
+                snake_to_pascal(name)
+            )?;
+            writeln!(f, "                    multiple: {},", field.multiple)?;
+            writeln!(f, "                    required: {},", field.required)?;
+            writeln!(f, "                    types: &[{}],", types.join(", "))?;
+            writeln!(f, "                }},")?;
+        }
+        writeln!(f, "            ],")?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_children<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_children\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    writeln!(
+        f,
+        "    /// The children this node can have outside of its named fields."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        f,
+        "    pub const fn children(self) -> Option<&'static Children> {{"
+    )?;
+    writeln!(f, "        match self {{")?;
+    for (i, (_, variant_name)) in variant_map.iter().enumerate() {
+        let node_type = &node_types[i];
+        writeln!(f, "            {}", variant_cfg(&gates[i]))?;
+        let Some(children) = &node_type.children else {
+            writeln!(f, "            Self::{variant_name} => None,")?;
+            continue;
+        };
+        let types: Vec<String> = children
+            .types
+            .iter()
+            .map(|t| type_ref(&t.child_type_name, t.named))
+            .collect();
+        writeln!(f, "            Self::{variant_name} => Some(&Children {{")?;
+        writeln!(f, "                multiple: {},", children.multiple)?;
+        writeln!(f, "                required: {},", children.required)?;
+        writeln!(f, "                types: &[{}],", types.join(", "))?;
+        writeln!(f, "            }}),")?;
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_subtypes<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_subtypes\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    writeln!(
+        f,
+        "    /// The direct subtypes of this node, if it is a supertype (e.g. `_expression`)."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(
+        f,
+        "    pub const fn subtypes(self) -> &'static [TypeRef] {{"
+    )?;
+    writeln!(f, "        match self {{")?;
+    for (i, (original, variant_name)) in variant_map.iter().enumerate() {
+        let node_type = &node_types[i];
+        writeln!(f, "            {}", variant_cfg(&gates[i]))?;
+        if node_type.subtypes.is_some() {
+            writeln!(
+                f,
+                "            Self::{variant_name} => subtypes_of({original:?}),"
+            )?;
+        } else {
+            writeln!(f, "            Self::{variant_name} => &[],")?;
+        }
+    }
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+fn generate_subtypes_of<W: Write>(f: &mut W, node_types: &[NodeType]) -> io::Result<()> {
+    writeln!(
+        f,
+        "/// The direct subtypes of the supertype `kind`, or an empty slice for any other kind."
+    )?;
+    writeln!(f, "///")?;
+    writeln!(
+        f,
+        "/// Unlike [`NodeType::subtypes`](crate::NodeType::subtypes), this lookup does not depend"
+    )?;
+    writeln!(f, "/// on which node type features are enabled.")?;
+    writeln!(f, "#[cfg(feature = \"meta_subtypes\")]")?;
+    writeln!(f, "#[must_use]")?;
+    writeln!(
+        f,
+        "pub const fn subtypes_of(kind: &str) -> &'static [TypeRef] {{"
+    )?;
+    writeln!(f, "    match kind.as_bytes() {{")?;
+    for node_type in node_types {
+        let Some(subtypes) = &node_type.subtypes else {
+            continue;
+        };
+        let types: Vec<String> = subtypes
+            .iter()
+            .map(|t| type_ref(&t.subchild_type_name, t.named))
+            .collect();
+        writeln!(
+            f,
+            "        b{:?} => &[{}],",
+            node_type.node_type_name,
+            types.join(", ")
+        )?;
+    }
+    writeln!(f, "        _ => &[],")?;
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+/// Write a `const fn` matching `self` against cfg-gated arms, with a fallback for the rest.
+pub fn write_match<'a, W: Write>(
+    f: &mut W,
+    docs: &[&str],
+    signature: &str,
+    arms: impl Iterator<Item = (&'a String, &'a String, String)>,
+    fallback: &str,
+) -> io::Result<()> {
+    for doc in docs {
+        writeln!(f, "    {doc}")?;
+    }
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(clippy::match_same_arms)]")?;
+    writeln!(f, "    #[allow(clippy::too_many_lines)]")?;
+    writeln!(f, "    #[allow(unreachable_patterns)]")?;
+    writeln!(f, "    pub const fn {signature} {{")?;
+    writeln!(f, "        match self {{")?;
+    for (variant, gate, value) in arms {
+        writeln!(
+            f,
+            "            #[cfg(any(feature = \"{gate}\", feature = \"node_full\"))]"
+        )?;
+        writeln!(f, "            Self::{variant} => {value},")?;
+    }
+    writeln!(f, "            _ => {fallback},")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    Ok(())
+}
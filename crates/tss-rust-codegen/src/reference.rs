@@ -0,0 +1,760 @@
+use super::meta::write_match;
+use super::schema::NodeType;
+use super::tokens::category;
+use std::io::{self, Write};
+
+/// The base URL of the Rust Reference.
+pub const BASE: &str = "https://doc.rust-lang.org/reference/";
+
+/// A Rust Reference section describing a node kind.
+pub struct Section {
+    /// The section title, used as the link text.
+    pub title: &'static str,
+    /// The page (and anchor) relative to [`BASE`].
+    pub path: &'static str,
+    /// The grammar production from the section, if it has one.
+    pub grammar: Option<&'static str>,
+}
+
+const fn section(
+    title: &'static str,
+    path: &'static str,
+    grammar: Option<&'static str>,
+) -> Section {
+    Section {
+        title,
+        path,
+        grammar,
+    }
+}
+
+/// The Rust Reference section for a node kind.
+///
+/// Named kinds map to the section for the construct they parse; keyword, operator and comment
+/// tokens map to the lexical sections that list them.
+#[allow(clippy::too_many_lines)]
+pub fn section_for(kind: &str, named: bool) -> Option<Section> {
+    if !named {
+        return match category(kind, named) {
+            "Keyword" => Some(section("Keywords", "keywords.html", None)),
+            "Operator" | "Punctuation" => {
+                Some(section("Punctuation", "tokens.html#punctuation", None))
+            }
+            "Comment" => Some(section("Comments", "comments.html", None)),
+            _ => None,
+        };
+    }
+    Some(match kind {
+        "source_file" => section(
+            "Crates and source files",
+            "crates-and-source-files.html",
+            Some("Crate : InnerAttribute* Item*"),
+        ),
+        "shebang" => section("Shebang removal", "input-format.html#shebang-removal", None),
+
+        // Items
+        "_declaration_statement" => section(
+            "Items",
+            "items.html",
+            Some("Item : OuterAttribute* ( VisItem | MacroItem )"),
+        ),
+        "function_item" | "function_signature_item" => section(
+            "Functions",
+            "items/functions.html",
+            Some("Function : FunctionQualifiers fn IDENTIFIER GenericParams? ( FunctionParameters? ) FunctionReturnType? WhereClause? ( BlockExpression | ; )"),
+        ),
+        "function_modifiers" => section(
+            "Function qualifiers",
+            "items/functions.html",
+            Some("FunctionQualifiers : const? async? ItemSafety? ( extern Abi? )?"),
+        ),
+        "extern_modifier" => section(
+            "Extern function qualifier",
+            "items/functions.html#extern-function-qualifier",
+            Some("extern Abi?"),
+        ),
+        "parameters" => section(
+            "Function parameters",
+            "items/functions.html#function-parameters",
+            Some("FunctionParameters : SelfParam ,? | ( SelfParam , )? FunctionParam ( , FunctionParam )* ,?"),
+        ),
+        "parameter" => section(
+            "Function parameters",
+            "items/functions.html#function-parameters",
+            Some("FunctionParam : OuterAttribute* ( FunctionParamPattern | ... | Type )"),
+        ),
+        "self_parameter" => section(
+            "Methods",
+            "items/associated-items.html#methods",
+            Some("SelfParam : OuterAttribute* ( ShorthandSelf | TypedSelf )"),
+        ),
+        "variadic_parameter" => section(
+            "Variadic functions",
+            "items/external-blocks.html#variadic-functions",
+            Some("NamedFunctionParametersWithVariadics : ( NamedFunctionParam , )* NamedFunctionParam , OuterAttribute* ..."),
+        ),
+        "struct_item" => section(
+            "Structs",
+            "items/structs.html",
+            Some("Struct : StructStruct | TupleStruct"),
+        ),
+        "field_declaration_list" => section(
+            "Structs",
+            "items/structs.html",
+            Some("StructFields : StructField ( , StructField )* ,?"),
+        ),
+        "field_declaration" => section(
+            "Structs",
+            "items/structs.html",
+            Some("StructField : OuterAttribute* Visibility? IDENTIFIER : Type"),
+        ),
+        "ordered_field_declaration_list" => section(
+            "Structs",
+            "items/structs.html",
+            Some("TupleFields : TupleField ( , TupleField )* ,?"),
+        ),
+        "enum_item" => section(
+            "Enumerations",
+            "items/enumerations.html",
+            Some("Enumeration : enum IDENTIFIER GenericParams? WhereClause? { EnumItems? }"),
+        ),
+        "enum_variant_list" => section(
+            "Enumerations",
+            "items/enumerations.html",
+            Some("EnumItems : EnumItem ( , EnumItem )* ,?"),
+        ),
+        "enum_variant" => section(
+            "Enumerations",
+            "items/enumerations.html",
+            Some("EnumItem : OuterAttribute* Visibility? IDENTIFIER ( EnumItemTuple | EnumItemStruct )? EnumItemDiscriminant?"),
+        ),
+        "union_item" => section(
+            "Unions",
+            "items/unions.html",
+            Some("Union : union IDENTIFIER GenericParams? WhereClause? { StructFields? }"),
+        ),
+        "impl_item" => section(
+            "Implementations",
+            "items/implementations.html",
+            Some("Implementation : InherentImpl | TraitImpl"),
+        ),
+        "trait_item" => section(
+            "Traits",
+            "items/traits.html",
+            Some("Trait : unsafe? trait IDENTIFIER GenericParams? ( : TypeParamBounds? )? WhereClause? { InnerAttribute* AssociatedItem* }"),
+        ),
+        "declaration_list" => section(
+            "Associated items",
+            "items/associated-items.html",
+            Some("AssociatedItem : OuterAttribute* ( MacroInvocationSemi | ( Visibility? ( TypeAlias | ConstantItem | Function ) ) )"),
+        ),
+        "associated_type" => section(
+            "Associated types",
+            "items/associated-items.html#associated-types",
+            Some("TypeAlias : type IDENTIFIER GenericParams? ( : TypeParamBounds )? WhereClause? ( = Type WhereClause? )? ;"),
+        ),
+        "type_item" => section(
+            "Type aliases",
+            "items/type-aliases.html",
+            Some("TypeAlias : type IDENTIFIER GenericParams? ( : TypeParamBounds )? WhereClause? ( = Type WhereClause? )? ;"),
+        ),
+        "const_item" => section(
+            "Constant items",
+            "items/constant-items.html",
+            Some("ConstantItem : const ( IDENTIFIER | _ ) : Type ( = Expression )? ;"),
+        ),
+        "static_item" => section(
+            "Static items",
+            "items/static-items.html",
+            Some("StaticItem : ItemSafety? static mut? IDENTIFIER : Type ( = Expression )? ;"),
+        ),
+        "mod_item" => section(
+            "Modules",
+            "items/modules.html",
+            Some("Module : unsafe? mod IDENTIFIER ; | unsafe? mod IDENTIFIER { InnerAttribute* Item* }"),
+        ),
+        "foreign_mod_item" => section(
+            "External blocks",
+            "items/external-blocks.html",
+            Some("ExternBlock : unsafe? extern Abi? { InnerAttribute* ExternalItem* }"),
+        ),
+        "extern_crate_declaration" => section(
+            "Extern crate declarations",
+            "items/extern-crates.html",
+            Some("ExternCrate : extern crate CrateRef AsClause? ;"),
+        ),
+        "use_declaration" => section(
+            "Use declarations",
+            "items/use-declarations.html",
+            Some("UseDeclaration : use UseTree ;"),
+        ),
+        "use_list" | "scoped_use_list" | "use_as_clause" | "use_wildcard" => section(
+            "Use declarations",
+            "items/use-declarations.html",
+            Some("UseTree : ( SimplePath? :: )? * | ( SimplePath? :: )? { ( UseTree ( , UseTree )* ,? )? } | SimplePath ( as ( IDENTIFIER | _ ) )?"),
+        ),
+
+        // Macros
+        "macro_invocation" => section(
+            "Macro invocation",
+            "macros.html#macro-invocation",
+            Some("MacroInvocation : SimplePath ! DelimTokenTree"),
+        ),
+        "token_tree" | "token_repetition" => section(
+            "Macro invocation",
+            "macros.html#macro-invocation",
+            Some("DelimTokenTree : ( TokenTree* ) | [ TokenTree* ] | { TokenTree* }"),
+        ),
+        "macro_definition" => section(
+            "Macros by example",
+            "macros-by-example.html",
+            Some("MacroRulesDefinition : macro_rules ! IDENTIFIER MacroRulesDef"),
+        ),
+        "macro_rule" => section(
+            "Macros by example",
+            "macros-by-example.html",
+            Some("MacroRule : MacroMatcher => MacroTranscriber"),
+        ),
+        "token_tree_pattern" => section(
+            "Macros by example",
+            "macros-by-example.html",
+            Some("MacroMatcher : ( MacroMatch* ) | [ MacroMatch* ] | { MacroMatch* }"),
+        ),
+        "token_binding_pattern" | "fragment_specifier" | "metavariable" => section(
+            "Metavariables",
+            "macros-by-example.html#metavariables",
+            Some("$ ( IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER | _ ) : MacroFragSpec"),
+        ),
+        "token_repetition_pattern" => section(
+            "Repetitions",
+            "macros-by-example.html#repetitions",
+            Some("$ ( MacroMatch+ ) MacroRepSep? MacroRepOp"),
+        ),
+
+        // Attributes and visibility
+        "attribute_item" => section(
+            "Attributes",
+            "attributes.html",
+            Some("OuterAttribute : # [ Attr ]"),
+        ),
+        "inner_attribute_item" => section(
+            "Attributes",
+            "attributes.html",
+            Some("InnerAttribute : # ! [ Attr ]"),
+        ),
+        "attribute" => section(
+            "Attributes",
+            "attributes.html",
+            Some("Attr : SimplePath AttrInput? | unsafe ( SimplePath AttrInput? )"),
+        ),
+        "visibility_modifier" => section(
+            "Visibility and privacy",
+            "visibility-and-privacy.html",
+            Some("Visibility : pub | pub ( crate ) | pub ( self ) | pub ( super ) | pub ( in SimplePath )"),
+        ),
+
+        // Generics and bounds
+        "type_parameters" => section(
+            "Generic parameters",
+            "items/generics.html",
+            Some("GenericParams : < ( GenericParam ( , GenericParam )* ,? )? >"),
+        ),
+        "type_parameter" => section(
+            "Generic parameters",
+            "items/generics.html",
+            Some("TypeParam : IDENTIFIER ( : TypeParamBounds? )? ( = Type )?"),
+        ),
+        "lifetime_parameter" => section(
+            "Generic parameters",
+            "items/generics.html",
+            Some("LifetimeParam : Lifetime ( : LifetimeBounds )?"),
+        ),
+        "const_parameter" => section(
+            "Const generics",
+            "items/generics.html#const-generics",
+            Some("ConstParam : const IDENTIFIER : Type ( = ( BlockExpression | IDENTIFIER | -? LiteralExpression ) )?"),
+        ),
+        "where_clause" => section(
+            "Where clauses",
+            "items/generics.html#where-clauses",
+            Some("WhereClause : where ( WhereClauseItem , )* WhereClauseItem?"),
+        ),
+        "where_predicate" => section(
+            "Where clauses",
+            "items/generics.html#where-clauses",
+            Some("WhereClauseItem : LifetimeWhereClauseItem | TypeBoundWhereClauseItem"),
+        ),
+        "trait_bounds" | "bounded_type" => section(
+            "Trait and lifetime bounds",
+            "trait-bounds.html",
+            Some("TypeParamBounds : TypeParamBound ( + TypeParamBound )* +?"),
+        ),
+        "removed_trait_bound" => section(
+            "Trait and lifetime bounds",
+            "trait-bounds.html",
+            Some("TraitBound : ( ? | ForLifetimes )? TypePath"),
+        ),
+        "higher_ranked_trait_bound" | "for_lifetimes" => section(
+            "Higher-ranked trait bounds",
+            "trait-bounds.html#higher-ranked-trait-bounds",
+            Some("ForLifetimes : for GenericParams"),
+        ),
+        "use_bounds" => section(
+            "Use bounds",
+            "trait-bounds.html#use-bounds",
+            Some("UseBound : use UseBoundGenericArgs"),
+        ),
+        "lifetime" => section(
+            "Lifetimes and loop labels",
+            "tokens.html#lifetimes-and-loop-labels",
+            Some("LIFETIME_OR_LABEL : ' NON_KEYWORD_IDENTIFIER"),
+        ),
+        "label" => section(
+            "Loop labels",
+            "expressions/loop-expr.html#loop-labels",
+            Some("LoopLabel : LIFETIME_OR_LABEL :"),
+        ),
+
+        // Paths
+        "scoped_identifier" | "generic_function" => section(
+            "Paths in expressions",
+            "paths.html#paths-in-expressions",
+            Some("PathInExpression : ::? PathExprSegment ( :: PathExprSegment )*"),
+        ),
+        "type_arguments" => section(
+            "Paths in expressions",
+            "paths.html#paths-in-expressions",
+            Some("GenericArgs : < > | < ( GenericArg , )* GenericArg ,? >"),
+        ),
+        "type_binding" => section(
+            "Paths in expressions",
+            "paths.html#paths-in-expressions",
+            Some("GenericArgsBinding : IDENTIFIER GenericArgs? = Type"),
+        ),
+        "scoped_type_identifier" | "generic_type" | "generic_type_with_turbofish" => section(
+            "Paths in types",
+            "paths.html#paths-in-types",
+            Some("TypePath : ::? TypePathSegment ( :: TypePathSegment )*"),
+        ),
+        "qualified_type" | "bracketed_type" => section(
+            "Qualified paths",
+            "paths.html#qualified-paths",
+            Some("QualifiedPathType : < Type ( as TypePath )? >"),
+        ),
+        "crate" | "self" | "super" => section("Path qualifiers", "paths.html#path-qualifiers", None),
+        "identifier" | "type_identifier" | "field_identifier" | "shorthand_field_identifier" => {
+            section(
+                "Identifiers",
+                "identifiers.html",
+                Some("IDENTIFIER : IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER"),
+            )
+        }
+
+        // Types
+        "_type" => section(
+            "Types",
+            "types.html",
+            Some("Type : TypeNoBounds | ImplTraitType | TraitObjectType"),
+        ),
+        "primitive_type" => section("Types", "types.html", None),
+        "array_type" => section(
+            "Array types",
+            "types/array.html",
+            Some("ArrayType : [ Type ; Expression ]"),
+        ),
+        "tuple_type" | "unit_type" => section(
+            "Tuple types",
+            "types/tuple.html",
+            Some("TupleType : ( ) | ( ( Type , )+ Type? )"),
+        ),
+        "reference_type" => section(
+            "References",
+            "types/pointer.html#references--and-mut",
+            Some("ReferenceType : & Lifetime? mut? TypeNoBounds"),
+        ),
+        "pointer_type" => section(
+            "Raw pointers",
+            "types/pointer.html#raw-pointers-const-and-mut",
+            Some("RawPointerType : * ( mut | const ) TypeNoBounds"),
+        ),
+        "function_type" => section(
+            "Function pointer types",
+            "types/function-pointer.html",
+            Some("BareFunctionType : ForLifetimes? FunctionTypeQualifiers fn ( FunctionParametersMaybeNamedVariadic? ) BareFunctionReturnType?"),
+        ),
+        "never_type" => section("Never type", "types/never.html", Some("NeverType : !")),
+        "dynamic_type" => section(
+            "Trait objects",
+            "types/trait-object.html",
+            Some("TraitObjectType : dyn? TypeParamBounds"),
+        ),
+        "abstract_type" => section(
+            "Impl trait",
+            "types/impl-trait.html",
+            Some("ImplTraitType : impl TypeParamBounds"),
+        ),
+        "mutable_specifier" => section(
+            "Identifier patterns",
+            "patterns.html#identifier-patterns",
+            None,
+        ),
+
+        // Statements
+        "let_declaration" => section(
+            "Let statements",
+            "statements.html#let-statements",
+            Some("LetStatement : OuterAttribute* let PatternNoTopAlt ( : Type )? ( = Expression ( else BlockExpression )? )? ;"),
+        ),
+        "expression_statement" => section(
+            "Expression statements",
+            "statements.html#expression-statements",
+            Some("ExpressionStatement : ExpressionWithoutBlock ; | ExpressionWithBlock ;?"),
+        ),
+        "empty_statement" => section("Statements", "statements.html", Some("Statement : ;")),
+
+        // Expressions
+        "_expression" => section(
+            "Expressions",
+            "expressions.html",
+            Some("Expression : ExpressionWithoutBlock | ExpressionWithBlock"),
+        ),
+        "binary_expression" => section(
+            "Operator expressions",
+            "expressions/operator-expr.html",
+            Some("ArithmeticOrLogicalExpression | ComparisonExpression | LazyBooleanExpression"),
+        ),
+        "unary_expression" => section(
+            "Negation operators",
+            "expressions/operator-expr.html#negation-operators",
+            Some("NegationExpression : - Expression | ! Expression"),
+        ),
+        "reference_expression" => section(
+            "Borrow operators",
+            "expressions/operator-expr.html#borrow-operators",
+            Some("BorrowExpression : ( & | && ) Expression | ( & | && ) mut Expression"),
+        ),
+        "assignment_expression" => section(
+            "Assignment expressions",
+            "expressions/operator-expr.html#assignment-expressions",
+            Some("AssignmentExpression : Expression = Expression"),
+        ),
+        "compound_assignment_expr" => section(
+            "Compound assignment expressions",
+            "expressions/operator-expr.html#compound-assignment-expressions",
+            Some("CompoundAssignmentExpression : Expression += Expression | Expression -= Expression | ..."),
+        ),
+        "type_cast_expression" => section(
+            "Type cast expressions",
+            "expressions/operator-expr.html#type-cast-expressions",
+            Some("TypeCastExpression : Expression as TypeNoBounds"),
+        ),
+        "try_expression" => section(
+            "The question mark operator",
+            "expressions/operator-expr.html#the-question-mark-operator",
+            Some("ErrorPropagationExpression : Expression ?"),
+        ),
+        "call_expression" => section(
+            "Call expressions",
+            "expressions/call-expr.html",
+            Some("CallExpression : Expression ( CallParams? )"),
+        ),
+        "arguments" => section(
+            "Call expressions",
+            "expressions/call-expr.html",
+            Some("CallParams : Expression ( , Expression )* ,?"),
+        ),
+        "field_expression" => section(
+            "Field access expressions",
+            "expressions/field-expr.html",
+            Some("FieldExpression : Expression . IDENTIFIER"),
+        ),
+        "index_expression" => section(
+            "Array and slice indexing expressions",
+            "expressions/array-expr.html#array-and-slice-indexing-expressions",
+            Some("IndexExpression : Expression [ Expression ]"),
+        ),
+        "array_expression" => section(
+            "Array expressions",
+            "expressions/array-expr.html",
+            Some("ArrayExpression : [ ArrayElements? ]"),
+        ),
+        "tuple_expression" | "unit_expression" => section(
+            "Tuple expressions",
+            "expressions/tuple-expr.html",
+            Some("TupleExpression : ( TupleElements? )"),
+        ),
+        "parenthesized_expression" => section(
+            "Grouped expressions",
+            "expressions/grouped-expr.html",
+            Some("GroupedExpression : ( Expression )"),
+        ),
+        "struct_expression" => section(
+            "Struct expressions",
+            "expressions/struct-expr.html",
+            Some("StructExpression : PathInExpression { ( StructExprFields | StructBase )? }"),
+        ),
+        "field_initializer_list" => section(
+            "Struct expressions",
+            "expressions/struct-expr.html",
+            Some("StructExprFields : StructExprField ( , StructExprField )* ( , StructBase | ,? )"),
+        ),
+        "field_initializer" | "shorthand_field_initializer" => section(
+            "Struct expressions",
+            "expressions/struct-expr.html",
+            Some("StructExprField : OuterAttribute* ( IDENTIFIER | ( IDENTIFIER | TUPLE_INDEX ) : Expression )"),
+        ),
+        "base_field_initializer" => section(
+            "Functional update syntax",
+            "expressions/struct-expr.html#functional-update-syntax",
+            Some("StructBase : .. Expression"),
+        ),
+        "closure_expression" => section(
+            "Closure expressions",
+            "expressions/closure-expr.html",
+            Some("ClosureExpression : async? move? ( || | | ClosureParameters? | ) ( Expression | -> TypeNoBounds BlockExpression )"),
+        ),
+        "closure_parameters" => section(
+            "Closure expressions",
+            "expressions/closure-expr.html",
+            Some("ClosureParameters : ClosureParam ( , ClosureParam )* ,?"),
+        ),
+        "block" => section(
+            "Block expressions",
+            "expressions/block-expr.html",
+            Some("BlockExpression : { InnerAttribute* Statements? }"),
+        ),
+        "async_block" => section(
+            "Async blocks",
+            "expressions/block-expr.html#async-blocks",
+            Some("AsyncBlockExpression : async move? BlockExpression"),
+        ),
+        "const_block" => section(
+            "Const blocks",
+            "expressions/block-expr.html#const-blocks",
+            Some("ConstBlockExpression : const BlockExpression"),
+        ),
+        "unsafe_block" => section(
+            "Unsafe blocks",
+            "expressions/block-expr.html#unsafe-blocks",
+            Some("UnsafeBlockExpression : unsafe BlockExpression"),
+        ),
+        "if_expression" | "else_clause" => section(
+            "If expressions",
+            "expressions/if-expr.html",
+            Some("IfExpression : if Conditions BlockExpression ( else ( BlockExpression | IfExpression ) )?"),
+        ),
+        "let_condition" | "let_chain" => section(
+            "Chains of conditions",
+            "expressions/if-expr.html#chains-of-conditions",
+            Some("Conditions : Expression | LetChain"),
+        ),
+        "match_expression" | "match_block" => section(
+            "Match expressions",
+            "expressions/match-expr.html",
+            Some("MatchExpression : match Scrutinee { InnerAttribute* MatchArms? }"),
+        ),
+        "match_arm" | "match_pattern" => section(
+            "Match expressions",
+            "expressions/match-expr.html",
+            Some("MatchArm : OuterAttribute* Pattern MatchArmGuard?"),
+        ),
+        "loop_expression" => section(
+            "Infinite loops",
+            "expressions/loop-expr.html#infinite-loops",
+            Some("InfiniteLoopExpression : loop BlockExpression"),
+        ),
+        "while_expression" => section(
+            "Predicate loops",
+            "expressions/loop-expr.html#predicate-loops",
+            Some("PredicateLoopExpression : while Conditions BlockExpression"),
+        ),
+        "for_expression" => section(
+            "Iterator loops",
+            "expressions/loop-expr.html#iterator-loops",
+            Some("IteratorLoopExpression : for Pattern in Expression BlockExpression"),
+        ),
+        "break_expression" => section(
+            "Break expressions",
+            "expressions/loop-expr.html#break-expressions",
+            Some("BreakExpression : break LIFETIME_OR_LABEL? Expression?"),
+        ),
+        "continue_expression" => section(
+            "Continue expressions",
+            "expressions/loop-expr.html#continue-expressions",
+            Some("ContinueExpression : continue LIFETIME_OR_LABEL?"),
+        ),
+        "return_expression" => section(
+            "Return expressions",
+            "expressions/return-expr.html",
+            Some("ReturnExpression : return Expression?"),
+        ),
+        "await_expression" => section(
+            "Await expressions",
+            "expressions/await-expr.html",
+            Some("AwaitExpression : Expression . await"),
+        ),
+        "range_expression" => section(
+            "Range expressions",
+            "expressions/range-expr.html",
+            Some("RangeExpression : RangeExpr | RangeFromExpr | RangeToExpr | RangeFullExpr | RangeInclusiveExpr | RangeToInclusiveExpr"),
+        ),
+
+        // Literals
+        "_literal" => section(
+            "Literal expressions",
+            "expressions/literal-expr.html",
+            Some("LiteralExpression : CHAR_LITERAL | STRING_LITERAL | RAW_STRING_LITERAL | BYTE_LITERAL | BYTE_STRING_LITERAL | RAW_BYTE_STRING_LITERAL | C_STRING_LITERAL | RAW_C_STRING_LITERAL | INTEGER_LITERAL | FLOAT_LITERAL | true | false"),
+        ),
+        "integer_literal" => section(
+            "Integer literals",
+            "tokens.html#integer-literals",
+            Some("INTEGER_LITERAL : ( DEC_LITERAL | BIN_LITERAL | OCT_LITERAL | HEX_LITERAL ) SUFFIX_NO_E?"),
+        ),
+        "float_literal" => section(
+            "Floating-point literals",
+            "tokens.html#floating-point-literals",
+            Some("FLOAT_LITERAL : DEC_LITERAL . | DEC_LITERAL . DEC_LITERAL SUFFIX_NO_E? | DEC_LITERAL ( . DEC_LITERAL )? FLOAT_EXPONENT SUFFIX?"),
+        ),
+        "string_literal" | "string_content" => section(
+            "String literals",
+            "tokens.html#string-literals",
+            Some("STRING_LITERAL : \" ( ~[\" \\ IsolatedCR] | QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE | STRING_CONTINUE )* \" SUFFIX?"),
+        ),
+        "raw_string_literal" => section(
+            "Raw string literals",
+            "tokens.html#raw-string-literals",
+            Some("RAW_STRING_LITERAL : r RAW_STRING_CONTENT SUFFIX?"),
+        ),
+        "char_literal" => section(
+            "Character literals",
+            "tokens.html#character-literals",
+            Some("CHAR_LITERAL : ' ( ~[' \\ LF CR TAB] | QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE ) ' SUFFIX?"),
+        ),
+        "boolean_literal" => section(
+            "Boolean literals",
+            "tokens.html#boolean-literals",
+            Some("true | false"),
+        ),
+        "escape_sequence" => section(
+            "Escapes",
+            "tokens.html#escapes",
+            Some("QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE"),
+        ),
+
+        // Patterns
+        "_pattern" | "or_pattern" => section(
+            "Patterns",
+            "patterns.html",
+            Some("Pattern : |? PatternNoTopAlt ( | PatternNoTopAlt )*"),
+        ),
+        "_literal_pattern" | "negative_literal" => section(
+            "Literal patterns",
+            "patterns.html#literal-patterns",
+            Some("LiteralPattern : true | false | CHAR_LITERAL | STRING_LITERAL | ... | -? INTEGER_LITERAL | -? FLOAT_LITERAL"),
+        ),
+        "ref_pattern" | "mut_pattern" | "captured_pattern" => section(
+            "Identifier patterns",
+            "patterns.html#identifier-patterns",
+            Some("IdentifierPattern : ref? mut? IDENTIFIER ( @ PatternNoTopAlt )?"),
+        ),
+        "tuple_pattern" => section(
+            "Tuple patterns",
+            "patterns.html#tuple-patterns",
+            Some("TuplePattern : ( TuplePatternItems? )"),
+        ),
+        "tuple_struct_pattern" => section(
+            "Tuple struct patterns",
+            "patterns.html#tuple-struct-patterns",
+            Some("TupleStructPattern : PathInExpression ( TupleStructItems? )"),
+        ),
+        "struct_pattern" => section(
+            "Struct patterns",
+            "patterns.html#struct-patterns",
+            Some("StructPattern : PathInExpression { StructPatternElements? }"),
+        ),
+        "field_pattern" => section(
+            "Struct patterns",
+            "patterns.html#struct-patterns",
+            Some("StructPatternField : OuterAttribute* ( TUPLE_INDEX : Pattern | IDENTIFIER : Pattern | ref? mut? IDENTIFIER )"),
+        ),
+        "remaining_field_pattern" => section(
+            "Rest patterns",
+            "patterns.html#rest-patterns",
+            Some("RestPattern : .."),
+        ),
+        "slice_pattern" => section(
+            "Slice patterns",
+            "patterns.html#slice-patterns",
+            Some("SlicePattern : [ SlicePatternItems? ]"),
+        ),
+        "range_pattern" => section(
+            "Range patterns",
+            "patterns.html#range-patterns",
+            Some("RangePattern : RangeInclusivePattern | RangeFromPattern | RangeToInclusivePattern | ObsoleteRangePattern"),
+        ),
+        "reference_pattern" => section(
+            "Reference patterns",
+            "patterns.html#reference-patterns",
+            Some("ReferencePattern : ( & | && ) mut? PatternWithoutRange"),
+        ),
+        "generic_pattern" => section(
+            "Path patterns",
+            "patterns.html#path-patterns",
+            Some("PathPattern : PathExpression"),
+        ),
+
+        // Comments
+        "line_comment" => section(
+            "Comments",
+            "comments.html",
+            Some("LINE_COMMENT : // ( ~[/ ! LF] | // ) ~LF* | //"),
+        ),
+        "block_comment" => section(
+            "Comments",
+            "comments.html",
+            Some("BLOCK_COMMENT : /* ( ~[* !] | ** | BlockCommentOrDoc ) ( BlockCommentOrDoc | ~*/ )* */ | /**/ | /***/"),
+        ),
+        "doc_comment" | "inner_doc_comment_marker" | "outer_doc_comment_marker" => section(
+            "Doc comments",
+            "comments.html#doc-comments",
+            Some("OUTER_LINE_DOC : /// ( ~/ ~[LF CR]* )?"),
+        ),
+
+        _ => return None,
+    })
+}
+
+/// The full URL of a node kind's Rust Reference section.
+pub fn reference_url(kind: &str, named: bool) -> Option<String> {
+    section_for(kind, named).map(|section| format!("{BASE}{}", section.path))
+}
+
+/// Write the `meta_docs` method `reference_url`.
+pub fn generate_docs<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    writeln!(f, "#[cfg(feature = \"meta_docs\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+    write_match(
+        f,
+        &[
+            "/// The Rust Reference section describing this node, for hover documentation.",
+            "///",
+            "/// Keyword, operator and comment tokens link to the lexical sections that list them.",
+        ],
+        "reference_url(self) -> Option<&'static str>",
+        variant_map.iter().zip(node_types).zip(gates).filter_map(
+            |(((original, variant), node_type), gate)| {
+                reference_url(original, node_type.named)
+                    .map(|url| (variant, gate, format!("Some({url:?})")))
+            },
+        ),
+        "None",
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
@@ -20,3 +20,18 @@ pub fn get_doc_info(node_type: &str) -> Option<(&'static str, &'static str)> {
         _ => None,
     }
 }
+
+/// The token group of a keyword or operator, used for the `group_*` features.
+///
+/// Splits the "operators and symbols" category of [`get_doc_info`] into operators and
+/// punctuation (delimiters, separators and other structural tokens).
+pub fn token_group(node_type: &str) -> Option<&'static str> {
+    match get_doc_info(node_type)? {
+        ("keywords", _) => Some("keywords"),
+        _ => match node_type {
+            "(" | ")" | "[" | "]" | "{" | "}" | "," | ";" | ":" | "::" | "." | "->" | "=>"
+            | "#" | "$" | "@" | "_" | "'" | "\"" | "//" | "/*" | "*/" => Some("punctuation"),
+            _ => Some("operators"),
+        },
+    }
+}
@@ -0,0 +1,89 @@
+use super::meta::write_match;
+use super::schema::NodeType;
+use std::io::{self, Write};
+
+/// The Universal Ctags kind and LSP `SymbolKind` variant of the node kinds that symbols and
+/// outline entries are reported for.
+///
+/// The ctags kinds are those of ctags' own Rust parser. Functions are mapped as functions; the
+/// writers report the ones inside an `impl` or trait as methods.
+pub fn symbol_kind(kind: &str, named: bool) -> Option<(&'static str, &'static str)> {
+    if !named {
+        return None;
+    }
+    Some(match kind {
+        "mod_item" => ("module", "Module"),
+        "struct_item" | "union_item" => ("struct", "Struct"),
+        "enum_item" => ("enum", "Enum"),
+        "enum_variant" => ("enumerator", "EnumMember"),
+        "trait_item" => ("interface", "Interface"),
+        "impl_item" => ("implementation", "Object"),
+        "function_item" | "function_signature_item" => ("function", "Function"),
+        "field_declaration" => ("field", "Field"),
+        "const_item" => ("variable", "Constant"),
+        "static_item" => ("variable", "Variable"),
+        "type_item" | "associated_type" => ("typedef", "TypeParameter"),
+        "macro_definition" => ("macro", "Function"),
+        _ => return None,
+    })
+}
+
+pub fn generate_symbol_kinds<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    let kinds: Vec<_> = node_types
+        .iter()
+        .map(|node_type| symbol_kind(&node_type.node_type_name, node_type.named))
+        .collect();
+
+    writeln!(f, "#[cfg(feature = \"symbols\")]")?;
+    writeln!(f, "impl NodeType {{")?;
+    write_match(
+        f,
+        &[
+            "/// The Universal Ctags kind of a symbol with this node type, e.g. `\"function\"`.",
+            "///",
+            "/// Functions inside an `impl` or trait are methods, which the writers in",
+            "/// [`export`](crate::export) take care of.",
+        ],
+        "ctags_kind(self) -> Option<&'static str>",
+        variant_map
+            .iter()
+            .zip(&kinds)
+            .zip(gates)
+            .filter_map(|(((_, variant), kind), gate)| {
+                kind.map(|(ctags, _)| (variant, gate, format!("Some({ctags:?})")))
+            }),
+        "None",
+    )?;
+    write_match(
+        f,
+        &[
+            "/// The LSP `SymbolKind` of a symbol with this node type.",
+            "///",
+            "/// Functions inside an `impl` or trait are methods, which the writers in",
+            "/// [`export`](crate::export) take care of.",
+        ],
+        "lsp_symbol_kind(self) -> Option<crate::export::SymbolKind>",
+        variant_map
+            .iter()
+            .zip(&kinds)
+            .zip(gates)
+            .filter_map(|(((_, variant), kind), gate)| {
+                kind.map(|(_, lsp)| {
+                    (
+                        variant,
+                        gate,
+                        format!("Some(crate::export::SymbolKind::{lsp})"),
+                    )
+                })
+            }),
+        "None",
+    )?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
@@ -0,0 +1,309 @@
+use super::meta::write_match;
+use super::rust_docs::token_group;
+use super::schema::NodeType;
+use std::io::{self, Write};
+
+/// The `TokenCategory` variant a node kind belongs to.
+pub fn category(kind: &str, named: bool) -> &'static str {
+    if named {
+        return match kind {
+            "line_comment"
+            | "block_comment"
+            | "doc_comment"
+            | "inner_doc_comment_marker"
+            | "outer_doc_comment_marker"
+            | "shebang" => "Comment",
+            "boolean_literal" | "char_literal" | "float_literal" | "integer_literal"
+            | "string_literal" | "raw_string_literal" | "string_content" | "escape_sequence"
+            | "negative_literal" => "Literal",
+            "identifier"
+            | "type_identifier"
+            | "field_identifier"
+            | "shorthand_field_identifier"
+            | "metavariable" => "Identifier",
+            "crate" | "self" | "super" | "mutable_specifier" => "Keyword",
+            _ => "Other",
+        };
+    }
+    match kind {
+        "//" | "/*" | "*/" => "Comment",
+        // Weak and reserved keywords missing from the book's keyword appendix
+        "default" | "gen" | "macro_rules!" | "raw" | "try" | "union" | "yield" => "Keyword",
+        _ => match token_group(kind) {
+            Some("keywords") => "Keyword",
+            Some("operators") => "Operator",
+            Some("punctuation") => "Punctuation",
+            _ => "Other",
+        },
+    }
+}
+
+/// Whether the unnamed token opens or closes a delimited region: a bracket, `/*` or `*/`.
+pub fn is_delimiter(kind: &str, named: bool) -> bool {
+    delimiter_pair(kind, named).is_some()
+}
+
+/// The token that pairs with a delimiter, and whether the delimiter is the opening one.
+pub fn delimiter_pair(kind: &str, named: bool) -> Option<(&'static str, bool)> {
+    if named {
+        return None;
+    }
+    Some(match kind {
+        "(" => (")", true),
+        "[" => ("]", true),
+        "{" => ("}", true),
+        "/*" => ("*/", true),
+        ")" => ("(", false),
+        "]" => ("[", false),
+        "}" => ("{", false),
+        "*/" => ("/*", false),
+        _ => return None,
+    })
+}
+
+/// Whether the unnamed token is `=` or a compound assignment operator such as `+=`.
+pub fn is_assignment_operator(kind: &str, named: bool) -> bool {
+    !named
+        && matches!(
+            kind,
+            "=" | "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" | "<<=" | ">>="
+        )
+}
+
+/// Precedence (higher binds tighter) and `Associativity` variant of binary operators, following
+/// the expression precedence table of the Rust Reference.
+pub fn binary_operator(kind: &str, named: bool) -> Option<(u8, &'static str)> {
+    if named {
+        return None;
+    }
+    Some(match kind {
+        "as" => (11, "Left"),
+        "*" | "/" | "%" => (10, "Left"),
+        "+" | "-" => (9, "Left"),
+        "<<" | ">>" => (8, "Left"),
+        "&" => (7, "Left"),
+        "^" => (6, "Left"),
+        "|" => (5, "Left"),
+        "==" | "!=" | "<" | ">" | "<=" | ">=" => (4, "None"),
+        "&&" => (3, "Left"),
+        "||" => (2, "Left"),
+        ".." | "..=" => (1, "None"),
+        _ if is_assignment_operator(kind, named) => (0, "Right"),
+        _ => return None,
+    })
+}
+
+/// Write the `meta_tokens` methods: `category`, `is_delimiter`, `is_assignment_operator`,
+/// `binary_precedence` and `associativity`.
+pub fn generate_tokens<W: Write>(
+    f: &mut W,
+    node_types: &[NodeType],
+    variant_map: &[(String, String)],
+    gates: &[String],
+) -> io::Result<()> {
+    let variants = || {
+        variant_map.iter().zip(node_types).zip(gates).map(
+            |(((original, variant), node_type), gate)| {
+                (original.as_str(), node_type.named, variant, gate)
+            },
+        )
+    };
+
+    writeln!(f, "#[cfg(feature = \"meta_tokens\")]")?;
+    writeln!(f, "impl crate::NodeType {{")?;
+
+    write_match(
+        f,
+        &[
+            "/// The lexical category of this node, for syntax highlighting and formatting.",
+            "///",
+            "/// Structural nodes such as `function_item` are [`TokenCategory::Other`].",
+        ],
+        "category(self) -> TokenCategory",
+        variants().filter_map(|(kind, named, variant, gate)| {
+            let category = category(kind, named);
+            (category != "Other").then(|| (variant, gate, format!("TokenCategory::{category}")))
+        }),
+        "TokenCategory::Other",
+    )?;
+
+    write_match(
+        f,
+        &["/// Whether this opens or closes a delimited region: a bracket, `/*` or `*/`."],
+        "is_delimiter(self) -> bool",
+        variants()
+            .filter(|(kind, named, _, _)| is_delimiter(kind, *named))
+            .map(|(_, _, variant, gate)| (variant, gate, "true".to_string())),
+        "false",
+    )?;
+
+    write_match(
+        f,
+        &["/// Whether this opens a delimited region: `(`, `[`, `{` or `/*`."],
+        "is_open_delimiter(self) -> bool",
+        variants()
+            .filter(|(kind, named, _, _)| {
+                delimiter_pair(kind, *named).is_some_and(|(_, open)| open)
+            })
+            .map(|(_, _, variant, gate)| (variant, gate, "true".to_string())),
+        "false",
+    )?;
+
+    write_match(
+        f,
+        &["/// Whether this closes a delimited region: `)`, `]`, `}` or `*/`."],
+        "is_close_delimiter(self) -> bool",
+        variants()
+            .filter(|(kind, named, _, _)| {
+                delimiter_pair(kind, *named).is_some_and(|(_, open)| !open)
+            })
+            .map(|(_, _, variant, gate)| (variant, gate, "true".to_string())),
+        "false",
+    )?;
+
+    generate_matching_delimiter(f, variants())?;
+    generate_from_delimiter(f, variants())?;
+
+    write_match(
+        f,
+        &["/// Whether this is `=` or a compound assignment operator such as `+=`."],
+        "is_assignment_operator(self) -> bool",
+        variants()
+            .filter(|(kind, named, _, _)| is_assignment_operator(kind, *named))
+            .map(|(_, _, variant, gate)| (variant, gate, "true".to_string())),
+        "false",
+    )?;
+
+    write_match(
+        f,
+        &[
+            "/// The precedence of this token as a binary operator, where higher binds tighter.",
+            "///",
+            "/// This follows the expression precedence table of the Rust Reference: `as` binds",
+            "/// tightest and assignment loosest. Tokens that are also unary operators (`-`, `*`,",
+            "/// `&`) report their binary precedence.",
+        ],
+        "binary_precedence(self) -> Option<u8>",
+        variants().filter_map(|(kind, named, variant, gate)| {
+            binary_operator(kind, named)
+                .map(|(precedence, _)| (variant, gate, format!("Some({precedence})")))
+        }),
+        "None",
+    )?;
+
+    write_match(
+        f,
+        &[
+            "/// How this token groups as a binary operator with others of the same precedence.",
+            "///",
+            "/// Comparison and range operators are [`Associativity::None`]: chaining them needs",
+            "/// parentheses.",
+        ],
+        "associativity(self) -> Option<Associativity>",
+        variants().filter_map(|(kind, named, variant, gate)| {
+            binary_operator(kind, named).map(|(_, associativity)| {
+                (
+                    variant,
+                    gate,
+                    format!("Some(Associativity::{associativity})"),
+                )
+            })
+        }),
+        "None",
+    )?;
+
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+/// Write `matching_delimiter`, whose arms need the features of both tokens in a pair.
+fn generate_matching_delimiter<'a, W: Write>(
+    f: &mut W,
+    variants: impl Iterator<Item = (&'a str, bool, &'a String, &'a String)>,
+) -> io::Result<()> {
+    let variants: Vec<_> = variants.collect();
+    let token = |kind: &str| {
+        variants
+            .iter()
+            .find(|(other, named, _, _)| !named && *other == kind)
+            .map(|(_, _, variant, gate)| (*variant, *gate))
+    };
+
+    writeln!(
+        f,
+        "    /// The delimiter that pairs with this one: `(` with `)`, `[` with `]`, `{{` with `}}` and"
+    )?;
+    writeln!(f, "    /// `/*` with `*/`, in either direction.")?;
+    writeln!(f, "    ///")?;
+    writeln!(
+        f,
+        "    /// Returns `None` for other nodes, or when the partner's feature is not enabled."
+    )?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(f, "    #[allow(unreachable_patterns)]")?;
+    writeln!(
+        f,
+        "    pub const fn matching_delimiter(self) -> Option<crate::NodeType> {{"
+    )?;
+    writeln!(f, "        match self {{")?;
+    for (kind, named, variant, gate) in &variants {
+        let Some((partner, _)) = delimiter_pair(kind, *named) else {
+            continue;
+        };
+        let Some((partner, partner_gate)) = token(partner) else {
+            continue;
+        };
+        writeln!(
+            f,
+            "            #[cfg(all(any(feature = \"{gate}\", feature = \"node_full\"), any(feature = \"{partner_gate}\", feature = \"node_full\")))]"
+        )?;
+        writeln!(f, "            Self::{variant} => Some(Self::{partner}),")?;
+    }
+    writeln!(f, "            _ => None,")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    Ok(())
+}
+
+/// Write `from_delimiter`, the lookup from a delimiter's text to its token.
+fn generate_from_delimiter<'a, W: Write>(
+    f: &mut W,
+    variants: impl Iterator<Item = (&'a str, bool, &'a String, &'a String)>,
+) -> io::Result<()> {
+    writeln!(
+        f,
+        "    /// The delimiter token spelled `kind`, such as `LParenToken` for `\"(\"`."
+    )?;
+    writeln!(f, "    ///")?;
+    writeln!(
+        f,
+        "    /// `FromStr` only parses named kinds, so this is how an unnamed tree-sitter node is"
+    )?;
+    writeln!(
+        f,
+        "    /// recognised as a delimiter. Returns `None` for any other kind, or when the token's"
+    )?;
+    writeln!(f, "    /// feature is not enabled.")?;
+    writeln!(f, "    #[must_use]")?;
+    writeln!(
+        f,
+        "    pub const fn from_delimiter(kind: &str) -> Option<crate::NodeType> {{"
+    )?;
+    writeln!(f, "        match kind.as_bytes() {{")?;
+    for (kind, named, variant, gate) in variants {
+        if delimiter_pair(kind, named).is_some() {
+            writeln!(
+                f,
+                "            #[cfg(any(feature = \"{gate}\", feature = \"node_full\"))]"
+            )?;
+            writeln!(f, "            b{kind:?} => Some(Self::{variant}),")?;
+        }
+    }
+    writeln!(f, "            _ => None,")?;
+    writeln!(f, "        }}")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    Ok(())
+}
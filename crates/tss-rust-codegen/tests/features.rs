@@ -0,0 +1,167 @@
+//! The generated features block of a `Cargo.toml`: comparing it with the expected one, and the
+//! dependency edges `feature-closure = true` gives it.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+// Every supported tree-sitter-rust release is a dependency
+#![allow(clippy::multiple_crate_versions)]
+
+use std::path::PathBuf;
+use tree_sitter_symbols_rust_codegen::{render, tss_rust_dir, Error, FeatureDiff};
+
+/// A copy of the tss-rust manifest with `feature-closure` set to `value`, in a fresh directory.
+fn crate_dir(name: &str, value: &str) -> PathBuf {
+    let cargo_toml = std::fs::read_to_string(tss_rust_dir().join("Cargo.toml")).unwrap();
+    let cargo_toml = cargo_toml.replace(
+        "[package.metadata.tss]\nfeature-closure = false\n",
+        &format!("[package.metadata.tss]\nfeature-closure = {value}\n"),
+    );
+    let dir = std::env::temp_dir().join(format!("tss-rust-codegen-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), cargo_toml).unwrap();
+    dir
+}
+
+fn cargo_toml(block: &str) -> String {
+    format!(
+        "[features]\nmeta = []\n# <!-- generated-features-start -->\n{block}\n\
+         # <!-- generated-features-end -->\n\n[lints.rust]\n"
+    )
+}
+
+#[test]
+fn reports_missing_extra_and_changed_features() {
+    let current =
+        cargo_toml("block = [\"node\"]\nold_item = [\"node\"]\nfunction_item = [\"node\"]");
+    let expected = cargo_toml(
+        "block = [\"node\"]\nfunction_item = [\n  \"function_item_shallow\",\n  \"block_shallow\",\n]\n\
+         gen_block = [\"node\"]",
+    );
+
+    let diff = FeatureDiff::between(&current, &expected).unwrap();
+    assert_eq!(diff.missing, ["gen_block"]);
+    assert_eq!(diff.extra, ["old_item"]);
+    assert_eq!(
+        diff.changed,
+        [(
+            "function_item".to_string(),
+            vec!["node".to_string()],
+            vec![
+                "function_item_shallow".to_string(),
+                "block_shallow".to_string()
+            ],
+        )]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "missing feature gen_block\n\
+         extra feature old_item\n\
+         changed feature function_item: [\"node\"] -> [\"function_item_shallow\", \"block_shallow\"]\n"
+    );
+}
+
+#[test]
+fn ignores_formatting() {
+    let current = cargo_toml("block = [\n  \"node\",\n]\n\n# comment\nitem = [\"node\"]");
+    let expected = cargo_toml("block = [\"node\"]\nitem = [\"node\"]");
+    assert!(FeatureDiff::between(&current, &expected)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn needs_a_features_block() {
+    assert_eq!(FeatureDiff::between("[features]\n", &cargo_toml("")), None);
+}
+
+#[test]
+fn closure_features_enable_reachable_nodes() {
+    let dir = crate_dir("closure", "true # with a comment");
+    let outputs = render(&dir).unwrap();
+    std::fs::remove_dir_all(dir).unwrap();
+    let contents = |name: &str| {
+        outputs
+            .iter()
+            .find(|output| output.path.ends_with(name))
+            .map(|output| output.contents.as_str())
+            .unwrap()
+    };
+
+    let cargo_toml = contents("Cargo.toml");
+    let feature = |name: &str| -> Vec<String> {
+        let table: toml::Table = cargo_toml.parse().unwrap();
+        table["features"][name]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|dep| dep.as_str().unwrap().to_string())
+            .collect()
+    };
+    // Each node's variant sits behind a shallow feature, which the full feature enables along
+    // with the shallow features of everything it can contain
+    assert_eq!(feature("identifier_shallow"), ["node"]);
+    assert_eq!(feature("identifier"), ["identifier_shallow"]);
+    let function_item = feature("function_item");
+    assert_eq!(function_item[0], "function_item_shallow");
+    for reachable in ["block_shallow", "identifier_shallow", "parameters_shallow"] {
+        assert!(
+            function_item.iter().any(|dep| dep == reachable),
+            "{reachable}"
+        );
+    }
+    assert!(!function_item.iter().any(|dep| dep == "function_item"));
+
+    let nodes = contents("grammar_0_24/nodes.rs");
+    assert!(
+        nodes.contains("#[cfg(any(feature = \"function_item_shallow\", feature = \"node_full\"))]")
+    );
+}
+
+#[test]
+fn feature_closure_must_be_a_boolean() {
+    let dir = crate_dir("closure-invalid", "\"yes\"");
+    let result = render(&dir);
+    std::fs::remove_dir_all(dir).unwrap();
+    let Err(err @ Error::Manifest(..)) = result else {
+        panic!("expected a manifest error");
+    };
+    assert!(err
+        .to_string()
+        .contains("invalid [package.metadata.tss.feature-closure]"));
+}
+
+#[test]
+fn token_groups_only_hold_unnamed_tokens() {
+    let outputs = render(&tss_rust_dir()).unwrap();
+    let cargo_toml = outputs
+        .iter()
+        .find(|output| output.path.ends_with("Cargo.toml"))
+        .map(|output| output.contents.as_str())
+        .unwrap();
+    let table: toml::Table = cargo_toml.parse().unwrap();
+    let group = |name: &str| -> Vec<&str> {
+        table["features"][name]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|feature| feature.as_str().unwrap())
+            .collect()
+    };
+    let keywords = group("group_keywords");
+    // Weak and reserved keywords count, the named `self`, `super` and `crate` nodes do not
+    for keyword in [
+        "default_token",
+        "gen_token",
+        "macro_rules_bang_token",
+        "yield_token",
+    ] {
+        assert!(keywords.contains(&keyword), "{keyword}");
+    }
+    for named in ["crate_kw", "self_kw", "super_kw"] {
+        assert!(!keywords.contains(&named), "{named}");
+    }
+    assert!(group("group_operators").contains(&"plus_token"));
+    let punctuation = group("group_punctuation");
+    assert!(punctuation.contains(&"path_sep_token"));
+    assert!(!punctuation.contains(&"line_comment_token"));
+}
@@ -0,0 +1,154 @@
+//! Naming overrides under `[package.metadata.tss.naming]` and deprecated kinds under
+//! `[package.metadata.tss.deprecated]` reach the generated code, and names that clash fail.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+// Every supported tree-sitter-rust release is a dependency
+#![allow(clippy::multiple_crate_versions)]
+
+use std::path::PathBuf;
+use tree_sitter_symbols_rust_codegen::{highlights_query, render, tss_rust_dir, Error};
+
+/// A copy of the tss-rust manifest with `entries` in its `[package.metadata.tss.{table}]`, in a
+/// fresh directory.
+fn crate_dir(name: &str, table: &str, entries: &str) -> PathBuf {
+    let cargo_toml = std::fs::read_to_string(tss_rust_dir().join("Cargo.toml")).unwrap();
+    let header = format!("[package.metadata.tss.{table}]\n");
+    let cargo_toml = cargo_toml.replace(&header, &format!("{header}{entries}\n"));
+    let dir = std::env::temp_dir().join(format!("tss-rust-codegen-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("Cargo.toml"), cargo_toml).unwrap();
+    dir
+}
+
+#[test]
+fn overrides_rename_variants_and_features() {
+    let dir = crate_dir(
+        "rename",
+        "naming",
+        "'\"::\"' = { variant = \"ColonColonToken\", feature = \"colon_colon_token\" }",
+    );
+    let outputs = render(&dir).unwrap();
+    let contents = |name: &str| {
+        outputs
+            .iter()
+            .find(|output| output.path.ends_with(name))
+            .map(|output| output.contents.as_str())
+            .unwrap()
+    };
+
+    let nodes = contents("grammar_0_24/nodes.rs");
+    assert!(nodes.contains("    ColonColonToken,"));
+    assert!(nodes.contains("#[cfg(any(feature = \"colon_colon_token\", feature = \"node_full\"))]"));
+    assert!(!nodes.contains("PathSepToken"));
+    let cargo_toml = contents("Cargo.toml");
+    assert!(cargo_toml.contains("\ncolon_colon_token = [\"node\"]\n"));
+    assert!(!cargo_toml.contains("\npath_sep_token = "));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn clashing_overrides_fail_loudly() {
+    let dir = crate_dir(
+        "clash",
+        "naming",
+        "'\"::\"' = { variant = \"FunctionItem\" }",
+    );
+    let Err(err @ Error::Naming(..)) = render(&dir) else {
+        panic!("expected a naming error");
+    };
+    std::fs::remove_dir_all(dir).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "cannot name the nodes of tree-sitter-rust 0.24.2 NODE_TYPES: variant FunctionItem is \
+         given to function_item, \"::\"; give all but one of them a variant override"
+    );
+}
+
+#[test]
+fn deprecated_kinds_become_aliases() {
+    let dir = crate_dir(
+        "deprecated",
+        "deprecated",
+        "function_definition = { replacement = \"function_item\", since = \"0.20\" }",
+    );
+    let outputs = render(&dir).unwrap();
+    std::fs::remove_dir_all(dir).unwrap();
+    let nodes = outputs
+        .iter()
+        .find(|output| output.path.ends_with("grammar_0_24/nodes.rs"))
+        .map(|output| output.contents.as_str())
+        .unwrap();
+
+    assert!(nodes.contains(
+        "    #[cfg(any(feature = \"function_item\", feature = \"node_full\"))]\n    \
+         #[deprecated(note = \"renamed to function_item in grammar 0.20; use NodeType::FunctionItem\")]\n    \
+         #[allow(non_upper_case_globals)]\n    \
+         pub const FunctionDefinition: Self = Self::FunctionItem;\n"
+    ));
+    assert!(nodes.contains("            \"function_definition\" => Ok(Self::FunctionItem),\n"));
+    assert!(nodes.contains(
+        "        crate::names::DeprecatedKind { kind: \"function_definition\", named: true, \
+         variant: \"FunctionDefinition\", replacement: Some(\"FunctionItem\"), note: \"renamed to \
+         function_item in grammar 0.20; use NodeType::FunctionItem\" },\n"
+    ));
+}
+
+#[test]
+fn dropped_kinds_fail_to_parse_with_their_note() {
+    let dir = crate_dir(
+        "dropped",
+        "deprecated",
+        "old_item = { since = \"0.20\", note = \"parsed as a macro\" }",
+    );
+    let outputs = render(&dir).unwrap();
+    std::fs::remove_dir_all(dir).unwrap();
+    let nodes = outputs
+        .iter()
+        .find(|output| output.path.ends_with("grammar_0_24/nodes.rs"))
+        .map(|output| output.contents.as_str())
+        .unwrap();
+
+    assert!(nodes.contains(
+        "            \"old_item\" => Err(\"old_item was dropped in grammar 0.20 (parsed as a \
+         macro)\".to_string()),\n"
+    ));
+    // Only the name table mentions the dropped variant
+    assert!(!nodes.contains("pub const OldItem"));
+    assert!(nodes.contains(
+        "        crate::names::DeprecatedKind { kind: \"old_item\", named: true, variant: \"OldItem\", \
+         replacement: None, note: \"dropped in grammar 0.20 (parsed as a macro)\" },\n"
+    ));
+}
+
+#[test]
+fn deprecating_a_present_kind_fails() {
+    let dir = crate_dir(
+        "present",
+        "deprecated",
+        "block = { replacement = \"function_item\" }",
+    );
+    let Err(err @ Error::Naming(..)) = render(&dir) else {
+        panic!("expected a naming error");
+    };
+    std::fs::remove_dir_all(dir).unwrap();
+    assert_eq!(
+        err.to_string(),
+        "cannot name the nodes of tree-sitter-rust 0.24.2 NODE_TYPES: deprecated kind block is \
+         still in the grammar"
+    );
+}
+
+#[test]
+fn clashing_captures_fail_loudly() {
+    let query = "(identifier) @function.method\n(field_identifier) @function_method\n";
+    let Err(err @ Error::CaptureCollision(..)) = highlights_query("HIGHLIGHTS", query) else {
+        panic!("expected a capture collision");
+    };
+    assert_eq!(
+        err.to_string(),
+        "cannot name the captures of HIGHLIGHTS: variant FunctionMethod is given to \
+         @function.method, @function_method"
+    );
+    assert!(highlights_query("HIGHLIGHTS", "(identifier) @function.method\n").is_ok());
+}
@@ -0,0 +1,99 @@
+//! The committed `tss-rust` code matches what the generator produces.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+// Every supported tree-sitter-rust release is a dependency
+#![allow(clippy::multiple_crate_versions)]
+
+use tree_sitter_symbols_rust_codegen::{obsolete, render, stale, tss_rust_dir, RELEASES};
+
+/// The releases the generated code is compared against, e.g. `tree-sitter-rust 0.24.2`.
+fn releases() -> String {
+    RELEASES
+        .iter()
+        .map(|release| format!("tree-sitter-rust {}", release.pinned))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[test]
+fn up_to_date() {
+    let outputs = render(&tss_rust_dir()).unwrap();
+    let mut stale: Vec<String> = stale(&outputs)
+        .iter()
+        .map(|output| match output.feature_diff() {
+            Some(diff) => format!("{}\n{diff}", output.path.display()),
+            None => output.path.display().to_string(),
+        })
+        .collect();
+    stale.extend(
+        obsolete(&tss_rust_dir(), &outputs)
+            .iter()
+            .map(|path| format!("{} (obsolete)", path.display())),
+    );
+    assert!(
+        stale.is_empty(),
+        "stale generated files for {} (run `cargo run -p tss-rust-codegen`):\n{}",
+        releases(),
+        stale.join("\n")
+    );
+}
+
+/// Each release is pinned to the exact version its code was generated from, so a fresh checkout
+/// without a lockfile cannot resolve a patch release with different node types.
+#[test]
+fn releases_are_pinned() {
+    let manifest: toml::Table = std::fs::read_to_string(tss_rust_dir().join("../../Cargo.toml"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    let dependencies = &manifest["workspace"]["dependencies"];
+    for (dependency, release) in ["tree-sitter-rust", "tree-sitter-rust-0-23"]
+        .into_iter()
+        .zip(RELEASES)
+    {
+        assert_eq!(
+            dependency_version(dependencies, dependency),
+            format!("={}", release.pinned),
+            "{dependency} is not pinned to the release tss-rust is generated from ({})",
+            releases()
+        );
+    }
+}
+
+fn dependency_version<'a>(dependencies: &'a toml::Value, name: &str) -> &'a str {
+    let dependency = &dependencies[name];
+    dependency
+        .get("version")
+        .unwrap_or(dependency)
+        .as_str()
+        .unwrap()
+}
+
+#[test]
+fn variant_docs_link_their_reference_urls() {
+    let outputs = render(&tss_rust_dir()).unwrap();
+    let contents = |file: &str| {
+        outputs
+            .iter()
+            .find(|output| output.path.ends_with(file))
+            .map(|output| output.contents.as_str())
+            .unwrap()
+    };
+    let nodes = contents("grammar_0_24/nodes.rs");
+    let meta = contents("grammar_0_24/meta.rs");
+
+    let links: Vec<&str> = nodes
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("/// - **Rust reference**: ["))
+        .filter_map(|line| line.split_once("](")?.1.strip_suffix(')'))
+        .collect();
+    assert!(!links.is_empty());
+    for link in links {
+        assert!(meta.contains(&format!("Some({link:?})")), "{link}");
+    }
+    assert!(nodes.contains(
+        "    /// `fn` (unnamed)\n    ///\n    \
+         /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)\n"
+    ));
+}
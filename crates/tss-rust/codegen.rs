@@ -1,5 +0,0 @@
-pub mod feature_toml;
-pub mod generator;
-pub mod rust_docs;
-pub mod schema;
-pub mod utils;
@@ -0,0 +1,142 @@
+//! Matching paired delimiters in a token stream.
+//!
+//! [`match_delimiters`] pairs up `(`/`)`, `[`/`]`, `{`/`}` and `/*`/`*/` tokens and records how
+//! deeply each pair is nested, which is what editor features such as rainbow brackets and
+//! expanding a selection to the enclosing brackets need. Tokens are given as `(NodeType, T)`
+//! pairs, so `T` can be a byte range, a tree-sitter node or anything else identifying the token.
+//!
+//! ```
+//! # #[cfg(feature = "node_full")]
+//! # {
+//! use tree_sitter_symbols_rust::delimiters::match_delimiters;
+//! use tree_sitter_symbols_rust::NodeType;
+//!
+//! // `f(a[0])`
+//! let tokens = [
+//!     (NodeType::LParenToken, 1),
+//!     (NodeType::LBracketToken, 3),
+//!     (NodeType::RBracketToken, 5),
+//!     (NodeType::RParenToken, 6),
+//! ];
+//! let matches = match_delimiters(tokens);
+//! assert_eq!(matches.pairs[0].open, 1);
+//! assert_eq!(matches.pairs[0].close, 6);
+//! assert_eq!(matches.pairs[1].depth, 1);
+//! assert!(matches.unmatched.is_empty());
+//! # }
+//! ```
+//!
+//! With the `tree-sitter` feature enabled, [`tree_delimiters`] collects the delimiter tokens of a
+//! parsed tree and matches them.
+
+use crate::NodeType;
+
+/// An opening delimiter and the closing delimiter that matches it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pair<T> {
+    /// The opening token, e.g. `(`.
+    pub open: T,
+    /// The closing token, e.g. `)`.
+    pub close: T,
+    /// How many delimiters were still open around this pair; top-level pairs have depth 0.
+    pub depth: usize,
+}
+
+/// The result of [`match_delimiters`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matches<T> {
+    /// Matched pairs, in the order their opening delimiters appear.
+    pub pairs: Vec<Pair<T>>,
+    /// Delimiters with no partner, in the order they appear.
+    pub unmatched: Vec<T>,
+}
+
+impl<T> Default for Matches<T> {
+    fn default() -> Self {
+        Self {
+            pairs: Vec::new(),
+            unmatched: Vec::new(),
+        }
+    }
+}
+
+/// Pair up the delimiters in `tokens`, skipping any token that is not a delimiter.
+///
+/// A closing delimiter matches the nearest unclosed opening delimiter of its kind. Opening
+/// delimiters it skips over are left unmatched, so `( [ )` pairs the parentheses and reports the
+/// bracket; a closing delimiter with no opening partner at all is reported unmatched.
+pub fn match_delimiters<T>(tokens: impl IntoIterator<Item = (NodeType, T)>) -> Matches<T> {
+    // Slots in `pairs` are reserved when a delimiter opens, so pairs stay in opening order
+    let mut slots: Vec<Option<Pair<T>>> = Vec::new();
+    // Unclosed opening delimiters: the token, its slot and its position among the delimiters
+    let mut open: Vec<(NodeType, usize, usize, T)> = Vec::new();
+    let mut unmatched: Vec<(usize, T)> = Vec::new();
+
+    let delimiters = tokens
+        .into_iter()
+        .filter(|(node, _)| node.is_open_delimiter() || node.is_close_delimiter());
+    for (position, (node, token)) in delimiters.enumerate() {
+        if node.is_open_delimiter() {
+            open.push((node, slots.len(), position, token));
+            slots.push(None);
+            continue;
+        }
+        let partner = node.matching_delimiter();
+        let Some(index) = open
+            .iter()
+            .rposition(|(opener, ..)| Some(*opener) == partner)
+        else {
+            unmatched.push((position, token));
+            continue;
+        };
+        for (_, _, position, skipped) in open.drain(index + 1..) {
+            unmatched.push((position, skipped));
+        }
+        let Some((_, slot, _, opener)) = open.pop() else {
+            continue;
+        };
+        slots[slot] = Some(Pair {
+            open: opener,
+            close: token,
+            depth: open.len(),
+        });
+    }
+    unmatched.extend(
+        open.into_iter()
+            .map(|(_, _, position, token)| (position, token)),
+    );
+
+    unmatched.sort_by_key(|(position, _)| *position);
+    Matches {
+        pairs: slots.into_iter().flatten().collect(),
+        unmatched: unmatched.into_iter().map(|(_, token)| token).collect(),
+    }
+}
+
+/// Match the delimiter tokens of a tree-sitter-rust syntax tree.
+///
+/// Missing nodes that tree-sitter inserted during error recovery are not part of the source, so
+/// they are skipped and the delimiter they stand in for is reported unmatched.
+#[cfg(feature = "tree-sitter")]
+#[must_use]
+pub fn tree_delimiters(tree: &tree_sitter::Tree) -> Matches<tree_sitter::Node<'_>> {
+    let mut tokens = Vec::new();
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        if node.child_count() == 0 && !node.is_named() && !node.is_missing() {
+            if let Some(delimiter) = NodeType::from_delimiter(node.kind()) {
+                tokens.push((delimiter, node));
+            }
+        }
+        if cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    match_delimiters(tokens)
+}
@@ -0,0 +1,302 @@
+//! Writers for Universal Ctags tags files and LSP symbol JSON.
+//!
+//! [`ctags_entries`] turns the [`Symbol`]s of a file into lines of the Universal Ctags extended
+//! format, with `kind:`, `line:`, `scope:` and `signature:` fields, and [`ctags_file`] sorts the
+//! lines of any number of files under the pseudo-tags editors expect. [`document_symbols`] writes
+//! an [`outline`](crate::outline::outline) as an LSP `DocumentSymbol[]` and
+//! [`symbol_information`] writes symbols as a flat `SymbolInformation[]`.
+//!
+//! The kinds come from [`NodeType::ctags_kind`] and [`NodeType::lsp_symbol_kind`]; functions
+//! inside an `impl` block or trait are reported as methods. LSP positions count UTF-16 code units,
+//! the protocol's default encoding.
+//!
+//! ```
+//! use tree_sitter_symbols_rust::export::{ctags_entries, ctags_file};
+//! use tree_sitter_symbols_rust::symbols::symbols;
+//!
+//! let source = "struct Point;\nimpl Point {\n    fn new(x: i32) -> Self { Point }\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_symbols_rust::language()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let entries = ctags_entries("src/point.rs", &symbols(&tree, source.as_bytes())?);
+//! assert_eq!(
+//!     entries[2],
+//!     "new\tsrc/point.rs\t3;\"\tkind:method\tline:3\tscope:implementation:Point\tsignature:(x: i32) -> Self"
+//! );
+//! assert!(ctags_file(entries).starts_with("!_TAG_FILE_FORMAT\t2\t"));
+//! # Ok::<(), tree_sitter_symbols_rust::symbols::SymbolsError>(())
+//! ```
+
+use crate::outline::OutlineNode;
+use crate::symbols::Symbol;
+use crate::NodeType;
+use std::fmt::Write;
+
+/// The kind of an LSP `DocumentSymbol` or `SymbolInformation`, numbered as in the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum SymbolKind {
+    /// `File`
+    File = 1,
+    /// `Module`
+    Module = 2,
+    /// `Namespace`
+    Namespace = 3,
+    /// `Package`
+    Package = 4,
+    /// `Class`
+    Class = 5,
+    /// `Method`
+    Method = 6,
+    /// `Property`
+    Property = 7,
+    /// `Field`
+    Field = 8,
+    /// `Constructor`
+    Constructor = 9,
+    /// `Enum`
+    Enum = 10,
+    /// `Interface`
+    Interface = 11,
+    /// `Function`
+    Function = 12,
+    /// `Variable`
+    Variable = 13,
+    /// `Constant`
+    Constant = 14,
+    /// `String`
+    String = 15,
+    /// `Number`
+    Number = 16,
+    /// `Boolean`
+    Boolean = 17,
+    /// `Array`
+    Array = 18,
+    /// `Object`
+    Object = 19,
+    /// `Key`
+    Key = 20,
+    /// `Null`
+    Null = 21,
+    /// `EnumMember`
+    EnumMember = 22,
+    /// `Struct`
+    Struct = 23,
+    /// `Event`
+    Event = 24,
+    /// `Operator`
+    Operator = 25,
+    /// `TypeParameter`
+    TypeParameter = 26,
+}
+
+impl SymbolKind {
+    /// The number the protocol uses for this kind.
+    #[must_use]
+    pub const fn number(self) -> u8 {
+        self as u8
+    }
+}
+
+/// The pseudo-tags at the top of a tags file written by [`ctags_file`].
+const CTAGS_HEADER: &str = "\
+!_TAG_FILE_FORMAT\t2\t/extended format; --format=1 will not append ;\" to lines/
+!_TAG_FILE_SORTED\t1\t/0=unsorted, 1=sorted, 2=foldcase/
+!_TAG_PROGRAM_NAME\ttree-sitter-symbols-rust\t//
+";
+
+/// The Universal Ctags extended-format lines for the symbols of the file at `path`.
+///
+/// Every symbol whose node type has a ctags kind gets a line, addressed by line number, so calls
+/// and macro invocations are left out. The `scope:` field names the enclosing definitions and
+/// `impl` blocks, outermost first and separated by `::`, with the kind of the innermost one.
+#[must_use]
+pub fn ctags_entries(path: &str, symbols: &[Symbol]) -> Vec<String> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let kind = ctags_kind(symbol)?;
+            let line = symbol.name_range.start_point.row + 1;
+            let mut entry = format!(
+                "{}\t{path}\t{line};\"\tkind:{kind}\tline:{line}",
+                symbol.name
+            );
+            if let Some(parent) = symbol.parent {
+                let parent_kind = ctags_kind(&symbols[parent]).unwrap_or("unknown");
+                let _ = write!(
+                    entry,
+                    "\tscope:{parent_kind}:{}",
+                    escape_ctags(&scope(symbols, parent))
+                );
+            }
+            if let Some(signature) = &symbol.signature {
+                let _ = write!(entry, "\tsignature:{}", escape_ctags(signature));
+            }
+            Some(entry)
+        })
+        .collect()
+}
+
+/// A tags file of `entries` from [`ctags_entries`], sorted, after the usual pseudo-tags.
+#[must_use]
+pub fn ctags_file(mut entries: Vec<String>) -> String {
+    entries.sort();
+    let mut file = CTAGS_HEADER.to_string();
+    for entry in entries {
+        file.push_str(&entry);
+        file.push('\n');
+    }
+    file
+}
+
+/// An outline of `source` as a JSON `DocumentSymbol[]`.
+#[must_use]
+pub fn document_symbols(outline: &[OutlineNode], source: &[u8]) -> String {
+    let mut json = String::new();
+    write_document_symbols(&mut json, outline, None, source);
+    json
+}
+
+fn write_document_symbols(
+    json: &mut String,
+    nodes: &[OutlineNode],
+    parent: Option<NodeType>,
+    source: &[u8],
+) {
+    json.push('[');
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let kind = lsp_kind(
+            node.kind,
+            parent == Some(NodeType::ImplItem) || parent == Some(NodeType::TraitItem),
+        )
+        .unwrap_or(SymbolKind::Object);
+        let _ = write!(
+            json,
+            "{{\"name\":{},\"kind\":{},\"range\":{},\"selectionRange\":{},\"children\":",
+            json_string(&node.name),
+            kind.number(),
+            lsp_range(node.range, source),
+            lsp_range(node.selection_range, source),
+        );
+        write_document_symbols(json, &node.children, Some(node.kind), source);
+        json.push('}');
+    }
+    json.push(']');
+}
+
+/// The symbols of `source`, the document at `uri`, as a JSON `SymbolInformation[]`.
+///
+/// Like [`ctags_entries`], only symbols whose node type has a kind are included. The
+/// `containerName` is the name of the enclosing definition or `impl` block.
+#[must_use]
+pub fn symbol_information(uri: &str, symbols: &[Symbol], source: &[u8]) -> String {
+    let mut json = String::from("[");
+    for symbol in symbols {
+        let Some(kind) = lsp_kind(symbol.kind, symbol.syntax == "method") else {
+            continue;
+        };
+        if json.len() > 1 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            "{{\"name\":{},\"kind\":{},\"location\":{{\"uri\":{},\"range\":{}}}",
+            json_string(&symbol.name),
+            kind.number(),
+            json_string(uri),
+            lsp_range(symbol.range, source),
+        );
+        if let Some(parent) = symbol.parent {
+            let _ = write!(
+                json,
+                ",\"containerName\":{}",
+                json_string(&symbols[parent].name)
+            );
+        }
+        json.push('}');
+    }
+    json.push(']');
+    json
+}
+
+fn ctags_kind(symbol: &Symbol) -> Option<&'static str> {
+    let kind = symbol.kind.ctags_kind()?;
+    Some(if symbol.syntax == "method" {
+        "method"
+    } else {
+        kind
+    })
+}
+
+fn lsp_kind(kind: NodeType, in_impl_or_trait: bool) -> Option<SymbolKind> {
+    let symbol_kind = kind.lsp_symbol_kind()?;
+    let is_function = matches!(
+        kind,
+        NodeType::FunctionItem | NodeType::FunctionSignatureItem
+    );
+    Some(if is_function && in_impl_or_trait {
+        SymbolKind::Method
+    } else {
+        symbol_kind
+    })
+}
+
+/// The names of the symbol at `index` and its ancestors, outermost first.
+fn scope(symbols: &[Symbol], index: usize) -> String {
+    let mut names = vec![symbols[index].name.as_str()];
+    let mut parent = symbols[index].parent;
+    while let Some(i) = parent {
+        names.push(&symbols[i].name);
+        parent = symbols[i].parent;
+    }
+    names.reverse();
+    names.join("::")
+}
+
+/// A ctags field value with backslashes, tabs and line breaks escaped.
+fn escape_ctags(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+/// An LSP `Range` object for `range`, with characters counted in UTF-16 code units.
+fn lsp_range(range: tree_sitter::Range, source: &[u8]) -> String {
+    format!(
+        "{{\"start\":{},\"end\":{}}}",
+        lsp_position(range.start_point, range.start_byte, source),
+        lsp_position(range.end_point, range.end_byte, source),
+    )
+}
+
+fn lsp_position(point: tree_sitter::Point, byte: usize, source: &[u8]) -> String {
+    let line = String::from_utf8_lossy(&source[byte - point.column..byte]);
+    let character = line.encode_utf16().count();
+    format!("{{\"line\":{},\"character\":{character}}}", point.row)
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,6130 @@
+// @generated by tss-rust-codegen from tree-sitter-rust 0.23.3 NODE_TYPES.
+// Run `cargo run -p tss-rust-codegen` to update it.
+
+/// Field names used by tree-sitter-rust nodes
+#[cfg(feature = "meta_fields")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldName {
+    /// `alias`
+    Alias,
+    /// `alternative`
+    Alternative,
+    /// `argument`
+    Argument,
+    /// `arguments`
+    Arguments,
+    /// `body`
+    Body,
+    /// `bounds`
+    Bounds,
+    /// `condition`
+    Condition,
+    /// `consequence`
+    Consequence,
+    /// `default_type`
+    DefaultType,
+    /// `doc`
+    Doc,
+    /// `element`
+    Element,
+    /// `field`
+    Field,
+    /// `function`
+    Function,
+    /// `inner`
+    Inner,
+    /// `left`
+    Left,
+    /// `length`
+    Length,
+    /// `list`
+    List,
+    /// `macro`
+    Macro,
+    /// `name`
+    Name,
+    /// `operator`
+    Operator,
+    /// `outer`
+    Outer,
+    /// `parameters`
+    Parameters,
+    /// `path`
+    Path,
+    /// `pattern`
+    Pattern,
+    /// `return_type`
+    ReturnType,
+    /// `right`
+    Right,
+    /// `trait`
+    Trait,
+    /// `type`
+    Type,
+    /// `type_arguments`
+    TypeArguments,
+    /// `type_parameters`
+    TypeParameters,
+    /// `value`
+    Value,
+}
+
+#[cfg(feature = "meta_fields")]
+impl FieldName {
+    /// The field name as it appears in the grammar.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Alias => "alias",
+            Self::Alternative => "alternative",
+            Self::Argument => "argument",
+            Self::Arguments => "arguments",
+            Self::Body => "body",
+            Self::Bounds => "bounds",
+            Self::Condition => "condition",
+            Self::Consequence => "consequence",
+            Self::DefaultType => "default_type",
+            Self::Doc => "doc",
+            Self::Element => "element",
+            Self::Field => "field",
+            Self::Function => "function",
+            Self::Inner => "inner",
+            Self::Left => "left",
+            Self::Length => "length",
+            Self::List => "list",
+            Self::Macro => "macro",
+            Self::Name => "name",
+            Self::Operator => "operator",
+            Self::Outer => "outer",
+            Self::Parameters => "parameters",
+            Self::Path => "path",
+            Self::Pattern => "pattern",
+            Self::ReturnType => "return_type",
+            Self::Right => "right",
+            Self::Trait => "trait",
+            Self::Type => "type",
+            Self::TypeArguments => "type_arguments",
+            Self::TypeParameters => "type_parameters",
+            Self::Value => "value",
+        }
+    }
+}
+
+#[cfg(feature = "meta_fields")]
+impl std::str::FromStr for FieldName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alias" => Ok(Self::Alias),
+            "alternative" => Ok(Self::Alternative),
+            "argument" => Ok(Self::Argument),
+            "arguments" => Ok(Self::Arguments),
+            "body" => Ok(Self::Body),
+            "bounds" => Ok(Self::Bounds),
+            "condition" => Ok(Self::Condition),
+            "consequence" => Ok(Self::Consequence),
+            "default_type" => Ok(Self::DefaultType),
+            "doc" => Ok(Self::Doc),
+            "element" => Ok(Self::Element),
+            "field" => Ok(Self::Field),
+            "function" => Ok(Self::Function),
+            "inner" => Ok(Self::Inner),
+            "left" => Ok(Self::Left),
+            "length" => Ok(Self::Length),
+            "list" => Ok(Self::List),
+            "macro" => Ok(Self::Macro),
+            "name" => Ok(Self::Name),
+            "operator" => Ok(Self::Operator),
+            "outer" => Ok(Self::Outer),
+            "parameters" => Ok(Self::Parameters),
+            "path" => Ok(Self::Path),
+            "pattern" => Ok(Self::Pattern),
+            "return_type" => Ok(Self::ReturnType),
+            "right" => Ok(Self::Right),
+            "trait" => Ok(Self::Trait),
+            "type" => Ok(Self::Type),
+            "type_arguments" => Ok(Self::TypeArguments),
+            "type_parameters" => Ok(Self::TypeParameters),
+            "value" => Ok(Self::Value),
+            _ => Err(format!("Unknown field name: {s}")),
+        }
+    }
+}
+
+#[cfg(feature = "meta_fields")]
+impl std::fmt::Display for FieldName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "meta_named")]
+impl crate::NodeType {
+    /// Whether this node is named in the grammar (unnamed nodes are literal tokens).
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub const fn is_named(self) -> bool {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => true,
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => true,
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => true,
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => true,
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => true,
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => true,
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => true,
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => true,
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => true,
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => true,
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => true,
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => true,
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => true,
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => true,
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => true,
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => true,
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => true,
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => true,
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => true,
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => true,
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => true,
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => true,
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => true,
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => true,
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => true,
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => true,
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => true,
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => true,
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => true,
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => true,
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => true,
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => true,
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => true,
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => true,
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => true,
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => true,
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => true,
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => true,
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => true,
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => true,
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => true,
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => true,
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => true,
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => true,
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => true,
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => true,
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => true,
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => true,
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => true,
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => true,
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => true,
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => true,
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => true,
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => true,
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => true,
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => true,
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => true,
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => true,
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => true,
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => true,
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => true,
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => true,
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => true,
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => true,
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => true,
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => true,
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => true,
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => true,
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => true,
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => true,
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => true,
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => true,
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => true,
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => true,
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => true,
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => true,
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => true,
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => true,
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => true,
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => true,
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => true,
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => true,
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => true,
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => true,
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => true,
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => true,
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => true,
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => true,
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => true,
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => true,
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => true,
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => true,
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => true,
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => true,
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => true,
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => true,
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => true,
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => true,
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => true,
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => true,
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => true,
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => true,
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => true,
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => true,
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => true,
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => true,
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => true,
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => true,
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => true,
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => true,
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => true,
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => true,
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => true,
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => true,
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => true,
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => true,
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => true,
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => true,
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => true,
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => true,
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => true,
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => true,
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => true,
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => true,
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => true,
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => true,
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => true,
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => true,
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => true,
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => true,
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => true,
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => true,
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => true,
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => true,
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => true,
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => true,
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => true,
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => true,
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => true,
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => true,
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => true,
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => true,
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => true,
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => true,
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => true,
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => true,
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => true,
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => true,
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => true,
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => true,
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => true,
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => true,
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => false,
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => false,
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => false,
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => false,
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => false,
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => false,
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => false,
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => false,
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => false,
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => false,
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => false,
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => false,
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => false,
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => false,
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => false,
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => false,
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => false,
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => false,
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => false,
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => false,
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => false,
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => false,
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => false,
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => false,
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => false,
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => false,
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => false,
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => false,
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => false,
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => false,
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => false,
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => false,
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => false,
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => false,
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => false,
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => false,
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => false,
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => false,
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => false,
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => false,
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => false,
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => false,
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => false,
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => false,
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => false,
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => false,
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => false,
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => false,
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => false,
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => false,
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => false,
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => false,
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => false,
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => false,
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => false,
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => false,
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => true,
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => false,
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => false,
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => true,
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => false,
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => true,
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => false,
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => false,
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => false,
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => true,
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => false,
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => false,
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => false,
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => false,
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => true,
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => true,
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => false,
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => false,
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => false,
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => false,
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => true,
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => false,
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => false,
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => false,
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => true,
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => false,
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => false,
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => false,
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => false,
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => false,
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => false,
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => false,
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => false,
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => true,
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => false,
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => false,
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => true,
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => false,
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => false,
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => false,
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => true,
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => false,
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => false,
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => false,
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => false,
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => true,
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => true,
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => true,
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => false,
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => false,
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => true,
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => false,
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => true,
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => false,
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => false,
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => false,
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => false,
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => false,
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => false,
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => true,
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => false,
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => false,
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => false,
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => false,
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => false,
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => false,
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => false,
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => false,
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => false,
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => false,
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => false,
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => false,
+        }
+    }
+}
+
+#[cfg(feature = "meta_extra")]
+impl crate::NodeType {
+    /// Whether this node is an extra, which can appear anywhere in the tree (comments).
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn is_extra(self) -> bool {
+        match self {
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => true,
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => true,
+            _ => false,
+        }
+    }
+
+}
+
+#[cfg(feature = "meta_fields")]
+impl crate::NodeType {
+    /// The named fields this node can have, sorted by field name.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub const fn fields(self) -> &'static [Field] {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => &[],
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => &[],
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => &[],
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => &[],
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => &[],
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => &[],
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => &[
+                Field {
+                    name: FieldName::Trait,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "bounded_type", named: true }, TypeRef { kind: "function_type", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "removed_trait_bound", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "tuple_type", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => &[
+            ],
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => &[
+                Field {
+                    name: FieldName::Length,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => &[
+                Field {
+                    name: FieldName::Element,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Length,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => &[
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+                Field {
+                    name: FieldName::Right,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => &[
+                Field {
+                    name: FieldName::Bounds,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "trait_bounds", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => &[
+            ],
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => &[
+                Field {
+                    name: FieldName::Arguments,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "token_tree", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => &[
+            ],
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => &[
+            ],
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => &[
+            ],
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => &[
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+                Field {
+                    name: FieldName::Operator,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "!=", named: false }, TypeRef { kind: "%", named: false }, TypeRef { kind: "&", named: false }, TypeRef { kind: "&&", named: false }, TypeRef { kind: "*", named: false }, TypeRef { kind: "+", named: false }, TypeRef { kind: "-", named: false }, TypeRef { kind: "/", named: false }, TypeRef { kind: "<", named: false }, TypeRef { kind: "<<", named: false }, TypeRef { kind: "<=", named: false }, TypeRef { kind: "==", named: false }, TypeRef { kind: ">", named: false }, TypeRef { kind: ">=", named: false }, TypeRef { kind: ">>", named: false }, TypeRef { kind: "^", named: false }, TypeRef { kind: "|", named: false }, TypeRef { kind: "||", named: false }],
+                },
+                Field {
+                    name: FieldName::Right,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => &[
+            ],
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => &[
+                Field {
+                    name: FieldName::Doc,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "doc_comment", named: true }],
+                },
+                Field {
+                    name: FieldName::Inner,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "inner_doc_comment_marker", named: true }],
+                },
+                Field {
+                    name: FieldName::Outer,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "outer_doc_comment_marker", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => &[
+            ],
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => &[
+            ],
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => &[
+            ],
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => &[
+            ],
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => &[
+                Field {
+                    name: FieldName::Arguments,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "arguments", named: true }],
+                },
+                Field {
+                    name: FieldName::Function,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "array_expression", named: true }, TypeRef { kind: "assignment_expression", named: true }, TypeRef { kind: "async_block", named: true }, TypeRef { kind: "await_expression", named: true }, TypeRef { kind: "binary_expression", named: true }, TypeRef { kind: "block", named: true }, TypeRef { kind: "break_expression", named: true }, TypeRef { kind: "call_expression", named: true }, TypeRef { kind: "closure_expression", named: true }, TypeRef { kind: "compound_assignment_expr", named: true }, TypeRef { kind: "const_block", named: true }, TypeRef { kind: "continue_expression", named: true }, TypeRef { kind: "field_expression", named: true }, TypeRef { kind: "for_expression", named: true }, TypeRef { kind: "gen_block", named: true }, TypeRef { kind: "generic_function", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "if_expression", named: true }, TypeRef { kind: "index_expression", named: true }, TypeRef { kind: "loop_expression", named: true }, TypeRef { kind: "macro_invocation", named: true }, TypeRef { kind: "match_expression", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "parenthesized_expression", named: true }, TypeRef { kind: "reference_expression", named: true }, TypeRef { kind: "return_expression", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "struct_expression", named: true }, TypeRef { kind: "try_block", named: true }, TypeRef { kind: "try_expression", named: true }, TypeRef { kind: "tuple_expression", named: true }, TypeRef { kind: "type_cast_expression", named: true }, TypeRef { kind: "unary_expression", named: true }, TypeRef { kind: "unit_expression", named: true }, TypeRef { kind: "unsafe_block", named: true }, TypeRef { kind: "while_expression", named: true }, TypeRef { kind: "yield_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => &[
+            ],
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_", named: false }, TypeRef { kind: "_expression", named: true }],
+                },
+                Field {
+                    name: FieldName::Parameters,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "closure_parameters", named: true }],
+                },
+                Field {
+                    name: FieldName::ReturnType,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => &[
+            ],
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => &[
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+                Field {
+                    name: FieldName::Operator,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "%=", named: false }, TypeRef { kind: "&=", named: false }, TypeRef { kind: "*=", named: false }, TypeRef { kind: "+=", named: false }, TypeRef { kind: "-=", named: false }, TypeRef { kind: "/=", named: false }, TypeRef { kind: "<<=", named: false }, TypeRef { kind: ">>=", named: false }, TypeRef { kind: "^=", named: false }, TypeRef { kind: "|=", named: false }],
+                },
+                Field {
+                    name: FieldName::Right,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "block", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "negative_literal", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => &[
+            ],
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => &[
+            ],
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => &[
+                Field {
+                    name: FieldName::Trait,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "function_type", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "higher_ranked_trait_bound", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "tuple_type", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => &[
+            ],
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => &[
+            ],
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "enum_variant_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "field_declaration_list", named: true }, TypeRef { kind: "ordered_field_declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => &[
+            ],
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => &[
+            ],
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => &[
+                Field {
+                    name: FieldName::Alias,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => &[
+            ],
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => &[
+            ],
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => &[
+                Field {
+                    name: FieldName::Field,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_identifier", named: true }, TypeRef { kind: "integer_literal", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => &[
+                Field {
+                    name: FieldName::Field,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_identifier", named: true }, TypeRef { kind: "integer_literal", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => &[
+            ],
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_identifier", named: true }, TypeRef { kind: "shorthand_field_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_pattern", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_pattern", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => &[
+            ],
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "declaration_list", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => &[
+            ],
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }],
+                },
+                Field {
+                    name: FieldName::Parameters,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "parameters", named: true }],
+                },
+                Field {
+                    name: FieldName::ReturnType,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => &[
+            ],
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }],
+                },
+                Field {
+                    name: FieldName::Parameters,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "parameters", named: true }],
+                },
+                Field {
+                    name: FieldName::ReturnType,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => &[
+                Field {
+                    name: FieldName::Parameters,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "parameters", named: true }],
+                },
+                Field {
+                    name: FieldName::ReturnType,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Trait,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => &[
+            ],
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => &[
+                Field {
+                    name: FieldName::Function,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_expression", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "scoped_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeArguments,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_arguments", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => &[
+                Field {
+                    name: FieldName::TypeArguments,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_arguments", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeArguments,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_arguments", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeArguments,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_arguments", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => &[
+                Field {
+                    name: FieldName::Alternative,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "else_clause", named: true }],
+                },
+                Field {
+                    name: FieldName::Condition,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "let_chain", named: true }, TypeRef { kind: "let_condition", named: true }],
+                },
+                Field {
+                    name: FieldName::Consequence,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Trait,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => &[
+            ],
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => &[
+            ],
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => &[
+            ],
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => &[
+            ],
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => &[
+            ],
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => &[
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_pattern", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => &[
+                Field {
+                    name: FieldName::Alternative,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_pattern", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => &[
+            ],
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => &[
+                Field {
+                    name: FieldName::Bounds,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "trait_bounds", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "lifetime", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => &[
+                Field {
+                    name: FieldName::Doc,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "doc_comment", named: true }],
+                },
+                Field {
+                    name: FieldName::Inner,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "inner_doc_comment_marker", named: true }],
+                },
+                Field {
+                    name: FieldName::Outer,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "outer_doc_comment_marker", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => &[
+                Field {
+                    name: FieldName::Macro,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "scoped_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => &[
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "token_tree_pattern", named: true }],
+                },
+                Field {
+                    name: FieldName::Right,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "token_tree", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => &[
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "match_pattern", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => &[
+            ],
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "match_block", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => &[
+                Field {
+                    name: FieldName::Condition,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "let_chain", named: true }, TypeRef { kind: "let_condition", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => &[
+            ],
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => &[
+            ],
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => &[
+            ],
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => &[
+            ],
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: true,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => &[
+            ],
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => &[
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_pattern", named: true }, TypeRef { kind: "self", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => &[
+            ],
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => &[
+            ],
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => &[
+                Field {
+                    name: FieldName::Alias,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => &[
+            ],
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => &[
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_literal_pattern", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+                Field {
+                    name: FieldName::Right,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_literal_pattern", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => &[
+            ],
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => &[
+            ],
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => &[
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => &[
+            ],
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => &[
+            ],
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => &[
+            ],
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => &[
+            ],
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "super", named: true }],
+                },
+                Field {
+                    name: FieldName::Path,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "bracketed_type", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Path,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "bracketed_type", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => &[
+                Field {
+                    name: FieldName::List,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "use_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Path,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => &[
+            ],
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => &[
+            ],
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => &[
+            ],
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => &[
+            ],
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => &[
+            ],
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_initializer_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "generic_type_with_turbofish", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "field_declaration_list", named: true }, TypeRef { kind: "ordered_field_declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "metavariable", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "fragment_specifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => &[
+            ],
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => &[
+            ],
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => &[
+            ],
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => &[
+            ],
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => &[
+            ],
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Bounds,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "trait_bounds", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => &[
+            ],
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => &[
+            ],
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => &[
+            ],
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => &[
+            ],
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "scoped_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => &[
+            ],
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => &[
+            ],
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeArguments,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_arguments", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => &[
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Value,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => &[
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Type,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => &[
+                Field {
+                    name: FieldName::Bounds,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "trait_bounds", named: true }],
+                },
+                Field {
+                    name: FieldName::DefaultType,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_type", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => &[
+            ],
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => &[
+            ],
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "field_declaration_list", named: true }],
+                },
+                Field {
+                    name: FieldName::Name,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "type_identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::TypeParameters,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "type_parameters", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => &[
+            ],
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => &[
+            ],
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => &[
+            ],
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => &[
+                Field {
+                    name: FieldName::Alias,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "identifier", named: true }],
+                },
+                Field {
+                    name: FieldName::Path,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => &[
+            ],
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => &[
+                Field {
+                    name: FieldName::Argument,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "scoped_use_list", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "use_as_clause", named: true }, TypeRef { kind: "use_list", named: true }, TypeRef { kind: "use_wildcard", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => &[
+            ],
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => &[
+            ],
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => &[
+                Field {
+                    name: FieldName::Pattern,
+                    multiple: false,
+                    required: false,
+                    types: &[TypeRef { kind: "_pattern", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => &[
+            ],
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => &[
+            ],
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => &[
+                Field {
+                    name: FieldName::Bounds,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "trait_bounds", named: true }],
+                },
+                Field {
+                    name: FieldName::Left,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "array_type", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "higher_ranked_trait_bound", named: true }, TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "pointer_type", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "reference_type", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "tuple_type", named: true }, TypeRef { kind: "type_identifier", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => &[
+                Field {
+                    name: FieldName::Body,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "block", named: true }],
+                },
+                Field {
+                    name: FieldName::Condition,
+                    multiple: false,
+                    required: true,
+                    types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "let_chain", named: true }, TypeRef { kind: "let_condition", named: true }],
+                },
+            ],
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => &[
+            ],
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => &[],
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => &[],
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => &[],
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => &[],
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => &[],
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => &[],
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => &[],
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => &[],
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => &[],
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => &[],
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => &[],
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => &[],
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => &[],
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => &[],
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => &[],
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => &[],
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => &[],
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => &[],
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => &[],
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => &[],
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => &[],
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => &[],
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => &[],
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => &[],
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => &[],
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => &[],
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => &[],
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => &[],
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => &[],
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => &[],
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => &[],
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => &[],
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => &[],
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => &[],
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => &[],
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => &[],
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => &[],
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => &[],
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => &[],
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => &[],
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => &[],
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => &[],
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => &[],
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => &[],
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => &[],
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => &[],
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => &[],
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => &[],
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => &[],
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => &[],
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => &[],
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => &[],
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => &[],
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => &[],
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => &[],
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => &[],
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => &[],
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => &[],
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => &[],
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => &[],
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => &[],
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => &[],
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => &[],
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => &[],
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => &[],
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => &[],
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => &[],
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => &[],
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => &[],
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => &[],
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => &[],
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => &[],
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => &[],
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => &[],
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => &[],
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => &[],
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => &[],
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => &[],
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => &[],
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => &[],
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => &[],
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => &[],
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => &[],
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => &[],
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => &[],
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => &[],
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => &[],
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => &[],
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => &[],
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => &[],
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => &[],
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => &[],
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => &[],
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => &[],
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => &[],
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => &[],
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => &[],
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => &[],
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => &[],
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => &[],
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => &[],
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => &[],
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => &[],
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => &[],
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => &[],
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => &[],
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => &[],
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => &[],
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => &[],
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => &[],
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => &[],
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => &[],
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => &[],
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => &[],
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => &[],
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => &[],
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => &[],
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => &[],
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => &[],
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => &[],
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => &[],
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => &[],
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => &[],
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => &[],
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => &[],
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => &[],
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => &[],
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => &[],
+        }
+    }
+}
+
+#[cfg(feature = "meta_children")]
+impl crate::NodeType {
+    /// The children this node can have outside of its named fields.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub const fn children(self) -> Option<&'static Children> {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => None,
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => None,
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => None,
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => None,
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => None,
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => None,
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "type_parameters", named: true }],
+            }),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "attribute_item", named: true }],
+            }),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "attribute_item", named: true }],
+            }),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => None,
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => None,
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "block", named: true }],
+            }),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+            }),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "attribute", named: true }],
+            }),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => None,
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_declaration_statement", named: true }, TypeRef { kind: "_expression", named: true }, TypeRef { kind: "expression_statement", named: true }, TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => None,
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => None,
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_type", named: true }, TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "use_bounds", named: true }],
+            }),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_type", named: true }, TypeRef { kind: "qualified_type", named: true }],
+            }),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => None,
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => None,
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_pattern", named: true }, TypeRef { kind: "parameter", named: true }],
+            }),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => None,
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => None,
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => None,
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_declaration_statement", named: true }],
+            }),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => None,
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "block", named: true }, TypeRef { kind: "if_expression", named: true }],
+            }),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => None,
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "enum_variant", named: true }],
+            }),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "string_literal", named: true }],
+            }),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "field_declaration", named: true }],
+            }),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => None,
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "attribute_item", named: true }],
+            }),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "base_field_initializer", named: true }, TypeRef { kind: "field_initializer", named: true }, TypeRef { kind: "shorthand_field_initializer", named: true }],
+            }),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "lifetime", named: true }],
+            }),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "extern_modifier", named: true }, TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => None,
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "function_modifiers", named: true }, TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "extern_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "function_modifiers", named: true }, TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "for_lifetimes", named: true }, TypeRef { kind: "function_modifiers", named: true }],
+            }),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "block", named: true }],
+            }),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => None,
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "identifier", named: true }, TypeRef { kind: "scoped_identifier", named: true }],
+            }),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => None,
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => None,
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => None,
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => None,
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "attribute", named: true }],
+            }),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => None,
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "identifier", named: true }],
+            }),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "let_condition", named: true }],
+            }),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => None,
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "identifier", named: true }],
+            }),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => None,
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => None,
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "macro_rule", named: true }],
+            }),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "token_tree", named: true }],
+            }),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => None,
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "inner_attribute_item", named: true }],
+            }),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "match_arm", named: true }],
+            }),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => None,
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }, TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "float_literal", named: true }, TypeRef { kind: "integer_literal", named: true }],
+            }),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => None,
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => None,
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_type", named: true }, TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "parameter", named: true }, TypeRef { kind: "self_parameter", named: true }, TypeRef { kind: "variadic_parameter", named: true }],
+            }),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => None,
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => None,
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "string_content", named: true }],
+            }),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_pattern", named: true }, TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => None,
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_type", named: true }],
+            }),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => None,
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => None,
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => None,
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "self", named: true }],
+            }),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "identifier", named: true }],
+            }),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_declaration_statement", named: true }, TypeRef { kind: "expression_statement", named: true }, TypeRef { kind: "shebang", named: true }],
+            }),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "escape_sequence", named: true }, TypeRef { kind: "string_content", named: true }],
+            }),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => None,
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "field_pattern", named: true }, TypeRef { kind: "remaining_field_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => None,
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "token_repetition", named: true }, TypeRef { kind: "token_tree", named: true }],
+            }),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "token_binding_pattern", named: true }, TypeRef { kind: "token_repetition_pattern", named: true }, TypeRef { kind: "token_tree_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "token_repetition", named: true }, TypeRef { kind: "token_tree", named: true }],
+            }),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "mutable_specifier", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "token_binding_pattern", named: true }, TypeRef { kind: "token_repetition_pattern", named: true }, TypeRef { kind: "token_tree_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_type", named: true }, TypeRef { kind: "higher_ranked_trait_bound", named: true }, TypeRef { kind: "lifetime", named: true }],
+            }),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "block", named: true }],
+            }),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }, TypeRef { kind: "attribute_item", named: true }],
+            }),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_pattern", named: true }, TypeRef { kind: "closure_expression", named: true }],
+            }),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "_pattern", named: true }],
+            }),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_type", named: true }],
+            }),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "_type", named: true }, TypeRef { kind: "block", named: true }, TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "trait_bounds", named: true }, TypeRef { kind: "type_binding", named: true }],
+            }),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => None,
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => None,
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => None,
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => Some(&Children {
+                multiple: true,
+                required: true,
+                types: &[TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "const_parameter", named: true }, TypeRef { kind: "lifetime_parameter", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "type_parameter", named: true }],
+            }),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }, TypeRef { kind: "where_clause", named: true }],
+            }),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => None,
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => None,
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => Some(&Children {
+                multiple: false,
+                required: true,
+                types: &[TypeRef { kind: "block", named: true }],
+            }),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => None,
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "lifetime", named: true }, TypeRef { kind: "type_identifier", named: true }],
+            }),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "visibility_modifier", named: true }],
+            }),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "scoped_use_list", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }, TypeRef { kind: "use_as_clause", named: true }, TypeRef { kind: "use_list", named: true }, TypeRef { kind: "use_wildcard", named: true }],
+            }),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+            }),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "mutable_specifier", named: true }],
+            }),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "crate", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "super", named: true }],
+            }),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => Some(&Children {
+                multiple: true,
+                required: false,
+                types: &[TypeRef { kind: "where_predicate", named: true }],
+            }),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => None,
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "label", named: true }],
+            }),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => Some(&Children {
+                multiple: false,
+                required: false,
+                types: &[TypeRef { kind: "_expression", named: true }],
+            }),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => None,
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => None,
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => None,
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => None,
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => None,
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => None,
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => None,
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => None,
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => None,
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => None,
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => None,
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => None,
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => None,
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => None,
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => None,
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => None,
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => None,
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => None,
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => None,
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => None,
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => None,
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => None,
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => None,
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => None,
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => None,
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => None,
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => None,
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => None,
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => None,
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => None,
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => None,
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => None,
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => None,
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => None,
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => None,
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => None,
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => None,
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => None,
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => None,
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => None,
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => None,
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => None,
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => None,
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => None,
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => None,
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => None,
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => None,
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => None,
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => None,
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => None,
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => None,
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => None,
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => None,
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => None,
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => None,
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => None,
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => None,
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => None,
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => None,
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => None,
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => None,
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => None,
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => None,
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => None,
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => None,
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => None,
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => None,
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => None,
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => None,
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => None,
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => None,
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => None,
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => None,
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => None,
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => None,
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => None,
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => None,
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => None,
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => None,
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => None,
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => None,
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => None,
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => None,
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => None,
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => None,
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => None,
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => None,
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => None,
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => None,
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => None,
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => None,
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => None,
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => None,
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => None,
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => None,
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => None,
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => None,
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => None,
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => None,
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => None,
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => None,
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => None,
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => None,
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => None,
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => None,
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => None,
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => None,
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => None,
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => None,
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => None,
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => None,
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => None,
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => None,
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => None,
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => None,
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => None,
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => None,
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => None,
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => None,
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => None,
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => None,
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => None,
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => None,
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => None,
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => None,
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => None,
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => None,
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => None,
+        }
+    }
+}
+
+#[cfg(feature = "meta_subtypes")]
+impl crate::NodeType {
+    /// The direct subtypes of this node, if it is a supertype (e.g. `_expression`).
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub const fn subtypes(self) -> &'static [TypeRef] {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => subtypes_of("_declaration_statement"),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => subtypes_of("_expression"),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => subtypes_of("_literal"),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => subtypes_of("_literal_pattern"),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => subtypes_of("_pattern"),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => subtypes_of("_type"),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => &[],
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => &[],
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => &[],
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => &[],
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => &[],
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => &[],
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => &[],
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => &[],
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => &[],
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => &[],
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => &[],
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => &[],
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => &[],
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => &[],
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => &[],
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => &[],
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => &[],
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => &[],
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => &[],
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => &[],
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => &[],
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => &[],
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => &[],
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => &[],
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => &[],
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => &[],
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => &[],
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => &[],
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => &[],
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => &[],
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => &[],
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => &[],
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => &[],
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => &[],
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => &[],
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => &[],
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => &[],
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => &[],
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => &[],
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => &[],
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => &[],
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => &[],
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => &[],
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => &[],
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => &[],
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => &[],
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => &[],
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => &[],
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => &[],
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => &[],
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => &[],
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => &[],
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => &[],
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => &[],
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => &[],
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => &[],
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => &[],
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => &[],
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => &[],
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => &[],
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => &[],
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => &[],
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => &[],
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => &[],
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => &[],
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => &[],
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => &[],
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => &[],
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => &[],
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => &[],
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => &[],
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => &[],
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => &[],
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => &[],
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => &[],
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => &[],
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => &[],
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => &[],
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => &[],
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => &[],
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => &[],
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => &[],
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => &[],
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => &[],
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => &[],
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => &[],
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => &[],
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => &[],
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => &[],
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => &[],
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => &[],
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => &[],
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => &[],
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => &[],
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => &[],
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => &[],
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => &[],
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => &[],
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => &[],
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => &[],
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => &[],
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => &[],
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => &[],
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => &[],
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => &[],
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => &[],
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => &[],
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => &[],
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => &[],
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => &[],
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => &[],
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => &[],
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => &[],
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => &[],
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => &[],
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => &[],
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => &[],
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => &[],
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => &[],
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => &[],
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => &[],
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => &[],
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => &[],
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => &[],
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => &[],
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => &[],
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => &[],
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => &[],
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => &[],
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => &[],
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => &[],
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => &[],
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => &[],
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => &[],
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => &[],
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => &[],
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => &[],
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => &[],
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => &[],
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => &[],
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => &[],
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => &[],
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => &[],
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => &[],
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => &[],
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => &[],
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => &[],
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => &[],
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => &[],
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => &[],
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => &[],
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => &[],
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => &[],
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => &[],
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => &[],
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => &[],
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => &[],
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => &[],
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => &[],
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => &[],
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => &[],
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => &[],
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => &[],
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => &[],
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => &[],
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => &[],
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => &[],
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => &[],
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => &[],
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => &[],
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => &[],
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => &[],
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => &[],
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => &[],
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => &[],
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => &[],
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => &[],
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => &[],
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => &[],
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => &[],
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => &[],
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => &[],
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => &[],
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => &[],
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => &[],
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => &[],
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => &[],
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => &[],
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => &[],
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => &[],
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => &[],
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => &[],
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => &[],
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => &[],
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => &[],
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => &[],
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => &[],
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => &[],
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => &[],
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => &[],
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => &[],
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => &[],
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => &[],
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => &[],
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => &[],
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => &[],
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => &[],
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => &[],
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => &[],
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => &[],
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => &[],
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => &[],
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => &[],
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => &[],
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => &[],
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => &[],
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => &[],
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => &[],
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => &[],
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => &[],
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => &[],
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => &[],
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => &[],
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => &[],
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => &[],
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => &[],
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => &[],
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => &[],
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => &[],
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => &[],
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => &[],
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => &[],
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => &[],
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => &[],
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => &[],
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => &[],
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => &[],
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => &[],
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => &[],
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => &[],
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => &[],
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => &[],
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => &[],
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => &[],
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => &[],
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => &[],
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => &[],
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => &[],
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => &[],
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => &[],
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => &[],
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => &[],
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => &[],
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => &[],
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => &[],
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => &[],
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => &[],
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => &[],
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => &[],
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => &[],
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => &[],
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => &[],
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => &[],
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => &[],
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => &[],
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => &[],
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => &[],
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => &[],
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => &[],
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => &[],
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => &[],
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => &[],
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => &[],
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => &[],
+        }
+    }
+}
+
+/// The direct subtypes of the supertype `kind`, or an empty slice for any other kind.
+///
+/// Unlike [`NodeType::subtypes`](crate::NodeType::subtypes), this lookup does not depend
+/// on which node type features are enabled.
+#[cfg(feature = "meta_subtypes")]
+#[must_use]
+pub const fn subtypes_of(kind: &str) -> &'static [TypeRef] {
+    match kind.as_bytes() {
+        b"_declaration_statement" => &[TypeRef { kind: "associated_type", named: true }, TypeRef { kind: "attribute_item", named: true }, TypeRef { kind: "const_item", named: true }, TypeRef { kind: "empty_statement", named: true }, TypeRef { kind: "enum_item", named: true }, TypeRef { kind: "extern_crate_declaration", named: true }, TypeRef { kind: "foreign_mod_item", named: true }, TypeRef { kind: "function_item", named: true }, TypeRef { kind: "function_signature_item", named: true }, TypeRef { kind: "impl_item", named: true }, TypeRef { kind: "inner_attribute_item", named: true }, TypeRef { kind: "let_declaration", named: true }, TypeRef { kind: "macro_definition", named: true }, TypeRef { kind: "macro_invocation", named: true }, TypeRef { kind: "mod_item", named: true }, TypeRef { kind: "static_item", named: true }, TypeRef { kind: "struct_item", named: true }, TypeRef { kind: "trait_item", named: true }, TypeRef { kind: "type_item", named: true }, TypeRef { kind: "union_item", named: true }, TypeRef { kind: "use_declaration", named: true }],
+        b"_expression" => &[TypeRef { kind: "_literal", named: true }, TypeRef { kind: "array_expression", named: true }, TypeRef { kind: "assignment_expression", named: true }, TypeRef { kind: "async_block", named: true }, TypeRef { kind: "await_expression", named: true }, TypeRef { kind: "binary_expression", named: true }, TypeRef { kind: "block", named: true }, TypeRef { kind: "break_expression", named: true }, TypeRef { kind: "call_expression", named: true }, TypeRef { kind: "closure_expression", named: true }, TypeRef { kind: "compound_assignment_expr", named: true }, TypeRef { kind: "const_block", named: true }, TypeRef { kind: "continue_expression", named: true }, TypeRef { kind: "field_expression", named: true }, TypeRef { kind: "for_expression", named: true }, TypeRef { kind: "gen_block", named: true }, TypeRef { kind: "generic_function", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "if_expression", named: true }, TypeRef { kind: "index_expression", named: true }, TypeRef { kind: "loop_expression", named: true }, TypeRef { kind: "macro_invocation", named: true }, TypeRef { kind: "match_expression", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "parenthesized_expression", named: true }, TypeRef { kind: "range_expression", named: true }, TypeRef { kind: "reference_expression", named: true }, TypeRef { kind: "return_expression", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "self", named: true }, TypeRef { kind: "struct_expression", named: true }, TypeRef { kind: "try_block", named: true }, TypeRef { kind: "try_expression", named: true }, TypeRef { kind: "tuple_expression", named: true }, TypeRef { kind: "type_cast_expression", named: true }, TypeRef { kind: "unary_expression", named: true }, TypeRef { kind: "unit_expression", named: true }, TypeRef { kind: "unsafe_block", named: true }, TypeRef { kind: "while_expression", named: true }, TypeRef { kind: "yield_expression", named: true }],
+        b"_literal" => &[TypeRef { kind: "boolean_literal", named: true }, TypeRef { kind: "char_literal", named: true }, TypeRef { kind: "float_literal", named: true }, TypeRef { kind: "integer_literal", named: true }, TypeRef { kind: "raw_string_literal", named: true }, TypeRef { kind: "string_literal", named: true }],
+        b"_literal_pattern" => &[TypeRef { kind: "boolean_literal", named: true }, TypeRef { kind: "char_literal", named: true }, TypeRef { kind: "float_literal", named: true }, TypeRef { kind: "integer_literal", named: true }, TypeRef { kind: "negative_literal", named: true }, TypeRef { kind: "raw_string_literal", named: true }, TypeRef { kind: "string_literal", named: true }],
+        b"_pattern" => &[TypeRef { kind: "_", named: false }, TypeRef { kind: "_literal_pattern", named: true }, TypeRef { kind: "captured_pattern", named: true }, TypeRef { kind: "const_block", named: true }, TypeRef { kind: "generic_pattern", named: true }, TypeRef { kind: "identifier", named: true }, TypeRef { kind: "macro_invocation", named: true }, TypeRef { kind: "mut_pattern", named: true }, TypeRef { kind: "or_pattern", named: true }, TypeRef { kind: "range_pattern", named: true }, TypeRef { kind: "ref_pattern", named: true }, TypeRef { kind: "reference_pattern", named: true }, TypeRef { kind: "remaining_field_pattern", named: true }, TypeRef { kind: "scoped_identifier", named: true }, TypeRef { kind: "slice_pattern", named: true }, TypeRef { kind: "struct_pattern", named: true }, TypeRef { kind: "tuple_pattern", named: true }, TypeRef { kind: "tuple_struct_pattern", named: true }],
+        b"_type" => &[TypeRef { kind: "abstract_type", named: true }, TypeRef { kind: "array_type", named: true }, TypeRef { kind: "bounded_type", named: true }, TypeRef { kind: "dynamic_type", named: true }, TypeRef { kind: "function_type", named: true }, TypeRef { kind: "generic_type", named: true }, TypeRef { kind: "macro_invocation", named: true }, TypeRef { kind: "metavariable", named: true }, TypeRef { kind: "never_type", named: true }, TypeRef { kind: "pointer_type", named: true }, TypeRef { kind: "primitive_type", named: true }, TypeRef { kind: "reference_type", named: true }, TypeRef { kind: "removed_trait_bound", named: true }, TypeRef { kind: "scoped_type_identifier", named: true }, TypeRef { kind: "tuple_type", named: true }, TypeRef { kind: "type_identifier", named: true }, TypeRef { kind: "unit_type", named: true }],
+        _ => &[],
+    }
+}
+#[cfg(feature = "meta_tokens")]
+impl crate::NodeType {
+    /// The lexical category of this node, for syntax highlighting and formatting.
+    ///
+    /// Structural nodes such as `function_item` are [`TokenCategory::Other`].
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn category(self) -> TokenCategory {
+        match self {
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => TokenCategory::Comment,
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => TokenCategory::Comment,
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => TokenCategory::Comment,
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => TokenCategory::Comment,
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => TokenCategory::Operator,
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => TokenCategory::Operator,
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => TokenCategory::Operator,
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => TokenCategory::Operator,
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => TokenCategory::Operator,
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => TokenCategory::Operator,
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => TokenCategory::Comment,
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => TokenCategory::Operator,
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => TokenCategory::Operator,
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => TokenCategory::Operator,
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => TokenCategory::Operator,
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => TokenCategory::Operator,
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => TokenCategory::Operator,
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => TokenCategory::Comment,
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => TokenCategory::Comment,
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => TokenCategory::Operator,
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => TokenCategory::Operator,
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => TokenCategory::Operator,
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => TokenCategory::Operator,
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => TokenCategory::Operator,
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => TokenCategory::Operator,
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => TokenCategory::Operator,
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => TokenCategory::Operator,
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => TokenCategory::Operator,
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => TokenCategory::Operator,
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => TokenCategory::Keyword,
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => TokenCategory::Comment,
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => TokenCategory::Literal,
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => TokenCategory::Identifier,
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => TokenCategory::Identifier,
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => TokenCategory::Literal,
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => TokenCategory::Identifier,
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => TokenCategory::Keyword,
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => TokenCategory::Keyword,
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => TokenCategory::Comment,
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => TokenCategory::Identifier,
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => TokenCategory::Literal,
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => TokenCategory::Keyword,
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => TokenCategory::Identifier,
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => TokenCategory::Keyword,
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => TokenCategory::Punctuation,
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => TokenCategory::Operator,
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => TokenCategory::Operator,
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => TokenCategory::Operator,
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => TokenCategory::Punctuation,
+            _ => TokenCategory::Other,
+        }
+    }
+
+    /// Whether this opens or closes a delimited region: a bracket, `/*` or `*/`.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn is_delimiter(self) -> bool {
+        match self {
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => true,
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => true,
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => true,
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => true,
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => true,
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => true,
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => true,
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this opens a delimited region: `(`, `[`, `{` or `/*`.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn is_open_delimiter(self) -> bool {
+        match self {
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => true,
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => true,
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => true,
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this closes a delimited region: `)`, `]`, `}` or `*/`.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn is_close_delimiter(self) -> bool {
+        match self {
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => true,
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => true,
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => true,
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => true,
+            _ => false,
+        }
+    }
+
+    /// The delimiter that pairs with this one: `(` with `)`, `[` with `]`, `{` with `}` and
+    /// `/*` with `*/`, in either direction.
+    ///
+    /// Returns `None` for other nodes, or when the partner's feature is not enabled.
+    #[must_use]
+    #[allow(unreachable_patterns)]
+    pub const fn matching_delimiter(self) -> Option<crate::NodeType> {
+        match self {
+            #[cfg(all(any(feature = "l_paren_token", feature = "node_full"), any(feature = "r_paren_token", feature = "node_full")))]
+            Self::LParenToken => Some(Self::RParenToken),
+            #[cfg(all(any(feature = "r_paren_token", feature = "node_full"), any(feature = "l_paren_token", feature = "node_full")))]
+            Self::RParenToken => Some(Self::LParenToken),
+            #[cfg(all(any(feature = "block_comment_end_token", feature = "node_full"), any(feature = "block_comment_start_token", feature = "node_full")))]
+            Self::BlockCommentEndToken => Some(Self::BlockCommentStartToken),
+            #[cfg(all(any(feature = "block_comment_start_token", feature = "node_full"), any(feature = "block_comment_end_token", feature = "node_full")))]
+            Self::BlockCommentStartToken => Some(Self::BlockCommentEndToken),
+            #[cfg(all(any(feature = "l_bracket_token", feature = "node_full"), any(feature = "r_bracket_token", feature = "node_full")))]
+            Self::LBracketToken => Some(Self::RBracketToken),
+            #[cfg(all(any(feature = "r_bracket_token", feature = "node_full"), any(feature = "l_bracket_token", feature = "node_full")))]
+            Self::RBracketToken => Some(Self::LBracketToken),
+            #[cfg(all(any(feature = "l_brace_token", feature = "node_full"), any(feature = "r_brace_token", feature = "node_full")))]
+            Self::LBraceToken => Some(Self::RBraceToken),
+            #[cfg(all(any(feature = "r_brace_token", feature = "node_full"), any(feature = "l_brace_token", feature = "node_full")))]
+            Self::RBraceToken => Some(Self::LBraceToken),
+            _ => None,
+        }
+    }
+
+    /// The delimiter token spelled `kind`, such as `LParenToken` for `"("`.
+    ///
+    /// `FromStr` only parses named kinds, so this is how an unnamed tree-sitter node is
+    /// recognised as a delimiter. Returns `None` for any other kind, or when the token's
+    /// feature is not enabled.
+    #[must_use]
+    pub const fn from_delimiter(kind: &str) -> Option<crate::NodeType> {
+        match kind.as_bytes() {
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            b"(" => Some(Self::LParenToken),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            b")" => Some(Self::RParenToken),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            b"*/" => Some(Self::BlockCommentEndToken),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            b"/*" => Some(Self::BlockCommentStartToken),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            b"[" => Some(Self::LBracketToken),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            b"]" => Some(Self::RBracketToken),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            b"{" => Some(Self::LBraceToken),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            b"}" => Some(Self::RBraceToken),
+            _ => None,
+        }
+    }
+
+    /// Whether this is `=` or a compound assignment operator such as `+=`.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn is_assignment_operator(self) -> bool {
+        match self {
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => true,
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => true,
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => true,
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => true,
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => true,
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => true,
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => true,
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => true,
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => true,
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => true,
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => true,
+            _ => false,
+        }
+    }
+
+    /// The precedence of this token as a binary operator, where higher binds tighter.
+    ///
+    /// This follows the expression precedence table of the Rust Reference: `as` binds
+    /// tightest and assignment loosest. Tokens that are also unary operators (`-`, `*`,
+    /// `&`) report their binary precedence.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn binary_precedence(self) -> Option<u8> {
+        match self {
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => Some(4),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => Some(10),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => Some(0),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => Some(7),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => Some(3),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => Some(0),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => Some(10),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => Some(0),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => Some(9),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => Some(0),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => Some(9),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => Some(0),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => Some(1),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => Some(1),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => Some(10),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => Some(0),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => Some(4),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => Some(8),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => Some(0),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => Some(4),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => Some(0),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => Some(4),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => Some(4),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => Some(4),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => Some(8),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => Some(0),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => Some(6),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => Some(0),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => Some(11),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => Some(5),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => Some(0),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => Some(2),
+            _ => None,
+        }
+    }
+
+    /// How this token groups as a binary operator with others of the same precedence.
+    ///
+    /// Comparison and range operators are [`Associativity::None`]: chaining them needs
+    /// parentheses.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn associativity(self) -> Option<Associativity> {
+        match self {
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => Some(Associativity::None),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => Some(Associativity::Left),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => Some(Associativity::Left),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => Some(Associativity::Left),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => Some(Associativity::Left),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => Some(Associativity::Left),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => Some(Associativity::Left),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => Some(Associativity::None),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => Some(Associativity::None),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => Some(Associativity::Left),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => Some(Associativity::None),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => Some(Associativity::Left),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => Some(Associativity::None),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => Some(Associativity::Right),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => Some(Associativity::None),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => Some(Associativity::None),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => Some(Associativity::None),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => Some(Associativity::Left),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => Some(Associativity::Left),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => Some(Associativity::Left),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => Some(Associativity::Left),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => Some(Associativity::Right),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => Some(Associativity::Left),
+            _ => None,
+        }
+    }
+
+}
+
+#[cfg(feature = "meta_docs")]
+impl crate::NodeType {
+    /// The Rust Reference section describing this node, for hover documentation.
+    ///
+    /// Keyword, operator and comment tokens link to the lexical sections that list them.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn reference_url(self) -> Option<&'static str> {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => Some("https://doc.rust-lang.org/reference/items.html"),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => Some("https://doc.rust-lang.org/reference/expressions.html"),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => Some("https://doc.rust-lang.org/reference/expressions/literal-expr.html"),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => Some("https://doc.rust-lang.org/reference/patterns.html#literal-patterns"),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => Some("https://doc.rust-lang.org/reference/patterns.html"),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => Some("https://doc.rust-lang.org/reference/types.html"),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => Some("https://doc.rust-lang.org/reference/types/impl-trait.html"),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => Some("https://doc.rust-lang.org/reference/expressions/call-expr.html"),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => Some("https://doc.rust-lang.org/reference/expressions/array-expr.html"),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => Some("https://doc.rust-lang.org/reference/types/array.html"),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#assignment-expressions"),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => Some("https://doc.rust-lang.org/reference/items/associated-items.html#associated-types"),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => Some("https://doc.rust-lang.org/reference/expressions/block-expr.html#async-blocks"),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => Some("https://doc.rust-lang.org/reference/attributes.html"),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => Some("https://doc.rust-lang.org/reference/attributes.html"),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => Some("https://doc.rust-lang.org/reference/expressions/await-expr.html"),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => Some("https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax"),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html"),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => Some("https://doc.rust-lang.org/reference/expressions/block-expr.html"),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => Some("https://doc.rust-lang.org/reference/comments.html"),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#boolean-literals"),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => Some("https://doc.rust-lang.org/reference/trait-bounds.html"),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => Some("https://doc.rust-lang.org/reference/paths.html#qualified-paths"),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#break-expressions"),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => Some("https://doc.rust-lang.org/reference/expressions/call-expr.html"),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => Some("https://doc.rust-lang.org/reference/patterns.html#identifier-patterns"),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => Some("https://doc.rust-lang.org/reference/expressions/closure-expr.html"),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => Some("https://doc.rust-lang.org/reference/expressions/closure-expr.html"),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#compound-assignment-expressions"),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => Some("https://doc.rust-lang.org/reference/expressions/block-expr.html#const-blocks"),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => Some("https://doc.rust-lang.org/reference/items/constant-items.html"),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => Some("https://doc.rust-lang.org/reference/items/generics.html#const-generics"),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#continue-expressions"),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => Some("https://doc.rust-lang.org/reference/items/associated-items.html"),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => Some("https://doc.rust-lang.org/reference/types/trait-object.html"),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => Some("https://doc.rust-lang.org/reference/expressions/if-expr.html"),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => Some("https://doc.rust-lang.org/reference/statements.html"),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => Some("https://doc.rust-lang.org/reference/items/enumerations.html"),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => Some("https://doc.rust-lang.org/reference/items/enumerations.html"),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => Some("https://doc.rust-lang.org/reference/items/enumerations.html"),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => Some("https://doc.rust-lang.org/reference/statements.html#expression-statements"),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => Some("https://doc.rust-lang.org/reference/items/extern-crates.html"),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => Some("https://doc.rust-lang.org/reference/items/functions.html#extern-function-qualifier"),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => Some("https://doc.rust-lang.org/reference/items/structs.html"),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => Some("https://doc.rust-lang.org/reference/items/structs.html"),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => Some("https://doc.rust-lang.org/reference/expressions/field-expr.html"),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => Some("https://doc.rust-lang.org/reference/expressions/struct-expr.html"),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => Some("https://doc.rust-lang.org/reference/expressions/struct-expr.html"),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => Some("https://doc.rust-lang.org/reference/patterns.html#struct-patterns"),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#iterator-loops"),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => Some("https://doc.rust-lang.org/reference/trait-bounds.html#higher-ranked-trait-bounds"),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => Some("https://doc.rust-lang.org/reference/items/external-blocks.html"),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => Some("https://doc.rust-lang.org/reference/macros-by-example.html#metavariables"),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => Some("https://doc.rust-lang.org/reference/items/functions.html"),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => Some("https://doc.rust-lang.org/reference/items/functions.html"),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => Some("https://doc.rust-lang.org/reference/items/functions.html"),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => Some("https://doc.rust-lang.org/reference/types/function-pointer.html"),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-expressions"),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => Some("https://doc.rust-lang.org/reference/patterns.html#path-patterns"),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-types"),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-types"),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => Some("https://doc.rust-lang.org/reference/trait-bounds.html#higher-ranked-trait-bounds"),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => Some("https://doc.rust-lang.org/reference/expressions/if-expr.html"),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => Some("https://doc.rust-lang.org/reference/items/implementations.html"),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => Some("https://doc.rust-lang.org/reference/expressions/array-expr.html#array-and-slice-indexing-expressions"),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => Some("https://doc.rust-lang.org/reference/attributes.html"),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => Some("https://doc.rust-lang.org/reference/comments.html#doc-comments"),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#loop-labels"),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => Some("https://doc.rust-lang.org/reference/expressions/if-expr.html#chains-of-conditions"),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => Some("https://doc.rust-lang.org/reference/expressions/if-expr.html#chains-of-conditions"),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => Some("https://doc.rust-lang.org/reference/statements.html#let-statements"),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => Some("https://doc.rust-lang.org/reference/tokens.html#lifetimes-and-loop-labels"),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => Some("https://doc.rust-lang.org/reference/items/generics.html"),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => Some("https://doc.rust-lang.org/reference/comments.html"),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#infinite-loops"),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => Some("https://doc.rust-lang.org/reference/macros-by-example.html"),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => Some("https://doc.rust-lang.org/reference/macros.html#macro-invocation"),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => Some("https://doc.rust-lang.org/reference/macros-by-example.html"),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => Some("https://doc.rust-lang.org/reference/expressions/match-expr.html"),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => Some("https://doc.rust-lang.org/reference/expressions/match-expr.html"),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => Some("https://doc.rust-lang.org/reference/expressions/match-expr.html"),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => Some("https://doc.rust-lang.org/reference/expressions/match-expr.html"),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => Some("https://doc.rust-lang.org/reference/items/modules.html"),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => Some("https://doc.rust-lang.org/reference/patterns.html#identifier-patterns"),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => Some("https://doc.rust-lang.org/reference/patterns.html#literal-patterns"),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => Some("https://doc.rust-lang.org/reference/types/never.html"),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => Some("https://doc.rust-lang.org/reference/patterns.html"),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => Some("https://doc.rust-lang.org/reference/items/structs.html"),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => Some("https://doc.rust-lang.org/reference/comments.html#doc-comments"),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => Some("https://doc.rust-lang.org/reference/items/functions.html#function-parameters"),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => Some("https://doc.rust-lang.org/reference/items/functions.html#function-parameters"),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => Some("https://doc.rust-lang.org/reference/expressions/grouped-expr.html"),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => Some("https://doc.rust-lang.org/reference/types/pointer.html#raw-pointers-const-and-mut"),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => Some("https://doc.rust-lang.org/reference/paths.html#qualified-paths"),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => Some("https://doc.rust-lang.org/reference/expressions/range-expr.html"),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => Some("https://doc.rust-lang.org/reference/patterns.html#range-patterns"),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#raw-string-literals"),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => Some("https://doc.rust-lang.org/reference/patterns.html#identifier-patterns"),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#borrow-operators"),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => Some("https://doc.rust-lang.org/reference/patterns.html#reference-patterns"),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => Some("https://doc.rust-lang.org/reference/types/pointer.html#references--and-mut"),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => Some("https://doc.rust-lang.org/reference/patterns.html#rest-patterns"),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => Some("https://doc.rust-lang.org/reference/trait-bounds.html"),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => Some("https://doc.rust-lang.org/reference/expressions/return-expr.html"),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-expressions"),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-types"),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => Some("https://doc.rust-lang.org/reference/items/use-declarations.html"),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => Some("https://doc.rust-lang.org/reference/items/associated-items.html#methods"),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => Some("https://doc.rust-lang.org/reference/expressions/struct-expr.html"),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => Some("https://doc.rust-lang.org/reference/patterns.html#slice-patterns"),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => Some("https://doc.rust-lang.org/reference/crates-and-source-files.html"),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => Some("https://doc.rust-lang.org/reference/items/static-items.html"),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#string-literals"),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => Some("https://doc.rust-lang.org/reference/expressions/struct-expr.html"),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => Some("https://doc.rust-lang.org/reference/items/structs.html"),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => Some("https://doc.rust-lang.org/reference/patterns.html#struct-patterns"),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => Some("https://doc.rust-lang.org/reference/macros-by-example.html#metavariables"),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => Some("https://doc.rust-lang.org/reference/macros.html#macro-invocation"),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => Some("https://doc.rust-lang.org/reference/macros-by-example.html#repetitions"),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => Some("https://doc.rust-lang.org/reference/macros.html#macro-invocation"),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => Some("https://doc.rust-lang.org/reference/macros-by-example.html"),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => Some("https://doc.rust-lang.org/reference/trait-bounds.html"),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => Some("https://doc.rust-lang.org/reference/items/traits.html"),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#the-question-mark-operator"),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => Some("https://doc.rust-lang.org/reference/expressions/tuple-expr.html"),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => Some("https://doc.rust-lang.org/reference/patterns.html#tuple-patterns"),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => Some("https://doc.rust-lang.org/reference/patterns.html#tuple-struct-patterns"),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => Some("https://doc.rust-lang.org/reference/types/tuple.html"),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-expressions"),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => Some("https://doc.rust-lang.org/reference/paths.html#paths-in-expressions"),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#type-cast-expressions"),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => Some("https://doc.rust-lang.org/reference/items/type-aliases.html"),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => Some("https://doc.rust-lang.org/reference/items/generics.html"),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => Some("https://doc.rust-lang.org/reference/items/generics.html"),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => Some("https://doc.rust-lang.org/reference/expressions/operator-expr.html#negation-operators"),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => Some("https://doc.rust-lang.org/reference/items/unions.html"),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => Some("https://doc.rust-lang.org/reference/expressions/tuple-expr.html"),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => Some("https://doc.rust-lang.org/reference/types/tuple.html"),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => Some("https://doc.rust-lang.org/reference/expressions/block-expr.html#unsafe-blocks"),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => Some("https://doc.rust-lang.org/reference/items/use-declarations.html"),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => Some("https://doc.rust-lang.org/reference/trait-bounds.html#use-bounds"),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => Some("https://doc.rust-lang.org/reference/items/use-declarations.html"),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => Some("https://doc.rust-lang.org/reference/items/use-declarations.html"),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => Some("https://doc.rust-lang.org/reference/items/use-declarations.html"),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => Some("https://doc.rust-lang.org/reference/items/external-blocks.html#variadic-functions"),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => Some("https://doc.rust-lang.org/reference/visibility-and-privacy.html"),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => Some("https://doc.rust-lang.org/reference/items/generics.html#where-clauses"),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => Some("https://doc.rust-lang.org/reference/items/generics.html#where-clauses"),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => Some("https://doc.rust-lang.org/reference/expressions/loop-expr.html#predicate-loops"),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => Some("https://doc.rust-lang.org/reference/comments.html"),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => Some("https://doc.rust-lang.org/reference/comments.html"),
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => Some("https://doc.rust-lang.org/reference/comments.html"),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#character-literals"),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => Some("https://doc.rust-lang.org/reference/paths.html#path-qualifiers"),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => Some("https://doc.rust-lang.org/reference/comments.html#doc-comments"),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => Some("https://doc.rust-lang.org/reference/tokens.html#escapes"),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => Some("https://doc.rust-lang.org/reference/identifiers.html"),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#floating-point-literals"),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => Some("https://doc.rust-lang.org/reference/identifiers.html"),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => Some("https://doc.rust-lang.org/reference/tokens.html#integer-literals"),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => Some("https://doc.rust-lang.org/reference/macros-by-example.html#metavariables"),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => Some("https://doc.rust-lang.org/reference/patterns.html#identifier-patterns"),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => Some("https://doc.rust-lang.org/reference/types.html"),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => Some("https://doc.rust-lang.org/reference/paths.html#path-qualifiers"),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => Some("https://doc.rust-lang.org/reference/input-format.html#shebang-removal"),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => Some("https://doc.rust-lang.org/reference/identifiers.html"),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => Some("https://doc.rust-lang.org/reference/tokens.html#string-literals"),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => Some("https://doc.rust-lang.org/reference/paths.html#path-qualifiers"),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => Some("https://doc.rust-lang.org/reference/identifiers.html"),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => Some("https://doc.rust-lang.org/reference/keywords.html"),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => Some("https://doc.rust-lang.org/reference/tokens.html#punctuation"),
+            _ => None,
+        }
+    }
+
+}
+
+#[cfg(feature = "meta_examples")]
+impl crate::NodeType {
+    /// A minimal Rust source file whose syntax tree contains this node.
+    ///
+    /// For supertypes such as `_expression`, which never appear in a tree themselves, the
+    /// example contains one of their subtypes.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn example(self) -> Option<&'static str> {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => Some("fn add(a: i32, b: i32) -> i32 { a + b }"),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => Some("a + b;"),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => Some("42;"),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => Some("match x { -1 => {} }"),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => Some("match x { 1 | 2 => {} }"),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => Some("let x: u8 = 1;"),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => Some("fn f() -> impl Display { 1 }"),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => Some("f(1, 2);"),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => Some("[1, 2, 3];"),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => Some("let a: [u8; 4] = b;"),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => Some("x = 1;"),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => Some("trait Iterator { type Item; }"),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => Some("async move { f().await };"),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => Some("#[derive(Debug)]\nstruct S;"),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => Some("#[derive(Debug)]\nstruct S;"),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => Some("f().await;"),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => Some("Point { x: 1, ..p };"),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => Some("a + b;"),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => Some("fn f() { g(); }"),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => Some("/* note */"),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => Some("true;"),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => Some("fn f(x: Box<dyn Send + 'static>) {}"),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => Some("<T as Default>::default();"),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => Some("loop { break; }"),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => Some("f(1, 2);"),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => Some("match x { n @ 1..=9 => {} }"),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => Some("|a, b| a + b;"),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => Some("|a, b| a + b;"),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => Some("x += 1;"),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => Some("const { 1 + 1 };"),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => Some("const MAX: u32 = 10;"),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => Some("struct Buf<const N: usize>;"),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => Some("loop { continue; }"),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => Some("impl Point { fn new() {} }"),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => Some("fn f(x: &dyn Display) {}"),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => Some("if x { f() } else { g() }"),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => Some(";"),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => Some("enum Color { Red, Green }"),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => Some("enum Color { Red, Green }"),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => Some("enum Color { Red, Green }"),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => Some("f();"),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => Some("extern crate alloc;"),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => Some("extern \"C\" fn f() {}"),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => Some("struct Point { x: i32, y: i32 }"),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => Some("struct Point { x: i32, y: i32 }"),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => Some("p.x;"),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => Some("Point { x: 1, y: 2 };"),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => Some("Point { x: 1, y: 2 };"),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => Some("let Point { x: a, y: b } = p;"),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => Some("for x in xs { f(x) }"),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => Some("let f: for<'a> fn(&'a u8) = g;"),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => Some("extern \"C\" { fn abs(x: i32) -> i32; }"),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => Some("fn add(a: i32, b: i32) -> i32 { a + b }"),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => Some("const unsafe fn f() {}"),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => Some("trait Shape { fn area(&self) -> f64; }"),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => Some("let f: fn(i32) -> i32 = g;"),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => Some("gen { yield 1; };"),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => Some("parse::<i32>(s);"),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => Some("match x { Foo::<u8> => {} }"),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => Some("let v: Vec<i32> = x;"),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => Some("Foo::<u8> { x: 1 };"),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => Some("fn f<F>(f: F) where F: for<'a> Fn(&'a str) {}"),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => Some("if x { f() }"),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => Some("impl Point { fn new() {} }"),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => Some("a[0];"),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => Some("#![no_std]"),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => Some("//! Docs"),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => Some("'outer: loop { break 'outer; }"),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => Some("if let Some(x) = y && x > 0 { f(x) }"),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => Some("if let Some(x) = y { f(x) }"),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => Some("let x = 1;"),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => Some("fn first<'a>(x: &'a str) -> &'a str { x }"),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => Some("fn first<'a>(x: &'a str) -> &'a str { x }"),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => Some("// note"),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => Some("loop { f() }"),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => Some("println!(\"hi\");"),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => Some("match x { Some(y) if y > 0 => y, _ => 0 }"),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => Some("match x { Some(y) if y > 0 => y, _ => 0 }"),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => Some("match x { Some(y) if y > 0 => y, _ => 0 }"),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => Some("match x { Some(y) if y > 0 => y, _ => 0 }"),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => Some("mod tests {}"),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => Some("match x { mut y => {} }"),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => Some("match x { -1 => {} }"),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => Some("fn exit() -> ! { loop {} }"),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => Some("match x { 1 | 2 => {} }"),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => Some("struct Pair(i32, i32);"),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => Some("/// Docs\nfn f() {}"),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => Some("fn f(x: i32) {}"),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => Some("fn f(x: i32) {}"),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => Some("(a + b) * c;"),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => Some("let p: *const u8 = q;"),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => Some("<T as Default>::default();"),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => Some("0..10;"),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => Some("match x { 1..=9 => {} }"),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => Some("r#\"raw\"#;"),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => Some("let ref x = y;"),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => Some("&mut x;"),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => Some("let &x = y;"),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => Some("fn f(x: &mut i32) {}"),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => Some("let Point { x, .. } = p;"),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => Some("fn f<T: ?Sized>(x: &T) {}"),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => Some("fn f() { return; }"),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => Some("std::mem::swap(a, b);"),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => Some("let x: std::io::Error = e;"),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => Some("use std::{fmt, io};"),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => Some("impl S { fn get(&self) {} }"),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => Some("Point { x, y };"),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => Some("let [a, b] = s;"),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => Some("fn main() {}"),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => Some("static NAME: &str = \"x\";"),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => Some("\"hello\";"),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => Some("Point { x: 1, y: 2 };"),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => Some("struct Point { x: i32, y: i32 }"),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => Some("let Point { x: a, y: b } = p;"),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => Some("macro_rules! m { ($($e:expr),*) => { $($e;)* }; }"),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => Some("macro_rules! all { ($($e:expr),*) => {}; }"),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => Some("println!(\"hi\");"),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => Some("fn f<T: Clone + Send>(x: T) {}"),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => Some("trait Shape { fn area(&self) -> f64; }"),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => Some("try { f()? };"),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => Some("f()?;"),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => Some("(1, true);"),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => Some("let (a, b) = t;"),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => Some("let Some(x) = y;"),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => Some("let t: (i32, bool) = u;"),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => Some("let v: Vec<i32> = x;"),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => Some("fn f(x: impl Iterator<Item = u8>) {}"),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => Some("x as u8;"),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => Some("type Id = u64;"),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => Some("fn id<T>(x: T) -> T { x }"),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => Some("fn id<T>(x: T) -> T { x }"),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => Some("-x;"),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => Some("union Bits { i: u32, f: f32 }"),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => Some("();"),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => Some("fn f() -> () {}"),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => Some("unsafe { f() };"),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => Some("use std::io::Result as IoResult;"),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => Some("fn f<'a>(x: &'a str) -> impl Sized + use<'a> { x }"),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => Some("use std::fmt;"),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => Some("use std::{fmt, io};"),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => Some("use std::io::*;"),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => Some("extern \"C\" { fn printf(format: *const u8, ...); }"),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => Some("pub(crate) fn f() {}"),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => Some("fn f<T>(x: T) where T: Clone {}"),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => Some("fn f<T>(x: T) where T: Clone {}"),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => Some("while x > 0 { x -= 1; }"),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => Some("gen { yield 1; };"),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => Some("!x;"),
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => Some("a != b;"),
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => Some("\"hello\";"),
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => Some("#[test]\nfn f() {}"),
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => Some("macro_rules! all { ($($e:expr),*) => {}; }"),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => Some("a % b;"),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => Some("x %= 2;"),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => Some("&x;"),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => Some("a && b;"),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => Some("x &= y;"),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => Some("fn f<'a>() {}"),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => Some("f();"),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => Some("f();"),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => Some("a * b;"),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => Some("/* note */"),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => Some("x *= 2;"),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => Some("a + b;"),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => Some("x += 1;"),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => Some("f(a, b);"),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => Some("a - b;"),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => Some("x -= 1;"),
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => Some("fn f() -> i32 { 1 }"),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => Some("p.x;"),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => Some("0..10;"),
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => Some("extern \"C\" { fn printf(format: *const u8, ...); }"),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => Some("0..=9;"),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => Some("a / b;"),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => Some("/* note */"),
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => Some("// note"),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => Some("x /= 2;"),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => Some("let x: u8 = 1;"),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => Some("std::mem::swap(a, b);"),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => Some("f();"),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => Some("a < b;"),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => Some("a << 1;"),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => Some("x <<= 1;"),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => Some("a <= b;"),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => Some("let x = 1;"),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => Some("a == b;"),
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => Some("match x { _ => 0 }"),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => Some("a > b;"),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => Some("a >= b;"),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => Some("a >> 1;"),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => Some("x >>= 1;"),
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => Some("f()?;"),
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => Some("match x { n @ 1..=9 => {} }"),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => Some("a[0];"),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => Some("a[0];"),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => Some("a ^ b;"),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => Some("x ^= y;"),
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => Some("let _ = f();"),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => Some("x as u8;"),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => Some("async fn f() {}"),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => Some("f().await;"),
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => Some("macro_rules! m { ($b:block) => {}; }"),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => Some("loop { break; }"),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => Some("'c';"),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => Some("const MAX: u32 = 10;"),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => Some("loop { continue; }"),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => Some("use crate::Node;"),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => Some("impl A for B { default fn f() {} }"),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => Some("/// Docs\nfn f() {}"),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => Some("fn f(x: &dyn Display) {}"),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => Some("if x { f() } else { g() }"),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => Some("enum Color { Red }"),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => Some("\"line\\n\";"),
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => Some("macro_rules! m { ($e:expr) => {}; }"),
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => Some("macro_rules! m { ($e:expr_2021) => {}; }"),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => Some("extern crate alloc;"),
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => Some("false;"),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => Some("p.x;"),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => Some("1.5;"),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => Some("fn f() {}"),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => Some("for x in xs { f(x) }"),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => Some("gen { yield 1; };"),
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => Some("macro_rules! m { ($i:ident) => {}; }"),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => Some("let x = 1;"),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => Some("if x { f() }"),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => Some("impl Point {}"),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => Some("for x in xs {}"),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => Some("42;"),
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => Some("macro_rules! m { ($i:item) => {}; }"),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => Some("let x = 1;"),
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => Some("macro_rules! m { ($l:lifetime) => {}; }"),
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => Some("macro_rules! m { ($l:literal) => {}; }"),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => Some("loop {}"),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => Some("macro_rules! m { () => {}; }"),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => Some("match x { _ => 0 }"),
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => Some("macro_rules! m { ($m:meta) => {}; }"),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => Some("macro_rules! id { ($e:expr) => { $e }; }"),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => Some("mod tests {}"),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => Some("move || x;"),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => Some("let mut x = 1;"),
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => Some("macro_rules! m { ($p:pat) => {}; }"),
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => Some("macro_rules! m { ($p:pat_param) => {}; }"),
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => Some("macro_rules! m { ($p:path) => {}; }"),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => Some("let x: u8 = 1;"),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => Some("pub fn f() {}"),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => Some("&raw const x;"),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => Some("let ref x = y;"),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => Some("fn f() { return; }"),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => Some("use self::Node;"),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => Some("#!/usr/bin/env run-cargo-script\nfn main() {}"),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => Some("let Point { x } = p;"),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => Some("static N: u8 = 1;"),
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => Some("macro_rules! m { ($s:stmt) => {}; }"),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => Some("\"hello\";"),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => Some("struct Point;"),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => Some("use super::Node;"),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => Some("trait Shape {}"),
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => Some("true;"),
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => Some("try { f()? };"),
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => Some("macro_rules! m { ($t:tt) => {}; }"),
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => Some("macro_rules! m { ($t:ty) => {}; }"),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => Some("type Id = u64;"),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => Some("struct Point;"),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => Some("union Bits { i: u32 }"),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => Some("unsafe { f() };"),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => Some("use std::fmt;"),
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => Some("macro_rules! m { ($v:vis) => {}; }"),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => Some("fn f<T>() where T: Clone {}"),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => Some("while x {}"),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => Some("gen { yield 1; };"),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => Some("fn f() {}"),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => Some("|x| x;"),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => Some("x |= y;"),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => Some("a || b;"),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => Some("fn f() {}"),
+            _ => None,
+        }
+    }
+
+}
+
+/// A capture name used by tree-sitter-rust's `HIGHLIGHTS_QUERY`
+#[cfg(feature = "meta_highlights")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Highlight {
+    /// `@attribute`
+    Attribute,
+    /// `@comment`
+    Comment,
+    /// `@comment.documentation`
+    CommentDocumentation,
+    /// `@constant`
+    Constant,
+    /// `@constant.builtin`
+    ConstantBuiltin,
+    /// `@constructor`
+    Constructor,
+    /// `@escape`
+    Escape,
+    /// `@function`
+    Function,
+    /// `@function.macro`
+    FunctionMacro,
+    /// `@function.method`
+    FunctionMethod,
+    /// `@keyword`
+    Keyword,
+    /// `@label`
+    Label,
+    /// `@operator`
+    Operator,
+    /// `@property`
+    Property,
+    /// `@punctuation.bracket`
+    PunctuationBracket,
+    /// `@punctuation.delimiter`
+    PunctuationDelimiter,
+    /// `@string`
+    String,
+    /// `@type`
+    Type,
+    /// `@type.builtin`
+    TypeBuiltin,
+    /// `@variable.builtin`
+    VariableBuiltin,
+    /// `@variable.parameter`
+    VariableParameter,
+}
+
+#[cfg(feature = "meta_highlights")]
+impl Highlight {
+    /// Every capture name in the query, sorted.
+    pub const ALL: &'static [Self] = &[
+        Self::Attribute,
+        Self::Comment,
+        Self::CommentDocumentation,
+        Self::Constant,
+        Self::ConstantBuiltin,
+        Self::Constructor,
+        Self::Escape,
+        Self::Function,
+        Self::FunctionMacro,
+        Self::FunctionMethod,
+        Self::Keyword,
+        Self::Label,
+        Self::Operator,
+        Self::Property,
+        Self::PunctuationBracket,
+        Self::PunctuationDelimiter,
+        Self::String,
+        Self::Type,
+        Self::TypeBuiltin,
+        Self::VariableBuiltin,
+        Self::VariableParameter,
+    ];
+
+    /// The capture name as it appears in the query, without the `@`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Attribute => "attribute",
+            Self::Comment => "comment",
+            Self::CommentDocumentation => "comment.documentation",
+            Self::Constant => "constant",
+            Self::ConstantBuiltin => "constant.builtin",
+            Self::Constructor => "constructor",
+            Self::Escape => "escape",
+            Self::Function => "function",
+            Self::FunctionMacro => "function.macro",
+            Self::FunctionMethod => "function.method",
+            Self::Keyword => "keyword",
+            Self::Label => "label",
+            Self::Operator => "operator",
+            Self::Property => "property",
+            Self::PunctuationBracket => "punctuation.bracket",
+            Self::PunctuationDelimiter => "punctuation.delimiter",
+            Self::String => "string",
+            Self::Type => "type",
+            Self::TypeBuiltin => "type.builtin",
+            Self::VariableBuiltin => "variable.builtin",
+            Self::VariableParameter => "variable.parameter",
+        }
+    }
+}
+
+#[cfg(feature = "meta_highlights")]
+impl std::str::FromStr for Highlight {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "attribute" => Ok(Self::Attribute),
+            "comment" => Ok(Self::Comment),
+            "comment.documentation" => Ok(Self::CommentDocumentation),
+            "constant" => Ok(Self::Constant),
+            "constant.builtin" => Ok(Self::ConstantBuiltin),
+            "constructor" => Ok(Self::Constructor),
+            "escape" => Ok(Self::Escape),
+            "function" => Ok(Self::Function),
+            "function.macro" => Ok(Self::FunctionMacro),
+            "function.method" => Ok(Self::FunctionMethod),
+            "keyword" => Ok(Self::Keyword),
+            "label" => Ok(Self::Label),
+            "operator" => Ok(Self::Operator),
+            "property" => Ok(Self::Property),
+            "punctuation.bracket" => Ok(Self::PunctuationBracket),
+            "punctuation.delimiter" => Ok(Self::PunctuationDelimiter),
+            "string" => Ok(Self::String),
+            "type" => Ok(Self::Type),
+            "type.builtin" => Ok(Self::TypeBuiltin),
+            "variable.builtin" => Ok(Self::VariableBuiltin),
+            "variable.parameter" => Ok(Self::VariableParameter),
+            _ => Err(format!("Unknown highlight capture: {s}")),
+        }
+    }
+}
+
+#[cfg(feature = "meta_highlights")]
+impl std::fmt::Display for Highlight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "meta_highlights")]
+impl crate::NodeType {
+    /// The captures `HIGHLIGHTS_QUERY` gives this node wherever it appears, in query order.
+    ///
+    /// Captures that depend on the node's context, such as `@function` on the identifier
+    /// of a call or `@constructor` on a capitalised identifier, are not included.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn highlight_captures(self) -> &'static [&'static str] {
+        match self {
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => &["attribute"],
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => &["comment"],
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => &["constant.builtin"],
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => &["attribute"],
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => &["comment"],
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => &["string"],
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => &["string"],
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => &["operator"],
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => &["operator"],
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => &["punctuation.bracket"],
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => &["punctuation.bracket"],
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => &["operator"],
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => &["punctuation.delimiter"],
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => &["punctuation.delimiter"],
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => &["punctuation.delimiter"],
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => &["punctuation.delimiter"],
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => &["punctuation.delimiter"],
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => &["punctuation.bracket"],
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => &["punctuation.bracket"],
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => &["keyword"],
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => &["keyword"],
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => &["keyword"],
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => &["keyword"],
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => &["string"],
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => &["keyword"],
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => &["keyword"],
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => &["keyword"],
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => &["keyword"],
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => &["keyword"],
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => &["keyword"],
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => &["keyword"],
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => &["escape"],
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => &["keyword"],
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => &["property"],
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => &["constant.builtin"],
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => &["keyword"],
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => &["keyword"],
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => &["keyword"],
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => &["keyword"],
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => &["keyword"],
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => &["keyword"],
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => &["constant.builtin"],
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => &["keyword"],
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => &["keyword"],
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => &["keyword"],
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => &["keyword"],
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => &["keyword"],
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => &["keyword"],
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => &["keyword"],
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => &["type.builtin"],
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => &["keyword"],
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => &["keyword"],
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => &["keyword"],
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => &["keyword"],
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => &["variable.builtin"],
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => &["keyword"],
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => &["keyword"],
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => &["keyword"],
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => &["keyword"],
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => &["keyword"],
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => &["type"],
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => &["keyword"],
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => &["keyword"],
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => &["keyword"],
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => &["keyword"],
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => &["keyword"],
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => &["keyword"],
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => &["punctuation.bracket"],
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => &["punctuation.bracket"],
+            _ => &[],
+        }
+    }
+
+    /// The first of [`highlight_captures`](Self::highlight_captures), which takes
+    /// precedence when a highlighter applies the query.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn highlight(self) -> Option<Highlight> {
+        match self {
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => Some(Highlight::Attribute),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => Some(Highlight::Comment),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => Some(Highlight::ConstantBuiltin),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => Some(Highlight::Attribute),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => Some(Highlight::Comment),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => Some(Highlight::String),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => Some(Highlight::String),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => Some(Highlight::Operator),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => Some(Highlight::Operator),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => Some(Highlight::PunctuationBracket),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => Some(Highlight::PunctuationBracket),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => Some(Highlight::Operator),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => Some(Highlight::PunctuationDelimiter),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => Some(Highlight::PunctuationDelimiter),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => Some(Highlight::PunctuationDelimiter),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => Some(Highlight::PunctuationDelimiter),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => Some(Highlight::PunctuationDelimiter),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => Some(Highlight::PunctuationBracket),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => Some(Highlight::PunctuationBracket),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => Some(Highlight::String),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => Some(Highlight::Keyword),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => Some(Highlight::Escape),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => Some(Highlight::Property),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => Some(Highlight::ConstantBuiltin),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => Some(Highlight::ConstantBuiltin),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => Some(Highlight::Keyword),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => Some(Highlight::TypeBuiltin),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => Some(Highlight::VariableBuiltin),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => Some(Highlight::Keyword),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => Some(Highlight::Type),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => Some(Highlight::Keyword),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => Some(Highlight::PunctuationBracket),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => Some(Highlight::PunctuationBracket),
+            _ => None,
+        }
+    }
+
+}
@@ -0,0 +1,7395 @@
+// @generated by tss-rust-codegen from tree-sitter-rust 0.24.2 NODE_TYPES (the same for 0.23.3).
+// Run `cargo run -p tss-rust-codegen` to update it.
+
+/// Tree-sitter node types for Rust
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeType {
+    /// `_declaration_statement` (named)
+    ///
+    /// - **Rust reference**: [Items](https://doc.rust-lang.org/reference/items.html)
+    /// - **Grammar**: `Item : OuterAttribute* ( VisItem | MacroItem )`
+    ///
+    /// ```text
+    /// fn add(a: i32, b: i32) -> i32 { a + b }
+    /// ```
+    #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+    DeclarationStatement,
+    /// `_expression` (named)
+    ///
+    /// - **Rust reference**: [Expressions](https://doc.rust-lang.org/reference/expressions.html)
+    /// - **Grammar**: `Expression : ExpressionWithoutBlock | ExpressionWithBlock`
+    ///
+    /// ```text
+    /// a + b;
+    /// ```
+    #[cfg(any(feature = "expression", feature = "node_full"))]
+    Expression,
+    /// `_literal` (named)
+    ///
+    /// - **Rust reference**: [Literal expressions](https://doc.rust-lang.org/reference/expressions/literal-expr.html)
+    /// - **Grammar**: `LiteralExpression : CHAR_LITERAL | STRING_LITERAL | RAW_STRING_LITERAL | BYTE_LITERAL | BYTE_STRING_LITERAL | RAW_BYTE_STRING_LITERAL | C_STRING_LITERAL | RAW_C_STRING_LITERAL | INTEGER_LITERAL | FLOAT_LITERAL | true | false`
+    ///
+    /// ```text
+    /// 42;
+    /// ```
+    #[cfg(any(feature = "literal", feature = "node_full"))]
+    Literal,
+    /// `_literal_pattern` (named)
+    ///
+    /// - **Rust reference**: [Literal patterns](https://doc.rust-lang.org/reference/patterns.html#literal-patterns)
+    /// - **Grammar**: `LiteralPattern : true | false | CHAR_LITERAL | STRING_LITERAL | ... | -? INTEGER_LITERAL | -? FLOAT_LITERAL`
+    ///
+    /// ```text
+    /// match x { -1 => {} }
+    /// ```
+    #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+    LiteralPattern,
+    /// `_pattern` (named)
+    ///
+    /// - **Rust reference**: [Patterns](https://doc.rust-lang.org/reference/patterns.html)
+    /// - **Grammar**: `Pattern : |? PatternNoTopAlt ( | PatternNoTopAlt )*`
+    ///
+    /// ```text
+    /// match x { 1 | 2 => {} }
+    /// ```
+    #[cfg(any(feature = "pattern", feature = "node_full"))]
+    Pattern,
+    /// `_type` (named)
+    ///
+    /// - **Rust reference**: [Types](https://doc.rust-lang.org/reference/types.html)
+    /// - **Grammar**: `Type : TypeNoBounds | ImplTraitType | TraitObjectType`
+    ///
+    /// ```text
+    /// let x: u8 = 1;
+    /// ```
+    #[cfg(any(feature = "type", feature = "node_full"))]
+    Type,
+    /// `abstract_type` (named)
+    ///
+    /// - **Rust reference**: [Impl trait](https://doc.rust-lang.org/reference/types/impl-trait.html)
+    /// - **Grammar**: `ImplTraitType : impl TypeParamBounds`
+    ///
+    /// ```text
+    /// fn f() -> impl Display { 1 }
+    /// ```
+    #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+    AbstractType,
+    /// `arguments` (named)
+    ///
+    /// - **Rust reference**: [Call expressions](https://doc.rust-lang.org/reference/expressions/call-expr.html)
+    /// - **Grammar**: `CallParams : Expression ( , Expression )* ,?`
+    ///
+    /// ```text
+    /// f(1, 2);
+    /// ```
+    #[cfg(any(feature = "arguments", feature = "node_full"))]
+    Arguments,
+    /// `array_expression` (named)
+    ///
+    /// - **Rust reference**: [Array expressions](https://doc.rust-lang.org/reference/expressions/array-expr.html)
+    /// - **Grammar**: `ArrayExpression : [ ArrayElements? ]`
+    ///
+    /// ```text
+    /// [1, 2, 3];
+    /// ```
+    #[cfg(any(feature = "array_expression", feature = "node_full"))]
+    ArrayExpression,
+    /// `array_type` (named)
+    ///
+    /// - **Rust reference**: [Array types](https://doc.rust-lang.org/reference/types/array.html)
+    /// - **Grammar**: `ArrayType : [ Type ; Expression ]`
+    ///
+    /// ```text
+    /// let a: [u8; 4] = b;
+    /// ```
+    #[cfg(any(feature = "array_type", feature = "node_full"))]
+    ArrayType,
+    /// `assignment_expression` (named)
+    ///
+    /// - **Rust reference**: [Assignment expressions](https://doc.rust-lang.org/reference/expressions/operator-expr.html#assignment-expressions)
+    /// - **Grammar**: `AssignmentExpression : Expression = Expression`
+    ///
+    /// ```text
+    /// x = 1;
+    /// ```
+    #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+    AssignmentExpression,
+    /// `associated_type` (named)
+    ///
+    /// - **Rust reference**: [Associated types](https://doc.rust-lang.org/reference/items/associated-items.html#associated-types)
+    /// - **Grammar**: `TypeAlias : type IDENTIFIER GenericParams? ( : TypeParamBounds )? WhereClause? ( = Type WhereClause? )? ;`
+    ///
+    /// ```text
+    /// trait Iterator { type Item; }
+    /// ```
+    #[cfg(any(feature = "associated_type", feature = "node_full"))]
+    AssociatedType,
+    /// `async_block` (named)
+    ///
+    /// - **Rust reference**: [Async blocks](https://doc.rust-lang.org/reference/expressions/block-expr.html#async-blocks)
+    /// - **Grammar**: `AsyncBlockExpression : async move? BlockExpression`
+    ///
+    /// ```text
+    /// async move { f().await };
+    /// ```
+    #[cfg(any(feature = "async_block", feature = "node_full"))]
+    AsyncBlock,
+    /// `attribute` (named)
+    ///
+    /// - **Rust reference**: [Attributes](https://doc.rust-lang.org/reference/attributes.html)
+    /// - **Grammar**: `Attr : SimplePath AttrInput? | unsafe ( SimplePath AttrInput? )`
+    ///
+    /// ```text
+    /// #[derive(Debug)]
+    /// struct S;
+    /// ```
+    #[cfg(any(feature = "attribute", feature = "node_full"))]
+    Attribute,
+    /// `attribute_item` (named)
+    ///
+    /// - **Rust reference**: [Attributes](https://doc.rust-lang.org/reference/attributes.html)
+    /// - **Grammar**: `OuterAttribute : # [ Attr ]`
+    ///
+    /// ```text
+    /// #[derive(Debug)]
+    /// struct S;
+    /// ```
+    #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+    AttributeItem,
+    /// `await_expression` (named)
+    ///
+    /// - **Rust reference**: [Await expressions](https://doc.rust-lang.org/reference/expressions/await-expr.html)
+    /// - **Grammar**: `AwaitExpression : Expression . await`
+    ///
+    /// ```text
+    /// f().await;
+    /// ```
+    #[cfg(any(feature = "await_expression", feature = "node_full"))]
+    AwaitExpression,
+    /// `base_field_initializer` (named)
+    ///
+    /// - **Rust reference**: [Functional update syntax](https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax)
+    /// - **Grammar**: `StructBase : .. Expression`
+    ///
+    /// ```text
+    /// Point { x: 1, ..p };
+    /// ```
+    #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+    BaseFieldInitializer,
+    /// `binary_expression` (named)
+    ///
+    /// - **Rust reference**: [Operator expressions](https://doc.rust-lang.org/reference/expressions/operator-expr.html)
+    /// - **Grammar**: `ArithmeticOrLogicalExpression | ComparisonExpression | LazyBooleanExpression`
+    ///
+    /// ```text
+    /// a + b;
+    /// ```
+    #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+    BinaryExpression,
+    /// `block` (named)
+    ///
+    /// - **Rust reference**: [Block expressions](https://doc.rust-lang.org/reference/expressions/block-expr.html)
+    /// - **Grammar**: `BlockExpression : { InnerAttribute* Statements? }`
+    ///
+    /// - unnamed variant: [`Self::BlockToken`]
+    ///
+    /// ```text
+    /// fn f() { g(); }
+    /// ```
+    #[cfg(any(feature = "block", feature = "node_full"))]
+    Block,
+    /// `block_comment` (named)
+    ///
+    /// - **Rust reference**: [Comments](https://doc.rust-lang.org/reference/comments.html)
+    /// - **Grammar**: `BLOCK_COMMENT : /* ( ~[* !] | ** | BlockCommentOrDoc ) ( BlockCommentOrDoc | ~*/ )* */ | /**/ | /***/`
+    ///
+    /// ```text
+    /// /* note */
+    /// ```
+    #[cfg(any(feature = "block_comment", feature = "node_full"))]
+    BlockComment,
+    /// `boolean_literal` (named)
+    ///
+    /// - **Rust reference**: [Boolean literals](https://doc.rust-lang.org/reference/tokens.html#boolean-literals)
+    /// - **Grammar**: `true | false`
+    ///
+    /// ```text
+    /// true;
+    /// ```
+    #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+    BooleanLiteral,
+    /// `bounded_type` (named)
+    ///
+    /// - **Rust reference**: [Trait and lifetime bounds](https://doc.rust-lang.org/reference/trait-bounds.html)
+    /// - **Grammar**: `TypeParamBounds : TypeParamBound ( + TypeParamBound )* +?`
+    ///
+    /// ```text
+    /// fn f(x: Box<dyn Send + 'static>) {}
+    /// ```
+    #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+    BoundedType,
+    /// `bracketed_type` (named)
+    ///
+    /// - **Rust reference**: [Qualified paths](https://doc.rust-lang.org/reference/paths.html#qualified-paths)
+    /// - **Grammar**: `QualifiedPathType : < Type ( as TypePath )? >`
+    ///
+    /// ```text
+    /// <T as Default>::default();
+    /// ```
+    #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+    BracketedType,
+    /// `break_expression` (named)
+    ///
+    /// - **Rust reference**: [Break expressions](https://doc.rust-lang.org/reference/expressions/loop-expr.html#break-expressions)
+    /// - **Grammar**: `BreakExpression : break LIFETIME_OR_LABEL? Expression?`
+    ///
+    /// ```text
+    /// loop { break; }
+    /// ```
+    #[cfg(any(feature = "break_expression", feature = "node_full"))]
+    BreakExpression,
+    /// `call_expression` (named)
+    ///
+    /// - **Rust reference**: [Call expressions](https://doc.rust-lang.org/reference/expressions/call-expr.html)
+    /// - **Grammar**: `CallExpression : Expression ( CallParams? )`
+    ///
+    /// ```text
+    /// f(1, 2);
+    /// ```
+    #[cfg(any(feature = "call_expression", feature = "node_full"))]
+    CallExpression,
+    /// `captured_pattern` (named)
+    ///
+    /// - **Rust reference**: [Identifier patterns](https://doc.rust-lang.org/reference/patterns.html#identifier-patterns)
+    /// - **Grammar**: `IdentifierPattern : ref? mut? IDENTIFIER ( @ PatternNoTopAlt )?`
+    ///
+    /// ```text
+    /// match x { n @ 1..=9 => {} }
+    /// ```
+    #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+    CapturedPattern,
+    /// `closure_expression` (named)
+    ///
+    /// - **Rust reference**: [Closure expressions](https://doc.rust-lang.org/reference/expressions/closure-expr.html)
+    /// - **Grammar**: `ClosureExpression : async? move? ( || | | ClosureParameters? | ) ( Expression | -> TypeNoBounds BlockExpression )`
+    ///
+    /// ```text
+    /// |a, b| a + b;
+    /// ```
+    #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+    ClosureExpression,
+    /// `closure_parameters` (named)
+    ///
+    /// - **Rust reference**: [Closure expressions](https://doc.rust-lang.org/reference/expressions/closure-expr.html)
+    /// - **Grammar**: `ClosureParameters : ClosureParam ( , ClosureParam )* ,?`
+    ///
+    /// ```text
+    /// |a, b| a + b;
+    /// ```
+    #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+    ClosureParameters,
+    /// `compound_assignment_expr` (named)
+    ///
+    /// - **Rust reference**: [Compound assignment expressions](https://doc.rust-lang.org/reference/expressions/operator-expr.html#compound-assignment-expressions)
+    /// - **Grammar**: `CompoundAssignmentExpression : Expression += Expression | Expression -= Expression | ...`
+    ///
+    /// ```text
+    /// x += 1;
+    /// ```
+    #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+    CompoundAssignmentExpr,
+    /// `const_block` (named)
+    ///
+    /// - **Rust reference**: [Const blocks](https://doc.rust-lang.org/reference/expressions/block-expr.html#const-blocks)
+    /// - **Grammar**: `ConstBlockExpression : const BlockExpression`
+    ///
+    /// ```text
+    /// const { 1 + 1 };
+    /// ```
+    #[cfg(any(feature = "const_block", feature = "node_full"))]
+    ConstBlock,
+    /// `const_item` (named)
+    ///
+    /// - **Rust reference**: [Constant items](https://doc.rust-lang.org/reference/items/constant-items.html)
+    /// - **Grammar**: `ConstantItem : const ( IDENTIFIER | _ ) : Type ( = Expression )? ;`
+    ///
+    /// ```text
+    /// const MAX: u32 = 10;
+    /// ```
+    #[cfg(any(feature = "const_item", feature = "node_full"))]
+    ConstItem,
+    /// `const_parameter` (named)
+    ///
+    /// - **Rust reference**: [Const generics](https://doc.rust-lang.org/reference/items/generics.html#const-generics)
+    /// - **Grammar**: `ConstParam : const IDENTIFIER : Type ( = ( BlockExpression | IDENTIFIER | -? LiteralExpression ) )?`
+    ///
+    /// ```text
+    /// struct Buf<const N: usize>;
+    /// ```
+    #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+    ConstParameter,
+    /// `continue_expression` (named)
+    ///
+    /// - **Rust reference**: [Continue expressions](https://doc.rust-lang.org/reference/expressions/loop-expr.html#continue-expressions)
+    /// - **Grammar**: `ContinueExpression : continue LIFETIME_OR_LABEL?`
+    ///
+    /// ```text
+    /// loop { continue; }
+    /// ```
+    #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+    ContinueExpression,
+    /// `declaration_list` (named)
+    ///
+    /// - **Rust reference**: [Associated items](https://doc.rust-lang.org/reference/items/associated-items.html)
+    /// - **Grammar**: `AssociatedItem : OuterAttribute* ( MacroInvocationSemi | ( Visibility? ( TypeAlias | ConstantItem | Function ) ) )`
+    ///
+    /// ```text
+    /// impl Point { fn new() {} }
+    /// ```
+    #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+    DeclarationList,
+    /// `dynamic_type` (named)
+    ///
+    /// - **Rust reference**: [Trait objects](https://doc.rust-lang.org/reference/types/trait-object.html)
+    /// - **Grammar**: `TraitObjectType : dyn? TypeParamBounds`
+    ///
+    /// ```text
+    /// fn f(x: &dyn Display) {}
+    /// ```
+    #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+    DynamicType,
+    /// `else_clause` (named)
+    ///
+    /// - **Rust reference**: [If expressions](https://doc.rust-lang.org/reference/expressions/if-expr.html)
+    /// - **Grammar**: `IfExpression : if Conditions BlockExpression ( else ( BlockExpression | IfExpression ) )?`
+    ///
+    /// ```text
+    /// if x { f() } else { g() }
+    /// ```
+    #[cfg(any(feature = "else_clause", feature = "node_full"))]
+    ElseClause,
+    /// `empty_statement` (named)
+    ///
+    /// - **Rust reference**: [Statements](https://doc.rust-lang.org/reference/statements.html)
+    /// - **Grammar**: `Statement : ;`
+    ///
+    /// ```text
+    /// ;
+    /// ```
+    #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+    EmptyStatement,
+    /// `enum_item` (named)
+    ///
+    /// - **Rust reference**: [Enumerations](https://doc.rust-lang.org/reference/items/enumerations.html)
+    /// - **Grammar**: `Enumeration : enum IDENTIFIER GenericParams? WhereClause? { EnumItems? }`
+    ///
+    /// ```text
+    /// enum Color { Red, Green }
+    /// ```
+    #[cfg(any(feature = "enum_item", feature = "node_full"))]
+    EnumItem,
+    /// `enum_variant` (named)
+    ///
+    /// - **Rust reference**: [Enumerations](https://doc.rust-lang.org/reference/items/enumerations.html)
+    /// - **Grammar**: `EnumItem : OuterAttribute* Visibility? IDENTIFIER ( EnumItemTuple | EnumItemStruct )? EnumItemDiscriminant?`
+    ///
+    /// ```text
+    /// enum Color { Red, Green }
+    /// ```
+    #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+    EnumVariant,
+    /// `enum_variant_list` (named)
+    ///
+    /// - **Rust reference**: [Enumerations](https://doc.rust-lang.org/reference/items/enumerations.html)
+    /// - **Grammar**: `EnumItems : EnumItem ( , EnumItem )* ,?`
+    ///
+    /// ```text
+    /// enum Color { Red, Green }
+    /// ```
+    #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+    EnumVariantList,
+    /// `expression_statement` (named)
+    ///
+    /// - **Rust reference**: [Expression statements](https://doc.rust-lang.org/reference/statements.html#expression-statements)
+    /// - **Grammar**: `ExpressionStatement : ExpressionWithoutBlock ; | ExpressionWithBlock ;?`
+    ///
+    /// ```text
+    /// f();
+    /// ```
+    #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+    ExpressionStatement,
+    /// `extern_crate_declaration` (named)
+    ///
+    /// - **Rust reference**: [Extern crate declarations](https://doc.rust-lang.org/reference/items/extern-crates.html)
+    /// - **Grammar**: `ExternCrate : extern crate CrateRef AsClause? ;`
+    ///
+    /// ```text
+    /// extern crate alloc;
+    /// ```
+    #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+    ExternCrateDeclaration,
+    /// `extern_modifier` (named)
+    ///
+    /// - **Rust reference**: [Extern function qualifier](https://doc.rust-lang.org/reference/items/functions.html#extern-function-qualifier)
+    /// - **Grammar**: `extern Abi?`
+    ///
+    /// ```text
+    /// extern "C" fn f() {}
+    /// ```
+    #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+    ExternModifier,
+    /// `field_declaration` (named)
+    ///
+    /// - **Rust reference**: [Structs](https://doc.rust-lang.org/reference/items/structs.html)
+    /// - **Grammar**: `StructField : OuterAttribute* Visibility? IDENTIFIER : Type`
+    ///
+    /// ```text
+    /// struct Point { x: i32, y: i32 }
+    /// ```
+    #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+    FieldDeclaration,
+    /// `field_declaration_list` (named)
+    ///
+    /// - **Rust reference**: [Structs](https://doc.rust-lang.org/reference/items/structs.html)
+    /// - **Grammar**: `StructFields : StructField ( , StructField )* ,?`
+    ///
+    /// ```text
+    /// struct Point { x: i32, y: i32 }
+    /// ```
+    #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+    FieldDeclarationList,
+    /// `field_expression` (named)
+    ///
+    /// - **Rust reference**: [Field access expressions](https://doc.rust-lang.org/reference/expressions/field-expr.html)
+    /// - **Grammar**: `FieldExpression : Expression . IDENTIFIER`
+    ///
+    /// ```text
+    /// p.x;
+    /// ```
+    #[cfg(any(feature = "field_expression", feature = "node_full"))]
+    FieldExpression,
+    /// `field_initializer` (named)
+    ///
+    /// - **Rust reference**: [Struct expressions](https://doc.rust-lang.org/reference/expressions/struct-expr.html)
+    /// - **Grammar**: `StructExprField : OuterAttribute* ( IDENTIFIER | ( IDENTIFIER | TUPLE_INDEX ) : Expression )`
+    ///
+    /// ```text
+    /// Point { x: 1, y: 2 };
+    /// ```
+    #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+    FieldInitializer,
+    /// `field_initializer_list` (named)
+    ///
+    /// - **Rust reference**: [Struct expressions](https://doc.rust-lang.org/reference/expressions/struct-expr.html)
+    /// - **Grammar**: `StructExprFields : StructExprField ( , StructExprField )* ( , StructBase | ,? )`
+    ///
+    /// ```text
+    /// Point { x: 1, y: 2 };
+    /// ```
+    #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+    FieldInitializerList,
+    /// `field_pattern` (named)
+    ///
+    /// - **Rust reference**: [Struct patterns](https://doc.rust-lang.org/reference/patterns.html#struct-patterns)
+    /// - **Grammar**: `StructPatternField : OuterAttribute* ( TUPLE_INDEX : Pattern | IDENTIFIER : Pattern | ref? mut? IDENTIFIER )`
+    ///
+    /// ```text
+    /// let Point { x: a, y: b } = p;
+    /// ```
+    #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+    FieldPattern,
+    /// `for_expression` (named)
+    ///
+    /// - **Rust reference**: [Iterator loops](https://doc.rust-lang.org/reference/expressions/loop-expr.html#iterator-loops)
+    /// - **Grammar**: `IteratorLoopExpression : for Pattern in Expression BlockExpression`
+    ///
+    /// ```text
+    /// for x in xs { f(x) }
+    /// ```
+    #[cfg(any(feature = "for_expression", feature = "node_full"))]
+    ForExpression,
+    /// `for_lifetimes` (named)
+    ///
+    /// - **Rust reference**: [Higher-ranked trait bounds](https://doc.rust-lang.org/reference/trait-bounds.html#higher-ranked-trait-bounds)
+    /// - **Grammar**: `ForLifetimes : for GenericParams`
+    ///
+    /// ```text
+    /// let f: for<'a> fn(&'a u8) = g;
+    /// ```
+    #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+    ForLifetimes,
+    /// `foreign_mod_item` (named)
+    ///
+    /// - **Rust reference**: [External blocks](https://doc.rust-lang.org/reference/items/external-blocks.html)
+    /// - **Grammar**: `ExternBlock : unsafe? extern Abi? { InnerAttribute* ExternalItem* }`
+    ///
+    /// ```text
+    /// extern "C" { fn abs(x: i32) -> i32; }
+    /// ```
+    #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+    ForeignModItem,
+    /// `fragment_specifier` (named)
+    ///
+    /// - **Rust reference**: [Metavariables](https://doc.rust-lang.org/reference/macros-by-example.html#metavariables)
+    /// - **Grammar**: `$ ( IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER | _ ) : MacroFragSpec`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+    FragmentSpecifier,
+    /// `function_item` (named)
+    ///
+    /// - **Rust reference**: [Functions](https://doc.rust-lang.org/reference/items/functions.html)
+    /// - **Grammar**: `Function : FunctionQualifiers fn IDENTIFIER GenericParams? ( FunctionParameters? ) FunctionReturnType? WhereClause? ( BlockExpression | ; )`
+    ///
+    /// ```text
+    /// fn add(a: i32, b: i32) -> i32 { a + b }
+    /// ```
+    #[cfg(any(feature = "function_item", feature = "node_full"))]
+    FunctionItem,
+    /// `function_modifiers` (named)
+    ///
+    /// - **Rust reference**: [Function qualifiers](https://doc.rust-lang.org/reference/items/functions.html)
+    /// - **Grammar**: `FunctionQualifiers : const? async? ItemSafety? ( extern Abi? )?`
+    ///
+    /// ```text
+    /// const unsafe fn f() {}
+    /// ```
+    #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+    FunctionModifiers,
+    /// `function_signature_item` (named)
+    ///
+    /// - **Rust reference**: [Functions](https://doc.rust-lang.org/reference/items/functions.html)
+    /// - **Grammar**: `Function : FunctionQualifiers fn IDENTIFIER GenericParams? ( FunctionParameters? ) FunctionReturnType? WhereClause? ( BlockExpression | ; )`
+    ///
+    /// ```text
+    /// trait Shape { fn area(&self) -> f64; }
+    /// ```
+    #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+    FunctionSignatureItem,
+    /// `function_type` (named)
+    ///
+    /// - **Rust reference**: [Function pointer types](https://doc.rust-lang.org/reference/types/function-pointer.html)
+    /// - **Grammar**: `BareFunctionType : ForLifetimes? FunctionTypeQualifiers fn ( FunctionParametersMaybeNamedVariadic? ) BareFunctionReturnType?`
+    ///
+    /// ```text
+    /// let f: fn(i32) -> i32 = g;
+    /// ```
+    #[cfg(any(feature = "function_type", feature = "node_full"))]
+    FunctionType,
+    /// `gen_block` (named)
+    ///
+    /// ```text
+    /// gen { yield 1; };
+    /// ```
+    #[cfg(any(feature = "gen_block", feature = "node_full"))]
+    GenBlock,
+    /// `generic_function` (named)
+    ///
+    /// - **Rust reference**: [Paths in expressions](https://doc.rust-lang.org/reference/paths.html#paths-in-expressions)
+    /// - **Grammar**: `PathInExpression : ::? PathExprSegment ( :: PathExprSegment )*`
+    ///
+    /// ```text
+    /// parse::<i32>(s);
+    /// ```
+    #[cfg(any(feature = "generic_function", feature = "node_full"))]
+    GenericFunction,
+    /// `generic_pattern` (named)
+    ///
+    /// - **Rust reference**: [Path patterns](https://doc.rust-lang.org/reference/patterns.html#path-patterns)
+    /// - **Grammar**: `PathPattern : PathExpression`
+    ///
+    /// ```text
+    /// match x { Foo::<u8> => {} }
+    /// ```
+    #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+    GenericPattern,
+    /// `generic_type` (named)
+    ///
+    /// - **Rust reference**: [Paths in types](https://doc.rust-lang.org/reference/paths.html#paths-in-types)
+    /// - **Grammar**: `TypePath : ::? TypePathSegment ( :: TypePathSegment )*`
+    ///
+    /// ```text
+    /// let v: Vec<i32> = x;
+    /// ```
+    #[cfg(any(feature = "generic_type", feature = "node_full"))]
+    GenericType,
+    /// `generic_type_with_turbofish` (named)
+    ///
+    /// - **Rust reference**: [Paths in types](https://doc.rust-lang.org/reference/paths.html#paths-in-types)
+    /// - **Grammar**: `TypePath : ::? TypePathSegment ( :: TypePathSegment )*`
+    ///
+    /// ```text
+    /// Foo::<u8> { x: 1 };
+    /// ```
+    #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+    GenericTypeWithTurbofish,
+    /// `higher_ranked_trait_bound` (named)
+    ///
+    /// - **Rust reference**: [Higher-ranked trait bounds](https://doc.rust-lang.org/reference/trait-bounds.html#higher-ranked-trait-bounds)
+    /// - **Grammar**: `ForLifetimes : for GenericParams`
+    ///
+    /// ```text
+    /// fn f<F>(f: F) where F: for<'a> Fn(&'a str) {}
+    /// ```
+    #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+    HigherRankedTraitBound,
+    /// `if_expression` (named)
+    ///
+    /// - **Rust reference**: [If expressions](https://doc.rust-lang.org/reference/expressions/if-expr.html)
+    /// - **Grammar**: `IfExpression : if Conditions BlockExpression ( else ( BlockExpression | IfExpression ) )?`
+    ///
+    /// ```text
+    /// if x { f() }
+    /// ```
+    #[cfg(any(feature = "if_expression", feature = "node_full"))]
+    IfExpression,
+    /// `impl_item` (named)
+    ///
+    /// - **Rust reference**: [Implementations](https://doc.rust-lang.org/reference/items/implementations.html)
+    /// - **Grammar**: `Implementation : InherentImpl | TraitImpl`
+    ///
+    /// ```text
+    /// impl Point { fn new() {} }
+    /// ```
+    #[cfg(any(feature = "impl_item", feature = "node_full"))]
+    ImplItem,
+    /// `index_expression` (named)
+    ///
+    /// - **Rust reference**: [Array and slice indexing expressions](https://doc.rust-lang.org/reference/expressions/array-expr.html#array-and-slice-indexing-expressions)
+    /// - **Grammar**: `IndexExpression : Expression [ Expression ]`
+    ///
+    /// ```text
+    /// a[0];
+    /// ```
+    #[cfg(any(feature = "index_expression", feature = "node_full"))]
+    IndexExpression,
+    /// `inner_attribute_item` (named)
+    ///
+    /// - **Rust reference**: [Attributes](https://doc.rust-lang.org/reference/attributes.html)
+    /// - **Grammar**: `InnerAttribute : # ! [ Attr ]`
+    ///
+    /// ```text
+    /// #![no_std]
+    /// ```
+    #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+    InnerAttributeItem,
+    /// `inner_doc_comment_marker` (named)
+    ///
+    /// - **Rust reference**: [Doc comments](https://doc.rust-lang.org/reference/comments.html#doc-comments)
+    /// - **Grammar**: `OUTER_LINE_DOC : /// ( ~/ ~[LF CR]* )?`
+    ///
+    /// ```text
+    /// //! Docs
+    /// ```
+    #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+    InnerDocCommentMarker,
+    /// `label` (named)
+    ///
+    /// - **Rust reference**: [Loop labels](https://doc.rust-lang.org/reference/expressions/loop-expr.html#loop-labels)
+    /// - **Grammar**: `LoopLabel : LIFETIME_OR_LABEL :`
+    ///
+    /// ```text
+    /// 'outer: loop { break 'outer; }
+    /// ```
+    #[cfg(any(feature = "label", feature = "node_full"))]
+    Label,
+    /// `let_chain` (named)
+    ///
+    /// - **Rust reference**: [Chains of conditions](https://doc.rust-lang.org/reference/expressions/if-expr.html#chains-of-conditions)
+    /// - **Grammar**: `Conditions : Expression | LetChain`
+    ///
+    /// ```text
+    /// if let Some(x) = y && x > 0 { f(x) }
+    /// ```
+    #[cfg(any(feature = "let_chain", feature = "node_full"))]
+    LetChain,
+    /// `let_condition` (named)
+    ///
+    /// - **Rust reference**: [Chains of conditions](https://doc.rust-lang.org/reference/expressions/if-expr.html#chains-of-conditions)
+    /// - **Grammar**: `Conditions : Expression | LetChain`
+    ///
+    /// ```text
+    /// if let Some(x) = y { f(x) }
+    /// ```
+    #[cfg(any(feature = "let_condition", feature = "node_full"))]
+    LetCondition,
+    /// `let_declaration` (named)
+    ///
+    /// - **Rust reference**: [Let statements](https://doc.rust-lang.org/reference/statements.html#let-statements)
+    /// - **Grammar**: `LetStatement : OuterAttribute* let PatternNoTopAlt ( : Type )? ( = Expression ( else BlockExpression )? )? ;`
+    ///
+    /// ```text
+    /// let x = 1;
+    /// ```
+    #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+    LetDeclaration,
+    /// `lifetime` (named)
+    ///
+    /// - **Rust reference**: [Lifetimes and loop labels](https://doc.rust-lang.org/reference/tokens.html#lifetimes-and-loop-labels)
+    /// - **Grammar**: `LIFETIME_OR_LABEL : ' NON_KEYWORD_IDENTIFIER`
+    ///
+    /// - unnamed variant: [`Self::LifetimeToken`]
+    ///
+    /// ```text
+    /// fn first<'a>(x: &'a str) -> &'a str { x }
+    /// ```
+    #[cfg(any(feature = "lifetime", feature = "node_full"))]
+    Lifetime,
+    /// `lifetime_parameter` (named)
+    ///
+    /// - **Rust reference**: [Generic parameters](https://doc.rust-lang.org/reference/items/generics.html)
+    /// - **Grammar**: `LifetimeParam : Lifetime ( : LifetimeBounds )?`
+    ///
+    /// ```text
+    /// fn first<'a>(x: &'a str) -> &'a str { x }
+    /// ```
+    #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+    LifetimeParameter,
+    /// `line_comment` (named)
+    ///
+    /// - **Rust reference**: [Comments](https://doc.rust-lang.org/reference/comments.html)
+    /// - **Grammar**: `LINE_COMMENT : // ( ~[/ ! LF] | // ) ~LF* | //`
+    ///
+    /// ```text
+    /// // note
+    /// ```
+    #[cfg(any(feature = "line_comment", feature = "node_full"))]
+    LineComment,
+    /// `loop_expression` (named)
+    ///
+    /// - **Rust reference**: [Infinite loops](https://doc.rust-lang.org/reference/expressions/loop-expr.html#infinite-loops)
+    /// - **Grammar**: `InfiniteLoopExpression : loop BlockExpression`
+    ///
+    /// ```text
+    /// loop { f() }
+    /// ```
+    #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+    LoopExpression,
+    /// `macro_definition` (named)
+    ///
+    /// - **Rust reference**: [Macros by example](https://doc.rust-lang.org/reference/macros-by-example.html)
+    /// - **Grammar**: `MacroRulesDefinition : macro_rules ! IDENTIFIER MacroRulesDef`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+    MacroDefinition,
+    /// `macro_invocation` (named)
+    ///
+    /// - **Rust reference**: [Macro invocation](https://doc.rust-lang.org/reference/macros.html#macro-invocation)
+    /// - **Grammar**: `MacroInvocation : SimplePath ! DelimTokenTree`
+    ///
+    /// ```text
+    /// println!("hi");
+    /// ```
+    #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+    MacroInvocation,
+    /// `macro_rule` (named)
+    ///
+    /// - **Rust reference**: [Macros by example](https://doc.rust-lang.org/reference/macros-by-example.html)
+    /// - **Grammar**: `MacroRule : MacroMatcher => MacroTranscriber`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+    MacroRule,
+    /// `match_arm` (named)
+    ///
+    /// - **Rust reference**: [Match expressions](https://doc.rust-lang.org/reference/expressions/match-expr.html)
+    /// - **Grammar**: `MatchArm : OuterAttribute* Pattern MatchArmGuard?`
+    ///
+    /// ```text
+    /// match x { Some(y) if y > 0 => y, _ => 0 }
+    /// ```
+    #[cfg(any(feature = "match_arm", feature = "node_full"))]
+    MatchArm,
+    /// `match_block` (named)
+    ///
+    /// - **Rust reference**: [Match expressions](https://doc.rust-lang.org/reference/expressions/match-expr.html)
+    /// - **Grammar**: `MatchExpression : match Scrutinee { InnerAttribute* MatchArms? }`
+    ///
+    /// ```text
+    /// match x { Some(y) if y > 0 => y, _ => 0 }
+    /// ```
+    #[cfg(any(feature = "match_block", feature = "node_full"))]
+    MatchBlock,
+    /// `match_expression` (named)
+    ///
+    /// - **Rust reference**: [Match expressions](https://doc.rust-lang.org/reference/expressions/match-expr.html)
+    /// - **Grammar**: `MatchExpression : match Scrutinee { InnerAttribute* MatchArms? }`
+    ///
+    /// ```text
+    /// match x { Some(y) if y > 0 => y, _ => 0 }
+    /// ```
+    #[cfg(any(feature = "match_expression", feature = "node_full"))]
+    MatchExpression,
+    /// `match_pattern` (named)
+    ///
+    /// - **Rust reference**: [Match expressions](https://doc.rust-lang.org/reference/expressions/match-expr.html)
+    /// - **Grammar**: `MatchArm : OuterAttribute* Pattern MatchArmGuard?`
+    ///
+    /// ```text
+    /// match x { Some(y) if y > 0 => y, _ => 0 }
+    /// ```
+    #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+    MatchPattern,
+    /// `mod_item` (named)
+    ///
+    /// - **Rust reference**: [Modules](https://doc.rust-lang.org/reference/items/modules.html)
+    /// - **Grammar**: `Module : unsafe? mod IDENTIFIER ; | unsafe? mod IDENTIFIER { InnerAttribute* Item* }`
+    ///
+    /// ```text
+    /// mod tests {}
+    /// ```
+    #[cfg(any(feature = "mod_item", feature = "node_full"))]
+    ModItem,
+    /// `mut_pattern` (named)
+    ///
+    /// - **Rust reference**: [Identifier patterns](https://doc.rust-lang.org/reference/patterns.html#identifier-patterns)
+    /// - **Grammar**: `IdentifierPattern : ref? mut? IDENTIFIER ( @ PatternNoTopAlt )?`
+    ///
+    /// ```text
+    /// match x { mut y => {} }
+    /// ```
+    #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+    MutPattern,
+    /// `negative_literal` (named)
+    ///
+    /// - **Rust reference**: [Literal patterns](https://doc.rust-lang.org/reference/patterns.html#literal-patterns)
+    /// - **Grammar**: `LiteralPattern : true | false | CHAR_LITERAL | STRING_LITERAL | ... | -? INTEGER_LITERAL | -? FLOAT_LITERAL`
+    ///
+    /// ```text
+    /// match x { -1 => {} }
+    /// ```
+    #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+    NegativeLiteral,
+    /// `never_type` (named)
+    ///
+    /// - **Rust reference**: [Never type](https://doc.rust-lang.org/reference/types/never.html)
+    /// - **Grammar**: `NeverType : !`
+    ///
+    /// ```text
+    /// fn exit() -> ! { loop {} }
+    /// ```
+    #[cfg(any(feature = "never_type", feature = "node_full"))]
+    NeverType,
+    /// `or_pattern` (named)
+    ///
+    /// - **Rust reference**: [Patterns](https://doc.rust-lang.org/reference/patterns.html)
+    /// - **Grammar**: `Pattern : |? PatternNoTopAlt ( | PatternNoTopAlt )*`
+    ///
+    /// ```text
+    /// match x { 1 | 2 => {} }
+    /// ```
+    #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+    OrPattern,
+    /// `ordered_field_declaration_list` (named)
+    ///
+    /// - **Rust reference**: [Structs](https://doc.rust-lang.org/reference/items/structs.html)
+    /// - **Grammar**: `TupleFields : TupleField ( , TupleField )* ,?`
+    ///
+    /// ```text
+    /// struct Pair(i32, i32);
+    /// ```
+    #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+    OrderedFieldDeclarationList,
+    /// `outer_doc_comment_marker` (named)
+    ///
+    /// - **Rust reference**: [Doc comments](https://doc.rust-lang.org/reference/comments.html#doc-comments)
+    /// - **Grammar**: `OUTER_LINE_DOC : /// ( ~/ ~[LF CR]* )?`
+    ///
+    /// ```text
+    /// /// Docs
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+    OuterDocCommentMarker,
+    /// `parameter` (named)
+    ///
+    /// - **Rust reference**: [Function parameters](https://doc.rust-lang.org/reference/items/functions.html#function-parameters)
+    /// - **Grammar**: `FunctionParam : OuterAttribute* ( FunctionParamPattern | ... | Type )`
+    ///
+    /// ```text
+    /// fn f(x: i32) {}
+    /// ```
+    #[cfg(any(feature = "parameter", feature = "node_full"))]
+    Parameter,
+    /// `parameters` (named)
+    ///
+    /// - **Rust reference**: [Function parameters](https://doc.rust-lang.org/reference/items/functions.html#function-parameters)
+    /// - **Grammar**: `FunctionParameters : SelfParam ,? | ( SelfParam , )? FunctionParam ( , FunctionParam )* ,?`
+    ///
+    /// ```text
+    /// fn f(x: i32) {}
+    /// ```
+    #[cfg(any(feature = "parameters", feature = "node_full"))]
+    Parameters,
+    /// `parenthesized_expression` (named)
+    ///
+    /// - **Rust reference**: [Grouped expressions](https://doc.rust-lang.org/reference/expressions/grouped-expr.html)
+    /// - **Grammar**: `GroupedExpression : ( Expression )`
+    ///
+    /// ```text
+    /// (a + b) * c;
+    /// ```
+    #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+    ParenthesizedExpression,
+    /// `pointer_type` (named)
+    ///
+    /// - **Rust reference**: [Raw pointers](https://doc.rust-lang.org/reference/types/pointer.html#raw-pointers-const-and-mut)
+    /// - **Grammar**: `RawPointerType : * ( mut | const ) TypeNoBounds`
+    ///
+    /// ```text
+    /// let p: *const u8 = q;
+    /// ```
+    #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+    PointerType,
+    /// `qualified_type` (named)
+    ///
+    /// - **Rust reference**: [Qualified paths](https://doc.rust-lang.org/reference/paths.html#qualified-paths)
+    /// - **Grammar**: `QualifiedPathType : < Type ( as TypePath )? >`
+    ///
+    /// ```text
+    /// <T as Default>::default();
+    /// ```
+    #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+    QualifiedType,
+    /// `range_expression` (named)
+    ///
+    /// - **Rust reference**: [Range expressions](https://doc.rust-lang.org/reference/expressions/range-expr.html)
+    /// - **Grammar**: `RangeExpression : RangeExpr | RangeFromExpr | RangeToExpr | RangeFullExpr | RangeInclusiveExpr | RangeToInclusiveExpr`
+    ///
+    /// ```text
+    /// 0..10;
+    /// ```
+    #[cfg(any(feature = "range_expression", feature = "node_full"))]
+    RangeExpression,
+    /// `range_pattern` (named)
+    ///
+    /// - **Rust reference**: [Range patterns](https://doc.rust-lang.org/reference/patterns.html#range-patterns)
+    /// - **Grammar**: `RangePattern : RangeInclusivePattern | RangeFromPattern | RangeToInclusivePattern | ObsoleteRangePattern`
+    ///
+    /// ```text
+    /// match x { 1..=9 => {} }
+    /// ```
+    #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+    RangePattern,
+    /// `raw_string_literal` (named)
+    ///
+    /// - **Rust reference**: [Raw string literals](https://doc.rust-lang.org/reference/tokens.html#raw-string-literals)
+    /// - **Grammar**: `RAW_STRING_LITERAL : r RAW_STRING_CONTENT SUFFIX?`
+    ///
+    /// ```text
+    /// r#"raw"#;
+    /// ```
+    #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+    RawStringLiteral,
+    /// `ref_pattern` (named)
+    ///
+    /// - **Rust reference**: [Identifier patterns](https://doc.rust-lang.org/reference/patterns.html#identifier-patterns)
+    /// - **Grammar**: `IdentifierPattern : ref? mut? IDENTIFIER ( @ PatternNoTopAlt )?`
+    ///
+    /// ```text
+    /// let ref x = y;
+    /// ```
+    #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+    RefPattern,
+    /// `reference_expression` (named)
+    ///
+    /// - **Rust reference**: [Borrow operators](https://doc.rust-lang.org/reference/expressions/operator-expr.html#borrow-operators)
+    /// - **Grammar**: `BorrowExpression : ( & | && ) Expression | ( & | && ) mut Expression`
+    ///
+    /// ```text
+    /// &mut x;
+    /// ```
+    #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+    ReferenceExpression,
+    /// `reference_pattern` (named)
+    ///
+    /// - **Rust reference**: [Reference patterns](https://doc.rust-lang.org/reference/patterns.html#reference-patterns)
+    /// - **Grammar**: `ReferencePattern : ( & | && ) mut? PatternWithoutRange`
+    ///
+    /// ```text
+    /// let &x = y;
+    /// ```
+    #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+    ReferencePattern,
+    /// `reference_type` (named)
+    ///
+    /// - **Rust reference**: [References](https://doc.rust-lang.org/reference/types/pointer.html#references--and-mut)
+    /// - **Grammar**: `ReferenceType : & Lifetime? mut? TypeNoBounds`
+    ///
+    /// ```text
+    /// fn f(x: &mut i32) {}
+    /// ```
+    #[cfg(any(feature = "reference_type", feature = "node_full"))]
+    ReferenceType,
+    /// `remaining_field_pattern` (named)
+    ///
+    /// - **Rust reference**: [Rest patterns](https://doc.rust-lang.org/reference/patterns.html#rest-patterns)
+    /// - **Grammar**: `RestPattern : ..`
+    ///
+    /// ```text
+    /// let Point { x, .. } = p;
+    /// ```
+    #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+    RemainingFieldPattern,
+    /// `removed_trait_bound` (named)
+    ///
+    /// - **Rust reference**: [Trait and lifetime bounds](https://doc.rust-lang.org/reference/trait-bounds.html)
+    /// - **Grammar**: `TraitBound : ( ? | ForLifetimes )? TypePath`
+    ///
+    /// ```text
+    /// fn f<T: ?Sized>(x: &T) {}
+    /// ```
+    #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+    RemovedTraitBound,
+    /// `return_expression` (named)
+    ///
+    /// - **Rust reference**: [Return expressions](https://doc.rust-lang.org/reference/expressions/return-expr.html)
+    /// - **Grammar**: `ReturnExpression : return Expression?`
+    ///
+    /// ```text
+    /// fn f() { return; }
+    /// ```
+    #[cfg(any(feature = "return_expression", feature = "node_full"))]
+    ReturnExpression,
+    /// `scoped_identifier` (named)
+    ///
+    /// - **Rust reference**: [Paths in expressions](https://doc.rust-lang.org/reference/paths.html#paths-in-expressions)
+    /// - **Grammar**: `PathInExpression : ::? PathExprSegment ( :: PathExprSegment )*`
+    ///
+    /// ```text
+    /// std::mem::swap(a, b);
+    /// ```
+    #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+    ScopedIdentifier,
+    /// `scoped_type_identifier` (named)
+    ///
+    /// - **Rust reference**: [Paths in types](https://doc.rust-lang.org/reference/paths.html#paths-in-types)
+    /// - **Grammar**: `TypePath : ::? TypePathSegment ( :: TypePathSegment )*`
+    ///
+    /// ```text
+    /// let x: std::io::Error = e;
+    /// ```
+    #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+    ScopedTypeIdentifier,
+    /// `scoped_use_list` (named)
+    ///
+    /// - **Rust reference**: [Use declarations](https://doc.rust-lang.org/reference/items/use-declarations.html)
+    /// - **Grammar**: `UseTree : ( SimplePath? :: )? * | ( SimplePath? :: )? { ( UseTree ( , UseTree )* ,? )? } | SimplePath ( as ( IDENTIFIER | _ ) )?`
+    ///
+    /// ```text
+    /// use std::{fmt, io};
+    /// ```
+    #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+    ScopedUseList,
+    /// `self_parameter` (named)
+    ///
+    /// - **Rust reference**: [Methods](https://doc.rust-lang.org/reference/items/associated-items.html#methods)
+    /// - **Grammar**: `SelfParam : OuterAttribute* ( ShorthandSelf | TypedSelf )`
+    ///
+    /// ```text
+    /// impl S { fn get(&self) {} }
+    /// ```
+    #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+    SelfParameter,
+    /// `shorthand_field_initializer` (named)
+    ///
+    /// - **Rust reference**: [Struct expressions](https://doc.rust-lang.org/reference/expressions/struct-expr.html)
+    /// - **Grammar**: `StructExprField : OuterAttribute* ( IDENTIFIER | ( IDENTIFIER | TUPLE_INDEX ) : Expression )`
+    ///
+    /// ```text
+    /// Point { x, y };
+    /// ```
+    #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+    ShorthandFieldInitializer,
+    /// `slice_pattern` (named)
+    ///
+    /// - **Rust reference**: [Slice patterns](https://doc.rust-lang.org/reference/patterns.html#slice-patterns)
+    /// - **Grammar**: `SlicePattern : [ SlicePatternItems? ]`
+    ///
+    /// ```text
+    /// let [a, b] = s;
+    /// ```
+    #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+    SlicePattern,
+    /// `source_file` (named)
+    ///
+    /// - **Rust reference**: [Crates and source files](https://doc.rust-lang.org/reference/crates-and-source-files.html)
+    /// - **Grammar**: `Crate : InnerAttribute* Item*`
+    ///
+    /// ```text
+    /// fn main() {}
+    /// ```
+    #[cfg(any(feature = "source_file", feature = "node_full"))]
+    SourceFile,
+    /// `static_item` (named)
+    ///
+    /// - **Rust reference**: [Static items](https://doc.rust-lang.org/reference/items/static-items.html)
+    /// - **Grammar**: `StaticItem : ItemSafety? static mut? IDENTIFIER : Type ( = Expression )? ;`
+    ///
+    /// ```text
+    /// static NAME: &str = "x";
+    /// ```
+    #[cfg(any(feature = "static_item", feature = "node_full"))]
+    StaticItem,
+    /// `string_literal` (named)
+    ///
+    /// - **Rust reference**: [String literals](https://doc.rust-lang.org/reference/tokens.html#string-literals)
+    /// - **Grammar**: `STRING_LITERAL : " ( ~[" \ IsolatedCR] | QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE | STRING_CONTINUE )* " SUFFIX?`
+    ///
+    /// ```text
+    /// "hello";
+    /// ```
+    #[cfg(any(feature = "string_literal", feature = "node_full"))]
+    StringLiteral,
+    /// `struct_expression` (named)
+    ///
+    /// - **Rust reference**: [Struct expressions](https://doc.rust-lang.org/reference/expressions/struct-expr.html)
+    /// - **Grammar**: `StructExpression : PathInExpression { ( StructExprFields | StructBase )? }`
+    ///
+    /// ```text
+    /// Point { x: 1, y: 2 };
+    /// ```
+    #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+    StructExpression,
+    /// `struct_item` (named)
+    ///
+    /// - **Rust reference**: [Structs](https://doc.rust-lang.org/reference/items/structs.html)
+    /// - **Grammar**: `Struct : StructStruct | TupleStruct`
+    ///
+    /// ```text
+    /// struct Point { x: i32, y: i32 }
+    /// ```
+    #[cfg(any(feature = "struct_item", feature = "node_full"))]
+    StructItem,
+    /// `struct_pattern` (named)
+    ///
+    /// - **Rust reference**: [Struct patterns](https://doc.rust-lang.org/reference/patterns.html#struct-patterns)
+    /// - **Grammar**: `StructPattern : PathInExpression { StructPatternElements? }`
+    ///
+    /// ```text
+    /// let Point { x: a, y: b } = p;
+    /// ```
+    #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+    StructPattern,
+    /// `token_binding_pattern` (named)
+    ///
+    /// - **Rust reference**: [Metavariables](https://doc.rust-lang.org/reference/macros-by-example.html#metavariables)
+    /// - **Grammar**: `$ ( IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER | _ ) : MacroFragSpec`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+    TokenBindingPattern,
+    /// `token_repetition` (named)
+    ///
+    /// - **Rust reference**: [Macro invocation](https://doc.rust-lang.org/reference/macros.html#macro-invocation)
+    /// - **Grammar**: `DelimTokenTree : ( TokenTree* ) | [ TokenTree* ] | { TokenTree* }`
+    ///
+    /// ```text
+    /// macro_rules! m { ($($e:expr),*) => { $($e;)* }; }
+    /// ```
+    #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+    TokenRepetition,
+    /// `token_repetition_pattern` (named)
+    ///
+    /// - **Rust reference**: [Repetitions](https://doc.rust-lang.org/reference/macros-by-example.html#repetitions)
+    /// - **Grammar**: `$ ( MacroMatch+ ) MacroRepSep? MacroRepOp`
+    ///
+    /// ```text
+    /// macro_rules! all { ($($e:expr),*) => {}; }
+    /// ```
+    #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+    TokenRepetitionPattern,
+    /// `token_tree` (named)
+    ///
+    /// - **Rust reference**: [Macro invocation](https://doc.rust-lang.org/reference/macros.html#macro-invocation)
+    /// - **Grammar**: `DelimTokenTree : ( TokenTree* ) | [ TokenTree* ] | { TokenTree* }`
+    ///
+    /// ```text
+    /// println!("hi");
+    /// ```
+    #[cfg(any(feature = "token_tree", feature = "node_full"))]
+    TokenTree,
+    /// `token_tree_pattern` (named)
+    ///
+    /// - **Rust reference**: [Macros by example](https://doc.rust-lang.org/reference/macros-by-example.html)
+    /// - **Grammar**: `MacroMatcher : ( MacroMatch* ) | [ MacroMatch* ] | { MacroMatch* }`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+    TokenTreePattern,
+    /// `trait_bounds` (named)
+    ///
+    /// - **Rust reference**: [Trait and lifetime bounds](https://doc.rust-lang.org/reference/trait-bounds.html)
+    /// - **Grammar**: `TypeParamBounds : TypeParamBound ( + TypeParamBound )* +?`
+    ///
+    /// ```text
+    /// fn f<T: Clone + Send>(x: T) {}
+    /// ```
+    #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+    TraitBounds,
+    /// `trait_item` (named)
+    ///
+    /// - **Rust reference**: [Traits](https://doc.rust-lang.org/reference/items/traits.html)
+    /// - **Grammar**: `Trait : unsafe? trait IDENTIFIER GenericParams? ( : TypeParamBounds? )? WhereClause? { InnerAttribute* AssociatedItem* }`
+    ///
+    /// ```text
+    /// trait Shape { fn area(&self) -> f64; }
+    /// ```
+    #[cfg(any(feature = "trait_item", feature = "node_full"))]
+    TraitItem,
+    /// `try_block` (named)
+    ///
+    /// ```text
+    /// try { f()? };
+    /// ```
+    #[cfg(any(feature = "try_block", feature = "node_full"))]
+    TryBlock,
+    /// `try_expression` (named)
+    ///
+    /// - **Rust reference**: [The question mark operator](https://doc.rust-lang.org/reference/expressions/operator-expr.html#the-question-mark-operator)
+    /// - **Grammar**: `ErrorPropagationExpression : Expression ?`
+    ///
+    /// ```text
+    /// f()?;
+    /// ```
+    #[cfg(any(feature = "try_expression", feature = "node_full"))]
+    TryExpression,
+    /// `tuple_expression` (named)
+    ///
+    /// - **Rust reference**: [Tuple expressions](https://doc.rust-lang.org/reference/expressions/tuple-expr.html)
+    /// - **Grammar**: `TupleExpression : ( TupleElements? )`
+    ///
+    /// ```text
+    /// (1, true);
+    /// ```
+    #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+    TupleExpression,
+    /// `tuple_pattern` (named)
+    ///
+    /// - **Rust reference**: [Tuple patterns](https://doc.rust-lang.org/reference/patterns.html#tuple-patterns)
+    /// - **Grammar**: `TuplePattern : ( TuplePatternItems? )`
+    ///
+    /// ```text
+    /// let (a, b) = t;
+    /// ```
+    #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+    TuplePattern,
+    /// `tuple_struct_pattern` (named)
+    ///
+    /// - **Rust reference**: [Tuple struct patterns](https://doc.rust-lang.org/reference/patterns.html#tuple-struct-patterns)
+    /// - **Grammar**: `TupleStructPattern : PathInExpression ( TupleStructItems? )`
+    ///
+    /// ```text
+    /// let Some(x) = y;
+    /// ```
+    #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+    TupleStructPattern,
+    /// `tuple_type` (named)
+    ///
+    /// - **Rust reference**: [Tuple types](https://doc.rust-lang.org/reference/types/tuple.html)
+    /// - **Grammar**: `TupleType : ( ) | ( ( Type , )+ Type? )`
+    ///
+    /// ```text
+    /// let t: (i32, bool) = u;
+    /// ```
+    #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+    TupleType,
+    /// `type_arguments` (named)
+    ///
+    /// - **Rust reference**: [Paths in expressions](https://doc.rust-lang.org/reference/paths.html#paths-in-expressions)
+    /// - **Grammar**: `GenericArgs : < > | < ( GenericArg , )* GenericArg ,? >`
+    ///
+    /// ```text
+    /// let v: Vec<i32> = x;
+    /// ```
+    #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+    TypeArguments,
+    /// `type_binding` (named)
+    ///
+    /// - **Rust reference**: [Paths in expressions](https://doc.rust-lang.org/reference/paths.html#paths-in-expressions)
+    /// - **Grammar**: `GenericArgsBinding : IDENTIFIER GenericArgs? = Type`
+    ///
+    /// ```text
+    /// fn f(x: impl Iterator<Item = u8>) {}
+    /// ```
+    #[cfg(any(feature = "type_binding", feature = "node_full"))]
+    TypeBinding,
+    /// `type_cast_expression` (named)
+    ///
+    /// - **Rust reference**: [Type cast expressions](https://doc.rust-lang.org/reference/expressions/operator-expr.html#type-cast-expressions)
+    /// - **Grammar**: `TypeCastExpression : Expression as TypeNoBounds`
+    ///
+    /// ```text
+    /// x as u8;
+    /// ```
+    #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+    TypeCastExpression,
+    /// `type_item` (named)
+    ///
+    /// - **Rust reference**: [Type aliases](https://doc.rust-lang.org/reference/items/type-aliases.html)
+    /// - **Grammar**: `TypeAlias : type IDENTIFIER GenericParams? ( : TypeParamBounds )? WhereClause? ( = Type WhereClause? )? ;`
+    ///
+    /// ```text
+    /// type Id = u64;
+    /// ```
+    #[cfg(any(feature = "type_item", feature = "node_full"))]
+    TypeItem,
+    /// `type_parameter` (named)
+    ///
+    /// - **Rust reference**: [Generic parameters](https://doc.rust-lang.org/reference/items/generics.html)
+    /// - **Grammar**: `TypeParam : IDENTIFIER ( : TypeParamBounds? )? ( = Type )?`
+    ///
+    /// ```text
+    /// fn id<T>(x: T) -> T { x }
+    /// ```
+    #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+    TypeParameter,
+    /// `type_parameters` (named)
+    ///
+    /// - **Rust reference**: [Generic parameters](https://doc.rust-lang.org/reference/items/generics.html)
+    /// - **Grammar**: `GenericParams : < ( GenericParam ( , GenericParam )* ,? )? >`
+    ///
+    /// ```text
+    /// fn id<T>(x: T) -> T { x }
+    /// ```
+    #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+    TypeParameters,
+    /// `unary_expression` (named)
+    ///
+    /// - **Rust reference**: [Negation operators](https://doc.rust-lang.org/reference/expressions/operator-expr.html#negation-operators)
+    /// - **Grammar**: `NegationExpression : - Expression | ! Expression`
+    ///
+    /// ```text
+    /// -x;
+    /// ```
+    #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+    UnaryExpression,
+    /// `union_item` (named)
+    ///
+    /// - **Rust reference**: [Unions](https://doc.rust-lang.org/reference/items/unions.html)
+    /// - **Grammar**: `Union : union IDENTIFIER GenericParams? WhereClause? { StructFields? }`
+    ///
+    /// ```text
+    /// union Bits { i: u32, f: f32 }
+    /// ```
+    #[cfg(any(feature = "union_item", feature = "node_full"))]
+    UnionItem,
+    /// `unit_expression` (named)
+    ///
+    /// - **Rust reference**: [Tuple expressions](https://doc.rust-lang.org/reference/expressions/tuple-expr.html)
+    /// - **Grammar**: `TupleExpression : ( TupleElements? )`
+    ///
+    /// ```text
+    /// ();
+    /// ```
+    #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+    UnitExpression,
+    /// `unit_type` (named)
+    ///
+    /// - **Rust reference**: [Tuple types](https://doc.rust-lang.org/reference/types/tuple.html)
+    /// - **Grammar**: `TupleType : ( ) | ( ( Type , )+ Type? )`
+    ///
+    /// ```text
+    /// fn f() -> () {}
+    /// ```
+    #[cfg(any(feature = "unit_type", feature = "node_full"))]
+    UnitType,
+    /// `unsafe_block` (named)
+    ///
+    /// - **Rust reference**: [Unsafe blocks](https://doc.rust-lang.org/reference/expressions/block-expr.html#unsafe-blocks)
+    /// - **Grammar**: `UnsafeBlockExpression : unsafe BlockExpression`
+    ///
+    /// ```text
+    /// unsafe { f() };
+    /// ```
+    #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+    UnsafeBlock,
+    /// `use_as_clause` (named)
+    ///
+    /// - **Rust reference**: [Use declarations](https://doc.rust-lang.org/reference/items/use-declarations.html)
+    /// - **Grammar**: `UseTree : ( SimplePath? :: )? * | ( SimplePath? :: )? { ( UseTree ( , UseTree )* ,? )? } | SimplePath ( as ( IDENTIFIER | _ ) )?`
+    ///
+    /// ```text
+    /// use std::io::Result as IoResult;
+    /// ```
+    #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+    UseAsClause,
+    /// `use_bounds` (named)
+    ///
+    /// - **Rust reference**: [Use bounds](https://doc.rust-lang.org/reference/trait-bounds.html#use-bounds)
+    /// - **Grammar**: `UseBound : use UseBoundGenericArgs`
+    ///
+    /// ```text
+    /// fn f<'a>(x: &'a str) -> impl Sized + use<'a> { x }
+    /// ```
+    #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+    UseBounds,
+    /// `use_declaration` (named)
+    ///
+    /// - **Rust reference**: [Use declarations](https://doc.rust-lang.org/reference/items/use-declarations.html)
+    /// - **Grammar**: `UseDeclaration : use UseTree ;`
+    ///
+    /// ```text
+    /// use std::fmt;
+    /// ```
+    #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+    UseDeclaration,
+    /// `use_list` (named)
+    ///
+    /// - **Rust reference**: [Use declarations](https://doc.rust-lang.org/reference/items/use-declarations.html)
+    /// - **Grammar**: `UseTree : ( SimplePath? :: )? * | ( SimplePath? :: )? { ( UseTree ( , UseTree )* ,? )? } | SimplePath ( as ( IDENTIFIER | _ ) )?`
+    ///
+    /// ```text
+    /// use std::{fmt, io};
+    /// ```
+    #[cfg(any(feature = "use_list", feature = "node_full"))]
+    UseList,
+    /// `use_wildcard` (named)
+    ///
+    /// - **Rust reference**: [Use declarations](https://doc.rust-lang.org/reference/items/use-declarations.html)
+    /// - **Grammar**: `UseTree : ( SimplePath? :: )? * | ( SimplePath? :: )? { ( UseTree ( , UseTree )* ,? )? } | SimplePath ( as ( IDENTIFIER | _ ) )?`
+    ///
+    /// ```text
+    /// use std::io::*;
+    /// ```
+    #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+    UseWildcard,
+    /// `variadic_parameter` (named)
+    ///
+    /// - **Rust reference**: [Variadic functions](https://doc.rust-lang.org/reference/items/external-blocks.html#variadic-functions)
+    /// - **Grammar**: `NamedFunctionParametersWithVariadics : ( NamedFunctionParam , )* NamedFunctionParam , OuterAttribute* ...`
+    ///
+    /// ```text
+    /// extern "C" { fn printf(format: *const u8, ...); }
+    /// ```
+    #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+    VariadicParameter,
+    /// `visibility_modifier` (named)
+    ///
+    /// - **Rust reference**: [Visibility and privacy](https://doc.rust-lang.org/reference/visibility-and-privacy.html)
+    /// - **Grammar**: `Visibility : pub | pub ( crate ) | pub ( self ) | pub ( super ) | pub ( in SimplePath )`
+    ///
+    /// ```text
+    /// pub(crate) fn f() {}
+    /// ```
+    #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+    VisibilityModifier,
+    /// `where_clause` (named)
+    ///
+    /// - **Rust reference**: [Where clauses](https://doc.rust-lang.org/reference/items/generics.html#where-clauses)
+    /// - **Grammar**: `WhereClause : where ( WhereClauseItem , )* WhereClauseItem?`
+    ///
+    /// ```text
+    /// fn f<T>(x: T) where T: Clone {}
+    /// ```
+    #[cfg(any(feature = "where_clause", feature = "node_full"))]
+    WhereClause,
+    /// `where_predicate` (named)
+    ///
+    /// - **Rust reference**: [Where clauses](https://doc.rust-lang.org/reference/items/generics.html#where-clauses)
+    /// - **Grammar**: `WhereClauseItem : LifetimeWhereClauseItem | TypeBoundWhereClauseItem`
+    ///
+    /// ```text
+    /// fn f<T>(x: T) where T: Clone {}
+    /// ```
+    #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+    WherePredicate,
+    /// `while_expression` (named)
+    ///
+    /// - **Rust reference**: [Predicate loops](https://doc.rust-lang.org/reference/expressions/loop-expr.html#predicate-loops)
+    /// - **Grammar**: `PredicateLoopExpression : while Conditions BlockExpression`
+    ///
+    /// ```text
+    /// while x > 0 { x -= 1; }
+    /// ```
+    #[cfg(any(feature = "while_expression", feature = "node_full"))]
+    WhileExpression,
+    /// `yield_expression` (named)
+    ///
+    /// ```text
+    /// gen { yield 1; };
+    /// ```
+    #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+    YieldExpression,
+    /// `!` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// !x;
+    /// ```
+    #[cfg(any(feature = "bang_token", feature = "node_full"))]
+    BangToken,
+    /// `!=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a != b;
+    /// ```
+    #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+    NotEqToken,
+    /// `"` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// "hello";
+    /// ```
+    #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+    DoubleQuoteToken,
+    /// `#` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// #[test]
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "pound_token", feature = "node_full"))]
+    PoundToken,
+    /// `$` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// macro_rules! all { ($($e:expr),*) => {}; }
+    /// ```
+    #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+    DollarToken,
+    /// `%` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a % b;
+    /// ```
+    #[cfg(any(feature = "percent_token", feature = "node_full"))]
+    PercentToken,
+    /// `%=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x %= 2;
+    /// ```
+    #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+    PercentAssignToken,
+    /// `&` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// &x;
+    /// ```
+    #[cfg(any(feature = "and_token", feature = "node_full"))]
+    AndToken,
+    /// `&&` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a && b;
+    /// ```
+    #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+    AndAndToken,
+    /// `&=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x &= y;
+    /// ```
+    #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+    AndAssignToken,
+    /// `'` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// fn f<'a>() {}
+    /// ```
+    #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+    SingleQuoteToken,
+    /// `(` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// f();
+    /// ```
+    #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+    LParenToken,
+    /// `)` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// f();
+    /// ```
+    #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+    RParenToken,
+    /// `*` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a * b;
+    /// ```
+    #[cfg(any(feature = "star_token", feature = "node_full"))]
+    StarToken,
+    /// `*/` (unnamed)
+    ///
+    /// - **Rust reference**: [Comments](https://doc.rust-lang.org/reference/comments.html)
+    ///
+    /// ```text
+    /// /* note */
+    /// ```
+    #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+    BlockCommentEndToken,
+    /// `*=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x *= 2;
+    /// ```
+    #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+    StarAssignToken,
+    /// `+` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a + b;
+    /// ```
+    #[cfg(any(feature = "plus_token", feature = "node_full"))]
+    PlusToken,
+    /// `+=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x += 1;
+    /// ```
+    #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+    PlusAssignToken,
+    /// `,` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// f(a, b);
+    /// ```
+    #[cfg(any(feature = "comma_token", feature = "node_full"))]
+    CommaToken,
+    /// `-` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a - b;
+    /// ```
+    #[cfg(any(feature = "minus_token", feature = "node_full"))]
+    MinusToken,
+    /// `-=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x -= 1;
+    /// ```
+    #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+    MinusAssignToken,
+    /// `->` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// fn f() -> i32 { 1 }
+    /// ```
+    #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+    RArrowToken,
+    /// `.` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// p.x;
+    /// ```
+    #[cfg(any(feature = "dot_token", feature = "node_full"))]
+    DotToken,
+    /// `..` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// 0..10;
+    /// ```
+    #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+    DotDotToken,
+    /// `...` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// extern "C" { fn printf(format: *const u8, ...); }
+    /// ```
+    #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+    DotDotDotToken,
+    /// `..=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// 0..=9;
+    /// ```
+    #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+    DotDotEqToken,
+    /// `/` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a / b;
+    /// ```
+    #[cfg(any(feature = "slash_token", feature = "node_full"))]
+    SlashToken,
+    /// `/*` (unnamed)
+    ///
+    /// - **Rust reference**: [Comments](https://doc.rust-lang.org/reference/comments.html)
+    ///
+    /// ```text
+    /// /* note */
+    /// ```
+    #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+    BlockCommentStartToken,
+    /// `//` (unnamed)
+    ///
+    /// - **Rust reference**: [Comments](https://doc.rust-lang.org/reference/comments.html)
+    ///
+    /// ```text
+    /// // note
+    /// ```
+    #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+    LineCommentToken,
+    /// `/=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x /= 2;
+    /// ```
+    #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+    DivAssignToken,
+    /// `:` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// let x: u8 = 1;
+    /// ```
+    #[cfg(any(feature = "colon_token", feature = "node_full"))]
+    ColonToken,
+    /// `::` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// std::mem::swap(a, b);
+    /// ```
+    #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+    PathSepToken,
+    /// `;` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// f();
+    /// ```
+    #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+    SemicolonToken,
+    /// `<` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a < b;
+    /// ```
+    #[cfg(any(feature = "lt_token", feature = "node_full"))]
+    LtToken,
+    /// `<<` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a << 1;
+    /// ```
+    #[cfg(any(feature = "shl_token", feature = "node_full"))]
+    ShlToken,
+    /// `<<=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x <<= 1;
+    /// ```
+    #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+    ShlAssignToken,
+    /// `<=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a <= b;
+    /// ```
+    #[cfg(any(feature = "lte_token", feature = "node_full"))]
+    LteToken,
+    /// `=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// let x = 1;
+    /// ```
+    #[cfg(any(feature = "eq_token", feature = "node_full"))]
+    EqToken,
+    /// `==` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a == b;
+    /// ```
+    #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+    EqEqToken,
+    /// `=>` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// match x { _ => 0 }
+    /// ```
+    #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+    FatArrowToken,
+    /// `>` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a > b;
+    /// ```
+    #[cfg(any(feature = "gt_token", feature = "node_full"))]
+    GtToken,
+    /// `>=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a >= b;
+    /// ```
+    #[cfg(any(feature = "gte_token", feature = "node_full"))]
+    GteToken,
+    /// `>>` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a >> 1;
+    /// ```
+    #[cfg(any(feature = "shr_token", feature = "node_full"))]
+    ShrToken,
+    /// `>>=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x >>= 1;
+    /// ```
+    #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+    ShrAssignToken,
+    /// `?` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// f()?;
+    /// ```
+    #[cfg(any(feature = "question_token", feature = "node_full"))]
+    QuestionToken,
+    /// `@` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// match x { n @ 1..=9 => {} }
+    /// ```
+    #[cfg(any(feature = "at_token", feature = "node_full"))]
+    AtToken,
+    /// `[` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a[0];
+    /// ```
+    #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+    LBracketToken,
+    /// `]` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a[0];
+    /// ```
+    #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+    RBracketToken,
+    /// `^` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a ^ b;
+    /// ```
+    #[cfg(any(feature = "xor_token", feature = "node_full"))]
+    XorToken,
+    /// `^=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x ^= y;
+    /// ```
+    #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+    XorAssignToken,
+    /// `_` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// let _ = f();
+    /// ```
+    #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+    UnderscoreToken,
+    /// `as` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// x as u8;
+    /// ```
+    #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+    AsKwToken,
+    /// `async` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// async fn f() {}
+    /// ```
+    #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+    AsyncKwToken,
+    /// `await` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// f().await;
+    /// ```
+    #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+    AwaitKwToken,
+    /// `block` (unnamed)
+    ///
+    /// - named variant: [`Self::Block`]
+    ///
+    /// ```text
+    /// macro_rules! m { ($b:block) => {}; }
+    /// ```
+    #[cfg(any(feature = "block_token", feature = "node_full"))]
+    BlockToken,
+    /// `break` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// loop { break; }
+    /// ```
+    #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+    BreakKwToken,
+    /// `char_literal` (named)
+    ///
+    /// - **Rust reference**: [Character literals](https://doc.rust-lang.org/reference/tokens.html#character-literals)
+    /// - **Grammar**: `CHAR_LITERAL : ' ( ~[' \ LF CR TAB] | QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE ) ' SUFFIX?`
+    ///
+    /// ```text
+    /// 'c';
+    /// ```
+    #[cfg(any(feature = "char_literal", feature = "node_full"))]
+    CharLiteral,
+    /// `const` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// const MAX: u32 = 10;
+    /// ```
+    #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+    ConstKwToken,
+    /// `continue` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// loop { continue; }
+    /// ```
+    #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+    ContinueKwToken,
+    /// `crate` (named)
+    ///
+    /// - **Rust reference**: [Path qualifiers](https://doc.rust-lang.org/reference/paths.html#path-qualifiers)
+    ///
+    /// ```text
+    /// use crate::Node;
+    /// ```
+    #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+    CrateKw,
+    /// `default` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// impl A for B { default fn f() {} }
+    /// ```
+    #[cfg(any(feature = "default_token", feature = "node_full"))]
+    DefaultToken,
+    /// `doc_comment` (named)
+    ///
+    /// - **Rust reference**: [Doc comments](https://doc.rust-lang.org/reference/comments.html#doc-comments)
+    /// - **Grammar**: `OUTER_LINE_DOC : /// ( ~/ ~[LF CR]* )?`
+    ///
+    /// ```text
+    /// /// Docs
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+    DocComment,
+    /// `dyn` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// fn f(x: &dyn Display) {}
+    /// ```
+    #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+    DynKwToken,
+    /// `else` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// if x { f() } else { g() }
+    /// ```
+    #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+    ElseKwToken,
+    /// `enum` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// enum Color { Red }
+    /// ```
+    #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+    EnumKwToken,
+    /// `escape_sequence` (named)
+    ///
+    /// - **Rust reference**: [Escapes](https://doc.rust-lang.org/reference/tokens.html#escapes)
+    /// - **Grammar**: `QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE`
+    ///
+    /// ```text
+    /// "line\n";
+    /// ```
+    #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+    EscapeSequence,
+    /// `expr` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($e:expr) => {}; }
+    /// ```
+    #[cfg(any(feature = "expr_token", feature = "node_full"))]
+    ExprToken,
+    /// `expr_2021` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($e:expr_2021) => {}; }
+    /// ```
+    #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+    Expr2021Token,
+    /// `extern` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// extern crate alloc;
+    /// ```
+    #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+    ExternKwToken,
+    /// `false` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// false;
+    /// ```
+    #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+    FalseKwToken,
+    /// `field_identifier` (named)
+    ///
+    /// - **Rust reference**: [Identifiers](https://doc.rust-lang.org/reference/identifiers.html)
+    /// - **Grammar**: `IDENTIFIER : IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER`
+    ///
+    /// ```text
+    /// p.x;
+    /// ```
+    #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+    FieldIdentifier,
+    /// `float_literal` (named)
+    ///
+    /// - **Rust reference**: [Floating-point literals](https://doc.rust-lang.org/reference/tokens.html#floating-point-literals)
+    /// - **Grammar**: `FLOAT_LITERAL : DEC_LITERAL . | DEC_LITERAL . DEC_LITERAL SUFFIX_NO_E? | DEC_LITERAL ( . DEC_LITERAL )? FLOAT_EXPONENT SUFFIX?`
+    ///
+    /// ```text
+    /// 1.5;
+    /// ```
+    #[cfg(any(feature = "float_literal", feature = "node_full"))]
+    FloatLiteral,
+    /// `fn` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+    FnKwToken,
+    /// `for` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// for x in xs { f(x) }
+    /// ```
+    #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+    ForKwToken,
+    /// `gen` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// gen { yield 1; };
+    /// ```
+    #[cfg(any(feature = "gen_token", feature = "node_full"))]
+    GenToken,
+    /// `ident` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($i:ident) => {}; }
+    /// ```
+    #[cfg(any(feature = "ident_token", feature = "node_full"))]
+    IdentToken,
+    /// `identifier` (named)
+    ///
+    /// - **Rust reference**: [Identifiers](https://doc.rust-lang.org/reference/identifiers.html)
+    /// - **Grammar**: `IDENTIFIER : IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER`
+    ///
+    /// ```text
+    /// let x = 1;
+    /// ```
+    #[cfg(any(feature = "identifier", feature = "node_full"))]
+    Identifier,
+    /// `if` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// if x { f() }
+    /// ```
+    #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+    IfKwToken,
+    /// `impl` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// impl Point {}
+    /// ```
+    #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+    ImplKwToken,
+    /// `in` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// for x in xs {}
+    /// ```
+    #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+    InKwToken,
+    /// `integer_literal` (named)
+    ///
+    /// - **Rust reference**: [Integer literals](https://doc.rust-lang.org/reference/tokens.html#integer-literals)
+    /// - **Grammar**: `INTEGER_LITERAL : ( DEC_LITERAL | BIN_LITERAL | OCT_LITERAL | HEX_LITERAL ) SUFFIX_NO_E?`
+    ///
+    /// ```text
+    /// 42;
+    /// ```
+    #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+    IntegerLiteral,
+    /// `item` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($i:item) => {}; }
+    /// ```
+    #[cfg(any(feature = "item_token", feature = "node_full"))]
+    ItemToken,
+    /// `let` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// let x = 1;
+    /// ```
+    #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+    LetKwToken,
+    /// `lifetime` (unnamed)
+    ///
+    /// - named variant: [`Self::Lifetime`]
+    ///
+    /// ```text
+    /// macro_rules! m { ($l:lifetime) => {}; }
+    /// ```
+    #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+    LifetimeToken,
+    /// `literal` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($l:literal) => {}; }
+    /// ```
+    #[cfg(any(feature = "literal_token", feature = "node_full"))]
+    LiteralToken,
+    /// `loop` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// loop {}
+    /// ```
+    #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+    LoopKwToken,
+    /// `macro_rules!` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// macro_rules! m { () => {}; }
+    /// ```
+    #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+    MacroRulesBangToken,
+    /// `match` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// match x { _ => 0 }
+    /// ```
+    #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+    MatchKwToken,
+    /// `meta` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($m:meta) => {}; }
+    /// ```
+    #[cfg(any(feature = "meta_token", feature = "node_full"))]
+    MetaToken,
+    /// `metavariable` (named)
+    ///
+    /// - **Rust reference**: [Metavariables](https://doc.rust-lang.org/reference/macros-by-example.html#metavariables)
+    /// - **Grammar**: `$ ( IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER | _ ) : MacroFragSpec`
+    ///
+    /// ```text
+    /// macro_rules! id { ($e:expr) => { $e }; }
+    /// ```
+    #[cfg(any(feature = "metavariable", feature = "node_full"))]
+    Metavariable,
+    /// `mod` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// mod tests {}
+    /// ```
+    #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+    ModKwToken,
+    /// `move` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// move || x;
+    /// ```
+    #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+    MoveKwToken,
+    /// `mutable_specifier` (named)
+    ///
+    /// - **Rust reference**: [Identifier patterns](https://doc.rust-lang.org/reference/patterns.html#identifier-patterns)
+    ///
+    /// ```text
+    /// let mut x = 1;
+    /// ```
+    #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+    MutableSpecifier,
+    /// `pat` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($p:pat) => {}; }
+    /// ```
+    #[cfg(any(feature = "pat_token", feature = "node_full"))]
+    PatToken,
+    /// `pat_param` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($p:pat_param) => {}; }
+    /// ```
+    #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+    PatParamToken,
+    /// `path` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($p:path) => {}; }
+    /// ```
+    #[cfg(any(feature = "path_token", feature = "node_full"))]
+    PathToken,
+    /// `primitive_type` (named)
+    ///
+    /// - **Rust reference**: [Types](https://doc.rust-lang.org/reference/types.html)
+    ///
+    /// ```text
+    /// let x: u8 = 1;
+    /// ```
+    #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+    PrimitiveType,
+    /// `pub` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// pub fn f() {}
+    /// ```
+    #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+    PubKwToken,
+    /// `raw` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// &raw const x;
+    /// ```
+    #[cfg(any(feature = "raw_token", feature = "node_full"))]
+    RawToken,
+    /// `ref` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// let ref x = y;
+    /// ```
+    #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+    RefKwToken,
+    /// `return` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// fn f() { return; }
+    /// ```
+    #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+    ReturnKwToken,
+    /// `self` (named)
+    ///
+    /// - **Rust reference**: [Path qualifiers](https://doc.rust-lang.org/reference/paths.html#path-qualifiers)
+    ///
+    /// ```text
+    /// use self::Node;
+    /// ```
+    #[cfg(any(feature = "self_kw", feature = "node_full"))]
+    SelfKw,
+    /// `shebang` (named)
+    ///
+    /// - **Rust reference**: [Shebang removal](https://doc.rust-lang.org/reference/input-format.html#shebang-removal)
+    ///
+    /// ```text
+    /// #!/usr/bin/env run-cargo-script
+    /// fn main() {}
+    /// ```
+    #[cfg(any(feature = "shebang", feature = "node_full"))]
+    Shebang,
+    /// `shorthand_field_identifier` (named)
+    ///
+    /// - **Rust reference**: [Identifiers](https://doc.rust-lang.org/reference/identifiers.html)
+    /// - **Grammar**: `IDENTIFIER : IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER`
+    ///
+    /// ```text
+    /// let Point { x } = p;
+    /// ```
+    #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+    ShorthandFieldIdentifier,
+    /// `static` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// static N: u8 = 1;
+    /// ```
+    #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+    StaticKwToken,
+    /// `stmt` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($s:stmt) => {}; }
+    /// ```
+    #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+    StmtToken,
+    /// `string_content` (named)
+    ///
+    /// - **Rust reference**: [String literals](https://doc.rust-lang.org/reference/tokens.html#string-literals)
+    /// - **Grammar**: `STRING_LITERAL : " ( ~[" \ IsolatedCR] | QUOTE_ESCAPE | ASCII_ESCAPE | UNICODE_ESCAPE | STRING_CONTINUE )* " SUFFIX?`
+    ///
+    /// ```text
+    /// "hello";
+    /// ```
+    #[cfg(any(feature = "string_content", feature = "node_full"))]
+    StringContent,
+    /// `struct` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// struct Point;
+    /// ```
+    #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+    StructKwToken,
+    /// `super` (named)
+    ///
+    /// - **Rust reference**: [Path qualifiers](https://doc.rust-lang.org/reference/paths.html#path-qualifiers)
+    ///
+    /// ```text
+    /// use super::Node;
+    /// ```
+    #[cfg(any(feature = "super_kw", feature = "node_full"))]
+    SuperKw,
+    /// `trait` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// trait Shape {}
+    /// ```
+    #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+    TraitKwToken,
+    /// `true` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// true;
+    /// ```
+    #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+    TrueKwToken,
+    /// `try` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// try { f()? };
+    /// ```
+    #[cfg(any(feature = "try_token", feature = "node_full"))]
+    TryToken,
+    /// `tt` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($t:tt) => {}; }
+    /// ```
+    #[cfg(any(feature = "tt_token", feature = "node_full"))]
+    TtToken,
+    /// `ty` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($t:ty) => {}; }
+    /// ```
+    #[cfg(any(feature = "ty_token", feature = "node_full"))]
+    TyToken,
+    /// `type` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// type Id = u64;
+    /// ```
+    #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+    TypeKwToken,
+    /// `type_identifier` (named)
+    ///
+    /// - **Rust reference**: [Identifiers](https://doc.rust-lang.org/reference/identifiers.html)
+    /// - **Grammar**: `IDENTIFIER : IDENTIFIER_OR_KEYWORD | RAW_IDENTIFIER`
+    ///
+    /// ```text
+    /// struct Point;
+    /// ```
+    #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+    TypeIdentifier,
+    /// `union` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// union Bits { i: u32 }
+    /// ```
+    #[cfg(any(feature = "union_token", feature = "node_full"))]
+    UnionToken,
+    /// `unsafe` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// unsafe { f() };
+    /// ```
+    #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+    UnsafeKwToken,
+    /// `use` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// use std::fmt;
+    /// ```
+    #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+    UseKwToken,
+    /// `vis` (unnamed)
+    ///
+    /// ```text
+    /// macro_rules! m { ($v:vis) => {}; }
+    /// ```
+    #[cfg(any(feature = "vis_token", feature = "node_full"))]
+    VisToken,
+    /// `where` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// fn f<T>() where T: Clone {}
+    /// ```
+    #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+    WhereKwToken,
+    /// `while` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// while x {}
+    /// ```
+    #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+    WhileKwToken,
+    /// `yield` (unnamed)
+    ///
+    /// - **Rust reference**: [Keywords](https://doc.rust-lang.org/reference/keywords.html)
+    ///
+    /// ```text
+    /// gen { yield 1; };
+    /// ```
+    #[cfg(any(feature = "yield_token", feature = "node_full"))]
+    YieldToken,
+    /// `{` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+    LBraceToken,
+    /// `|` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// |x| x;
+    /// ```
+    #[cfg(any(feature = "or_token", feature = "node_full"))]
+    OrToken,
+    /// `|=` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// x |= y;
+    /// ```
+    #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+    OrAssignToken,
+    /// `||` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// a || b;
+    /// ```
+    #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+    OrOrToken,
+    /// `}` (unnamed)
+    ///
+    /// - **Rust reference**: [Punctuation](https://doc.rust-lang.org/reference/tokens.html#punctuation)
+    ///
+    /// ```text
+    /// fn f() {}
+    /// ```
+    #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+    RBraceToken,
+}
+
+#[cfg(any(feature = "declaration_statement", feature = "expression", feature = "literal", feature = "literal_pattern", feature = "pattern", feature = "type", feature = "abstract_type", feature = "arguments", feature = "array_expression", feature = "array_type", feature = "assignment_expression", feature = "associated_type", feature = "async_block", feature = "attribute", feature = "attribute_item", feature = "await_expression", feature = "base_field_initializer", feature = "binary_expression", feature = "block", feature = "block_comment", feature = "boolean_literal", feature = "bounded_type", feature = "bracketed_type", feature = "break_expression", feature = "call_expression", feature = "captured_pattern", feature = "closure_expression", feature = "closure_parameters", feature = "compound_assignment_expr", feature = "const_block", feature = "const_item", feature = "const_parameter", feature = "continue_expression", feature = "declaration_list", feature = "dynamic_type", feature = "else_clause", feature = "empty_statement", feature = "enum_item", feature = "enum_variant", feature = "enum_variant_list", feature = "expression_statement", feature = "extern_crate_declaration", feature = "extern_modifier", feature = "field_declaration", feature = "field_declaration_list", feature = "field_expression", feature = "field_initializer", feature = "field_initializer_list", feature = "field_pattern", feature = "for_expression", feature = "for_lifetimes", feature = "foreign_mod_item", feature = "fragment_specifier", feature = "function_item", feature = "function_modifiers", feature = "function_signature_item", feature = "function_type", feature = "gen_block", feature = "generic_function", feature = "generic_pattern", feature = "generic_type", feature = "generic_type_with_turbofish", feature = "higher_ranked_trait_bound", feature = "if_expression", feature = "impl_item", feature = "index_expression", feature = "inner_attribute_item", feature = "inner_doc_comment_marker", feature = "label", feature = "let_chain", feature = "let_condition", feature = "let_declaration", feature = "lifetime", feature = "lifetime_parameter", feature = "line_comment", feature = "loop_expression", feature = "macro_definition", feature = "macro_invocation", feature = "macro_rule", feature = "match_arm", feature = "match_block", feature = "match_expression", feature = "match_pattern", feature = "mod_item", feature = "mut_pattern", feature = "negative_literal", feature = "never_type", feature = "or_pattern", feature = "ordered_field_declaration_list", feature = "outer_doc_comment_marker", feature = "parameter", feature = "parameters", feature = "parenthesized_expression", feature = "pointer_type", feature = "qualified_type", feature = "range_expression", feature = "range_pattern", feature = "raw_string_literal", feature = "ref_pattern", feature = "reference_expression", feature = "reference_pattern", feature = "reference_type", feature = "remaining_field_pattern", feature = "removed_trait_bound", feature = "return_expression", feature = "scoped_identifier", feature = "scoped_type_identifier", feature = "scoped_use_list", feature = "self_parameter", feature = "shorthand_field_initializer", feature = "slice_pattern", feature = "source_file", feature = "static_item", feature = "string_literal", feature = "struct_expression", feature = "struct_item", feature = "struct_pattern", feature = "token_binding_pattern", feature = "token_repetition", feature = "token_repetition_pattern", feature = "token_tree", feature = "token_tree_pattern", feature = "trait_bounds", feature = "trait_item", feature = "try_block", feature = "try_expression", feature = "tuple_expression", feature = "tuple_pattern", feature = "tuple_struct_pattern", feature = "tuple_type", feature = "type_arguments", feature = "type_binding", feature = "type_cast_expression", feature = "type_item", feature = "type_parameter", feature = "type_parameters", feature = "unary_expression", feature = "union_item", feature = "unit_expression", feature = "unit_type", feature = "unsafe_block", feature = "use_as_clause", feature = "use_bounds", feature = "use_declaration", feature = "use_list", feature = "use_wildcard", feature = "variadic_parameter", feature = "visibility_modifier", feature = "where_clause", feature = "where_predicate", feature = "while_expression", feature = "yield_expression", feature = "bang_token", feature = "not_eq_token", feature = "double_quote_token", feature = "pound_token", feature = "dollar_token", feature = "percent_token", feature = "percent_assign_token", feature = "and_token", feature = "and_and_token", feature = "and_assign_token", feature = "single_quote_token", feature = "l_paren_token", feature = "r_paren_token", feature = "star_token", feature = "block_comment_end_token", feature = "star_assign_token", feature = "plus_token", feature = "plus_assign_token", feature = "comma_token", feature = "minus_token", feature = "minus_assign_token", feature = "r_arrow_token", feature = "dot_token", feature = "dot_dot_token", feature = "dot_dot_dot_token", feature = "dot_dot_eq_token", feature = "slash_token", feature = "block_comment_start_token", feature = "line_comment_token", feature = "div_assign_token", feature = "colon_token", feature = "path_sep_token", feature = "semicolon_token", feature = "lt_token", feature = "shl_token", feature = "shl_assign_token", feature = "lte_token", feature = "eq_token", feature = "eq_eq_token", feature = "fat_arrow_token", feature = "gt_token", feature = "gte_token", feature = "shr_token", feature = "shr_assign_token", feature = "question_token", feature = "at_token", feature = "l_bracket_token", feature = "r_bracket_token", feature = "xor_token", feature = "xor_assign_token", feature = "underscore_token", feature = "as_kw_token", feature = "async_kw_token", feature = "await_kw_token", feature = "block_token", feature = "break_kw_token", feature = "char_literal", feature = "const_kw_token", feature = "continue_kw_token", feature = "crate_kw", feature = "default_token", feature = "doc_comment", feature = "dyn_kw_token", feature = "else_kw_token", feature = "enum_kw_token", feature = "escape_sequence", feature = "expr_token", feature = "expr2021_token", feature = "extern_kw_token", feature = "false_kw_token", feature = "field_identifier", feature = "float_literal", feature = "fn_kw_token", feature = "for_kw_token", feature = "gen_token", feature = "ident_token", feature = "identifier", feature = "if_kw_token", feature = "impl_kw_token", feature = "in_kw_token", feature = "integer_literal", feature = "item_token", feature = "let_kw_token", feature = "lifetime_token", feature = "literal_token", feature = "loop_kw_token", feature = "macro_rules_bang_token", feature = "match_kw_token", feature = "meta_token", feature = "metavariable", feature = "mod_kw_token", feature = "move_kw_token", feature = "mutable_specifier", feature = "pat_token", feature = "pat_param_token", feature = "path_token", feature = "primitive_type", feature = "pub_kw_token", feature = "raw_token", feature = "ref_kw_token", feature = "return_kw_token", feature = "self_kw", feature = "shebang", feature = "shorthand_field_identifier", feature = "static_kw_token", feature = "stmt_token", feature = "string_content", feature = "struct_kw_token", feature = "super_kw", feature = "trait_kw_token", feature = "true_kw_token", feature = "try_token", feature = "tt_token", feature = "ty_token", feature = "type_kw_token", feature = "type_identifier", feature = "union_token", feature = "unsafe_kw_token", feature = "use_kw_token", feature = "vis_token", feature = "where_kw_token", feature = "while_kw_token", feature = "yield_token", feature = "l_brace_token", feature = "or_token", feature = "or_assign_token", feature = "or_or_token", feature = "r_brace_token", feature = "node_full"))]
+impl std::str::FromStr for NodeType {
+    type Err = String;
+
+    #[allow(clippy::too_many_lines)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            "_declaration_statement" => Ok(Self::DeclarationStatement),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            "_expression" => Ok(Self::Expression),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            "_literal" => Ok(Self::Literal),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            "_literal_pattern" => Ok(Self::LiteralPattern),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            "_pattern" => Ok(Self::Pattern),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            "_type" => Ok(Self::Type),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            "abstract_type" => Ok(Self::AbstractType),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            "arguments" => Ok(Self::Arguments),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            "array_expression" => Ok(Self::ArrayExpression),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            "array_type" => Ok(Self::ArrayType),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            "assignment_expression" => Ok(Self::AssignmentExpression),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            "associated_type" => Ok(Self::AssociatedType),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            "async_block" => Ok(Self::AsyncBlock),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            "attribute" => Ok(Self::Attribute),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            "attribute_item" => Ok(Self::AttributeItem),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            "await_expression" => Ok(Self::AwaitExpression),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            "base_field_initializer" => Ok(Self::BaseFieldInitializer),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            "binary_expression" => Ok(Self::BinaryExpression),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            "block" => Ok(Self::Block),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            "block_comment" => Ok(Self::BlockComment),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            "boolean_literal" => Ok(Self::BooleanLiteral),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            "bounded_type" => Ok(Self::BoundedType),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            "bracketed_type" => Ok(Self::BracketedType),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            "break_expression" => Ok(Self::BreakExpression),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            "call_expression" => Ok(Self::CallExpression),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            "captured_pattern" => Ok(Self::CapturedPattern),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            "closure_expression" => Ok(Self::ClosureExpression),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            "closure_parameters" => Ok(Self::ClosureParameters),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            "compound_assignment_expr" => Ok(Self::CompoundAssignmentExpr),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            "const_block" => Ok(Self::ConstBlock),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            "const_item" => Ok(Self::ConstItem),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            "const_parameter" => Ok(Self::ConstParameter),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            "continue_expression" => Ok(Self::ContinueExpression),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            "declaration_list" => Ok(Self::DeclarationList),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            "dynamic_type" => Ok(Self::DynamicType),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            "else_clause" => Ok(Self::ElseClause),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            "empty_statement" => Ok(Self::EmptyStatement),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            "enum_item" => Ok(Self::EnumItem),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            "enum_variant" => Ok(Self::EnumVariant),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            "enum_variant_list" => Ok(Self::EnumVariantList),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            "expression_statement" => Ok(Self::ExpressionStatement),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            "extern_crate_declaration" => Ok(Self::ExternCrateDeclaration),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            "extern_modifier" => Ok(Self::ExternModifier),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            "field_declaration" => Ok(Self::FieldDeclaration),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            "field_declaration_list" => Ok(Self::FieldDeclarationList),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            "field_expression" => Ok(Self::FieldExpression),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            "field_initializer" => Ok(Self::FieldInitializer),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            "field_initializer_list" => Ok(Self::FieldInitializerList),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            "field_pattern" => Ok(Self::FieldPattern),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            "for_expression" => Ok(Self::ForExpression),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            "for_lifetimes" => Ok(Self::ForLifetimes),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            "foreign_mod_item" => Ok(Self::ForeignModItem),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            "fragment_specifier" => Ok(Self::FragmentSpecifier),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            "function_item" => Ok(Self::FunctionItem),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            "function_modifiers" => Ok(Self::FunctionModifiers),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            "function_signature_item" => Ok(Self::FunctionSignatureItem),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            "function_type" => Ok(Self::FunctionType),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            "gen_block" => Ok(Self::GenBlock),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            "generic_function" => Ok(Self::GenericFunction),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            "generic_pattern" => Ok(Self::GenericPattern),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            "generic_type" => Ok(Self::GenericType),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            "generic_type_with_turbofish" => Ok(Self::GenericTypeWithTurbofish),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            "higher_ranked_trait_bound" => Ok(Self::HigherRankedTraitBound),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            "if_expression" => Ok(Self::IfExpression),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            "impl_item" => Ok(Self::ImplItem),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            "index_expression" => Ok(Self::IndexExpression),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            "inner_attribute_item" => Ok(Self::InnerAttributeItem),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            "inner_doc_comment_marker" => Ok(Self::InnerDocCommentMarker),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            "label" => Ok(Self::Label),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            "let_chain" => Ok(Self::LetChain),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            "let_condition" => Ok(Self::LetCondition),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            "let_declaration" => Ok(Self::LetDeclaration),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            "lifetime" => Ok(Self::Lifetime),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            "lifetime_parameter" => Ok(Self::LifetimeParameter),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            "line_comment" => Ok(Self::LineComment),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            "loop_expression" => Ok(Self::LoopExpression),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            "macro_definition" => Ok(Self::MacroDefinition),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            "macro_invocation" => Ok(Self::MacroInvocation),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            "macro_rule" => Ok(Self::MacroRule),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            "match_arm" => Ok(Self::MatchArm),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            "match_block" => Ok(Self::MatchBlock),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            "match_expression" => Ok(Self::MatchExpression),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            "match_pattern" => Ok(Self::MatchPattern),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            "mod_item" => Ok(Self::ModItem),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            "mut_pattern" => Ok(Self::MutPattern),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            "negative_literal" => Ok(Self::NegativeLiteral),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            "never_type" => Ok(Self::NeverType),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            "or_pattern" => Ok(Self::OrPattern),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            "ordered_field_declaration_list" => Ok(Self::OrderedFieldDeclarationList),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            "outer_doc_comment_marker" => Ok(Self::OuterDocCommentMarker),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            "parameter" => Ok(Self::Parameter),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            "parameters" => Ok(Self::Parameters),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            "parenthesized_expression" => Ok(Self::ParenthesizedExpression),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            "pointer_type" => Ok(Self::PointerType),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            "qualified_type" => Ok(Self::QualifiedType),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            "range_expression" => Ok(Self::RangeExpression),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            "range_pattern" => Ok(Self::RangePattern),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            "raw_string_literal" => Ok(Self::RawStringLiteral),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            "ref_pattern" => Ok(Self::RefPattern),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            "reference_expression" => Ok(Self::ReferenceExpression),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            "reference_pattern" => Ok(Self::ReferencePattern),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            "reference_type" => Ok(Self::ReferenceType),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            "remaining_field_pattern" => Ok(Self::RemainingFieldPattern),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            "removed_trait_bound" => Ok(Self::RemovedTraitBound),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            "return_expression" => Ok(Self::ReturnExpression),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            "scoped_identifier" => Ok(Self::ScopedIdentifier),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            "scoped_type_identifier" => Ok(Self::ScopedTypeIdentifier),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            "scoped_use_list" => Ok(Self::ScopedUseList),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            "self_parameter" => Ok(Self::SelfParameter),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            "shorthand_field_initializer" => Ok(Self::ShorthandFieldInitializer),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            "slice_pattern" => Ok(Self::SlicePattern),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            "source_file" => Ok(Self::SourceFile),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            "static_item" => Ok(Self::StaticItem),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            "string_literal" => Ok(Self::StringLiteral),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            "struct_expression" => Ok(Self::StructExpression),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            "struct_item" => Ok(Self::StructItem),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            "struct_pattern" => Ok(Self::StructPattern),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            "token_binding_pattern" => Ok(Self::TokenBindingPattern),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            "token_repetition" => Ok(Self::TokenRepetition),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            "token_repetition_pattern" => Ok(Self::TokenRepetitionPattern),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            "token_tree" => Ok(Self::TokenTree),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            "token_tree_pattern" => Ok(Self::TokenTreePattern),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            "trait_bounds" => Ok(Self::TraitBounds),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            "trait_item" => Ok(Self::TraitItem),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            "try_block" => Ok(Self::TryBlock),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            "try_expression" => Ok(Self::TryExpression),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            "tuple_expression" => Ok(Self::TupleExpression),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            "tuple_pattern" => Ok(Self::TuplePattern),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            "tuple_struct_pattern" => Ok(Self::TupleStructPattern),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            "tuple_type" => Ok(Self::TupleType),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            "type_arguments" => Ok(Self::TypeArguments),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            "type_binding" => Ok(Self::TypeBinding),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            "type_cast_expression" => Ok(Self::TypeCastExpression),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            "type_item" => Ok(Self::TypeItem),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            "type_parameter" => Ok(Self::TypeParameter),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            "type_parameters" => Ok(Self::TypeParameters),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            "unary_expression" => Ok(Self::UnaryExpression),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            "union_item" => Ok(Self::UnionItem),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            "unit_expression" => Ok(Self::UnitExpression),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            "unit_type" => Ok(Self::UnitType),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            "unsafe_block" => Ok(Self::UnsafeBlock),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            "use_as_clause" => Ok(Self::UseAsClause),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            "use_bounds" => Ok(Self::UseBounds),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            "use_declaration" => Ok(Self::UseDeclaration),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            "use_list" => Ok(Self::UseList),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            "use_wildcard" => Ok(Self::UseWildcard),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            "variadic_parameter" => Ok(Self::VariadicParameter),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            "visibility_modifier" => Ok(Self::VisibilityModifier),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            "where_clause" => Ok(Self::WhereClause),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            "where_predicate" => Ok(Self::WherePredicate),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            "while_expression" => Ok(Self::WhileExpression),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            "yield_expression" => Ok(Self::YieldExpression),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            "char_literal" => Ok(Self::CharLiteral),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            "crate" => Ok(Self::CrateKw),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            "doc_comment" => Ok(Self::DocComment),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            "escape_sequence" => Ok(Self::EscapeSequence),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            "field_identifier" => Ok(Self::FieldIdentifier),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            "float_literal" => Ok(Self::FloatLiteral),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            "identifier" => Ok(Self::Identifier),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            "integer_literal" => Ok(Self::IntegerLiteral),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            "metavariable" => Ok(Self::Metavariable),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            "mutable_specifier" => Ok(Self::MutableSpecifier),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            "primitive_type" => Ok(Self::PrimitiveType),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            "self" => Ok(Self::SelfKw),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            "shebang" => Ok(Self::Shebang),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            "shorthand_field_identifier" => Ok(Self::ShorthandFieldIdentifier),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            "string_content" => Ok(Self::StringContent),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            "super" => Ok(Self::SuperKw),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            "type_identifier" => Ok(Self::TypeIdentifier),
+            _ => Err(match Self::feature_for_kind(s, true) {
+                Some(feature) => format!("Node type {s} needs the `{feature}` feature"),
+                None => format!("Unknown node type: {s}"),
+            }),
+        }
+    }
+}
+
+#[cfg(any(feature = "declaration_statement", feature = "expression", feature = "literal", feature = "literal_pattern", feature = "pattern", feature = "type", feature = "abstract_type", feature = "arguments", feature = "array_expression", feature = "array_type", feature = "assignment_expression", feature = "associated_type", feature = "async_block", feature = "attribute", feature = "attribute_item", feature = "await_expression", feature = "base_field_initializer", feature = "binary_expression", feature = "block", feature = "block_comment", feature = "boolean_literal", feature = "bounded_type", feature = "bracketed_type", feature = "break_expression", feature = "call_expression", feature = "captured_pattern", feature = "closure_expression", feature = "closure_parameters", feature = "compound_assignment_expr", feature = "const_block", feature = "const_item", feature = "const_parameter", feature = "continue_expression", feature = "declaration_list", feature = "dynamic_type", feature = "else_clause", feature = "empty_statement", feature = "enum_item", feature = "enum_variant", feature = "enum_variant_list", feature = "expression_statement", feature = "extern_crate_declaration", feature = "extern_modifier", feature = "field_declaration", feature = "field_declaration_list", feature = "field_expression", feature = "field_initializer", feature = "field_initializer_list", feature = "field_pattern", feature = "for_expression", feature = "for_lifetimes", feature = "foreign_mod_item", feature = "fragment_specifier", feature = "function_item", feature = "function_modifiers", feature = "function_signature_item", feature = "function_type", feature = "gen_block", feature = "generic_function", feature = "generic_pattern", feature = "generic_type", feature = "generic_type_with_turbofish", feature = "higher_ranked_trait_bound", feature = "if_expression", feature = "impl_item", feature = "index_expression", feature = "inner_attribute_item", feature = "inner_doc_comment_marker", feature = "label", feature = "let_chain", feature = "let_condition", feature = "let_declaration", feature = "lifetime", feature = "lifetime_parameter", feature = "line_comment", feature = "loop_expression", feature = "macro_definition", feature = "macro_invocation", feature = "macro_rule", feature = "match_arm", feature = "match_block", feature = "match_expression", feature = "match_pattern", feature = "mod_item", feature = "mut_pattern", feature = "negative_literal", feature = "never_type", feature = "or_pattern", feature = "ordered_field_declaration_list", feature = "outer_doc_comment_marker", feature = "parameter", feature = "parameters", feature = "parenthesized_expression", feature = "pointer_type", feature = "qualified_type", feature = "range_expression", feature = "range_pattern", feature = "raw_string_literal", feature = "ref_pattern", feature = "reference_expression", feature = "reference_pattern", feature = "reference_type", feature = "remaining_field_pattern", feature = "removed_trait_bound", feature = "return_expression", feature = "scoped_identifier", feature = "scoped_type_identifier", feature = "scoped_use_list", feature = "self_parameter", feature = "shorthand_field_initializer", feature = "slice_pattern", feature = "source_file", feature = "static_item", feature = "string_literal", feature = "struct_expression", feature = "struct_item", feature = "struct_pattern", feature = "token_binding_pattern", feature = "token_repetition", feature = "token_repetition_pattern", feature = "token_tree", feature = "token_tree_pattern", feature = "trait_bounds", feature = "trait_item", feature = "try_block", feature = "try_expression", feature = "tuple_expression", feature = "tuple_pattern", feature = "tuple_struct_pattern", feature = "tuple_type", feature = "type_arguments", feature = "type_binding", feature = "type_cast_expression", feature = "type_item", feature = "type_parameter", feature = "type_parameters", feature = "unary_expression", feature = "union_item", feature = "unit_expression", feature = "unit_type", feature = "unsafe_block", feature = "use_as_clause", feature = "use_bounds", feature = "use_declaration", feature = "use_list", feature = "use_wildcard", feature = "variadic_parameter", feature = "visibility_modifier", feature = "where_clause", feature = "where_predicate", feature = "while_expression", feature = "yield_expression", feature = "bang_token", feature = "not_eq_token", feature = "double_quote_token", feature = "pound_token", feature = "dollar_token", feature = "percent_token", feature = "percent_assign_token", feature = "and_token", feature = "and_and_token", feature = "and_assign_token", feature = "single_quote_token", feature = "l_paren_token", feature = "r_paren_token", feature = "star_token", feature = "block_comment_end_token", feature = "star_assign_token", feature = "plus_token", feature = "plus_assign_token", feature = "comma_token", feature = "minus_token", feature = "minus_assign_token", feature = "r_arrow_token", feature = "dot_token", feature = "dot_dot_token", feature = "dot_dot_dot_token", feature = "dot_dot_eq_token", feature = "slash_token", feature = "block_comment_start_token", feature = "line_comment_token", feature = "div_assign_token", feature = "colon_token", feature = "path_sep_token", feature = "semicolon_token", feature = "lt_token", feature = "shl_token", feature = "shl_assign_token", feature = "lte_token", feature = "eq_token", feature = "eq_eq_token", feature = "fat_arrow_token", feature = "gt_token", feature = "gte_token", feature = "shr_token", feature = "shr_assign_token", feature = "question_token", feature = "at_token", feature = "l_bracket_token", feature = "r_bracket_token", feature = "xor_token", feature = "xor_assign_token", feature = "underscore_token", feature = "as_kw_token", feature = "async_kw_token", feature = "await_kw_token", feature = "block_token", feature = "break_kw_token", feature = "char_literal", feature = "const_kw_token", feature = "continue_kw_token", feature = "crate_kw", feature = "default_token", feature = "doc_comment", feature = "dyn_kw_token", feature = "else_kw_token", feature = "enum_kw_token", feature = "escape_sequence", feature = "expr_token", feature = "expr2021_token", feature = "extern_kw_token", feature = "false_kw_token", feature = "field_identifier", feature = "float_literal", feature = "fn_kw_token", feature = "for_kw_token", feature = "gen_token", feature = "ident_token", feature = "identifier", feature = "if_kw_token", feature = "impl_kw_token", feature = "in_kw_token", feature = "integer_literal", feature = "item_token", feature = "let_kw_token", feature = "lifetime_token", feature = "literal_token", feature = "loop_kw_token", feature = "macro_rules_bang_token", feature = "match_kw_token", feature = "meta_token", feature = "metavariable", feature = "mod_kw_token", feature = "move_kw_token", feature = "mutable_specifier", feature = "pat_token", feature = "pat_param_token", feature = "path_token", feature = "primitive_type", feature = "pub_kw_token", feature = "raw_token", feature = "ref_kw_token", feature = "return_kw_token", feature = "self_kw", feature = "shebang", feature = "shorthand_field_identifier", feature = "static_kw_token", feature = "stmt_token", feature = "string_content", feature = "struct_kw_token", feature = "super_kw", feature = "trait_kw_token", feature = "true_kw_token", feature = "try_token", feature = "tt_token", feature = "ty_token", feature = "type_kw_token", feature = "type_identifier", feature = "union_token", feature = "unsafe_kw_token", feature = "use_kw_token", feature = "vis_token", feature = "where_kw_token", feature = "while_kw_token", feature = "yield_token", feature = "l_brace_token", feature = "or_token", feature = "or_assign_token", feature = "or_or_token", feature = "r_brace_token", feature = "node_full"))]
+impl std::fmt::Display for NodeType {
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => write!(f, "_declaration_statement"),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => write!(f, "_expression"),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => write!(f, "_literal"),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => write!(f, "_literal_pattern"),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => write!(f, "_pattern"),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => write!(f, "_type"),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => write!(f, "abstract_type"),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => write!(f, "arguments"),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => write!(f, "array_expression"),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => write!(f, "array_type"),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => write!(f, "assignment_expression"),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => write!(f, "associated_type"),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => write!(f, "async_block"),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => write!(f, "attribute"),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => write!(f, "attribute_item"),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => write!(f, "await_expression"),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => write!(f, "base_field_initializer"),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => write!(f, "binary_expression"),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => write!(f, "block"),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => write!(f, "block_comment"),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => write!(f, "boolean_literal"),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => write!(f, "bounded_type"),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => write!(f, "bracketed_type"),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => write!(f, "break_expression"),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => write!(f, "call_expression"),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => write!(f, "captured_pattern"),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => write!(f, "closure_expression"),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => write!(f, "closure_parameters"),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => write!(f, "compound_assignment_expr"),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => write!(f, "const_block"),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => write!(f, "const_item"),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => write!(f, "const_parameter"),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => write!(f, "continue_expression"),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => write!(f, "declaration_list"),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => write!(f, "dynamic_type"),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => write!(f, "else_clause"),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => write!(f, "empty_statement"),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => write!(f, "enum_item"),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => write!(f, "enum_variant"),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => write!(f, "enum_variant_list"),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => write!(f, "expression_statement"),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => write!(f, "extern_crate_declaration"),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => write!(f, "extern_modifier"),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => write!(f, "field_declaration"),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => write!(f, "field_declaration_list"),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => write!(f, "field_expression"),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => write!(f, "field_initializer"),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => write!(f, "field_initializer_list"),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => write!(f, "field_pattern"),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => write!(f, "for_expression"),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => write!(f, "for_lifetimes"),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => write!(f, "foreign_mod_item"),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => write!(f, "fragment_specifier"),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => write!(f, "function_item"),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => write!(f, "function_modifiers"),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => write!(f, "function_signature_item"),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => write!(f, "function_type"),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => write!(f, "gen_block"),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => write!(f, "generic_function"),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => write!(f, "generic_pattern"),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => write!(f, "generic_type"),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => write!(f, "generic_type_with_turbofish"),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => write!(f, "higher_ranked_trait_bound"),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => write!(f, "if_expression"),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => write!(f, "impl_item"),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => write!(f, "index_expression"),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => write!(f, "inner_attribute_item"),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => write!(f, "inner_doc_comment_marker"),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => write!(f, "label"),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => write!(f, "let_chain"),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => write!(f, "let_condition"),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => write!(f, "let_declaration"),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => write!(f, "lifetime"),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => write!(f, "lifetime_parameter"),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => write!(f, "line_comment"),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => write!(f, "loop_expression"),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => write!(f, "macro_definition"),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => write!(f, "macro_invocation"),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => write!(f, "macro_rule"),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => write!(f, "match_arm"),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => write!(f, "match_block"),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => write!(f, "match_expression"),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => write!(f, "match_pattern"),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => write!(f, "mod_item"),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => write!(f, "mut_pattern"),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => write!(f, "negative_literal"),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => write!(f, "never_type"),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => write!(f, "or_pattern"),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => write!(f, "ordered_field_declaration_list"),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => write!(f, "outer_doc_comment_marker"),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => write!(f, "parameter"),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => write!(f, "parameters"),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => write!(f, "parenthesized_expression"),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => write!(f, "pointer_type"),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => write!(f, "qualified_type"),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => write!(f, "range_expression"),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => write!(f, "range_pattern"),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => write!(f, "raw_string_literal"),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => write!(f, "ref_pattern"),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => write!(f, "reference_expression"),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => write!(f, "reference_pattern"),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => write!(f, "reference_type"),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => write!(f, "remaining_field_pattern"),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => write!(f, "removed_trait_bound"),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => write!(f, "return_expression"),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => write!(f, "scoped_identifier"),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => write!(f, "scoped_type_identifier"),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => write!(f, "scoped_use_list"),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => write!(f, "self_parameter"),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => write!(f, "shorthand_field_initializer"),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => write!(f, "slice_pattern"),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => write!(f, "source_file"),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => write!(f, "static_item"),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => write!(f, "string_literal"),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => write!(f, "struct_expression"),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => write!(f, "struct_item"),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => write!(f, "struct_pattern"),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => write!(f, "token_binding_pattern"),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => write!(f, "token_repetition"),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => write!(f, "token_repetition_pattern"),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => write!(f, "token_tree"),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => write!(f, "token_tree_pattern"),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => write!(f, "trait_bounds"),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => write!(f, "trait_item"),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => write!(f, "try_block"),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => write!(f, "try_expression"),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => write!(f, "tuple_expression"),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => write!(f, "tuple_pattern"),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => write!(f, "tuple_struct_pattern"),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => write!(f, "tuple_type"),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => write!(f, "type_arguments"),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => write!(f, "type_binding"),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => write!(f, "type_cast_expression"),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => write!(f, "type_item"),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => write!(f, "type_parameter"),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => write!(f, "type_parameters"),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => write!(f, "unary_expression"),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => write!(f, "union_item"),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => write!(f, "unit_expression"),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => write!(f, "unit_type"),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => write!(f, "unsafe_block"),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => write!(f, "use_as_clause"),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => write!(f, "use_bounds"),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => write!(f, "use_declaration"),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => write!(f, "use_list"),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => write!(f, "use_wildcard"),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => write!(f, "variadic_parameter"),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => write!(f, "visibility_modifier"),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => write!(f, "where_clause"),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => write!(f, "where_predicate"),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => write!(f, "while_expression"),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => write!(f, "yield_expression"),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => write!(f, "!"),
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => write!(f, "!="),
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => write!(f, "\""),
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => write!(f, "#"),
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => write!(f, "$"),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => write!(f, "%"),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => write!(f, "%="),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => write!(f, "&"),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => write!(f, "&&"),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => write!(f, "&="),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => write!(f, "'"),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => write!(f, "("),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => write!(f, ")"),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => write!(f, "*"),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => write!(f, "*/"),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => write!(f, "*="),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => write!(f, "+"),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => write!(f, "+="),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => write!(f, ","),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => write!(f, "-"),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => write!(f, "-="),
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => write!(f, "->"),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => write!(f, "."),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => write!(f, ".."),
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => write!(f, "..."),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => write!(f, "..="),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => write!(f, "/"),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => write!(f, "/*"),
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => write!(f, "//"),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => write!(f, "/="),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => write!(f, ":"),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => write!(f, "::"),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => write!(f, ";"),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => write!(f, "<"),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => write!(f, "<<"),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => write!(f, "<<="),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => write!(f, "<="),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => write!(f, "="),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => write!(f, "=="),
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => write!(f, "=>"),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => write!(f, ">"),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => write!(f, ">="),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => write!(f, ">>"),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => write!(f, ">>="),
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => write!(f, "?"),
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => write!(f, "@"),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => write!(f, "["),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => write!(f, "]"),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => write!(f, "^"),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => write!(f, "^="),
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => write!(f, "_"),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => write!(f, "as"),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => write!(f, "async"),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => write!(f, "await"),
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => write!(f, "block"),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => write!(f, "break"),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => write!(f, "char_literal"),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => write!(f, "const"),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => write!(f, "continue"),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => write!(f, "crate"),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => write!(f, "default"),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => write!(f, "doc_comment"),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => write!(f, "dyn"),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => write!(f, "else"),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => write!(f, "enum"),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => write!(f, "escape_sequence"),
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => write!(f, "expr"),
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => write!(f, "expr_2021"),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => write!(f, "extern"),
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => write!(f, "false"),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => write!(f, "field_identifier"),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => write!(f, "float_literal"),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => write!(f, "fn"),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => write!(f, "for"),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => write!(f, "gen"),
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => write!(f, "ident"),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => write!(f, "identifier"),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => write!(f, "if"),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => write!(f, "impl"),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => write!(f, "in"),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => write!(f, "integer_literal"),
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => write!(f, "item"),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => write!(f, "let"),
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => write!(f, "lifetime"),
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => write!(f, "literal"),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => write!(f, "loop"),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => write!(f, "macro_rules!"),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => write!(f, "match"),
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => write!(f, "meta"),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => write!(f, "metavariable"),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => write!(f, "mod"),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => write!(f, "move"),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => write!(f, "mutable_specifier"),
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => write!(f, "pat"),
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => write!(f, "pat_param"),
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => write!(f, "path"),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => write!(f, "primitive_type"),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => write!(f, "pub"),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => write!(f, "raw"),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => write!(f, "ref"),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => write!(f, "return"),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => write!(f, "self"),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => write!(f, "shebang"),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => write!(f, "shorthand_field_identifier"),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => write!(f, "static"),
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => write!(f, "stmt"),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => write!(f, "string_content"),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => write!(f, "struct"),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => write!(f, "super"),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => write!(f, "trait"),
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => write!(f, "true"),
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => write!(f, "try"),
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => write!(f, "tt"),
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => write!(f, "ty"),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => write!(f, "type"),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => write!(f, "type_identifier"),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => write!(f, "union"),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => write!(f, "unsafe"),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => write!(f, "use"),
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => write!(f, "vis"),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => write!(f, "where"),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => write!(f, "while"),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => write!(f, "yield"),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => write!(f, "{{"),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => write!(f, "|"),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => write!(f, "|="),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => write!(f, "||"),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => write!(f, "}}"),
+        }
+    }
+}
+impl NodeType {
+    /// Every node type enabled by the current features, in `node-types.json` order.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+        Self::DeclarationStatement,
+        #[cfg(any(feature = "expression", feature = "node_full"))]
+        Self::Expression,
+        #[cfg(any(feature = "literal", feature = "node_full"))]
+        Self::Literal,
+        #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+        Self::LiteralPattern,
+        #[cfg(any(feature = "pattern", feature = "node_full"))]
+        Self::Pattern,
+        #[cfg(any(feature = "type", feature = "node_full"))]
+        Self::Type,
+        #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+        Self::AbstractType,
+        #[cfg(any(feature = "arguments", feature = "node_full"))]
+        Self::Arguments,
+        #[cfg(any(feature = "array_expression", feature = "node_full"))]
+        Self::ArrayExpression,
+        #[cfg(any(feature = "array_type", feature = "node_full"))]
+        Self::ArrayType,
+        #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+        Self::AssignmentExpression,
+        #[cfg(any(feature = "associated_type", feature = "node_full"))]
+        Self::AssociatedType,
+        #[cfg(any(feature = "async_block", feature = "node_full"))]
+        Self::AsyncBlock,
+        #[cfg(any(feature = "attribute", feature = "node_full"))]
+        Self::Attribute,
+        #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+        Self::AttributeItem,
+        #[cfg(any(feature = "await_expression", feature = "node_full"))]
+        Self::AwaitExpression,
+        #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+        Self::BaseFieldInitializer,
+        #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+        Self::BinaryExpression,
+        #[cfg(any(feature = "block", feature = "node_full"))]
+        Self::Block,
+        #[cfg(any(feature = "block_comment", feature = "node_full"))]
+        Self::BlockComment,
+        #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+        Self::BooleanLiteral,
+        #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+        Self::BoundedType,
+        #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+        Self::BracketedType,
+        #[cfg(any(feature = "break_expression", feature = "node_full"))]
+        Self::BreakExpression,
+        #[cfg(any(feature = "call_expression", feature = "node_full"))]
+        Self::CallExpression,
+        #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+        Self::CapturedPattern,
+        #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+        Self::ClosureExpression,
+        #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+        Self::ClosureParameters,
+        #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+        Self::CompoundAssignmentExpr,
+        #[cfg(any(feature = "const_block", feature = "node_full"))]
+        Self::ConstBlock,
+        #[cfg(any(feature = "const_item", feature = "node_full"))]
+        Self::ConstItem,
+        #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+        Self::ConstParameter,
+        #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+        Self::ContinueExpression,
+        #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+        Self::DeclarationList,
+        #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+        Self::DynamicType,
+        #[cfg(any(feature = "else_clause", feature = "node_full"))]
+        Self::ElseClause,
+        #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+        Self::EmptyStatement,
+        #[cfg(any(feature = "enum_item", feature = "node_full"))]
+        Self::EnumItem,
+        #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+        Self::EnumVariant,
+        #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+        Self::EnumVariantList,
+        #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+        Self::ExpressionStatement,
+        #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+        Self::ExternCrateDeclaration,
+        #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+        Self::ExternModifier,
+        #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+        Self::FieldDeclaration,
+        #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+        Self::FieldDeclarationList,
+        #[cfg(any(feature = "field_expression", feature = "node_full"))]
+        Self::FieldExpression,
+        #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+        Self::FieldInitializer,
+        #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+        Self::FieldInitializerList,
+        #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+        Self::FieldPattern,
+        #[cfg(any(feature = "for_expression", feature = "node_full"))]
+        Self::ForExpression,
+        #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+        Self::ForLifetimes,
+        #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+        Self::ForeignModItem,
+        #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+        Self::FragmentSpecifier,
+        #[cfg(any(feature = "function_item", feature = "node_full"))]
+        Self::FunctionItem,
+        #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+        Self::FunctionModifiers,
+        #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+        Self::FunctionSignatureItem,
+        #[cfg(any(feature = "function_type", feature = "node_full"))]
+        Self::FunctionType,
+        #[cfg(any(feature = "gen_block", feature = "node_full"))]
+        Self::GenBlock,
+        #[cfg(any(feature = "generic_function", feature = "node_full"))]
+        Self::GenericFunction,
+        #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+        Self::GenericPattern,
+        #[cfg(any(feature = "generic_type", feature = "node_full"))]
+        Self::GenericType,
+        #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+        Self::GenericTypeWithTurbofish,
+        #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+        Self::HigherRankedTraitBound,
+        #[cfg(any(feature = "if_expression", feature = "node_full"))]
+        Self::IfExpression,
+        #[cfg(any(feature = "impl_item", feature = "node_full"))]
+        Self::ImplItem,
+        #[cfg(any(feature = "index_expression", feature = "node_full"))]
+        Self::IndexExpression,
+        #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+        Self::InnerAttributeItem,
+        #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+        Self::InnerDocCommentMarker,
+        #[cfg(any(feature = "label", feature = "node_full"))]
+        Self::Label,
+        #[cfg(any(feature = "let_chain", feature = "node_full"))]
+        Self::LetChain,
+        #[cfg(any(feature = "let_condition", feature = "node_full"))]
+        Self::LetCondition,
+        #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+        Self::LetDeclaration,
+        #[cfg(any(feature = "lifetime", feature = "node_full"))]
+        Self::Lifetime,
+        #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+        Self::LifetimeParameter,
+        #[cfg(any(feature = "line_comment", feature = "node_full"))]
+        Self::LineComment,
+        #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+        Self::LoopExpression,
+        #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+        Self::MacroDefinition,
+        #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+        Self::MacroInvocation,
+        #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+        Self::MacroRule,
+        #[cfg(any(feature = "match_arm", feature = "node_full"))]
+        Self::MatchArm,
+        #[cfg(any(feature = "match_block", feature = "node_full"))]
+        Self::MatchBlock,
+        #[cfg(any(feature = "match_expression", feature = "node_full"))]
+        Self::MatchExpression,
+        #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+        Self::MatchPattern,
+        #[cfg(any(feature = "mod_item", feature = "node_full"))]
+        Self::ModItem,
+        #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+        Self::MutPattern,
+        #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+        Self::NegativeLiteral,
+        #[cfg(any(feature = "never_type", feature = "node_full"))]
+        Self::NeverType,
+        #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+        Self::OrPattern,
+        #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+        Self::OrderedFieldDeclarationList,
+        #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+        Self::OuterDocCommentMarker,
+        #[cfg(any(feature = "parameter", feature = "node_full"))]
+        Self::Parameter,
+        #[cfg(any(feature = "parameters", feature = "node_full"))]
+        Self::Parameters,
+        #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+        Self::ParenthesizedExpression,
+        #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+        Self::PointerType,
+        #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+        Self::QualifiedType,
+        #[cfg(any(feature = "range_expression", feature = "node_full"))]
+        Self::RangeExpression,
+        #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+        Self::RangePattern,
+        #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+        Self::RawStringLiteral,
+        #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+        Self::RefPattern,
+        #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+        Self::ReferenceExpression,
+        #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+        Self::ReferencePattern,
+        #[cfg(any(feature = "reference_type", feature = "node_full"))]
+        Self::ReferenceType,
+        #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+        Self::RemainingFieldPattern,
+        #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+        Self::RemovedTraitBound,
+        #[cfg(any(feature = "return_expression", feature = "node_full"))]
+        Self::ReturnExpression,
+        #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+        Self::ScopedIdentifier,
+        #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+        Self::ScopedTypeIdentifier,
+        #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+        Self::ScopedUseList,
+        #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+        Self::SelfParameter,
+        #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+        Self::ShorthandFieldInitializer,
+        #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+        Self::SlicePattern,
+        #[cfg(any(feature = "source_file", feature = "node_full"))]
+        Self::SourceFile,
+        #[cfg(any(feature = "static_item", feature = "node_full"))]
+        Self::StaticItem,
+        #[cfg(any(feature = "string_literal", feature = "node_full"))]
+        Self::StringLiteral,
+        #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+        Self::StructExpression,
+        #[cfg(any(feature = "struct_item", feature = "node_full"))]
+        Self::StructItem,
+        #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+        Self::StructPattern,
+        #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+        Self::TokenBindingPattern,
+        #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+        Self::TokenRepetition,
+        #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+        Self::TokenRepetitionPattern,
+        #[cfg(any(feature = "token_tree", feature = "node_full"))]
+        Self::TokenTree,
+        #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+        Self::TokenTreePattern,
+        #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+        Self::TraitBounds,
+        #[cfg(any(feature = "trait_item", feature = "node_full"))]
+        Self::TraitItem,
+        #[cfg(any(feature = "try_block", feature = "node_full"))]
+        Self::TryBlock,
+        #[cfg(any(feature = "try_expression", feature = "node_full"))]
+        Self::TryExpression,
+        #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+        Self::TupleExpression,
+        #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+        Self::TuplePattern,
+        #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+        Self::TupleStructPattern,
+        #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+        Self::TupleType,
+        #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+        Self::TypeArguments,
+        #[cfg(any(feature = "type_binding", feature = "node_full"))]
+        Self::TypeBinding,
+        #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+        Self::TypeCastExpression,
+        #[cfg(any(feature = "type_item", feature = "node_full"))]
+        Self::TypeItem,
+        #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+        Self::TypeParameter,
+        #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+        Self::TypeParameters,
+        #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+        Self::UnaryExpression,
+        #[cfg(any(feature = "union_item", feature = "node_full"))]
+        Self::UnionItem,
+        #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+        Self::UnitExpression,
+        #[cfg(any(feature = "unit_type", feature = "node_full"))]
+        Self::UnitType,
+        #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+        Self::UnsafeBlock,
+        #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+        Self::UseAsClause,
+        #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+        Self::UseBounds,
+        #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+        Self::UseDeclaration,
+        #[cfg(any(feature = "use_list", feature = "node_full"))]
+        Self::UseList,
+        #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+        Self::UseWildcard,
+        #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+        Self::VariadicParameter,
+        #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+        Self::VisibilityModifier,
+        #[cfg(any(feature = "where_clause", feature = "node_full"))]
+        Self::WhereClause,
+        #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+        Self::WherePredicate,
+        #[cfg(any(feature = "while_expression", feature = "node_full"))]
+        Self::WhileExpression,
+        #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+        Self::YieldExpression,
+        #[cfg(any(feature = "bang_token", feature = "node_full"))]
+        Self::BangToken,
+        #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+        Self::NotEqToken,
+        #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+        Self::DoubleQuoteToken,
+        #[cfg(any(feature = "pound_token", feature = "node_full"))]
+        Self::PoundToken,
+        #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+        Self::DollarToken,
+        #[cfg(any(feature = "percent_token", feature = "node_full"))]
+        Self::PercentToken,
+        #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+        Self::PercentAssignToken,
+        #[cfg(any(feature = "and_token", feature = "node_full"))]
+        Self::AndToken,
+        #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+        Self::AndAndToken,
+        #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+        Self::AndAssignToken,
+        #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+        Self::SingleQuoteToken,
+        #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+        Self::LParenToken,
+        #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+        Self::RParenToken,
+        #[cfg(any(feature = "star_token", feature = "node_full"))]
+        Self::StarToken,
+        #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+        Self::BlockCommentEndToken,
+        #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+        Self::StarAssignToken,
+        #[cfg(any(feature = "plus_token", feature = "node_full"))]
+        Self::PlusToken,
+        #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+        Self::PlusAssignToken,
+        #[cfg(any(feature = "comma_token", feature = "node_full"))]
+        Self::CommaToken,
+        #[cfg(any(feature = "minus_token", feature = "node_full"))]
+        Self::MinusToken,
+        #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+        Self::MinusAssignToken,
+        #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+        Self::RArrowToken,
+        #[cfg(any(feature = "dot_token", feature = "node_full"))]
+        Self::DotToken,
+        #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+        Self::DotDotToken,
+        #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+        Self::DotDotDotToken,
+        #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+        Self::DotDotEqToken,
+        #[cfg(any(feature = "slash_token", feature = "node_full"))]
+        Self::SlashToken,
+        #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+        Self::BlockCommentStartToken,
+        #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+        Self::LineCommentToken,
+        #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+        Self::DivAssignToken,
+        #[cfg(any(feature = "colon_token", feature = "node_full"))]
+        Self::ColonToken,
+        #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+        Self::PathSepToken,
+        #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+        Self::SemicolonToken,
+        #[cfg(any(feature = "lt_token", feature = "node_full"))]
+        Self::LtToken,
+        #[cfg(any(feature = "shl_token", feature = "node_full"))]
+        Self::ShlToken,
+        #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+        Self::ShlAssignToken,
+        #[cfg(any(feature = "lte_token", feature = "node_full"))]
+        Self::LteToken,
+        #[cfg(any(feature = "eq_token", feature = "node_full"))]
+        Self::EqToken,
+        #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+        Self::EqEqToken,
+        #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+        Self::FatArrowToken,
+        #[cfg(any(feature = "gt_token", feature = "node_full"))]
+        Self::GtToken,
+        #[cfg(any(feature = "gte_token", feature = "node_full"))]
+        Self::GteToken,
+        #[cfg(any(feature = "shr_token", feature = "node_full"))]
+        Self::ShrToken,
+        #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+        Self::ShrAssignToken,
+        #[cfg(any(feature = "question_token", feature = "node_full"))]
+        Self::QuestionToken,
+        #[cfg(any(feature = "at_token", feature = "node_full"))]
+        Self::AtToken,
+        #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+        Self::LBracketToken,
+        #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+        Self::RBracketToken,
+        #[cfg(any(feature = "xor_token", feature = "node_full"))]
+        Self::XorToken,
+        #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+        Self::XorAssignToken,
+        #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+        Self::UnderscoreToken,
+        #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+        Self::AsKwToken,
+        #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+        Self::AsyncKwToken,
+        #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+        Self::AwaitKwToken,
+        #[cfg(any(feature = "block_token", feature = "node_full"))]
+        Self::BlockToken,
+        #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+        Self::BreakKwToken,
+        #[cfg(any(feature = "char_literal", feature = "node_full"))]
+        Self::CharLiteral,
+        #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+        Self::ConstKwToken,
+        #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+        Self::ContinueKwToken,
+        #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+        Self::CrateKw,
+        #[cfg(any(feature = "default_token", feature = "node_full"))]
+        Self::DefaultToken,
+        #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+        Self::DocComment,
+        #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+        Self::DynKwToken,
+        #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+        Self::ElseKwToken,
+        #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+        Self::EnumKwToken,
+        #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+        Self::EscapeSequence,
+        #[cfg(any(feature = "expr_token", feature = "node_full"))]
+        Self::ExprToken,
+        #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+        Self::Expr2021Token,
+        #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+        Self::ExternKwToken,
+        #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+        Self::FalseKwToken,
+        #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+        Self::FieldIdentifier,
+        #[cfg(any(feature = "float_literal", feature = "node_full"))]
+        Self::FloatLiteral,
+        #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+        Self::FnKwToken,
+        #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+        Self::ForKwToken,
+        #[cfg(any(feature = "gen_token", feature = "node_full"))]
+        Self::GenToken,
+        #[cfg(any(feature = "ident_token", feature = "node_full"))]
+        Self::IdentToken,
+        #[cfg(any(feature = "identifier", feature = "node_full"))]
+        Self::Identifier,
+        #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+        Self::IfKwToken,
+        #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+        Self::ImplKwToken,
+        #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+        Self::InKwToken,
+        #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+        Self::IntegerLiteral,
+        #[cfg(any(feature = "item_token", feature = "node_full"))]
+        Self::ItemToken,
+        #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+        Self::LetKwToken,
+        #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+        Self::LifetimeToken,
+        #[cfg(any(feature = "literal_token", feature = "node_full"))]
+        Self::LiteralToken,
+        #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+        Self::LoopKwToken,
+        #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+        Self::MacroRulesBangToken,
+        #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+        Self::MatchKwToken,
+        #[cfg(any(feature = "meta_token", feature = "node_full"))]
+        Self::MetaToken,
+        #[cfg(any(feature = "metavariable", feature = "node_full"))]
+        Self::Metavariable,
+        #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+        Self::ModKwToken,
+        #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+        Self::MoveKwToken,
+        #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+        Self::MutableSpecifier,
+        #[cfg(any(feature = "pat_token", feature = "node_full"))]
+        Self::PatToken,
+        #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+        Self::PatParamToken,
+        #[cfg(any(feature = "path_token", feature = "node_full"))]
+        Self::PathToken,
+        #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+        Self::PrimitiveType,
+        #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+        Self::PubKwToken,
+        #[cfg(any(feature = "raw_token", feature = "node_full"))]
+        Self::RawToken,
+        #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+        Self::RefKwToken,
+        #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+        Self::ReturnKwToken,
+        #[cfg(any(feature = "self_kw", feature = "node_full"))]
+        Self::SelfKw,
+        #[cfg(any(feature = "shebang", feature = "node_full"))]
+        Self::Shebang,
+        #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+        Self::ShorthandFieldIdentifier,
+        #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+        Self::StaticKwToken,
+        #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+        Self::StmtToken,
+        #[cfg(any(feature = "string_content", feature = "node_full"))]
+        Self::StringContent,
+        #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+        Self::StructKwToken,
+        #[cfg(any(feature = "super_kw", feature = "node_full"))]
+        Self::SuperKw,
+        #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+        Self::TraitKwToken,
+        #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+        Self::TrueKwToken,
+        #[cfg(any(feature = "try_token", feature = "node_full"))]
+        Self::TryToken,
+        #[cfg(any(feature = "tt_token", feature = "node_full"))]
+        Self::TtToken,
+        #[cfg(any(feature = "ty_token", feature = "node_full"))]
+        Self::TyToken,
+        #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+        Self::TypeKwToken,
+        #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+        Self::TypeIdentifier,
+        #[cfg(any(feature = "union_token", feature = "node_full"))]
+        Self::UnionToken,
+        #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+        Self::UnsafeKwToken,
+        #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+        Self::UseKwToken,
+        #[cfg(any(feature = "vis_token", feature = "node_full"))]
+        Self::VisToken,
+        #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+        Self::WhereKwToken,
+        #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+        Self::WhileKwToken,
+        #[cfg(any(feature = "yield_token", feature = "node_full"))]
+        Self::YieldToken,
+        #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+        Self::LBraceToken,
+        #[cfg(any(feature = "or_token", feature = "node_full"))]
+        Self::OrToken,
+        #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+        Self::OrAssignToken,
+        #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+        Self::OrOrToken,
+        #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+        Self::RBraceToken,
+    ];
+}
+
+impl NodeType {
+    /// The name of this variant, e.g. `"FunctionItem"`.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub const fn variant_name(self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => "DeclarationStatement",
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => "Expression",
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => "Literal",
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => "LiteralPattern",
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => "Pattern",
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => "Type",
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => "AbstractType",
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => "Arguments",
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => "ArrayExpression",
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => "ArrayType",
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => "AssignmentExpression",
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => "AssociatedType",
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => "AsyncBlock",
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => "Attribute",
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => "AttributeItem",
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => "AwaitExpression",
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => "BaseFieldInitializer",
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => "BinaryExpression",
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => "Block",
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => "BlockComment",
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => "BooleanLiteral",
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => "BoundedType",
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => "BracketedType",
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => "BreakExpression",
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => "CallExpression",
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => "CapturedPattern",
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => "ClosureExpression",
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => "ClosureParameters",
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => "CompoundAssignmentExpr",
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => "ConstBlock",
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => "ConstItem",
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => "ConstParameter",
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => "ContinueExpression",
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => "DeclarationList",
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => "DynamicType",
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => "ElseClause",
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => "EmptyStatement",
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => "EnumItem",
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => "EnumVariant",
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => "EnumVariantList",
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => "ExpressionStatement",
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => "ExternCrateDeclaration",
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => "ExternModifier",
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => "FieldDeclaration",
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => "FieldDeclarationList",
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => "FieldExpression",
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => "FieldInitializer",
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => "FieldInitializerList",
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => "FieldPattern",
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => "ForExpression",
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => "ForLifetimes",
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => "ForeignModItem",
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => "FragmentSpecifier",
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => "FunctionItem",
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => "FunctionModifiers",
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => "FunctionSignatureItem",
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => "FunctionType",
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => "GenBlock",
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => "GenericFunction",
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => "GenericPattern",
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => "GenericType",
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => "GenericTypeWithTurbofish",
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => "HigherRankedTraitBound",
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => "IfExpression",
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => "ImplItem",
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => "IndexExpression",
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => "InnerAttributeItem",
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => "InnerDocCommentMarker",
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => "Label",
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => "LetChain",
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => "LetCondition",
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => "LetDeclaration",
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => "Lifetime",
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => "LifetimeParameter",
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => "LineComment",
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => "LoopExpression",
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => "MacroDefinition",
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => "MacroInvocation",
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => "MacroRule",
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => "MatchArm",
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => "MatchBlock",
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => "MatchExpression",
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => "MatchPattern",
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => "ModItem",
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => "MutPattern",
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => "NegativeLiteral",
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => "NeverType",
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => "OrPattern",
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => "OrderedFieldDeclarationList",
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => "OuterDocCommentMarker",
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => "Parameter",
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => "Parameters",
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => "ParenthesizedExpression",
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => "PointerType",
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => "QualifiedType",
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => "RangeExpression",
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => "RangePattern",
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => "RawStringLiteral",
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => "RefPattern",
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => "ReferenceExpression",
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => "ReferencePattern",
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => "ReferenceType",
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => "RemainingFieldPattern",
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => "RemovedTraitBound",
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => "ReturnExpression",
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => "ScopedIdentifier",
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => "ScopedTypeIdentifier",
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => "ScopedUseList",
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => "SelfParameter",
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => "ShorthandFieldInitializer",
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => "SlicePattern",
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => "SourceFile",
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => "StaticItem",
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => "StringLiteral",
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => "StructExpression",
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => "StructItem",
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => "StructPattern",
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => "TokenBindingPattern",
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => "TokenRepetition",
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => "TokenRepetitionPattern",
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => "TokenTree",
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => "TokenTreePattern",
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => "TraitBounds",
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => "TraitItem",
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => "TryBlock",
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => "TryExpression",
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => "TupleExpression",
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => "TuplePattern",
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => "TupleStructPattern",
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => "TupleType",
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => "TypeArguments",
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => "TypeBinding",
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => "TypeCastExpression",
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => "TypeItem",
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => "TypeParameter",
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => "TypeParameters",
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => "UnaryExpression",
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => "UnionItem",
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => "UnitExpression",
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => "UnitType",
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => "UnsafeBlock",
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => "UseAsClause",
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => "UseBounds",
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => "UseDeclaration",
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => "UseList",
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => "UseWildcard",
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => "VariadicParameter",
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => "VisibilityModifier",
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => "WhereClause",
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => "WherePredicate",
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => "WhileExpression",
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => "YieldExpression",
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => "BangToken",
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => "NotEqToken",
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => "DoubleQuoteToken",
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => "PoundToken",
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => "DollarToken",
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => "PercentToken",
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => "PercentAssignToken",
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => "AndToken",
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => "AndAndToken",
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => "AndAssignToken",
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => "SingleQuoteToken",
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => "LParenToken",
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => "RParenToken",
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => "StarToken",
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => "BlockCommentEndToken",
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => "StarAssignToken",
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => "PlusToken",
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => "PlusAssignToken",
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => "CommaToken",
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => "MinusToken",
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => "MinusAssignToken",
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => "RArrowToken",
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => "DotToken",
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => "DotDotToken",
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => "DotDotDotToken",
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => "DotDotEqToken",
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => "SlashToken",
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => "BlockCommentStartToken",
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => "LineCommentToken",
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => "DivAssignToken",
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => "ColonToken",
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => "PathSepToken",
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => "SemicolonToken",
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => "LtToken",
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => "ShlToken",
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => "ShlAssignToken",
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => "LteToken",
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => "EqToken",
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => "EqEqToken",
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => "FatArrowToken",
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => "GtToken",
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => "GteToken",
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => "ShrToken",
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => "ShrAssignToken",
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => "QuestionToken",
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => "AtToken",
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => "LBracketToken",
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => "RBracketToken",
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => "XorToken",
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => "XorAssignToken",
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => "UnderscoreToken",
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => "AsKwToken",
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => "AsyncKwToken",
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => "AwaitKwToken",
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => "BlockToken",
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => "BreakKwToken",
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => "CharLiteral",
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => "ConstKwToken",
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => "ContinueKwToken",
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => "CrateKw",
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => "DefaultToken",
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => "DocComment",
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => "DynKwToken",
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => "ElseKwToken",
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => "EnumKwToken",
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => "EscapeSequence",
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => "ExprToken",
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => "Expr2021Token",
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => "ExternKwToken",
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => "FalseKwToken",
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => "FieldIdentifier",
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => "FloatLiteral",
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => "FnKwToken",
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => "ForKwToken",
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => "GenToken",
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => "IdentToken",
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => "Identifier",
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => "IfKwToken",
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => "ImplKwToken",
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => "InKwToken",
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => "IntegerLiteral",
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => "ItemToken",
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => "LetKwToken",
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => "LifetimeToken",
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => "LiteralToken",
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => "LoopKwToken",
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => "MacroRulesBangToken",
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => "MatchKwToken",
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => "MetaToken",
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => "Metavariable",
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => "ModKwToken",
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => "MoveKwToken",
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => "MutableSpecifier",
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => "PatToken",
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => "PatParamToken",
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => "PathToken",
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => "PrimitiveType",
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => "PubKwToken",
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => "RawToken",
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => "RefKwToken",
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => "ReturnKwToken",
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => "SelfKw",
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => "Shebang",
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => "ShorthandFieldIdentifier",
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => "StaticKwToken",
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => "StmtToken",
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => "StringContent",
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => "StructKwToken",
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => "SuperKw",
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => "TraitKwToken",
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => "TrueKwToken",
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => "TryToken",
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => "TtToken",
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => "TyToken",
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => "TypeKwToken",
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => "TypeIdentifier",
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => "UnionToken",
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => "UnsafeKwToken",
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => "UseKwToken",
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => "VisToken",
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => "WhereKwToken",
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => "WhileKwToken",
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => "YieldToken",
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => "LBraceToken",
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => "OrToken",
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => "OrAssignToken",
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => "OrOrToken",
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => "RBraceToken",
+        }
+    }
+
+    /// The Cargo feature that enables this node type, e.g. `"function_item"`.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub const fn feature_name(self) -> &'static str {
+        match self {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            Self::DeclarationStatement => "declaration_statement",
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            Self::Expression => "expression",
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            Self::Literal => "literal",
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            Self::LiteralPattern => "literal_pattern",
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            Self::Pattern => "pattern",
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            Self::Type => "type",
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            Self::AbstractType => "abstract_type",
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            Self::Arguments => "arguments",
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            Self::ArrayExpression => "array_expression",
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            Self::ArrayType => "array_type",
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            Self::AssignmentExpression => "assignment_expression",
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => "associated_type",
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            Self::AsyncBlock => "async_block",
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            Self::Attribute => "attribute",
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            Self::AttributeItem => "attribute_item",
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            Self::AwaitExpression => "await_expression",
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            Self::BaseFieldInitializer => "base_field_initializer",
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            Self::BinaryExpression => "binary_expression",
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            Self::Block => "block",
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            Self::BlockComment => "block_comment",
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            Self::BooleanLiteral => "boolean_literal",
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            Self::BoundedType => "bounded_type",
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            Self::BracketedType => "bracketed_type",
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            Self::BreakExpression => "break_expression",
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            Self::CallExpression => "call_expression",
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            Self::CapturedPattern => "captured_pattern",
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            Self::ClosureExpression => "closure_expression",
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            Self::ClosureParameters => "closure_parameters",
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            Self::CompoundAssignmentExpr => "compound_assignment_expr",
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            Self::ConstBlock => "const_block",
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => "const_item",
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            Self::ConstParameter => "const_parameter",
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            Self::ContinueExpression => "continue_expression",
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            Self::DeclarationList => "declaration_list",
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            Self::DynamicType => "dynamic_type",
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            Self::ElseClause => "else_clause",
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            Self::EmptyStatement => "empty_statement",
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => "enum_item",
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => "enum_variant",
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            Self::EnumVariantList => "enum_variant_list",
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            Self::ExpressionStatement => "expression_statement",
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            Self::ExternCrateDeclaration => "extern_crate_declaration",
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            Self::ExternModifier => "extern_modifier",
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => "field_declaration",
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            Self::FieldDeclarationList => "field_declaration_list",
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            Self::FieldExpression => "field_expression",
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            Self::FieldInitializer => "field_initializer",
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            Self::FieldInitializerList => "field_initializer_list",
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            Self::FieldPattern => "field_pattern",
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            Self::ForExpression => "for_expression",
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            Self::ForLifetimes => "for_lifetimes",
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            Self::ForeignModItem => "foreign_mod_item",
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            Self::FragmentSpecifier => "fragment_specifier",
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => "function_item",
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            Self::FunctionModifiers => "function_modifiers",
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => "function_signature_item",
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            Self::FunctionType => "function_type",
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            Self::GenBlock => "gen_block",
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            Self::GenericFunction => "generic_function",
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            Self::GenericPattern => "generic_pattern",
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            Self::GenericType => "generic_type",
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            Self::GenericTypeWithTurbofish => "generic_type_with_turbofish",
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            Self::HigherRankedTraitBound => "higher_ranked_trait_bound",
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            Self::IfExpression => "if_expression",
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => "impl_item",
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            Self::IndexExpression => "index_expression",
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            Self::InnerAttributeItem => "inner_attribute_item",
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            Self::InnerDocCommentMarker => "inner_doc_comment_marker",
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            Self::Label => "label",
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            Self::LetChain => "let_chain",
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            Self::LetCondition => "let_condition",
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            Self::LetDeclaration => "let_declaration",
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            Self::Lifetime => "lifetime",
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            Self::LifetimeParameter => "lifetime_parameter",
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            Self::LineComment => "line_comment",
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            Self::LoopExpression => "loop_expression",
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => "macro_definition",
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            Self::MacroInvocation => "macro_invocation",
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            Self::MacroRule => "macro_rule",
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            Self::MatchArm => "match_arm",
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            Self::MatchBlock => "match_block",
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            Self::MatchExpression => "match_expression",
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            Self::MatchPattern => "match_pattern",
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => "mod_item",
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            Self::MutPattern => "mut_pattern",
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            Self::NegativeLiteral => "negative_literal",
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            Self::NeverType => "never_type",
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            Self::OrPattern => "or_pattern",
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            Self::OrderedFieldDeclarationList => "ordered_field_declaration_list",
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            Self::OuterDocCommentMarker => "outer_doc_comment_marker",
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            Self::Parameter => "parameter",
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            Self::Parameters => "parameters",
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            Self::ParenthesizedExpression => "parenthesized_expression",
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            Self::PointerType => "pointer_type",
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            Self::QualifiedType => "qualified_type",
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            Self::RangeExpression => "range_expression",
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            Self::RangePattern => "range_pattern",
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            Self::RawStringLiteral => "raw_string_literal",
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            Self::RefPattern => "ref_pattern",
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            Self::ReferenceExpression => "reference_expression",
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            Self::ReferencePattern => "reference_pattern",
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            Self::ReferenceType => "reference_type",
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            Self::RemainingFieldPattern => "remaining_field_pattern",
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            Self::RemovedTraitBound => "removed_trait_bound",
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            Self::ReturnExpression => "return_expression",
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            Self::ScopedIdentifier => "scoped_identifier",
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            Self::ScopedTypeIdentifier => "scoped_type_identifier",
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            Self::ScopedUseList => "scoped_use_list",
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            Self::SelfParameter => "self_parameter",
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            Self::ShorthandFieldInitializer => "shorthand_field_initializer",
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            Self::SlicePattern => "slice_pattern",
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            Self::SourceFile => "source_file",
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => "static_item",
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            Self::StringLiteral => "string_literal",
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            Self::StructExpression => "struct_expression",
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => "struct_item",
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            Self::StructPattern => "struct_pattern",
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            Self::TokenBindingPattern => "token_binding_pattern",
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            Self::TokenRepetition => "token_repetition",
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            Self::TokenRepetitionPattern => "token_repetition_pattern",
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            Self::TokenTree => "token_tree",
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            Self::TokenTreePattern => "token_tree_pattern",
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            Self::TraitBounds => "trait_bounds",
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => "trait_item",
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            Self::TryBlock => "try_block",
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            Self::TryExpression => "try_expression",
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            Self::TupleExpression => "tuple_expression",
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            Self::TuplePattern => "tuple_pattern",
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            Self::TupleStructPattern => "tuple_struct_pattern",
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            Self::TupleType => "tuple_type",
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            Self::TypeArguments => "type_arguments",
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            Self::TypeBinding => "type_binding",
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            Self::TypeCastExpression => "type_cast_expression",
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => "type_item",
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            Self::TypeParameter => "type_parameter",
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            Self::TypeParameters => "type_parameters",
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            Self::UnaryExpression => "unary_expression",
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => "union_item",
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            Self::UnitExpression => "unit_expression",
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            Self::UnitType => "unit_type",
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            Self::UnsafeBlock => "unsafe_block",
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            Self::UseAsClause => "use_as_clause",
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            Self::UseBounds => "use_bounds",
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            Self::UseDeclaration => "use_declaration",
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            Self::UseList => "use_list",
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            Self::UseWildcard => "use_wildcard",
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            Self::VariadicParameter => "variadic_parameter",
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            Self::VisibilityModifier => "visibility_modifier",
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            Self::WhereClause => "where_clause",
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            Self::WherePredicate => "where_predicate",
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            Self::WhileExpression => "while_expression",
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            Self::YieldExpression => "yield_expression",
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            Self::BangToken => "bang_token",
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            Self::NotEqToken => "not_eq_token",
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            Self::DoubleQuoteToken => "double_quote_token",
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            Self::PoundToken => "pound_token",
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            Self::DollarToken => "dollar_token",
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            Self::PercentToken => "percent_token",
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            Self::PercentAssignToken => "percent_assign_token",
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            Self::AndToken => "and_token",
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            Self::AndAndToken => "and_and_token",
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            Self::AndAssignToken => "and_assign_token",
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            Self::SingleQuoteToken => "single_quote_token",
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            Self::LParenToken => "l_paren_token",
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            Self::RParenToken => "r_paren_token",
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            Self::StarToken => "star_token",
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            Self::BlockCommentEndToken => "block_comment_end_token",
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            Self::StarAssignToken => "star_assign_token",
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            Self::PlusToken => "plus_token",
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            Self::PlusAssignToken => "plus_assign_token",
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            Self::CommaToken => "comma_token",
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            Self::MinusToken => "minus_token",
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            Self::MinusAssignToken => "minus_assign_token",
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            Self::RArrowToken => "r_arrow_token",
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            Self::DotToken => "dot_token",
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            Self::DotDotToken => "dot_dot_token",
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            Self::DotDotDotToken => "dot_dot_dot_token",
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            Self::DotDotEqToken => "dot_dot_eq_token",
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            Self::SlashToken => "slash_token",
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            Self::BlockCommentStartToken => "block_comment_start_token",
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            Self::LineCommentToken => "line_comment_token",
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            Self::DivAssignToken => "div_assign_token",
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            Self::ColonToken => "colon_token",
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            Self::PathSepToken => "path_sep_token",
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            Self::SemicolonToken => "semicolon_token",
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            Self::LtToken => "lt_token",
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            Self::ShlToken => "shl_token",
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            Self::ShlAssignToken => "shl_assign_token",
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            Self::LteToken => "lte_token",
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            Self::EqToken => "eq_token",
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            Self::EqEqToken => "eq_eq_token",
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            Self::FatArrowToken => "fat_arrow_token",
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            Self::GtToken => "gt_token",
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            Self::GteToken => "gte_token",
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            Self::ShrToken => "shr_token",
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            Self::ShrAssignToken => "shr_assign_token",
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            Self::QuestionToken => "question_token",
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            Self::AtToken => "at_token",
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            Self::LBracketToken => "l_bracket_token",
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            Self::RBracketToken => "r_bracket_token",
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            Self::XorToken => "xor_token",
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            Self::XorAssignToken => "xor_assign_token",
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            Self::UnderscoreToken => "underscore_token",
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            Self::AsKwToken => "as_kw_token",
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            Self::AsyncKwToken => "async_kw_token",
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            Self::AwaitKwToken => "await_kw_token",
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            Self::BlockToken => "block_token",
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            Self::BreakKwToken => "break_kw_token",
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            Self::CharLiteral => "char_literal",
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            Self::ConstKwToken => "const_kw_token",
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            Self::ContinueKwToken => "continue_kw_token",
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            Self::CrateKw => "crate_kw",
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            Self::DefaultToken => "default_token",
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            Self::DocComment => "doc_comment",
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            Self::DynKwToken => "dyn_kw_token",
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            Self::ElseKwToken => "else_kw_token",
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            Self::EnumKwToken => "enum_kw_token",
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            Self::EscapeSequence => "escape_sequence",
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            Self::ExprToken => "expr_token",
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            Self::Expr2021Token => "expr2021_token",
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            Self::ExternKwToken => "extern_kw_token",
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            Self::FalseKwToken => "false_kw_token",
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            Self::FieldIdentifier => "field_identifier",
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            Self::FloatLiteral => "float_literal",
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            Self::FnKwToken => "fn_kw_token",
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            Self::ForKwToken => "for_kw_token",
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            Self::GenToken => "gen_token",
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            Self::IdentToken => "ident_token",
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            Self::Identifier => "identifier",
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            Self::IfKwToken => "if_kw_token",
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            Self::ImplKwToken => "impl_kw_token",
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            Self::InKwToken => "in_kw_token",
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            Self::IntegerLiteral => "integer_literal",
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            Self::ItemToken => "item_token",
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            Self::LetKwToken => "let_kw_token",
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            Self::LifetimeToken => "lifetime_token",
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            Self::LiteralToken => "literal_token",
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            Self::LoopKwToken => "loop_kw_token",
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            Self::MacroRulesBangToken => "macro_rules_bang_token",
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            Self::MatchKwToken => "match_kw_token",
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            Self::MetaToken => "meta_token",
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            Self::Metavariable => "metavariable",
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            Self::ModKwToken => "mod_kw_token",
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            Self::MoveKwToken => "move_kw_token",
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            Self::MutableSpecifier => "mutable_specifier",
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            Self::PatToken => "pat_token",
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            Self::PatParamToken => "pat_param_token",
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            Self::PathToken => "path_token",
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            Self::PrimitiveType => "primitive_type",
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            Self::PubKwToken => "pub_kw_token",
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            Self::RawToken => "raw_token",
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            Self::RefKwToken => "ref_kw_token",
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            Self::ReturnKwToken => "return_kw_token",
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            Self::SelfKw => "self_kw",
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            Self::Shebang => "shebang",
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            Self::ShorthandFieldIdentifier => "shorthand_field_identifier",
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            Self::StaticKwToken => "static_kw_token",
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            Self::StmtToken => "stmt_token",
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            Self::StringContent => "string_content",
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            Self::StructKwToken => "struct_kw_token",
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            Self::SuperKw => "super_kw",
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            Self::TraitKwToken => "trait_kw_token",
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            Self::TrueKwToken => "true_kw_token",
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            Self::TryToken => "try_token",
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            Self::TtToken => "tt_token",
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            Self::TyToken => "ty_token",
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            Self::TypeKwToken => "type_kw_token",
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            Self::TypeIdentifier => "type_identifier",
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            Self::UnionToken => "union_token",
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            Self::UnsafeKwToken => "unsafe_kw_token",
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            Self::UseKwToken => "use_kw_token",
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            Self::VisToken => "vis_token",
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            Self::WhereKwToken => "where_kw_token",
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            Self::WhileKwToken => "while_kw_token",
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            Self::YieldToken => "yield_token",
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            Self::LBraceToken => "l_brace_token",
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            Self::OrToken => "or_token",
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            Self::OrAssignToken => "or_assign_token",
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            Self::OrOrToken => "or_or_token",
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            Self::RBraceToken => "r_brace_token",
+        }
+    }
+
+    /// The node type whose variant is called `name`, if its feature is enabled.
+    /// [`Self::feature_for_variant`] names the feature otherwise.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_variant_name(name: &str) -> Option<Self> {
+        match name {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            "DeclarationStatement" => Some(Self::DeclarationStatement),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            "Expression" => Some(Self::Expression),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            "Literal" => Some(Self::Literal),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            "LiteralPattern" => Some(Self::LiteralPattern),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            "Pattern" => Some(Self::Pattern),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            "Type" => Some(Self::Type),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            "AbstractType" => Some(Self::AbstractType),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            "Arguments" => Some(Self::Arguments),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            "ArrayExpression" => Some(Self::ArrayExpression),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            "ArrayType" => Some(Self::ArrayType),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            "AssignmentExpression" => Some(Self::AssignmentExpression),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            "AssociatedType" => Some(Self::AssociatedType),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            "AsyncBlock" => Some(Self::AsyncBlock),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            "Attribute" => Some(Self::Attribute),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            "AttributeItem" => Some(Self::AttributeItem),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            "AwaitExpression" => Some(Self::AwaitExpression),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            "BaseFieldInitializer" => Some(Self::BaseFieldInitializer),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            "BinaryExpression" => Some(Self::BinaryExpression),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            "Block" => Some(Self::Block),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            "BlockComment" => Some(Self::BlockComment),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            "BooleanLiteral" => Some(Self::BooleanLiteral),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            "BoundedType" => Some(Self::BoundedType),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            "BracketedType" => Some(Self::BracketedType),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            "BreakExpression" => Some(Self::BreakExpression),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            "CallExpression" => Some(Self::CallExpression),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            "CapturedPattern" => Some(Self::CapturedPattern),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            "ClosureExpression" => Some(Self::ClosureExpression),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            "ClosureParameters" => Some(Self::ClosureParameters),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            "CompoundAssignmentExpr" => Some(Self::CompoundAssignmentExpr),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            "ConstBlock" => Some(Self::ConstBlock),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            "ConstItem" => Some(Self::ConstItem),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            "ConstParameter" => Some(Self::ConstParameter),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            "ContinueExpression" => Some(Self::ContinueExpression),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            "DeclarationList" => Some(Self::DeclarationList),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            "DynamicType" => Some(Self::DynamicType),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            "ElseClause" => Some(Self::ElseClause),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            "EmptyStatement" => Some(Self::EmptyStatement),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            "EnumItem" => Some(Self::EnumItem),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            "EnumVariant" => Some(Self::EnumVariant),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            "EnumVariantList" => Some(Self::EnumVariantList),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            "ExpressionStatement" => Some(Self::ExpressionStatement),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            "ExternCrateDeclaration" => Some(Self::ExternCrateDeclaration),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            "ExternModifier" => Some(Self::ExternModifier),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            "FieldDeclaration" => Some(Self::FieldDeclaration),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            "FieldDeclarationList" => Some(Self::FieldDeclarationList),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            "FieldExpression" => Some(Self::FieldExpression),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            "FieldInitializer" => Some(Self::FieldInitializer),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            "FieldInitializerList" => Some(Self::FieldInitializerList),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            "FieldPattern" => Some(Self::FieldPattern),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            "ForExpression" => Some(Self::ForExpression),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            "ForLifetimes" => Some(Self::ForLifetimes),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            "ForeignModItem" => Some(Self::ForeignModItem),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            "FragmentSpecifier" => Some(Self::FragmentSpecifier),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            "FunctionItem" => Some(Self::FunctionItem),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            "FunctionModifiers" => Some(Self::FunctionModifiers),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            "FunctionSignatureItem" => Some(Self::FunctionSignatureItem),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            "FunctionType" => Some(Self::FunctionType),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            "GenBlock" => Some(Self::GenBlock),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            "GenericFunction" => Some(Self::GenericFunction),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            "GenericPattern" => Some(Self::GenericPattern),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            "GenericType" => Some(Self::GenericType),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            "GenericTypeWithTurbofish" => Some(Self::GenericTypeWithTurbofish),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            "HigherRankedTraitBound" => Some(Self::HigherRankedTraitBound),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            "IfExpression" => Some(Self::IfExpression),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            "ImplItem" => Some(Self::ImplItem),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            "IndexExpression" => Some(Self::IndexExpression),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            "InnerAttributeItem" => Some(Self::InnerAttributeItem),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            "InnerDocCommentMarker" => Some(Self::InnerDocCommentMarker),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            "Label" => Some(Self::Label),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            "LetChain" => Some(Self::LetChain),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            "LetCondition" => Some(Self::LetCondition),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            "LetDeclaration" => Some(Self::LetDeclaration),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            "Lifetime" => Some(Self::Lifetime),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            "LifetimeParameter" => Some(Self::LifetimeParameter),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            "LineComment" => Some(Self::LineComment),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            "LoopExpression" => Some(Self::LoopExpression),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            "MacroDefinition" => Some(Self::MacroDefinition),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            "MacroInvocation" => Some(Self::MacroInvocation),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            "MacroRule" => Some(Self::MacroRule),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            "MatchArm" => Some(Self::MatchArm),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            "MatchBlock" => Some(Self::MatchBlock),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            "MatchExpression" => Some(Self::MatchExpression),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            "MatchPattern" => Some(Self::MatchPattern),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            "ModItem" => Some(Self::ModItem),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            "MutPattern" => Some(Self::MutPattern),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            "NegativeLiteral" => Some(Self::NegativeLiteral),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            "NeverType" => Some(Self::NeverType),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            "OrPattern" => Some(Self::OrPattern),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            "OrderedFieldDeclarationList" => Some(Self::OrderedFieldDeclarationList),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            "OuterDocCommentMarker" => Some(Self::OuterDocCommentMarker),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            "Parameter" => Some(Self::Parameter),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            "Parameters" => Some(Self::Parameters),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            "ParenthesizedExpression" => Some(Self::ParenthesizedExpression),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            "PointerType" => Some(Self::PointerType),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            "QualifiedType" => Some(Self::QualifiedType),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            "RangeExpression" => Some(Self::RangeExpression),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            "RangePattern" => Some(Self::RangePattern),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            "RawStringLiteral" => Some(Self::RawStringLiteral),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            "RefPattern" => Some(Self::RefPattern),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            "ReferenceExpression" => Some(Self::ReferenceExpression),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            "ReferencePattern" => Some(Self::ReferencePattern),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            "ReferenceType" => Some(Self::ReferenceType),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            "RemainingFieldPattern" => Some(Self::RemainingFieldPattern),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            "RemovedTraitBound" => Some(Self::RemovedTraitBound),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            "ReturnExpression" => Some(Self::ReturnExpression),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            "ScopedIdentifier" => Some(Self::ScopedIdentifier),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            "ScopedTypeIdentifier" => Some(Self::ScopedTypeIdentifier),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            "ScopedUseList" => Some(Self::ScopedUseList),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            "SelfParameter" => Some(Self::SelfParameter),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            "ShorthandFieldInitializer" => Some(Self::ShorthandFieldInitializer),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            "SlicePattern" => Some(Self::SlicePattern),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            "SourceFile" => Some(Self::SourceFile),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            "StaticItem" => Some(Self::StaticItem),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            "StringLiteral" => Some(Self::StringLiteral),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            "StructExpression" => Some(Self::StructExpression),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            "StructItem" => Some(Self::StructItem),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            "StructPattern" => Some(Self::StructPattern),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            "TokenBindingPattern" => Some(Self::TokenBindingPattern),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            "TokenRepetition" => Some(Self::TokenRepetition),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            "TokenRepetitionPattern" => Some(Self::TokenRepetitionPattern),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            "TokenTree" => Some(Self::TokenTree),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            "TokenTreePattern" => Some(Self::TokenTreePattern),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            "TraitBounds" => Some(Self::TraitBounds),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            "TraitItem" => Some(Self::TraitItem),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            "TryBlock" => Some(Self::TryBlock),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            "TryExpression" => Some(Self::TryExpression),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            "TupleExpression" => Some(Self::TupleExpression),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            "TuplePattern" => Some(Self::TuplePattern),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            "TupleStructPattern" => Some(Self::TupleStructPattern),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            "TupleType" => Some(Self::TupleType),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            "TypeArguments" => Some(Self::TypeArguments),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            "TypeBinding" => Some(Self::TypeBinding),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            "TypeCastExpression" => Some(Self::TypeCastExpression),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            "TypeItem" => Some(Self::TypeItem),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            "TypeParameter" => Some(Self::TypeParameter),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            "TypeParameters" => Some(Self::TypeParameters),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            "UnaryExpression" => Some(Self::UnaryExpression),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            "UnionItem" => Some(Self::UnionItem),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            "UnitExpression" => Some(Self::UnitExpression),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            "UnitType" => Some(Self::UnitType),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            "UnsafeBlock" => Some(Self::UnsafeBlock),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            "UseAsClause" => Some(Self::UseAsClause),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            "UseBounds" => Some(Self::UseBounds),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            "UseDeclaration" => Some(Self::UseDeclaration),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            "UseList" => Some(Self::UseList),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            "UseWildcard" => Some(Self::UseWildcard),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            "VariadicParameter" => Some(Self::VariadicParameter),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            "VisibilityModifier" => Some(Self::VisibilityModifier),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            "WhereClause" => Some(Self::WhereClause),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            "WherePredicate" => Some(Self::WherePredicate),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            "WhileExpression" => Some(Self::WhileExpression),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            "YieldExpression" => Some(Self::YieldExpression),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            "BangToken" => Some(Self::BangToken),
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            "NotEqToken" => Some(Self::NotEqToken),
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            "DoubleQuoteToken" => Some(Self::DoubleQuoteToken),
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            "PoundToken" => Some(Self::PoundToken),
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            "DollarToken" => Some(Self::DollarToken),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            "PercentToken" => Some(Self::PercentToken),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            "PercentAssignToken" => Some(Self::PercentAssignToken),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            "AndToken" => Some(Self::AndToken),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            "AndAndToken" => Some(Self::AndAndToken),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            "AndAssignToken" => Some(Self::AndAssignToken),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            "SingleQuoteToken" => Some(Self::SingleQuoteToken),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            "LParenToken" => Some(Self::LParenToken),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            "RParenToken" => Some(Self::RParenToken),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            "StarToken" => Some(Self::StarToken),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            "BlockCommentEndToken" => Some(Self::BlockCommentEndToken),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            "StarAssignToken" => Some(Self::StarAssignToken),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            "PlusToken" => Some(Self::PlusToken),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            "PlusAssignToken" => Some(Self::PlusAssignToken),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            "CommaToken" => Some(Self::CommaToken),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            "MinusToken" => Some(Self::MinusToken),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            "MinusAssignToken" => Some(Self::MinusAssignToken),
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            "RArrowToken" => Some(Self::RArrowToken),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            "DotToken" => Some(Self::DotToken),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            "DotDotToken" => Some(Self::DotDotToken),
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            "DotDotDotToken" => Some(Self::DotDotDotToken),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            "DotDotEqToken" => Some(Self::DotDotEqToken),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            "SlashToken" => Some(Self::SlashToken),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            "BlockCommentStartToken" => Some(Self::BlockCommentStartToken),
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            "LineCommentToken" => Some(Self::LineCommentToken),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            "DivAssignToken" => Some(Self::DivAssignToken),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            "ColonToken" => Some(Self::ColonToken),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            "PathSepToken" => Some(Self::PathSepToken),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            "SemicolonToken" => Some(Self::SemicolonToken),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            "LtToken" => Some(Self::LtToken),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            "ShlToken" => Some(Self::ShlToken),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            "ShlAssignToken" => Some(Self::ShlAssignToken),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            "LteToken" => Some(Self::LteToken),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            "EqToken" => Some(Self::EqToken),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            "EqEqToken" => Some(Self::EqEqToken),
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            "FatArrowToken" => Some(Self::FatArrowToken),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            "GtToken" => Some(Self::GtToken),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            "GteToken" => Some(Self::GteToken),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            "ShrToken" => Some(Self::ShrToken),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            "ShrAssignToken" => Some(Self::ShrAssignToken),
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            "QuestionToken" => Some(Self::QuestionToken),
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            "AtToken" => Some(Self::AtToken),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            "LBracketToken" => Some(Self::LBracketToken),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            "RBracketToken" => Some(Self::RBracketToken),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            "XorToken" => Some(Self::XorToken),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            "XorAssignToken" => Some(Self::XorAssignToken),
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            "UnderscoreToken" => Some(Self::UnderscoreToken),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            "AsKwToken" => Some(Self::AsKwToken),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            "AsyncKwToken" => Some(Self::AsyncKwToken),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            "AwaitKwToken" => Some(Self::AwaitKwToken),
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            "BlockToken" => Some(Self::BlockToken),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            "BreakKwToken" => Some(Self::BreakKwToken),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            "CharLiteral" => Some(Self::CharLiteral),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            "ConstKwToken" => Some(Self::ConstKwToken),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            "ContinueKwToken" => Some(Self::ContinueKwToken),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            "CrateKw" => Some(Self::CrateKw),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            "DefaultToken" => Some(Self::DefaultToken),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            "DocComment" => Some(Self::DocComment),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            "DynKwToken" => Some(Self::DynKwToken),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            "ElseKwToken" => Some(Self::ElseKwToken),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            "EnumKwToken" => Some(Self::EnumKwToken),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            "EscapeSequence" => Some(Self::EscapeSequence),
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            "ExprToken" => Some(Self::ExprToken),
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            "Expr2021Token" => Some(Self::Expr2021Token),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            "ExternKwToken" => Some(Self::ExternKwToken),
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            "FalseKwToken" => Some(Self::FalseKwToken),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            "FieldIdentifier" => Some(Self::FieldIdentifier),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            "FloatLiteral" => Some(Self::FloatLiteral),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            "FnKwToken" => Some(Self::FnKwToken),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            "ForKwToken" => Some(Self::ForKwToken),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            "GenToken" => Some(Self::GenToken),
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            "IdentToken" => Some(Self::IdentToken),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            "Identifier" => Some(Self::Identifier),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            "IfKwToken" => Some(Self::IfKwToken),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            "ImplKwToken" => Some(Self::ImplKwToken),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            "InKwToken" => Some(Self::InKwToken),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            "IntegerLiteral" => Some(Self::IntegerLiteral),
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            "ItemToken" => Some(Self::ItemToken),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            "LetKwToken" => Some(Self::LetKwToken),
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            "LifetimeToken" => Some(Self::LifetimeToken),
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            "LiteralToken" => Some(Self::LiteralToken),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            "LoopKwToken" => Some(Self::LoopKwToken),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            "MacroRulesBangToken" => Some(Self::MacroRulesBangToken),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            "MatchKwToken" => Some(Self::MatchKwToken),
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            "MetaToken" => Some(Self::MetaToken),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            "Metavariable" => Some(Self::Metavariable),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            "ModKwToken" => Some(Self::ModKwToken),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            "MoveKwToken" => Some(Self::MoveKwToken),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            "MutableSpecifier" => Some(Self::MutableSpecifier),
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            "PatToken" => Some(Self::PatToken),
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            "PatParamToken" => Some(Self::PatParamToken),
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            "PathToken" => Some(Self::PathToken),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            "PrimitiveType" => Some(Self::PrimitiveType),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            "PubKwToken" => Some(Self::PubKwToken),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            "RawToken" => Some(Self::RawToken),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            "RefKwToken" => Some(Self::RefKwToken),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            "ReturnKwToken" => Some(Self::ReturnKwToken),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            "SelfKw" => Some(Self::SelfKw),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            "Shebang" => Some(Self::Shebang),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            "ShorthandFieldIdentifier" => Some(Self::ShorthandFieldIdentifier),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            "StaticKwToken" => Some(Self::StaticKwToken),
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            "StmtToken" => Some(Self::StmtToken),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            "StringContent" => Some(Self::StringContent),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            "StructKwToken" => Some(Self::StructKwToken),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            "SuperKw" => Some(Self::SuperKw),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            "TraitKwToken" => Some(Self::TraitKwToken),
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            "TrueKwToken" => Some(Self::TrueKwToken),
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            "TryToken" => Some(Self::TryToken),
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            "TtToken" => Some(Self::TtToken),
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            "TyToken" => Some(Self::TyToken),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            "TypeKwToken" => Some(Self::TypeKwToken),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            "TypeIdentifier" => Some(Self::TypeIdentifier),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            "UnionToken" => Some(Self::UnionToken),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            "UnsafeKwToken" => Some(Self::UnsafeKwToken),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            "UseKwToken" => Some(Self::UseKwToken),
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            "VisToken" => Some(Self::VisToken),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            "WhereKwToken" => Some(Self::WhereKwToken),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            "WhileKwToken" => Some(Self::WhileKwToken),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            "YieldToken" => Some(Self::YieldToken),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            "LBraceToken" => Some(Self::LBraceToken),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            "OrToken" => Some(Self::OrToken),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            "OrAssignToken" => Some(Self::OrAssignToken),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            "OrOrToken" => Some(Self::OrOrToken),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            "RBraceToken" => Some(Self::RBraceToken),
+            _ => None,
+        }
+    }
+
+    /// The node type enabled by the Cargo feature `name`, if its feature is enabled.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn from_feature_name(name: &str) -> Option<Self> {
+        match name {
+            #[cfg(any(feature = "declaration_statement", feature = "node_full"))]
+            "declaration_statement" => Some(Self::DeclarationStatement),
+            #[cfg(any(feature = "expression", feature = "node_full"))]
+            "expression" => Some(Self::Expression),
+            #[cfg(any(feature = "literal", feature = "node_full"))]
+            "literal" => Some(Self::Literal),
+            #[cfg(any(feature = "literal_pattern", feature = "node_full"))]
+            "literal_pattern" => Some(Self::LiteralPattern),
+            #[cfg(any(feature = "pattern", feature = "node_full"))]
+            "pattern" => Some(Self::Pattern),
+            #[cfg(any(feature = "type", feature = "node_full"))]
+            "type" => Some(Self::Type),
+            #[cfg(any(feature = "abstract_type", feature = "node_full"))]
+            "abstract_type" => Some(Self::AbstractType),
+            #[cfg(any(feature = "arguments", feature = "node_full"))]
+            "arguments" => Some(Self::Arguments),
+            #[cfg(any(feature = "array_expression", feature = "node_full"))]
+            "array_expression" => Some(Self::ArrayExpression),
+            #[cfg(any(feature = "array_type", feature = "node_full"))]
+            "array_type" => Some(Self::ArrayType),
+            #[cfg(any(feature = "assignment_expression", feature = "node_full"))]
+            "assignment_expression" => Some(Self::AssignmentExpression),
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            "associated_type" => Some(Self::AssociatedType),
+            #[cfg(any(feature = "async_block", feature = "node_full"))]
+            "async_block" => Some(Self::AsyncBlock),
+            #[cfg(any(feature = "attribute", feature = "node_full"))]
+            "attribute" => Some(Self::Attribute),
+            #[cfg(any(feature = "attribute_item", feature = "node_full"))]
+            "attribute_item" => Some(Self::AttributeItem),
+            #[cfg(any(feature = "await_expression", feature = "node_full"))]
+            "await_expression" => Some(Self::AwaitExpression),
+            #[cfg(any(feature = "base_field_initializer", feature = "node_full"))]
+            "base_field_initializer" => Some(Self::BaseFieldInitializer),
+            #[cfg(any(feature = "binary_expression", feature = "node_full"))]
+            "binary_expression" => Some(Self::BinaryExpression),
+            #[cfg(any(feature = "block", feature = "node_full"))]
+            "block" => Some(Self::Block),
+            #[cfg(any(feature = "block_comment", feature = "node_full"))]
+            "block_comment" => Some(Self::BlockComment),
+            #[cfg(any(feature = "boolean_literal", feature = "node_full"))]
+            "boolean_literal" => Some(Self::BooleanLiteral),
+            #[cfg(any(feature = "bounded_type", feature = "node_full"))]
+            "bounded_type" => Some(Self::BoundedType),
+            #[cfg(any(feature = "bracketed_type", feature = "node_full"))]
+            "bracketed_type" => Some(Self::BracketedType),
+            #[cfg(any(feature = "break_expression", feature = "node_full"))]
+            "break_expression" => Some(Self::BreakExpression),
+            #[cfg(any(feature = "call_expression", feature = "node_full"))]
+            "call_expression" => Some(Self::CallExpression),
+            #[cfg(any(feature = "captured_pattern", feature = "node_full"))]
+            "captured_pattern" => Some(Self::CapturedPattern),
+            #[cfg(any(feature = "closure_expression", feature = "node_full"))]
+            "closure_expression" => Some(Self::ClosureExpression),
+            #[cfg(any(feature = "closure_parameters", feature = "node_full"))]
+            "closure_parameters" => Some(Self::ClosureParameters),
+            #[cfg(any(feature = "compound_assignment_expr", feature = "node_full"))]
+            "compound_assignment_expr" => Some(Self::CompoundAssignmentExpr),
+            #[cfg(any(feature = "const_block", feature = "node_full"))]
+            "const_block" => Some(Self::ConstBlock),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            "const_item" => Some(Self::ConstItem),
+            #[cfg(any(feature = "const_parameter", feature = "node_full"))]
+            "const_parameter" => Some(Self::ConstParameter),
+            #[cfg(any(feature = "continue_expression", feature = "node_full"))]
+            "continue_expression" => Some(Self::ContinueExpression),
+            #[cfg(any(feature = "declaration_list", feature = "node_full"))]
+            "declaration_list" => Some(Self::DeclarationList),
+            #[cfg(any(feature = "dynamic_type", feature = "node_full"))]
+            "dynamic_type" => Some(Self::DynamicType),
+            #[cfg(any(feature = "else_clause", feature = "node_full"))]
+            "else_clause" => Some(Self::ElseClause),
+            #[cfg(any(feature = "empty_statement", feature = "node_full"))]
+            "empty_statement" => Some(Self::EmptyStatement),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            "enum_item" => Some(Self::EnumItem),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            "enum_variant" => Some(Self::EnumVariant),
+            #[cfg(any(feature = "enum_variant_list", feature = "node_full"))]
+            "enum_variant_list" => Some(Self::EnumVariantList),
+            #[cfg(any(feature = "expression_statement", feature = "node_full"))]
+            "expression_statement" => Some(Self::ExpressionStatement),
+            #[cfg(any(feature = "extern_crate_declaration", feature = "node_full"))]
+            "extern_crate_declaration" => Some(Self::ExternCrateDeclaration),
+            #[cfg(any(feature = "extern_modifier", feature = "node_full"))]
+            "extern_modifier" => Some(Self::ExternModifier),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            "field_declaration" => Some(Self::FieldDeclaration),
+            #[cfg(any(feature = "field_declaration_list", feature = "node_full"))]
+            "field_declaration_list" => Some(Self::FieldDeclarationList),
+            #[cfg(any(feature = "field_expression", feature = "node_full"))]
+            "field_expression" => Some(Self::FieldExpression),
+            #[cfg(any(feature = "field_initializer", feature = "node_full"))]
+            "field_initializer" => Some(Self::FieldInitializer),
+            #[cfg(any(feature = "field_initializer_list", feature = "node_full"))]
+            "field_initializer_list" => Some(Self::FieldInitializerList),
+            #[cfg(any(feature = "field_pattern", feature = "node_full"))]
+            "field_pattern" => Some(Self::FieldPattern),
+            #[cfg(any(feature = "for_expression", feature = "node_full"))]
+            "for_expression" => Some(Self::ForExpression),
+            #[cfg(any(feature = "for_lifetimes", feature = "node_full"))]
+            "for_lifetimes" => Some(Self::ForLifetimes),
+            #[cfg(any(feature = "foreign_mod_item", feature = "node_full"))]
+            "foreign_mod_item" => Some(Self::ForeignModItem),
+            #[cfg(any(feature = "fragment_specifier", feature = "node_full"))]
+            "fragment_specifier" => Some(Self::FragmentSpecifier),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            "function_item" => Some(Self::FunctionItem),
+            #[cfg(any(feature = "function_modifiers", feature = "node_full"))]
+            "function_modifiers" => Some(Self::FunctionModifiers),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            "function_signature_item" => Some(Self::FunctionSignatureItem),
+            #[cfg(any(feature = "function_type", feature = "node_full"))]
+            "function_type" => Some(Self::FunctionType),
+            #[cfg(any(feature = "gen_block", feature = "node_full"))]
+            "gen_block" => Some(Self::GenBlock),
+            #[cfg(any(feature = "generic_function", feature = "node_full"))]
+            "generic_function" => Some(Self::GenericFunction),
+            #[cfg(any(feature = "generic_pattern", feature = "node_full"))]
+            "generic_pattern" => Some(Self::GenericPattern),
+            #[cfg(any(feature = "generic_type", feature = "node_full"))]
+            "generic_type" => Some(Self::GenericType),
+            #[cfg(any(feature = "generic_type_with_turbofish", feature = "node_full"))]
+            "generic_type_with_turbofish" => Some(Self::GenericTypeWithTurbofish),
+            #[cfg(any(feature = "higher_ranked_trait_bound", feature = "node_full"))]
+            "higher_ranked_trait_bound" => Some(Self::HigherRankedTraitBound),
+            #[cfg(any(feature = "if_expression", feature = "node_full"))]
+            "if_expression" => Some(Self::IfExpression),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            "impl_item" => Some(Self::ImplItem),
+            #[cfg(any(feature = "index_expression", feature = "node_full"))]
+            "index_expression" => Some(Self::IndexExpression),
+            #[cfg(any(feature = "inner_attribute_item", feature = "node_full"))]
+            "inner_attribute_item" => Some(Self::InnerAttributeItem),
+            #[cfg(any(feature = "inner_doc_comment_marker", feature = "node_full"))]
+            "inner_doc_comment_marker" => Some(Self::InnerDocCommentMarker),
+            #[cfg(any(feature = "label", feature = "node_full"))]
+            "label" => Some(Self::Label),
+            #[cfg(any(feature = "let_chain", feature = "node_full"))]
+            "let_chain" => Some(Self::LetChain),
+            #[cfg(any(feature = "let_condition", feature = "node_full"))]
+            "let_condition" => Some(Self::LetCondition),
+            #[cfg(any(feature = "let_declaration", feature = "node_full"))]
+            "let_declaration" => Some(Self::LetDeclaration),
+            #[cfg(any(feature = "lifetime", feature = "node_full"))]
+            "lifetime" => Some(Self::Lifetime),
+            #[cfg(any(feature = "lifetime_parameter", feature = "node_full"))]
+            "lifetime_parameter" => Some(Self::LifetimeParameter),
+            #[cfg(any(feature = "line_comment", feature = "node_full"))]
+            "line_comment" => Some(Self::LineComment),
+            #[cfg(any(feature = "loop_expression", feature = "node_full"))]
+            "loop_expression" => Some(Self::LoopExpression),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            "macro_definition" => Some(Self::MacroDefinition),
+            #[cfg(any(feature = "macro_invocation", feature = "node_full"))]
+            "macro_invocation" => Some(Self::MacroInvocation),
+            #[cfg(any(feature = "macro_rule", feature = "node_full"))]
+            "macro_rule" => Some(Self::MacroRule),
+            #[cfg(any(feature = "match_arm", feature = "node_full"))]
+            "match_arm" => Some(Self::MatchArm),
+            #[cfg(any(feature = "match_block", feature = "node_full"))]
+            "match_block" => Some(Self::MatchBlock),
+            #[cfg(any(feature = "match_expression", feature = "node_full"))]
+            "match_expression" => Some(Self::MatchExpression),
+            #[cfg(any(feature = "match_pattern", feature = "node_full"))]
+            "match_pattern" => Some(Self::MatchPattern),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            "mod_item" => Some(Self::ModItem),
+            #[cfg(any(feature = "mut_pattern", feature = "node_full"))]
+            "mut_pattern" => Some(Self::MutPattern),
+            #[cfg(any(feature = "negative_literal", feature = "node_full"))]
+            "negative_literal" => Some(Self::NegativeLiteral),
+            #[cfg(any(feature = "never_type", feature = "node_full"))]
+            "never_type" => Some(Self::NeverType),
+            #[cfg(any(feature = "or_pattern", feature = "node_full"))]
+            "or_pattern" => Some(Self::OrPattern),
+            #[cfg(any(feature = "ordered_field_declaration_list", feature = "node_full"))]
+            "ordered_field_declaration_list" => Some(Self::OrderedFieldDeclarationList),
+            #[cfg(any(feature = "outer_doc_comment_marker", feature = "node_full"))]
+            "outer_doc_comment_marker" => Some(Self::OuterDocCommentMarker),
+            #[cfg(any(feature = "parameter", feature = "node_full"))]
+            "parameter" => Some(Self::Parameter),
+            #[cfg(any(feature = "parameters", feature = "node_full"))]
+            "parameters" => Some(Self::Parameters),
+            #[cfg(any(feature = "parenthesized_expression", feature = "node_full"))]
+            "parenthesized_expression" => Some(Self::ParenthesizedExpression),
+            #[cfg(any(feature = "pointer_type", feature = "node_full"))]
+            "pointer_type" => Some(Self::PointerType),
+            #[cfg(any(feature = "qualified_type", feature = "node_full"))]
+            "qualified_type" => Some(Self::QualifiedType),
+            #[cfg(any(feature = "range_expression", feature = "node_full"))]
+            "range_expression" => Some(Self::RangeExpression),
+            #[cfg(any(feature = "range_pattern", feature = "node_full"))]
+            "range_pattern" => Some(Self::RangePattern),
+            #[cfg(any(feature = "raw_string_literal", feature = "node_full"))]
+            "raw_string_literal" => Some(Self::RawStringLiteral),
+            #[cfg(any(feature = "ref_pattern", feature = "node_full"))]
+            "ref_pattern" => Some(Self::RefPattern),
+            #[cfg(any(feature = "reference_expression", feature = "node_full"))]
+            "reference_expression" => Some(Self::ReferenceExpression),
+            #[cfg(any(feature = "reference_pattern", feature = "node_full"))]
+            "reference_pattern" => Some(Self::ReferencePattern),
+            #[cfg(any(feature = "reference_type", feature = "node_full"))]
+            "reference_type" => Some(Self::ReferenceType),
+            #[cfg(any(feature = "remaining_field_pattern", feature = "node_full"))]
+            "remaining_field_pattern" => Some(Self::RemainingFieldPattern),
+            #[cfg(any(feature = "removed_trait_bound", feature = "node_full"))]
+            "removed_trait_bound" => Some(Self::RemovedTraitBound),
+            #[cfg(any(feature = "return_expression", feature = "node_full"))]
+            "return_expression" => Some(Self::ReturnExpression),
+            #[cfg(any(feature = "scoped_identifier", feature = "node_full"))]
+            "scoped_identifier" => Some(Self::ScopedIdentifier),
+            #[cfg(any(feature = "scoped_type_identifier", feature = "node_full"))]
+            "scoped_type_identifier" => Some(Self::ScopedTypeIdentifier),
+            #[cfg(any(feature = "scoped_use_list", feature = "node_full"))]
+            "scoped_use_list" => Some(Self::ScopedUseList),
+            #[cfg(any(feature = "self_parameter", feature = "node_full"))]
+            "self_parameter" => Some(Self::SelfParameter),
+            #[cfg(any(feature = "shorthand_field_initializer", feature = "node_full"))]
+            "shorthand_field_initializer" => Some(Self::ShorthandFieldInitializer),
+            #[cfg(any(feature = "slice_pattern", feature = "node_full"))]
+            "slice_pattern" => Some(Self::SlicePattern),
+            #[cfg(any(feature = "source_file", feature = "node_full"))]
+            "source_file" => Some(Self::SourceFile),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            "static_item" => Some(Self::StaticItem),
+            #[cfg(any(feature = "string_literal", feature = "node_full"))]
+            "string_literal" => Some(Self::StringLiteral),
+            #[cfg(any(feature = "struct_expression", feature = "node_full"))]
+            "struct_expression" => Some(Self::StructExpression),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            "struct_item" => Some(Self::StructItem),
+            #[cfg(any(feature = "struct_pattern", feature = "node_full"))]
+            "struct_pattern" => Some(Self::StructPattern),
+            #[cfg(any(feature = "token_binding_pattern", feature = "node_full"))]
+            "token_binding_pattern" => Some(Self::TokenBindingPattern),
+            #[cfg(any(feature = "token_repetition", feature = "node_full"))]
+            "token_repetition" => Some(Self::TokenRepetition),
+            #[cfg(any(feature = "token_repetition_pattern", feature = "node_full"))]
+            "token_repetition_pattern" => Some(Self::TokenRepetitionPattern),
+            #[cfg(any(feature = "token_tree", feature = "node_full"))]
+            "token_tree" => Some(Self::TokenTree),
+            #[cfg(any(feature = "token_tree_pattern", feature = "node_full"))]
+            "token_tree_pattern" => Some(Self::TokenTreePattern),
+            #[cfg(any(feature = "trait_bounds", feature = "node_full"))]
+            "trait_bounds" => Some(Self::TraitBounds),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            "trait_item" => Some(Self::TraitItem),
+            #[cfg(any(feature = "try_block", feature = "node_full"))]
+            "try_block" => Some(Self::TryBlock),
+            #[cfg(any(feature = "try_expression", feature = "node_full"))]
+            "try_expression" => Some(Self::TryExpression),
+            #[cfg(any(feature = "tuple_expression", feature = "node_full"))]
+            "tuple_expression" => Some(Self::TupleExpression),
+            #[cfg(any(feature = "tuple_pattern", feature = "node_full"))]
+            "tuple_pattern" => Some(Self::TuplePattern),
+            #[cfg(any(feature = "tuple_struct_pattern", feature = "node_full"))]
+            "tuple_struct_pattern" => Some(Self::TupleStructPattern),
+            #[cfg(any(feature = "tuple_type", feature = "node_full"))]
+            "tuple_type" => Some(Self::TupleType),
+            #[cfg(any(feature = "type_arguments", feature = "node_full"))]
+            "type_arguments" => Some(Self::TypeArguments),
+            #[cfg(any(feature = "type_binding", feature = "node_full"))]
+            "type_binding" => Some(Self::TypeBinding),
+            #[cfg(any(feature = "type_cast_expression", feature = "node_full"))]
+            "type_cast_expression" => Some(Self::TypeCastExpression),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            "type_item" => Some(Self::TypeItem),
+            #[cfg(any(feature = "type_parameter", feature = "node_full"))]
+            "type_parameter" => Some(Self::TypeParameter),
+            #[cfg(any(feature = "type_parameters", feature = "node_full"))]
+            "type_parameters" => Some(Self::TypeParameters),
+            #[cfg(any(feature = "unary_expression", feature = "node_full"))]
+            "unary_expression" => Some(Self::UnaryExpression),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            "union_item" => Some(Self::UnionItem),
+            #[cfg(any(feature = "unit_expression", feature = "node_full"))]
+            "unit_expression" => Some(Self::UnitExpression),
+            #[cfg(any(feature = "unit_type", feature = "node_full"))]
+            "unit_type" => Some(Self::UnitType),
+            #[cfg(any(feature = "unsafe_block", feature = "node_full"))]
+            "unsafe_block" => Some(Self::UnsafeBlock),
+            #[cfg(any(feature = "use_as_clause", feature = "node_full"))]
+            "use_as_clause" => Some(Self::UseAsClause),
+            #[cfg(any(feature = "use_bounds", feature = "node_full"))]
+            "use_bounds" => Some(Self::UseBounds),
+            #[cfg(any(feature = "use_declaration", feature = "node_full"))]
+            "use_declaration" => Some(Self::UseDeclaration),
+            #[cfg(any(feature = "use_list", feature = "node_full"))]
+            "use_list" => Some(Self::UseList),
+            #[cfg(any(feature = "use_wildcard", feature = "node_full"))]
+            "use_wildcard" => Some(Self::UseWildcard),
+            #[cfg(any(feature = "variadic_parameter", feature = "node_full"))]
+            "variadic_parameter" => Some(Self::VariadicParameter),
+            #[cfg(any(feature = "visibility_modifier", feature = "node_full"))]
+            "visibility_modifier" => Some(Self::VisibilityModifier),
+            #[cfg(any(feature = "where_clause", feature = "node_full"))]
+            "where_clause" => Some(Self::WhereClause),
+            #[cfg(any(feature = "where_predicate", feature = "node_full"))]
+            "where_predicate" => Some(Self::WherePredicate),
+            #[cfg(any(feature = "while_expression", feature = "node_full"))]
+            "while_expression" => Some(Self::WhileExpression),
+            #[cfg(any(feature = "yield_expression", feature = "node_full"))]
+            "yield_expression" => Some(Self::YieldExpression),
+            #[cfg(any(feature = "bang_token", feature = "node_full"))]
+            "bang_token" => Some(Self::BangToken),
+            #[cfg(any(feature = "not_eq_token", feature = "node_full"))]
+            "not_eq_token" => Some(Self::NotEqToken),
+            #[cfg(any(feature = "double_quote_token", feature = "node_full"))]
+            "double_quote_token" => Some(Self::DoubleQuoteToken),
+            #[cfg(any(feature = "pound_token", feature = "node_full"))]
+            "pound_token" => Some(Self::PoundToken),
+            #[cfg(any(feature = "dollar_token", feature = "node_full"))]
+            "dollar_token" => Some(Self::DollarToken),
+            #[cfg(any(feature = "percent_token", feature = "node_full"))]
+            "percent_token" => Some(Self::PercentToken),
+            #[cfg(any(feature = "percent_assign_token", feature = "node_full"))]
+            "percent_assign_token" => Some(Self::PercentAssignToken),
+            #[cfg(any(feature = "and_token", feature = "node_full"))]
+            "and_token" => Some(Self::AndToken),
+            #[cfg(any(feature = "and_and_token", feature = "node_full"))]
+            "and_and_token" => Some(Self::AndAndToken),
+            #[cfg(any(feature = "and_assign_token", feature = "node_full"))]
+            "and_assign_token" => Some(Self::AndAssignToken),
+            #[cfg(any(feature = "single_quote_token", feature = "node_full"))]
+            "single_quote_token" => Some(Self::SingleQuoteToken),
+            #[cfg(any(feature = "l_paren_token", feature = "node_full"))]
+            "l_paren_token" => Some(Self::LParenToken),
+            #[cfg(any(feature = "r_paren_token", feature = "node_full"))]
+            "r_paren_token" => Some(Self::RParenToken),
+            #[cfg(any(feature = "star_token", feature = "node_full"))]
+            "star_token" => Some(Self::StarToken),
+            #[cfg(any(feature = "block_comment_end_token", feature = "node_full"))]
+            "block_comment_end_token" => Some(Self::BlockCommentEndToken),
+            #[cfg(any(feature = "star_assign_token", feature = "node_full"))]
+            "star_assign_token" => Some(Self::StarAssignToken),
+            #[cfg(any(feature = "plus_token", feature = "node_full"))]
+            "plus_token" => Some(Self::PlusToken),
+            #[cfg(any(feature = "plus_assign_token", feature = "node_full"))]
+            "plus_assign_token" => Some(Self::PlusAssignToken),
+            #[cfg(any(feature = "comma_token", feature = "node_full"))]
+            "comma_token" => Some(Self::CommaToken),
+            #[cfg(any(feature = "minus_token", feature = "node_full"))]
+            "minus_token" => Some(Self::MinusToken),
+            #[cfg(any(feature = "minus_assign_token", feature = "node_full"))]
+            "minus_assign_token" => Some(Self::MinusAssignToken),
+            #[cfg(any(feature = "r_arrow_token", feature = "node_full"))]
+            "r_arrow_token" => Some(Self::RArrowToken),
+            #[cfg(any(feature = "dot_token", feature = "node_full"))]
+            "dot_token" => Some(Self::DotToken),
+            #[cfg(any(feature = "dot_dot_token", feature = "node_full"))]
+            "dot_dot_token" => Some(Self::DotDotToken),
+            #[cfg(any(feature = "dot_dot_dot_token", feature = "node_full"))]
+            "dot_dot_dot_token" => Some(Self::DotDotDotToken),
+            #[cfg(any(feature = "dot_dot_eq_token", feature = "node_full"))]
+            "dot_dot_eq_token" => Some(Self::DotDotEqToken),
+            #[cfg(any(feature = "slash_token", feature = "node_full"))]
+            "slash_token" => Some(Self::SlashToken),
+            #[cfg(any(feature = "block_comment_start_token", feature = "node_full"))]
+            "block_comment_start_token" => Some(Self::BlockCommentStartToken),
+            #[cfg(any(feature = "line_comment_token", feature = "node_full"))]
+            "line_comment_token" => Some(Self::LineCommentToken),
+            #[cfg(any(feature = "div_assign_token", feature = "node_full"))]
+            "div_assign_token" => Some(Self::DivAssignToken),
+            #[cfg(any(feature = "colon_token", feature = "node_full"))]
+            "colon_token" => Some(Self::ColonToken),
+            #[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+            "path_sep_token" => Some(Self::PathSepToken),
+            #[cfg(any(feature = "semicolon_token", feature = "node_full"))]
+            "semicolon_token" => Some(Self::SemicolonToken),
+            #[cfg(any(feature = "lt_token", feature = "node_full"))]
+            "lt_token" => Some(Self::LtToken),
+            #[cfg(any(feature = "shl_token", feature = "node_full"))]
+            "shl_token" => Some(Self::ShlToken),
+            #[cfg(any(feature = "shl_assign_token", feature = "node_full"))]
+            "shl_assign_token" => Some(Self::ShlAssignToken),
+            #[cfg(any(feature = "lte_token", feature = "node_full"))]
+            "lte_token" => Some(Self::LteToken),
+            #[cfg(any(feature = "eq_token", feature = "node_full"))]
+            "eq_token" => Some(Self::EqToken),
+            #[cfg(any(feature = "eq_eq_token", feature = "node_full"))]
+            "eq_eq_token" => Some(Self::EqEqToken),
+            #[cfg(any(feature = "fat_arrow_token", feature = "node_full"))]
+            "fat_arrow_token" => Some(Self::FatArrowToken),
+            #[cfg(any(feature = "gt_token", feature = "node_full"))]
+            "gt_token" => Some(Self::GtToken),
+            #[cfg(any(feature = "gte_token", feature = "node_full"))]
+            "gte_token" => Some(Self::GteToken),
+            #[cfg(any(feature = "shr_token", feature = "node_full"))]
+            "shr_token" => Some(Self::ShrToken),
+            #[cfg(any(feature = "shr_assign_token", feature = "node_full"))]
+            "shr_assign_token" => Some(Self::ShrAssignToken),
+            #[cfg(any(feature = "question_token", feature = "node_full"))]
+            "question_token" => Some(Self::QuestionToken),
+            #[cfg(any(feature = "at_token", feature = "node_full"))]
+            "at_token" => Some(Self::AtToken),
+            #[cfg(any(feature = "l_bracket_token", feature = "node_full"))]
+            "l_bracket_token" => Some(Self::LBracketToken),
+            #[cfg(any(feature = "r_bracket_token", feature = "node_full"))]
+            "r_bracket_token" => Some(Self::RBracketToken),
+            #[cfg(any(feature = "xor_token", feature = "node_full"))]
+            "xor_token" => Some(Self::XorToken),
+            #[cfg(any(feature = "xor_assign_token", feature = "node_full"))]
+            "xor_assign_token" => Some(Self::XorAssignToken),
+            #[cfg(any(feature = "underscore_token", feature = "node_full"))]
+            "underscore_token" => Some(Self::UnderscoreToken),
+            #[cfg(any(feature = "as_kw_token", feature = "node_full"))]
+            "as_kw_token" => Some(Self::AsKwToken),
+            #[cfg(any(feature = "async_kw_token", feature = "node_full"))]
+            "async_kw_token" => Some(Self::AsyncKwToken),
+            #[cfg(any(feature = "await_kw_token", feature = "node_full"))]
+            "await_kw_token" => Some(Self::AwaitKwToken),
+            #[cfg(any(feature = "block_token", feature = "node_full"))]
+            "block_token" => Some(Self::BlockToken),
+            #[cfg(any(feature = "break_kw_token", feature = "node_full"))]
+            "break_kw_token" => Some(Self::BreakKwToken),
+            #[cfg(any(feature = "char_literal", feature = "node_full"))]
+            "char_literal" => Some(Self::CharLiteral),
+            #[cfg(any(feature = "const_kw_token", feature = "node_full"))]
+            "const_kw_token" => Some(Self::ConstKwToken),
+            #[cfg(any(feature = "continue_kw_token", feature = "node_full"))]
+            "continue_kw_token" => Some(Self::ContinueKwToken),
+            #[cfg(any(feature = "crate_kw", feature = "node_full"))]
+            "crate_kw" => Some(Self::CrateKw),
+            #[cfg(any(feature = "default_token", feature = "node_full"))]
+            "default_token" => Some(Self::DefaultToken),
+            #[cfg(any(feature = "doc_comment", feature = "node_full"))]
+            "doc_comment" => Some(Self::DocComment),
+            #[cfg(any(feature = "dyn_kw_token", feature = "node_full"))]
+            "dyn_kw_token" => Some(Self::DynKwToken),
+            #[cfg(any(feature = "else_kw_token", feature = "node_full"))]
+            "else_kw_token" => Some(Self::ElseKwToken),
+            #[cfg(any(feature = "enum_kw_token", feature = "node_full"))]
+            "enum_kw_token" => Some(Self::EnumKwToken),
+            #[cfg(any(feature = "escape_sequence", feature = "node_full"))]
+            "escape_sequence" => Some(Self::EscapeSequence),
+            #[cfg(any(feature = "expr_token", feature = "node_full"))]
+            "expr_token" => Some(Self::ExprToken),
+            #[cfg(any(feature = "expr2021_token", feature = "node_full"))]
+            "expr2021_token" => Some(Self::Expr2021Token),
+            #[cfg(any(feature = "extern_kw_token", feature = "node_full"))]
+            "extern_kw_token" => Some(Self::ExternKwToken),
+            #[cfg(any(feature = "false_kw_token", feature = "node_full"))]
+            "false_kw_token" => Some(Self::FalseKwToken),
+            #[cfg(any(feature = "field_identifier", feature = "node_full"))]
+            "field_identifier" => Some(Self::FieldIdentifier),
+            #[cfg(any(feature = "float_literal", feature = "node_full"))]
+            "float_literal" => Some(Self::FloatLiteral),
+            #[cfg(any(feature = "fn_kw_token", feature = "node_full"))]
+            "fn_kw_token" => Some(Self::FnKwToken),
+            #[cfg(any(feature = "for_kw_token", feature = "node_full"))]
+            "for_kw_token" => Some(Self::ForKwToken),
+            #[cfg(any(feature = "gen_token", feature = "node_full"))]
+            "gen_token" => Some(Self::GenToken),
+            #[cfg(any(feature = "ident_token", feature = "node_full"))]
+            "ident_token" => Some(Self::IdentToken),
+            #[cfg(any(feature = "identifier", feature = "node_full"))]
+            "identifier" => Some(Self::Identifier),
+            #[cfg(any(feature = "if_kw_token", feature = "node_full"))]
+            "if_kw_token" => Some(Self::IfKwToken),
+            #[cfg(any(feature = "impl_kw_token", feature = "node_full"))]
+            "impl_kw_token" => Some(Self::ImplKwToken),
+            #[cfg(any(feature = "in_kw_token", feature = "node_full"))]
+            "in_kw_token" => Some(Self::InKwToken),
+            #[cfg(any(feature = "integer_literal", feature = "node_full"))]
+            "integer_literal" => Some(Self::IntegerLiteral),
+            #[cfg(any(feature = "item_token", feature = "node_full"))]
+            "item_token" => Some(Self::ItemToken),
+            #[cfg(any(feature = "let_kw_token", feature = "node_full"))]
+            "let_kw_token" => Some(Self::LetKwToken),
+            #[cfg(any(feature = "lifetime_token", feature = "node_full"))]
+            "lifetime_token" => Some(Self::LifetimeToken),
+            #[cfg(any(feature = "literal_token", feature = "node_full"))]
+            "literal_token" => Some(Self::LiteralToken),
+            #[cfg(any(feature = "loop_kw_token", feature = "node_full"))]
+            "loop_kw_token" => Some(Self::LoopKwToken),
+            #[cfg(any(feature = "macro_rules_bang_token", feature = "node_full"))]
+            "macro_rules_bang_token" => Some(Self::MacroRulesBangToken),
+            #[cfg(any(feature = "match_kw_token", feature = "node_full"))]
+            "match_kw_token" => Some(Self::MatchKwToken),
+            #[cfg(any(feature = "meta_token", feature = "node_full"))]
+            "meta_token" => Some(Self::MetaToken),
+            #[cfg(any(feature = "metavariable", feature = "node_full"))]
+            "metavariable" => Some(Self::Metavariable),
+            #[cfg(any(feature = "mod_kw_token", feature = "node_full"))]
+            "mod_kw_token" => Some(Self::ModKwToken),
+            #[cfg(any(feature = "move_kw_token", feature = "node_full"))]
+            "move_kw_token" => Some(Self::MoveKwToken),
+            #[cfg(any(feature = "mutable_specifier", feature = "node_full"))]
+            "mutable_specifier" => Some(Self::MutableSpecifier),
+            #[cfg(any(feature = "pat_token", feature = "node_full"))]
+            "pat_token" => Some(Self::PatToken),
+            #[cfg(any(feature = "pat_param_token", feature = "node_full"))]
+            "pat_param_token" => Some(Self::PatParamToken),
+            #[cfg(any(feature = "path_token", feature = "node_full"))]
+            "path_token" => Some(Self::PathToken),
+            #[cfg(any(feature = "primitive_type", feature = "node_full"))]
+            "primitive_type" => Some(Self::PrimitiveType),
+            #[cfg(any(feature = "pub_kw_token", feature = "node_full"))]
+            "pub_kw_token" => Some(Self::PubKwToken),
+            #[cfg(any(feature = "raw_token", feature = "node_full"))]
+            "raw_token" => Some(Self::RawToken),
+            #[cfg(any(feature = "ref_kw_token", feature = "node_full"))]
+            "ref_kw_token" => Some(Self::RefKwToken),
+            #[cfg(any(feature = "return_kw_token", feature = "node_full"))]
+            "return_kw_token" => Some(Self::ReturnKwToken),
+            #[cfg(any(feature = "self_kw", feature = "node_full"))]
+            "self_kw" => Some(Self::SelfKw),
+            #[cfg(any(feature = "shebang", feature = "node_full"))]
+            "shebang" => Some(Self::Shebang),
+            #[cfg(any(feature = "shorthand_field_identifier", feature = "node_full"))]
+            "shorthand_field_identifier" => Some(Self::ShorthandFieldIdentifier),
+            #[cfg(any(feature = "static_kw_token", feature = "node_full"))]
+            "static_kw_token" => Some(Self::StaticKwToken),
+            #[cfg(any(feature = "stmt_token", feature = "node_full"))]
+            "stmt_token" => Some(Self::StmtToken),
+            #[cfg(any(feature = "string_content", feature = "node_full"))]
+            "string_content" => Some(Self::StringContent),
+            #[cfg(any(feature = "struct_kw_token", feature = "node_full"))]
+            "struct_kw_token" => Some(Self::StructKwToken),
+            #[cfg(any(feature = "super_kw", feature = "node_full"))]
+            "super_kw" => Some(Self::SuperKw),
+            #[cfg(any(feature = "trait_kw_token", feature = "node_full"))]
+            "trait_kw_token" => Some(Self::TraitKwToken),
+            #[cfg(any(feature = "true_kw_token", feature = "node_full"))]
+            "true_kw_token" => Some(Self::TrueKwToken),
+            #[cfg(any(feature = "try_token", feature = "node_full"))]
+            "try_token" => Some(Self::TryToken),
+            #[cfg(any(feature = "tt_token", feature = "node_full"))]
+            "tt_token" => Some(Self::TtToken),
+            #[cfg(any(feature = "ty_token", feature = "node_full"))]
+            "ty_token" => Some(Self::TyToken),
+            #[cfg(any(feature = "type_kw_token", feature = "node_full"))]
+            "type_kw_token" => Some(Self::TypeKwToken),
+            #[cfg(any(feature = "type_identifier", feature = "node_full"))]
+            "type_identifier" => Some(Self::TypeIdentifier),
+            #[cfg(any(feature = "union_token", feature = "node_full"))]
+            "union_token" => Some(Self::UnionToken),
+            #[cfg(any(feature = "unsafe_kw_token", feature = "node_full"))]
+            "unsafe_kw_token" => Some(Self::UnsafeKwToken),
+            #[cfg(any(feature = "use_kw_token", feature = "node_full"))]
+            "use_kw_token" => Some(Self::UseKwToken),
+            #[cfg(any(feature = "vis_token", feature = "node_full"))]
+            "vis_token" => Some(Self::VisToken),
+            #[cfg(any(feature = "where_kw_token", feature = "node_full"))]
+            "where_kw_token" => Some(Self::WhereKwToken),
+            #[cfg(any(feature = "while_kw_token", feature = "node_full"))]
+            "while_kw_token" => Some(Self::WhileKwToken),
+            #[cfg(any(feature = "yield_token", feature = "node_full"))]
+            "yield_token" => Some(Self::YieldToken),
+            #[cfg(any(feature = "l_brace_token", feature = "node_full"))]
+            "l_brace_token" => Some(Self::LBraceToken),
+            #[cfg(any(feature = "or_token", feature = "node_full"))]
+            "or_token" => Some(Self::OrToken),
+            #[cfg(any(feature = "or_assign_token", feature = "node_full"))]
+            "or_assign_token" => Some(Self::OrAssignToken),
+            #[cfg(any(feature = "or_or_token", feature = "node_full"))]
+            "or_or_token" => Some(Self::OrOrToken),
+            #[cfg(any(feature = "r_brace_token", feature = "node_full"))]
+            "r_brace_token" => Some(Self::RBraceToken),
+            _ => None,
+        }
+    }
+
+    /// The Cargo feature that enables the variant called `name`, whether or not it is enabled,
+    /// e.g. `"function_item"` for `"FunctionItem"`. A deprecated variant gives its replacement's.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub fn feature_for_variant(name: &str) -> Option<&'static str> {
+        match name {
+            "DeclarationStatement" => Some("declaration_statement"),
+            "Expression" => Some("expression"),
+            "Literal" => Some("literal"),
+            "LiteralPattern" => Some("literal_pattern"),
+            "Pattern" => Some("pattern"),
+            "Type" => Some("type"),
+            "AbstractType" => Some("abstract_type"),
+            "Arguments" => Some("arguments"),
+            "ArrayExpression" => Some("array_expression"),
+            "ArrayType" => Some("array_type"),
+            "AssignmentExpression" => Some("assignment_expression"),
+            "AssociatedType" => Some("associated_type"),
+            "AsyncBlock" => Some("async_block"),
+            "Attribute" => Some("attribute"),
+            "AttributeItem" => Some("attribute_item"),
+            "AwaitExpression" => Some("await_expression"),
+            "BaseFieldInitializer" => Some("base_field_initializer"),
+            "BinaryExpression" => Some("binary_expression"),
+            "Block" => Some("block"),
+            "BlockComment" => Some("block_comment"),
+            "BooleanLiteral" => Some("boolean_literal"),
+            "BoundedType" => Some("bounded_type"),
+            "BracketedType" => Some("bracketed_type"),
+            "BreakExpression" => Some("break_expression"),
+            "CallExpression" => Some("call_expression"),
+            "CapturedPattern" => Some("captured_pattern"),
+            "ClosureExpression" => Some("closure_expression"),
+            "ClosureParameters" => Some("closure_parameters"),
+            "CompoundAssignmentExpr" => Some("compound_assignment_expr"),
+            "ConstBlock" => Some("const_block"),
+            "ConstItem" => Some("const_item"),
+            "ConstParameter" => Some("const_parameter"),
+            "ContinueExpression" => Some("continue_expression"),
+            "DeclarationList" => Some("declaration_list"),
+            "DynamicType" => Some("dynamic_type"),
+            "ElseClause" => Some("else_clause"),
+            "EmptyStatement" => Some("empty_statement"),
+            "EnumItem" => Some("enum_item"),
+            "EnumVariant" => Some("enum_variant"),
+            "EnumVariantList" => Some("enum_variant_list"),
+            "ExpressionStatement" => Some("expression_statement"),
+            "ExternCrateDeclaration" => Some("extern_crate_declaration"),
+            "ExternModifier" => Some("extern_modifier"),
+            "FieldDeclaration" => Some("field_declaration"),
+            "FieldDeclarationList" => Some("field_declaration_list"),
+            "FieldExpression" => Some("field_expression"),
+            "FieldInitializer" => Some("field_initializer"),
+            "FieldInitializerList" => Some("field_initializer_list"),
+            "FieldPattern" => Some("field_pattern"),
+            "ForExpression" => Some("for_expression"),
+            "ForLifetimes" => Some("for_lifetimes"),
+            "ForeignModItem" => Some("foreign_mod_item"),
+            "FragmentSpecifier" => Some("fragment_specifier"),
+            "FunctionItem" => Some("function_item"),
+            "FunctionModifiers" => Some("function_modifiers"),
+            "FunctionSignatureItem" => Some("function_signature_item"),
+            "FunctionType" => Some("function_type"),
+            "GenBlock" => Some("gen_block"),
+            "GenericFunction" => Some("generic_function"),
+            "GenericPattern" => Some("generic_pattern"),
+            "GenericType" => Some("generic_type"),
+            "GenericTypeWithTurbofish" => Some("generic_type_with_turbofish"),
+            "HigherRankedTraitBound" => Some("higher_ranked_trait_bound"),
+            "IfExpression" => Some("if_expression"),
+            "ImplItem" => Some("impl_item"),
+            "IndexExpression" => Some("index_expression"),
+            "InnerAttributeItem" => Some("inner_attribute_item"),
+            "InnerDocCommentMarker" => Some("inner_doc_comment_marker"),
+            "Label" => Some("label"),
+            "LetChain" => Some("let_chain"),
+            "LetCondition" => Some("let_condition"),
+            "LetDeclaration" => Some("let_declaration"),
+            "Lifetime" => Some("lifetime"),
+            "LifetimeParameter" => Some("lifetime_parameter"),
+            "LineComment" => Some("line_comment"),
+            "LoopExpression" => Some("loop_expression"),
+            "MacroDefinition" => Some("macro_definition"),
+            "MacroInvocation" => Some("macro_invocation"),
+            "MacroRule" => Some("macro_rule"),
+            "MatchArm" => Some("match_arm"),
+            "MatchBlock" => Some("match_block"),
+            "MatchExpression" => Some("match_expression"),
+            "MatchPattern" => Some("match_pattern"),
+            "ModItem" => Some("mod_item"),
+            "MutPattern" => Some("mut_pattern"),
+            "NegativeLiteral" => Some("negative_literal"),
+            "NeverType" => Some("never_type"),
+            "OrPattern" => Some("or_pattern"),
+            "OrderedFieldDeclarationList" => Some("ordered_field_declaration_list"),
+            "OuterDocCommentMarker" => Some("outer_doc_comment_marker"),
+            "Parameter" => Some("parameter"),
+            "Parameters" => Some("parameters"),
+            "ParenthesizedExpression" => Some("parenthesized_expression"),
+            "PointerType" => Some("pointer_type"),
+            "QualifiedType" => Some("qualified_type"),
+            "RangeExpression" => Some("range_expression"),
+            "RangePattern" => Some("range_pattern"),
+            "RawStringLiteral" => Some("raw_string_literal"),
+            "RefPattern" => Some("ref_pattern"),
+            "ReferenceExpression" => Some("reference_expression"),
+            "ReferencePattern" => Some("reference_pattern"),
+            "ReferenceType" => Some("reference_type"),
+            "RemainingFieldPattern" => Some("remaining_field_pattern"),
+            "RemovedTraitBound" => Some("removed_trait_bound"),
+            "ReturnExpression" => Some("return_expression"),
+            "ScopedIdentifier" => Some("scoped_identifier"),
+            "ScopedTypeIdentifier" => Some("scoped_type_identifier"),
+            "ScopedUseList" => Some("scoped_use_list"),
+            "SelfParameter" => Some("self_parameter"),
+            "ShorthandFieldInitializer" => Some("shorthand_field_initializer"),
+            "SlicePattern" => Some("slice_pattern"),
+            "SourceFile" => Some("source_file"),
+            "StaticItem" => Some("static_item"),
+            "StringLiteral" => Some("string_literal"),
+            "StructExpression" => Some("struct_expression"),
+            "StructItem" => Some("struct_item"),
+            "StructPattern" => Some("struct_pattern"),
+            "TokenBindingPattern" => Some("token_binding_pattern"),
+            "TokenRepetition" => Some("token_repetition"),
+            "TokenRepetitionPattern" => Some("token_repetition_pattern"),
+            "TokenTree" => Some("token_tree"),
+            "TokenTreePattern" => Some("token_tree_pattern"),
+            "TraitBounds" => Some("trait_bounds"),
+            "TraitItem" => Some("trait_item"),
+            "TryBlock" => Some("try_block"),
+            "TryExpression" => Some("try_expression"),
+            "TupleExpression" => Some("tuple_expression"),
+            "TuplePattern" => Some("tuple_pattern"),
+            "TupleStructPattern" => Some("tuple_struct_pattern"),
+            "TupleType" => Some("tuple_type"),
+            "TypeArguments" => Some("type_arguments"),
+            "TypeBinding" => Some("type_binding"),
+            "TypeCastExpression" => Some("type_cast_expression"),
+            "TypeItem" => Some("type_item"),
+            "TypeParameter" => Some("type_parameter"),
+            "TypeParameters" => Some("type_parameters"),
+            "UnaryExpression" => Some("unary_expression"),
+            "UnionItem" => Some("union_item"),
+            "UnitExpression" => Some("unit_expression"),
+            "UnitType" => Some("unit_type"),
+            "UnsafeBlock" => Some("unsafe_block"),
+            "UseAsClause" => Some("use_as_clause"),
+            "UseBounds" => Some("use_bounds"),
+            "UseDeclaration" => Some("use_declaration"),
+            "UseList" => Some("use_list"),
+            "UseWildcard" => Some("use_wildcard"),
+            "VariadicParameter" => Some("variadic_parameter"),
+            "VisibilityModifier" => Some("visibility_modifier"),
+            "WhereClause" => Some("where_clause"),
+            "WherePredicate" => Some("where_predicate"),
+            "WhileExpression" => Some("while_expression"),
+            "YieldExpression" => Some("yield_expression"),
+            "BangToken" => Some("bang_token"),
+            "NotEqToken" => Some("not_eq_token"),
+            "DoubleQuoteToken" => Some("double_quote_token"),
+            "PoundToken" => Some("pound_token"),
+            "DollarToken" => Some("dollar_token"),
+            "PercentToken" => Some("percent_token"),
+            "PercentAssignToken" => Some("percent_assign_token"),
+            "AndToken" => Some("and_token"),
+            "AndAndToken" => Some("and_and_token"),
+            "AndAssignToken" => Some("and_assign_token"),
+            "SingleQuoteToken" => Some("single_quote_token"),
+            "LParenToken" => Some("l_paren_token"),
+            "RParenToken" => Some("r_paren_token"),
+            "StarToken" => Some("star_token"),
+            "BlockCommentEndToken" => Some("block_comment_end_token"),
+            "StarAssignToken" => Some("star_assign_token"),
+            "PlusToken" => Some("plus_token"),
+            "PlusAssignToken" => Some("plus_assign_token"),
+            "CommaToken" => Some("comma_token"),
+            "MinusToken" => Some("minus_token"),
+            "MinusAssignToken" => Some("minus_assign_token"),
+            "RArrowToken" => Some("r_arrow_token"),
+            "DotToken" => Some("dot_token"),
+            "DotDotToken" => Some("dot_dot_token"),
+            "DotDotDotToken" => Some("dot_dot_dot_token"),
+            "DotDotEqToken" => Some("dot_dot_eq_token"),
+            "SlashToken" => Some("slash_token"),
+            "BlockCommentStartToken" => Some("block_comment_start_token"),
+            "LineCommentToken" => Some("line_comment_token"),
+            "DivAssignToken" => Some("div_assign_token"),
+            "ColonToken" => Some("colon_token"),
+            "PathSepToken" => Some("path_sep_token"),
+            "SemicolonToken" => Some("semicolon_token"),
+            "LtToken" => Some("lt_token"),
+            "ShlToken" => Some("shl_token"),
+            "ShlAssignToken" => Some("shl_assign_token"),
+            "LteToken" => Some("lte_token"),
+            "EqToken" => Some("eq_token"),
+            "EqEqToken" => Some("eq_eq_token"),
+            "FatArrowToken" => Some("fat_arrow_token"),
+            "GtToken" => Some("gt_token"),
+            "GteToken" => Some("gte_token"),
+            "ShrToken" => Some("shr_token"),
+            "ShrAssignToken" => Some("shr_assign_token"),
+            "QuestionToken" => Some("question_token"),
+            "AtToken" => Some("at_token"),
+            "LBracketToken" => Some("l_bracket_token"),
+            "RBracketToken" => Some("r_bracket_token"),
+            "XorToken" => Some("xor_token"),
+            "XorAssignToken" => Some("xor_assign_token"),
+            "UnderscoreToken" => Some("underscore_token"),
+            "AsKwToken" => Some("as_kw_token"),
+            "AsyncKwToken" => Some("async_kw_token"),
+            "AwaitKwToken" => Some("await_kw_token"),
+            "BlockToken" => Some("block_token"),
+            "BreakKwToken" => Some("break_kw_token"),
+            "CharLiteral" => Some("char_literal"),
+            "ConstKwToken" => Some("const_kw_token"),
+            "ContinueKwToken" => Some("continue_kw_token"),
+            "CrateKw" => Some("crate_kw"),
+            "DefaultToken" => Some("default_token"),
+            "DocComment" => Some("doc_comment"),
+            "DynKwToken" => Some("dyn_kw_token"),
+            "ElseKwToken" => Some("else_kw_token"),
+            "EnumKwToken" => Some("enum_kw_token"),
+            "EscapeSequence" => Some("escape_sequence"),
+            "ExprToken" => Some("expr_token"),
+            "Expr2021Token" => Some("expr2021_token"),
+            "ExternKwToken" => Some("extern_kw_token"),
+            "FalseKwToken" => Some("false_kw_token"),
+            "FieldIdentifier" => Some("field_identifier"),
+            "FloatLiteral" => Some("float_literal"),
+            "FnKwToken" => Some("fn_kw_token"),
+            "ForKwToken" => Some("for_kw_token"),
+            "GenToken" => Some("gen_token"),
+            "IdentToken" => Some("ident_token"),
+            "Identifier" => Some("identifier"),
+            "IfKwToken" => Some("if_kw_token"),
+            "ImplKwToken" => Some("impl_kw_token"),
+            "InKwToken" => Some("in_kw_token"),
+            "IntegerLiteral" => Some("integer_literal"),
+            "ItemToken" => Some("item_token"),
+            "LetKwToken" => Some("let_kw_token"),
+            "LifetimeToken" => Some("lifetime_token"),
+            "LiteralToken" => Some("literal_token"),
+            "LoopKwToken" => Some("loop_kw_token"),
+            "MacroRulesBangToken" => Some("macro_rules_bang_token"),
+            "MatchKwToken" => Some("match_kw_token"),
+            "MetaToken" => Some("meta_token"),
+            "Metavariable" => Some("metavariable"),
+            "ModKwToken" => Some("mod_kw_token"),
+            "MoveKwToken" => Some("move_kw_token"),
+            "MutableSpecifier" => Some("mutable_specifier"),
+            "PatToken" => Some("pat_token"),
+            "PatParamToken" => Some("pat_param_token"),
+            "PathToken" => Some("path_token"),
+            "PrimitiveType" => Some("primitive_type"),
+            "PubKwToken" => Some("pub_kw_token"),
+            "RawToken" => Some("raw_token"),
+            "RefKwToken" => Some("ref_kw_token"),
+            "ReturnKwToken" => Some("return_kw_token"),
+            "SelfKw" => Some("self_kw"),
+            "Shebang" => Some("shebang"),
+            "ShorthandFieldIdentifier" => Some("shorthand_field_identifier"),
+            "StaticKwToken" => Some("static_kw_token"),
+            "StmtToken" => Some("stmt_token"),
+            "StringContent" => Some("string_content"),
+            "StructKwToken" => Some("struct_kw_token"),
+            "SuperKw" => Some("super_kw"),
+            "TraitKwToken" => Some("trait_kw_token"),
+            "TrueKwToken" => Some("true_kw_token"),
+            "TryToken" => Some("try_token"),
+            "TtToken" => Some("tt_token"),
+            "TyToken" => Some("ty_token"),
+            "TypeKwToken" => Some("type_kw_token"),
+            "TypeIdentifier" => Some("type_identifier"),
+            "UnionToken" => Some("union_token"),
+            "UnsafeKwToken" => Some("unsafe_kw_token"),
+            "UseKwToken" => Some("use_kw_token"),
+            "VisToken" => Some("vis_token"),
+            "WhereKwToken" => Some("where_kw_token"),
+            "WhileKwToken" => Some("while_kw_token"),
+            "YieldToken" => Some("yield_token"),
+            "LBraceToken" => Some("l_brace_token"),
+            "OrToken" => Some("or_token"),
+            "OrAssignToken" => Some("or_assign_token"),
+            "OrOrToken" => Some("or_or_token"),
+            "RBraceToken" => Some("r_brace_token"),
+            _ => None,
+        }
+    }
+
+    /// The Cargo feature that enables the node of kind `kind` (named or not), whether or not it
+    /// is enabled, e.g. `"path_sep_token"` for `("::", false)`. A deprecated kind gives its
+    /// replacement's.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub fn feature_for_kind(kind: &str, named: bool) -> Option<&'static str> {
+        match (kind, named) {
+            ("_declaration_statement", true) => Some("declaration_statement"),
+            ("_expression", true) => Some("expression"),
+            ("_literal", true) => Some("literal"),
+            ("_literal_pattern", true) => Some("literal_pattern"),
+            ("_pattern", true) => Some("pattern"),
+            ("_type", true) => Some("type"),
+            ("abstract_type", true) => Some("abstract_type"),
+            ("arguments", true) => Some("arguments"),
+            ("array_expression", true) => Some("array_expression"),
+            ("array_type", true) => Some("array_type"),
+            ("assignment_expression", true) => Some("assignment_expression"),
+            ("associated_type", true) => Some("associated_type"),
+            ("async_block", true) => Some("async_block"),
+            ("attribute", true) => Some("attribute"),
+            ("attribute_item", true) => Some("attribute_item"),
+            ("await_expression", true) => Some("await_expression"),
+            ("base_field_initializer", true) => Some("base_field_initializer"),
+            ("binary_expression", true) => Some("binary_expression"),
+            ("block", true) => Some("block"),
+            ("block_comment", true) => Some("block_comment"),
+            ("boolean_literal", true) => Some("boolean_literal"),
+            ("bounded_type", true) => Some("bounded_type"),
+            ("bracketed_type", true) => Some("bracketed_type"),
+            ("break_expression", true) => Some("break_expression"),
+            ("call_expression", true) => Some("call_expression"),
+            ("captured_pattern", true) => Some("captured_pattern"),
+            ("closure_expression", true) => Some("closure_expression"),
+            ("closure_parameters", true) => Some("closure_parameters"),
+            ("compound_assignment_expr", true) => Some("compound_assignment_expr"),
+            ("const_block", true) => Some("const_block"),
+            ("const_item", true) => Some("const_item"),
+            ("const_parameter", true) => Some("const_parameter"),
+            ("continue_expression", true) => Some("continue_expression"),
+            ("declaration_list", true) => Some("declaration_list"),
+            ("dynamic_type", true) => Some("dynamic_type"),
+            ("else_clause", true) => Some("else_clause"),
+            ("empty_statement", true) => Some("empty_statement"),
+            ("enum_item", true) => Some("enum_item"),
+            ("enum_variant", true) => Some("enum_variant"),
+            ("enum_variant_list", true) => Some("enum_variant_list"),
+            ("expression_statement", true) => Some("expression_statement"),
+            ("extern_crate_declaration", true) => Some("extern_crate_declaration"),
+            ("extern_modifier", true) => Some("extern_modifier"),
+            ("field_declaration", true) => Some("field_declaration"),
+            ("field_declaration_list", true) => Some("field_declaration_list"),
+            ("field_expression", true) => Some("field_expression"),
+            ("field_initializer", true) => Some("field_initializer"),
+            ("field_initializer_list", true) => Some("field_initializer_list"),
+            ("field_pattern", true) => Some("field_pattern"),
+            ("for_expression", true) => Some("for_expression"),
+            ("for_lifetimes", true) => Some("for_lifetimes"),
+            ("foreign_mod_item", true) => Some("foreign_mod_item"),
+            ("fragment_specifier", true) => Some("fragment_specifier"),
+            ("function_item", true) => Some("function_item"),
+            ("function_modifiers", true) => Some("function_modifiers"),
+            ("function_signature_item", true) => Some("function_signature_item"),
+            ("function_type", true) => Some("function_type"),
+            ("gen_block", true) => Some("gen_block"),
+            ("generic_function", true) => Some("generic_function"),
+            ("generic_pattern", true) => Some("generic_pattern"),
+            ("generic_type", true) => Some("generic_type"),
+            ("generic_type_with_turbofish", true) => Some("generic_type_with_turbofish"),
+            ("higher_ranked_trait_bound", true) => Some("higher_ranked_trait_bound"),
+            ("if_expression", true) => Some("if_expression"),
+            ("impl_item", true) => Some("impl_item"),
+            ("index_expression", true) => Some("index_expression"),
+            ("inner_attribute_item", true) => Some("inner_attribute_item"),
+            ("inner_doc_comment_marker", true) => Some("inner_doc_comment_marker"),
+            ("label", true) => Some("label"),
+            ("let_chain", true) => Some("let_chain"),
+            ("let_condition", true) => Some("let_condition"),
+            ("let_declaration", true) => Some("let_declaration"),
+            ("lifetime", true) => Some("lifetime"),
+            ("lifetime_parameter", true) => Some("lifetime_parameter"),
+            ("line_comment", true) => Some("line_comment"),
+            ("loop_expression", true) => Some("loop_expression"),
+            ("macro_definition", true) => Some("macro_definition"),
+            ("macro_invocation", true) => Some("macro_invocation"),
+            ("macro_rule", true) => Some("macro_rule"),
+            ("match_arm", true) => Some("match_arm"),
+            ("match_block", true) => Some("match_block"),
+            ("match_expression", true) => Some("match_expression"),
+            ("match_pattern", true) => Some("match_pattern"),
+            ("mod_item", true) => Some("mod_item"),
+            ("mut_pattern", true) => Some("mut_pattern"),
+            ("negative_literal", true) => Some("negative_literal"),
+            ("never_type", true) => Some("never_type"),
+            ("or_pattern", true) => Some("or_pattern"),
+            ("ordered_field_declaration_list", true) => Some("ordered_field_declaration_list"),
+            ("outer_doc_comment_marker", true) => Some("outer_doc_comment_marker"),
+            ("parameter", true) => Some("parameter"),
+            ("parameters", true) => Some("parameters"),
+            ("parenthesized_expression", true) => Some("parenthesized_expression"),
+            ("pointer_type", true) => Some("pointer_type"),
+            ("qualified_type", true) => Some("qualified_type"),
+            ("range_expression", true) => Some("range_expression"),
+            ("range_pattern", true) => Some("range_pattern"),
+            ("raw_string_literal", true) => Some("raw_string_literal"),
+            ("ref_pattern", true) => Some("ref_pattern"),
+            ("reference_expression", true) => Some("reference_expression"),
+            ("reference_pattern", true) => Some("reference_pattern"),
+            ("reference_type", true) => Some("reference_type"),
+            ("remaining_field_pattern", true) => Some("remaining_field_pattern"),
+            ("removed_trait_bound", true) => Some("removed_trait_bound"),
+            ("return_expression", true) => Some("return_expression"),
+            ("scoped_identifier", true) => Some("scoped_identifier"),
+            ("scoped_type_identifier", true) => Some("scoped_type_identifier"),
+            ("scoped_use_list", true) => Some("scoped_use_list"),
+            ("self_parameter", true) => Some("self_parameter"),
+            ("shorthand_field_initializer", true) => Some("shorthand_field_initializer"),
+            ("slice_pattern", true) => Some("slice_pattern"),
+            ("source_file", true) => Some("source_file"),
+            ("static_item", true) => Some("static_item"),
+            ("string_literal", true) => Some("string_literal"),
+            ("struct_expression", true) => Some("struct_expression"),
+            ("struct_item", true) => Some("struct_item"),
+            ("struct_pattern", true) => Some("struct_pattern"),
+            ("token_binding_pattern", true) => Some("token_binding_pattern"),
+            ("token_repetition", true) => Some("token_repetition"),
+            ("token_repetition_pattern", true) => Some("token_repetition_pattern"),
+            ("token_tree", true) => Some("token_tree"),
+            ("token_tree_pattern", true) => Some("token_tree_pattern"),
+            ("trait_bounds", true) => Some("trait_bounds"),
+            ("trait_item", true) => Some("trait_item"),
+            ("try_block", true) => Some("try_block"),
+            ("try_expression", true) => Some("try_expression"),
+            ("tuple_expression", true) => Some("tuple_expression"),
+            ("tuple_pattern", true) => Some("tuple_pattern"),
+            ("tuple_struct_pattern", true) => Some("tuple_struct_pattern"),
+            ("tuple_type", true) => Some("tuple_type"),
+            ("type_arguments", true) => Some("type_arguments"),
+            ("type_binding", true) => Some("type_binding"),
+            ("type_cast_expression", true) => Some("type_cast_expression"),
+            ("type_item", true) => Some("type_item"),
+            ("type_parameter", true) => Some("type_parameter"),
+            ("type_parameters", true) => Some("type_parameters"),
+            ("unary_expression", true) => Some("unary_expression"),
+            ("union_item", true) => Some("union_item"),
+            ("unit_expression", true) => Some("unit_expression"),
+            ("unit_type", true) => Some("unit_type"),
+            ("unsafe_block", true) => Some("unsafe_block"),
+            ("use_as_clause", true) => Some("use_as_clause"),
+            ("use_bounds", true) => Some("use_bounds"),
+            ("use_declaration", true) => Some("use_declaration"),
+            ("use_list", true) => Some("use_list"),
+            ("use_wildcard", true) => Some("use_wildcard"),
+            ("variadic_parameter", true) => Some("variadic_parameter"),
+            ("visibility_modifier", true) => Some("visibility_modifier"),
+            ("where_clause", true) => Some("where_clause"),
+            ("where_predicate", true) => Some("where_predicate"),
+            ("while_expression", true) => Some("while_expression"),
+            ("yield_expression", true) => Some("yield_expression"),
+            ("!", false) => Some("bang_token"),
+            ("!=", false) => Some("not_eq_token"),
+            ("\"", false) => Some("double_quote_token"),
+            ("#", false) => Some("pound_token"),
+            ("$", false) => Some("dollar_token"),
+            ("%", false) => Some("percent_token"),
+            ("%=", false) => Some("percent_assign_token"),
+            ("&", false) => Some("and_token"),
+            ("&&", false) => Some("and_and_token"),
+            ("&=", false) => Some("and_assign_token"),
+            ("'", false) => Some("single_quote_token"),
+            ("(", false) => Some("l_paren_token"),
+            (")", false) => Some("r_paren_token"),
+            ("*", false) => Some("star_token"),
+            ("*/", false) => Some("block_comment_end_token"),
+            ("*=", false) => Some("star_assign_token"),
+            ("+", false) => Some("plus_token"),
+            ("+=", false) => Some("plus_assign_token"),
+            (",", false) => Some("comma_token"),
+            ("-", false) => Some("minus_token"),
+            ("-=", false) => Some("minus_assign_token"),
+            ("->", false) => Some("r_arrow_token"),
+            (".", false) => Some("dot_token"),
+            ("..", false) => Some("dot_dot_token"),
+            ("...", false) => Some("dot_dot_dot_token"),
+            ("..=", false) => Some("dot_dot_eq_token"),
+            ("/", false) => Some("slash_token"),
+            ("/*", false) => Some("block_comment_start_token"),
+            ("//", false) => Some("line_comment_token"),
+            ("/=", false) => Some("div_assign_token"),
+            (":", false) => Some("colon_token"),
+            ("::", false) => Some("path_sep_token"),
+            (";", false) => Some("semicolon_token"),
+            ("<", false) => Some("lt_token"),
+            ("<<", false) => Some("shl_token"),
+            ("<<=", false) => Some("shl_assign_token"),
+            ("<=", false) => Some("lte_token"),
+            ("=", false) => Some("eq_token"),
+            ("==", false) => Some("eq_eq_token"),
+            ("=>", false) => Some("fat_arrow_token"),
+            (">", false) => Some("gt_token"),
+            (">=", false) => Some("gte_token"),
+            (">>", false) => Some("shr_token"),
+            (">>=", false) => Some("shr_assign_token"),
+            ("?", false) => Some("question_token"),
+            ("@", false) => Some("at_token"),
+            ("[", false) => Some("l_bracket_token"),
+            ("]", false) => Some("r_bracket_token"),
+            ("^", false) => Some("xor_token"),
+            ("^=", false) => Some("xor_assign_token"),
+            ("_", false) => Some("underscore_token"),
+            ("as", false) => Some("as_kw_token"),
+            ("async", false) => Some("async_kw_token"),
+            ("await", false) => Some("await_kw_token"),
+            ("block", false) => Some("block_token"),
+            ("break", false) => Some("break_kw_token"),
+            ("char_literal", true) => Some("char_literal"),
+            ("const", false) => Some("const_kw_token"),
+            ("continue", false) => Some("continue_kw_token"),
+            ("crate", true) => Some("crate_kw"),
+            ("default", false) => Some("default_token"),
+            ("doc_comment", true) => Some("doc_comment"),
+            ("dyn", false) => Some("dyn_kw_token"),
+            ("else", false) => Some("else_kw_token"),
+            ("enum", false) => Some("enum_kw_token"),
+            ("escape_sequence", true) => Some("escape_sequence"),
+            ("expr", false) => Some("expr_token"),
+            ("expr_2021", false) => Some("expr2021_token"),
+            ("extern", false) => Some("extern_kw_token"),
+            ("false", false) => Some("false_kw_token"),
+            ("field_identifier", true) => Some("field_identifier"),
+            ("float_literal", true) => Some("float_literal"),
+            ("fn", false) => Some("fn_kw_token"),
+            ("for", false) => Some("for_kw_token"),
+            ("gen", false) => Some("gen_token"),
+            ("ident", false) => Some("ident_token"),
+            ("identifier", true) => Some("identifier"),
+            ("if", false) => Some("if_kw_token"),
+            ("impl", false) => Some("impl_kw_token"),
+            ("in", false) => Some("in_kw_token"),
+            ("integer_literal", true) => Some("integer_literal"),
+            ("item", false) => Some("item_token"),
+            ("let", false) => Some("let_kw_token"),
+            ("lifetime", false) => Some("lifetime_token"),
+            ("literal", false) => Some("literal_token"),
+            ("loop", false) => Some("loop_kw_token"),
+            ("macro_rules!", false) => Some("macro_rules_bang_token"),
+            ("match", false) => Some("match_kw_token"),
+            ("meta", false) => Some("meta_token"),
+            ("metavariable", true) => Some("metavariable"),
+            ("mod", false) => Some("mod_kw_token"),
+            ("move", false) => Some("move_kw_token"),
+            ("mutable_specifier", true) => Some("mutable_specifier"),
+            ("pat", false) => Some("pat_token"),
+            ("pat_param", false) => Some("pat_param_token"),
+            ("path", false) => Some("path_token"),
+            ("primitive_type", true) => Some("primitive_type"),
+            ("pub", false) => Some("pub_kw_token"),
+            ("raw", false) => Some("raw_token"),
+            ("ref", false) => Some("ref_kw_token"),
+            ("return", false) => Some("return_kw_token"),
+            ("self", true) => Some("self_kw"),
+            ("shebang", true) => Some("shebang"),
+            ("shorthand_field_identifier", true) => Some("shorthand_field_identifier"),
+            ("static", false) => Some("static_kw_token"),
+            ("stmt", false) => Some("stmt_token"),
+            ("string_content", true) => Some("string_content"),
+            ("struct", false) => Some("struct_kw_token"),
+            ("super", true) => Some("super_kw"),
+            ("trait", false) => Some("trait_kw_token"),
+            ("true", false) => Some("true_kw_token"),
+            ("try", false) => Some("try_token"),
+            ("tt", false) => Some("tt_token"),
+            ("ty", false) => Some("ty_token"),
+            ("type", false) => Some("type_kw_token"),
+            ("type_identifier", true) => Some("type_identifier"),
+            ("union", false) => Some("union_token"),
+            ("unsafe", false) => Some("unsafe_kw_token"),
+            ("use", false) => Some("use_kw_token"),
+            ("vis", false) => Some("vis_token"),
+            ("where", false) => Some("where_kw_token"),
+            ("while", false) => Some("while_kw_token"),
+            ("yield", false) => Some("yield_token"),
+            ("{", false) => Some("l_brace_token"),
+            ("|", false) => Some("or_token"),
+            ("|=", false) => Some("or_assign_token"),
+            ("||", false) => Some("or_or_token"),
+            ("}", false) => Some("r_brace_token"),
+            _ => None,
+        }
+    }
+
+    /// Every node kind of the grammar with its variant and feature, in `node-types.json` order,
+    /// whether or not its feature is enabled.
+    pub const NAMES: &'static [crate::names::KindNames] = &[
+        crate::names::KindNames { kind: "_declaration_statement", named: true, variant: "DeclarationStatement", feature: "declaration_statement" },
+        crate::names::KindNames { kind: "_expression", named: true, variant: "Expression", feature: "expression" },
+        crate::names::KindNames { kind: "_literal", named: true, variant: "Literal", feature: "literal" },
+        crate::names::KindNames { kind: "_literal_pattern", named: true, variant: "LiteralPattern", feature: "literal_pattern" },
+        crate::names::KindNames { kind: "_pattern", named: true, variant: "Pattern", feature: "pattern" },
+        crate::names::KindNames { kind: "_type", named: true, variant: "Type", feature: "type" },
+        crate::names::KindNames { kind: "abstract_type", named: true, variant: "AbstractType", feature: "abstract_type" },
+        crate::names::KindNames { kind: "arguments", named: true, variant: "Arguments", feature: "arguments" },
+        crate::names::KindNames { kind: "array_expression", named: true, variant: "ArrayExpression", feature: "array_expression" },
+        crate::names::KindNames { kind: "array_type", named: true, variant: "ArrayType", feature: "array_type" },
+        crate::names::KindNames { kind: "assignment_expression", named: true, variant: "AssignmentExpression", feature: "assignment_expression" },
+        crate::names::KindNames { kind: "associated_type", named: true, variant: "AssociatedType", feature: "associated_type" },
+        crate::names::KindNames { kind: "async_block", named: true, variant: "AsyncBlock", feature: "async_block" },
+        crate::names::KindNames { kind: "attribute", named: true, variant: "Attribute", feature: "attribute" },
+        crate::names::KindNames { kind: "attribute_item", named: true, variant: "AttributeItem", feature: "attribute_item" },
+        crate::names::KindNames { kind: "await_expression", named: true, variant: "AwaitExpression", feature: "await_expression" },
+        crate::names::KindNames { kind: "base_field_initializer", named: true, variant: "BaseFieldInitializer", feature: "base_field_initializer" },
+        crate::names::KindNames { kind: "binary_expression", named: true, variant: "BinaryExpression", feature: "binary_expression" },
+        crate::names::KindNames { kind: "block", named: true, variant: "Block", feature: "block" },
+        crate::names::KindNames { kind: "block_comment", named: true, variant: "BlockComment", feature: "block_comment" },
+        crate::names::KindNames { kind: "boolean_literal", named: true, variant: "BooleanLiteral", feature: "boolean_literal" },
+        crate::names::KindNames { kind: "bounded_type", named: true, variant: "BoundedType", feature: "bounded_type" },
+        crate::names::KindNames { kind: "bracketed_type", named: true, variant: "BracketedType", feature: "bracketed_type" },
+        crate::names::KindNames { kind: "break_expression", named: true, variant: "BreakExpression", feature: "break_expression" },
+        crate::names::KindNames { kind: "call_expression", named: true, variant: "CallExpression", feature: "call_expression" },
+        crate::names::KindNames { kind: "captured_pattern", named: true, variant: "CapturedPattern", feature: "captured_pattern" },
+        crate::names::KindNames { kind: "closure_expression", named: true, variant: "ClosureExpression", feature: "closure_expression" },
+        crate::names::KindNames { kind: "closure_parameters", named: true, variant: "ClosureParameters", feature: "closure_parameters" },
+        crate::names::KindNames { kind: "compound_assignment_expr", named: true, variant: "CompoundAssignmentExpr", feature: "compound_assignment_expr" },
+        crate::names::KindNames { kind: "const_block", named: true, variant: "ConstBlock", feature: "const_block" },
+        crate::names::KindNames { kind: "const_item", named: true, variant: "ConstItem", feature: "const_item" },
+        crate::names::KindNames { kind: "const_parameter", named: true, variant: "ConstParameter", feature: "const_parameter" },
+        crate::names::KindNames { kind: "continue_expression", named: true, variant: "ContinueExpression", feature: "continue_expression" },
+        crate::names::KindNames { kind: "declaration_list", named: true, variant: "DeclarationList", feature: "declaration_list" },
+        crate::names::KindNames { kind: "dynamic_type", named: true, variant: "DynamicType", feature: "dynamic_type" },
+        crate::names::KindNames { kind: "else_clause", named: true, variant: "ElseClause", feature: "else_clause" },
+        crate::names::KindNames { kind: "empty_statement", named: true, variant: "EmptyStatement", feature: "empty_statement" },
+        crate::names::KindNames { kind: "enum_item", named: true, variant: "EnumItem", feature: "enum_item" },
+        crate::names::KindNames { kind: "enum_variant", named: true, variant: "EnumVariant", feature: "enum_variant" },
+        crate::names::KindNames { kind: "enum_variant_list", named: true, variant: "EnumVariantList", feature: "enum_variant_list" },
+        crate::names::KindNames { kind: "expression_statement", named: true, variant: "ExpressionStatement", feature: "expression_statement" },
+        crate::names::KindNames { kind: "extern_crate_declaration", named: true, variant: "ExternCrateDeclaration", feature: "extern_crate_declaration" },
+        crate::names::KindNames { kind: "extern_modifier", named: true, variant: "ExternModifier", feature: "extern_modifier" },
+        crate::names::KindNames { kind: "field_declaration", named: true, variant: "FieldDeclaration", feature: "field_declaration" },
+        crate::names::KindNames { kind: "field_declaration_list", named: true, variant: "FieldDeclarationList", feature: "field_declaration_list" },
+        crate::names::KindNames { kind: "field_expression", named: true, variant: "FieldExpression", feature: "field_expression" },
+        crate::names::KindNames { kind: "field_initializer", named: true, variant: "FieldInitializer", feature: "field_initializer" },
+        crate::names::KindNames { kind: "field_initializer_list", named: true, variant: "FieldInitializerList", feature: "field_initializer_list" },
+        crate::names::KindNames { kind: "field_pattern", named: true, variant: "FieldPattern", feature: "field_pattern" },
+        crate::names::KindNames { kind: "for_expression", named: true, variant: "ForExpression", feature: "for_expression" },
+        crate::names::KindNames { kind: "for_lifetimes", named: true, variant: "ForLifetimes", feature: "for_lifetimes" },
+        crate::names::KindNames { kind: "foreign_mod_item", named: true, variant: "ForeignModItem", feature: "foreign_mod_item" },
+        crate::names::KindNames { kind: "fragment_specifier", named: true, variant: "FragmentSpecifier", feature: "fragment_specifier" },
+        crate::names::KindNames { kind: "function_item", named: true, variant: "FunctionItem", feature: "function_item" },
+        crate::names::KindNames { kind: "function_modifiers", named: true, variant: "FunctionModifiers", feature: "function_modifiers" },
+        crate::names::KindNames { kind: "function_signature_item", named: true, variant: "FunctionSignatureItem", feature: "function_signature_item" },
+        crate::names::KindNames { kind: "function_type", named: true, variant: "FunctionType", feature: "function_type" },
+        crate::names::KindNames { kind: "gen_block", named: true, variant: "GenBlock", feature: "gen_block" },
+        crate::names::KindNames { kind: "generic_function", named: true, variant: "GenericFunction", feature: "generic_function" },
+        crate::names::KindNames { kind: "generic_pattern", named: true, variant: "GenericPattern", feature: "generic_pattern" },
+        crate::names::KindNames { kind: "generic_type", named: true, variant: "GenericType", feature: "generic_type" },
+        crate::names::KindNames { kind: "generic_type_with_turbofish", named: true, variant: "GenericTypeWithTurbofish", feature: "generic_type_with_turbofish" },
+        crate::names::KindNames { kind: "higher_ranked_trait_bound", named: true, variant: "HigherRankedTraitBound", feature: "higher_ranked_trait_bound" },
+        crate::names::KindNames { kind: "if_expression", named: true, variant: "IfExpression", feature: "if_expression" },
+        crate::names::KindNames { kind: "impl_item", named: true, variant: "ImplItem", feature: "impl_item" },
+        crate::names::KindNames { kind: "index_expression", named: true, variant: "IndexExpression", feature: "index_expression" },
+        crate::names::KindNames { kind: "inner_attribute_item", named: true, variant: "InnerAttributeItem", feature: "inner_attribute_item" },
+        crate::names::KindNames { kind: "inner_doc_comment_marker", named: true, variant: "InnerDocCommentMarker", feature: "inner_doc_comment_marker" },
+        crate::names::KindNames { kind: "label", named: true, variant: "Label", feature: "label" },
+        crate::names::KindNames { kind: "let_chain", named: true, variant: "LetChain", feature: "let_chain" },
+        crate::names::KindNames { kind: "let_condition", named: true, variant: "LetCondition", feature: "let_condition" },
+        crate::names::KindNames { kind: "let_declaration", named: true, variant: "LetDeclaration", feature: "let_declaration" },
+        crate::names::KindNames { kind: "lifetime", named: true, variant: "Lifetime", feature: "lifetime" },
+        crate::names::KindNames { kind: "lifetime_parameter", named: true, variant: "LifetimeParameter", feature: "lifetime_parameter" },
+        crate::names::KindNames { kind: "line_comment", named: true, variant: "LineComment", feature: "line_comment" },
+        crate::names::KindNames { kind: "loop_expression", named: true, variant: "LoopExpression", feature: "loop_expression" },
+        crate::names::KindNames { kind: "macro_definition", named: true, variant: "MacroDefinition", feature: "macro_definition" },
+        crate::names::KindNames { kind: "macro_invocation", named: true, variant: "MacroInvocation", feature: "macro_invocation" },
+        crate::names::KindNames { kind: "macro_rule", named: true, variant: "MacroRule", feature: "macro_rule" },
+        crate::names::KindNames { kind: "match_arm", named: true, variant: "MatchArm", feature: "match_arm" },
+        crate::names::KindNames { kind: "match_block", named: true, variant: "MatchBlock", feature: "match_block" },
+        crate::names::KindNames { kind: "match_expression", named: true, variant: "MatchExpression", feature: "match_expression" },
+        crate::names::KindNames { kind: "match_pattern", named: true, variant: "MatchPattern", feature: "match_pattern" },
+        crate::names::KindNames { kind: "mod_item", named: true, variant: "ModItem", feature: "mod_item" },
+        crate::names::KindNames { kind: "mut_pattern", named: true, variant: "MutPattern", feature: "mut_pattern" },
+        crate::names::KindNames { kind: "negative_literal", named: true, variant: "NegativeLiteral", feature: "negative_literal" },
+        crate::names::KindNames { kind: "never_type", named: true, variant: "NeverType", feature: "never_type" },
+        crate::names::KindNames { kind: "or_pattern", named: true, variant: "OrPattern", feature: "or_pattern" },
+        crate::names::KindNames { kind: "ordered_field_declaration_list", named: true, variant: "OrderedFieldDeclarationList", feature: "ordered_field_declaration_list" },
+        crate::names::KindNames { kind: "outer_doc_comment_marker", named: true, variant: "OuterDocCommentMarker", feature: "outer_doc_comment_marker" },
+        crate::names::KindNames { kind: "parameter", named: true, variant: "Parameter", feature: "parameter" },
+        crate::names::KindNames { kind: "parameters", named: true, variant: "Parameters", feature: "parameters" },
+        crate::names::KindNames { kind: "parenthesized_expression", named: true, variant: "ParenthesizedExpression", feature: "parenthesized_expression" },
+        crate::names::KindNames { kind: "pointer_type", named: true, variant: "PointerType", feature: "pointer_type" },
+        crate::names::KindNames { kind: "qualified_type", named: true, variant: "QualifiedType", feature: "qualified_type" },
+        crate::names::KindNames { kind: "range_expression", named: true, variant: "RangeExpression", feature: "range_expression" },
+        crate::names::KindNames { kind: "range_pattern", named: true, variant: "RangePattern", feature: "range_pattern" },
+        crate::names::KindNames { kind: "raw_string_literal", named: true, variant: "RawStringLiteral", feature: "raw_string_literal" },
+        crate::names::KindNames { kind: "ref_pattern", named: true, variant: "RefPattern", feature: "ref_pattern" },
+        crate::names::KindNames { kind: "reference_expression", named: true, variant: "ReferenceExpression", feature: "reference_expression" },
+        crate::names::KindNames { kind: "reference_pattern", named: true, variant: "ReferencePattern", feature: "reference_pattern" },
+        crate::names::KindNames { kind: "reference_type", named: true, variant: "ReferenceType", feature: "reference_type" },
+        crate::names::KindNames { kind: "remaining_field_pattern", named: true, variant: "RemainingFieldPattern", feature: "remaining_field_pattern" },
+        crate::names::KindNames { kind: "removed_trait_bound", named: true, variant: "RemovedTraitBound", feature: "removed_trait_bound" },
+        crate::names::KindNames { kind: "return_expression", named: true, variant: "ReturnExpression", feature: "return_expression" },
+        crate::names::KindNames { kind: "scoped_identifier", named: true, variant: "ScopedIdentifier", feature: "scoped_identifier" },
+        crate::names::KindNames { kind: "scoped_type_identifier", named: true, variant: "ScopedTypeIdentifier", feature: "scoped_type_identifier" },
+        crate::names::KindNames { kind: "scoped_use_list", named: true, variant: "ScopedUseList", feature: "scoped_use_list" },
+        crate::names::KindNames { kind: "self_parameter", named: true, variant: "SelfParameter", feature: "self_parameter" },
+        crate::names::KindNames { kind: "shorthand_field_initializer", named: true, variant: "ShorthandFieldInitializer", feature: "shorthand_field_initializer" },
+        crate::names::KindNames { kind: "slice_pattern", named: true, variant: "SlicePattern", feature: "slice_pattern" },
+        crate::names::KindNames { kind: "source_file", named: true, variant: "SourceFile", feature: "source_file" },
+        crate::names::KindNames { kind: "static_item", named: true, variant: "StaticItem", feature: "static_item" },
+        crate::names::KindNames { kind: "string_literal", named: true, variant: "StringLiteral", feature: "string_literal" },
+        crate::names::KindNames { kind: "struct_expression", named: true, variant: "StructExpression", feature: "struct_expression" },
+        crate::names::KindNames { kind: "struct_item", named: true, variant: "StructItem", feature: "struct_item" },
+        crate::names::KindNames { kind: "struct_pattern", named: true, variant: "StructPattern", feature: "struct_pattern" },
+        crate::names::KindNames { kind: "token_binding_pattern", named: true, variant: "TokenBindingPattern", feature: "token_binding_pattern" },
+        crate::names::KindNames { kind: "token_repetition", named: true, variant: "TokenRepetition", feature: "token_repetition" },
+        crate::names::KindNames { kind: "token_repetition_pattern", named: true, variant: "TokenRepetitionPattern", feature: "token_repetition_pattern" },
+        crate::names::KindNames { kind: "token_tree", named: true, variant: "TokenTree", feature: "token_tree" },
+        crate::names::KindNames { kind: "token_tree_pattern", named: true, variant: "TokenTreePattern", feature: "token_tree_pattern" },
+        crate::names::KindNames { kind: "trait_bounds", named: true, variant: "TraitBounds", feature: "trait_bounds" },
+        crate::names::KindNames { kind: "trait_item", named: true, variant: "TraitItem", feature: "trait_item" },
+        crate::names::KindNames { kind: "try_block", named: true, variant: "TryBlock", feature: "try_block" },
+        crate::names::KindNames { kind: "try_expression", named: true, variant: "TryExpression", feature: "try_expression" },
+        crate::names::KindNames { kind: "tuple_expression", named: true, variant: "TupleExpression", feature: "tuple_expression" },
+        crate::names::KindNames { kind: "tuple_pattern", named: true, variant: "TuplePattern", feature: "tuple_pattern" },
+        crate::names::KindNames { kind: "tuple_struct_pattern", named: true, variant: "TupleStructPattern", feature: "tuple_struct_pattern" },
+        crate::names::KindNames { kind: "tuple_type", named: true, variant: "TupleType", feature: "tuple_type" },
+        crate::names::KindNames { kind: "type_arguments", named: true, variant: "TypeArguments", feature: "type_arguments" },
+        crate::names::KindNames { kind: "type_binding", named: true, variant: "TypeBinding", feature: "type_binding" },
+        crate::names::KindNames { kind: "type_cast_expression", named: true, variant: "TypeCastExpression", feature: "type_cast_expression" },
+        crate::names::KindNames { kind: "type_item", named: true, variant: "TypeItem", feature: "type_item" },
+        crate::names::KindNames { kind: "type_parameter", named: true, variant: "TypeParameter", feature: "type_parameter" },
+        crate::names::KindNames { kind: "type_parameters", named: true, variant: "TypeParameters", feature: "type_parameters" },
+        crate::names::KindNames { kind: "unary_expression", named: true, variant: "UnaryExpression", feature: "unary_expression" },
+        crate::names::KindNames { kind: "union_item", named: true, variant: "UnionItem", feature: "union_item" },
+        crate::names::KindNames { kind: "unit_expression", named: true, variant: "UnitExpression", feature: "unit_expression" },
+        crate::names::KindNames { kind: "unit_type", named: true, variant: "UnitType", feature: "unit_type" },
+        crate::names::KindNames { kind: "unsafe_block", named: true, variant: "UnsafeBlock", feature: "unsafe_block" },
+        crate::names::KindNames { kind: "use_as_clause", named: true, variant: "UseAsClause", feature: "use_as_clause" },
+        crate::names::KindNames { kind: "use_bounds", named: true, variant: "UseBounds", feature: "use_bounds" },
+        crate::names::KindNames { kind: "use_declaration", named: true, variant: "UseDeclaration", feature: "use_declaration" },
+        crate::names::KindNames { kind: "use_list", named: true, variant: "UseList", feature: "use_list" },
+        crate::names::KindNames { kind: "use_wildcard", named: true, variant: "UseWildcard", feature: "use_wildcard" },
+        crate::names::KindNames { kind: "variadic_parameter", named: true, variant: "VariadicParameter", feature: "variadic_parameter" },
+        crate::names::KindNames { kind: "visibility_modifier", named: true, variant: "VisibilityModifier", feature: "visibility_modifier" },
+        crate::names::KindNames { kind: "where_clause", named: true, variant: "WhereClause", feature: "where_clause" },
+        crate::names::KindNames { kind: "where_predicate", named: true, variant: "WherePredicate", feature: "where_predicate" },
+        crate::names::KindNames { kind: "while_expression", named: true, variant: "WhileExpression", feature: "while_expression" },
+        crate::names::KindNames { kind: "yield_expression", named: true, variant: "YieldExpression", feature: "yield_expression" },
+        crate::names::KindNames { kind: "!", named: false, variant: "BangToken", feature: "bang_token" },
+        crate::names::KindNames { kind: "!=", named: false, variant: "NotEqToken", feature: "not_eq_token" },
+        crate::names::KindNames { kind: "\"", named: false, variant: "DoubleQuoteToken", feature: "double_quote_token" },
+        crate::names::KindNames { kind: "#", named: false, variant: "PoundToken", feature: "pound_token" },
+        crate::names::KindNames { kind: "$", named: false, variant: "DollarToken", feature: "dollar_token" },
+        crate::names::KindNames { kind: "%", named: false, variant: "PercentToken", feature: "percent_token" },
+        crate::names::KindNames { kind: "%=", named: false, variant: "PercentAssignToken", feature: "percent_assign_token" },
+        crate::names::KindNames { kind: "&", named: false, variant: "AndToken", feature: "and_token" },
+        crate::names::KindNames { kind: "&&", named: false, variant: "AndAndToken", feature: "and_and_token" },
+        crate::names::KindNames { kind: "&=", named: false, variant: "AndAssignToken", feature: "and_assign_token" },
+        crate::names::KindNames { kind: "'", named: false, variant: "SingleQuoteToken", feature: "single_quote_token" },
+        crate::names::KindNames { kind: "(", named: false, variant: "LParenToken", feature: "l_paren_token" },
+        crate::names::KindNames { kind: ")", named: false, variant: "RParenToken", feature: "r_paren_token" },
+        crate::names::KindNames { kind: "*", named: false, variant: "StarToken", feature: "star_token" },
+        crate::names::KindNames { kind: "*/", named: false, variant: "BlockCommentEndToken", feature: "block_comment_end_token" },
+        crate::names::KindNames { kind: "*=", named: false, variant: "StarAssignToken", feature: "star_assign_token" },
+        crate::names::KindNames { kind: "+", named: false, variant: "PlusToken", feature: "plus_token" },
+        crate::names::KindNames { kind: "+=", named: false, variant: "PlusAssignToken", feature: "plus_assign_token" },
+        crate::names::KindNames { kind: ",", named: false, variant: "CommaToken", feature: "comma_token" },
+        crate::names::KindNames { kind: "-", named: false, variant: "MinusToken", feature: "minus_token" },
+        crate::names::KindNames { kind: "-=", named: false, variant: "MinusAssignToken", feature: "minus_assign_token" },
+        crate::names::KindNames { kind: "->", named: false, variant: "RArrowToken", feature: "r_arrow_token" },
+        crate::names::KindNames { kind: ".", named: false, variant: "DotToken", feature: "dot_token" },
+        crate::names::KindNames { kind: "..", named: false, variant: "DotDotToken", feature: "dot_dot_token" },
+        crate::names::KindNames { kind: "...", named: false, variant: "DotDotDotToken", feature: "dot_dot_dot_token" },
+        crate::names::KindNames { kind: "..=", named: false, variant: "DotDotEqToken", feature: "dot_dot_eq_token" },
+        crate::names::KindNames { kind: "/", named: false, variant: "SlashToken", feature: "slash_token" },
+        crate::names::KindNames { kind: "/*", named: false, variant: "BlockCommentStartToken", feature: "block_comment_start_token" },
+        crate::names::KindNames { kind: "//", named: false, variant: "LineCommentToken", feature: "line_comment_token" },
+        crate::names::KindNames { kind: "/=", named: false, variant: "DivAssignToken", feature: "div_assign_token" },
+        crate::names::KindNames { kind: ":", named: false, variant: "ColonToken", feature: "colon_token" },
+        crate::names::KindNames { kind: "::", named: false, variant: "PathSepToken", feature: "path_sep_token" },
+        crate::names::KindNames { kind: ";", named: false, variant: "SemicolonToken", feature: "semicolon_token" },
+        crate::names::KindNames { kind: "<", named: false, variant: "LtToken", feature: "lt_token" },
+        crate::names::KindNames { kind: "<<", named: false, variant: "ShlToken", feature: "shl_token" },
+        crate::names::KindNames { kind: "<<=", named: false, variant: "ShlAssignToken", feature: "shl_assign_token" },
+        crate::names::KindNames { kind: "<=", named: false, variant: "LteToken", feature: "lte_token" },
+        crate::names::KindNames { kind: "=", named: false, variant: "EqToken", feature: "eq_token" },
+        crate::names::KindNames { kind: "==", named: false, variant: "EqEqToken", feature: "eq_eq_token" },
+        crate::names::KindNames { kind: "=>", named: false, variant: "FatArrowToken", feature: "fat_arrow_token" },
+        crate::names::KindNames { kind: ">", named: false, variant: "GtToken", feature: "gt_token" },
+        crate::names::KindNames { kind: ">=", named: false, variant: "GteToken", feature: "gte_token" },
+        crate::names::KindNames { kind: ">>", named: false, variant: "ShrToken", feature: "shr_token" },
+        crate::names::KindNames { kind: ">>=", named: false, variant: "ShrAssignToken", feature: "shr_assign_token" },
+        crate::names::KindNames { kind: "?", named: false, variant: "QuestionToken", feature: "question_token" },
+        crate::names::KindNames { kind: "@", named: false, variant: "AtToken", feature: "at_token" },
+        crate::names::KindNames { kind: "[", named: false, variant: "LBracketToken", feature: "l_bracket_token" },
+        crate::names::KindNames { kind: "]", named: false, variant: "RBracketToken", feature: "r_bracket_token" },
+        crate::names::KindNames { kind: "^", named: false, variant: "XorToken", feature: "xor_token" },
+        crate::names::KindNames { kind: "^=", named: false, variant: "XorAssignToken", feature: "xor_assign_token" },
+        crate::names::KindNames { kind: "_", named: false, variant: "UnderscoreToken", feature: "underscore_token" },
+        crate::names::KindNames { kind: "as", named: false, variant: "AsKwToken", feature: "as_kw_token" },
+        crate::names::KindNames { kind: "async", named: false, variant: "AsyncKwToken", feature: "async_kw_token" },
+        crate::names::KindNames { kind: "await", named: false, variant: "AwaitKwToken", feature: "await_kw_token" },
+        crate::names::KindNames { kind: "block", named: false, variant: "BlockToken", feature: "block_token" },
+        crate::names::KindNames { kind: "break", named: false, variant: "BreakKwToken", feature: "break_kw_token" },
+        crate::names::KindNames { kind: "char_literal", named: true, variant: "CharLiteral", feature: "char_literal" },
+        crate::names::KindNames { kind: "const", named: false, variant: "ConstKwToken", feature: "const_kw_token" },
+        crate::names::KindNames { kind: "continue", named: false, variant: "ContinueKwToken", feature: "continue_kw_token" },
+        crate::names::KindNames { kind: "crate", named: true, variant: "CrateKw", feature: "crate_kw" },
+        crate::names::KindNames { kind: "default", named: false, variant: "DefaultToken", feature: "default_token" },
+        crate::names::KindNames { kind: "doc_comment", named: true, variant: "DocComment", feature: "doc_comment" },
+        crate::names::KindNames { kind: "dyn", named: false, variant: "DynKwToken", feature: "dyn_kw_token" },
+        crate::names::KindNames { kind: "else", named: false, variant: "ElseKwToken", feature: "else_kw_token" },
+        crate::names::KindNames { kind: "enum", named: false, variant: "EnumKwToken", feature: "enum_kw_token" },
+        crate::names::KindNames { kind: "escape_sequence", named: true, variant: "EscapeSequence", feature: "escape_sequence" },
+        crate::names::KindNames { kind: "expr", named: false, variant: "ExprToken", feature: "expr_token" },
+        crate::names::KindNames { kind: "expr_2021", named: false, variant: "Expr2021Token", feature: "expr2021_token" },
+        crate::names::KindNames { kind: "extern", named: false, variant: "ExternKwToken", feature: "extern_kw_token" },
+        crate::names::KindNames { kind: "false", named: false, variant: "FalseKwToken", feature: "false_kw_token" },
+        crate::names::KindNames { kind: "field_identifier", named: true, variant: "FieldIdentifier", feature: "field_identifier" },
+        crate::names::KindNames { kind: "float_literal", named: true, variant: "FloatLiteral", feature: "float_literal" },
+        crate::names::KindNames { kind: "fn", named: false, variant: "FnKwToken", feature: "fn_kw_token" },
+        crate::names::KindNames { kind: "for", named: false, variant: "ForKwToken", feature: "for_kw_token" },
+        crate::names::KindNames { kind: "gen", named: false, variant: "GenToken", feature: "gen_token" },
+        crate::names::KindNames { kind: "ident", named: false, variant: "IdentToken", feature: "ident_token" },
+        crate::names::KindNames { kind: "identifier", named: true, variant: "Identifier", feature: "identifier" },
+        crate::names::KindNames { kind: "if", named: false, variant: "IfKwToken", feature: "if_kw_token" },
+        crate::names::KindNames { kind: "impl", named: false, variant: "ImplKwToken", feature: "impl_kw_token" },
+        crate::names::KindNames { kind: "in", named: false, variant: "InKwToken", feature: "in_kw_token" },
+        crate::names::KindNames { kind: "integer_literal", named: true, variant: "IntegerLiteral", feature: "integer_literal" },
+        crate::names::KindNames { kind: "item", named: false, variant: "ItemToken", feature: "item_token" },
+        crate::names::KindNames { kind: "let", named: false, variant: "LetKwToken", feature: "let_kw_token" },
+        crate::names::KindNames { kind: "lifetime", named: false, variant: "LifetimeToken", feature: "lifetime_token" },
+        crate::names::KindNames { kind: "literal", named: false, variant: "LiteralToken", feature: "literal_token" },
+        crate::names::KindNames { kind: "loop", named: false, variant: "LoopKwToken", feature: "loop_kw_token" },
+        crate::names::KindNames { kind: "macro_rules!", named: false, variant: "MacroRulesBangToken", feature: "macro_rules_bang_token" },
+        crate::names::KindNames { kind: "match", named: false, variant: "MatchKwToken", feature: "match_kw_token" },
+        crate::names::KindNames { kind: "meta", named: false, variant: "MetaToken", feature: "meta_token" },
+        crate::names::KindNames { kind: "metavariable", named: true, variant: "Metavariable", feature: "metavariable" },
+        crate::names::KindNames { kind: "mod", named: false, variant: "ModKwToken", feature: "mod_kw_token" },
+        crate::names::KindNames { kind: "move", named: false, variant: "MoveKwToken", feature: "move_kw_token" },
+        crate::names::KindNames { kind: "mutable_specifier", named: true, variant: "MutableSpecifier", feature: "mutable_specifier" },
+        crate::names::KindNames { kind: "pat", named: false, variant: "PatToken", feature: "pat_token" },
+        crate::names::KindNames { kind: "pat_param", named: false, variant: "PatParamToken", feature: "pat_param_token" },
+        crate::names::KindNames { kind: "path", named: false, variant: "PathToken", feature: "path_token" },
+        crate::names::KindNames { kind: "primitive_type", named: true, variant: "PrimitiveType", feature: "primitive_type" },
+        crate::names::KindNames { kind: "pub", named: false, variant: "PubKwToken", feature: "pub_kw_token" },
+        crate::names::KindNames { kind: "raw", named: false, variant: "RawToken", feature: "raw_token" },
+        crate::names::KindNames { kind: "ref", named: false, variant: "RefKwToken", feature: "ref_kw_token" },
+        crate::names::KindNames { kind: "return", named: false, variant: "ReturnKwToken", feature: "return_kw_token" },
+        crate::names::KindNames { kind: "self", named: true, variant: "SelfKw", feature: "self_kw" },
+        crate::names::KindNames { kind: "shebang", named: true, variant: "Shebang", feature: "shebang" },
+        crate::names::KindNames { kind: "shorthand_field_identifier", named: true, variant: "ShorthandFieldIdentifier", feature: "shorthand_field_identifier" },
+        crate::names::KindNames { kind: "static", named: false, variant: "StaticKwToken", feature: "static_kw_token" },
+        crate::names::KindNames { kind: "stmt", named: false, variant: "StmtToken", feature: "stmt_token" },
+        crate::names::KindNames { kind: "string_content", named: true, variant: "StringContent", feature: "string_content" },
+        crate::names::KindNames { kind: "struct", named: false, variant: "StructKwToken", feature: "struct_kw_token" },
+        crate::names::KindNames { kind: "super", named: true, variant: "SuperKw", feature: "super_kw" },
+        crate::names::KindNames { kind: "trait", named: false, variant: "TraitKwToken", feature: "trait_kw_token" },
+        crate::names::KindNames { kind: "true", named: false, variant: "TrueKwToken", feature: "true_kw_token" },
+        crate::names::KindNames { kind: "try", named: false, variant: "TryToken", feature: "try_token" },
+        crate::names::KindNames { kind: "tt", named: false, variant: "TtToken", feature: "tt_token" },
+        crate::names::KindNames { kind: "ty", named: false, variant: "TyToken", feature: "ty_token" },
+        crate::names::KindNames { kind: "type", named: false, variant: "TypeKwToken", feature: "type_kw_token" },
+        crate::names::KindNames { kind: "type_identifier", named: true, variant: "TypeIdentifier", feature: "type_identifier" },
+        crate::names::KindNames { kind: "union", named: false, variant: "UnionToken", feature: "union_token" },
+        crate::names::KindNames { kind: "unsafe", named: false, variant: "UnsafeKwToken", feature: "unsafe_kw_token" },
+        crate::names::KindNames { kind: "use", named: false, variant: "UseKwToken", feature: "use_kw_token" },
+        crate::names::KindNames { kind: "vis", named: false, variant: "VisToken", feature: "vis_token" },
+        crate::names::KindNames { kind: "where", named: false, variant: "WhereKwToken", feature: "where_kw_token" },
+        crate::names::KindNames { kind: "while", named: false, variant: "WhileKwToken", feature: "while_kw_token" },
+        crate::names::KindNames { kind: "yield", named: false, variant: "YieldToken", feature: "yield_token" },
+        crate::names::KindNames { kind: "{", named: false, variant: "LBraceToken", feature: "l_brace_token" },
+        crate::names::KindNames { kind: "|", named: false, variant: "OrToken", feature: "or_token" },
+        crate::names::KindNames { kind: "|=", named: false, variant: "OrAssignToken", feature: "or_assign_token" },
+        crate::names::KindNames { kind: "||", named: false, variant: "OrOrToken", feature: "or_or_token" },
+        crate::names::KindNames { kind: "}", named: false, variant: "RBraceToken", feature: "r_brace_token" },
+    ];
+
+    /// The deprecated variants kept for kinds earlier grammar releases had, including kinds
+    /// that were dropped.
+    pub const DEPRECATED: &'static [crate::names::DeprecatedKind] = &[
+    ];
+}
+
+#[cfg(feature = "symbols")]
+impl NodeType {
+    /// The Universal Ctags kind of a symbol with this node type, e.g. `"function"`.
+    ///
+    /// Functions inside an `impl` or trait are methods, which the writers in
+    /// [`export`](crate::export) take care of.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn ctags_kind(self) -> Option<&'static str> {
+        match self {
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => Some("typedef"),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => Some("variable"),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => Some("enum"),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => Some("enumerator"),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => Some("field"),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => Some("function"),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => Some("function"),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => Some("implementation"),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => Some("macro"),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => Some("module"),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => Some("variable"),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => Some("struct"),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => Some("interface"),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => Some("typedef"),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => Some("struct"),
+            _ => None,
+        }
+    }
+
+    /// The LSP `SymbolKind` of a symbol with this node type.
+    ///
+    /// Functions inside an `impl` or trait are methods, which the writers in
+    /// [`export`](crate::export) take care of.
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    #[allow(unreachable_patterns)]
+    pub const fn lsp_symbol_kind(self) -> Option<crate::export::SymbolKind> {
+        match self {
+            #[cfg(any(feature = "associated_type", feature = "node_full"))]
+            Self::AssociatedType => Some(crate::export::SymbolKind::TypeParameter),
+            #[cfg(any(feature = "const_item", feature = "node_full"))]
+            Self::ConstItem => Some(crate::export::SymbolKind::Constant),
+            #[cfg(any(feature = "enum_item", feature = "node_full"))]
+            Self::EnumItem => Some(crate::export::SymbolKind::Enum),
+            #[cfg(any(feature = "enum_variant", feature = "node_full"))]
+            Self::EnumVariant => Some(crate::export::SymbolKind::EnumMember),
+            #[cfg(any(feature = "field_declaration", feature = "node_full"))]
+            Self::FieldDeclaration => Some(crate::export::SymbolKind::Field),
+            #[cfg(any(feature = "function_item", feature = "node_full"))]
+            Self::FunctionItem => Some(crate::export::SymbolKind::Function),
+            #[cfg(any(feature = "function_signature_item", feature = "node_full"))]
+            Self::FunctionSignatureItem => Some(crate::export::SymbolKind::Function),
+            #[cfg(any(feature = "impl_item", feature = "node_full"))]
+            Self::ImplItem => Some(crate::export::SymbolKind::Object),
+            #[cfg(any(feature = "macro_definition", feature = "node_full"))]
+            Self::MacroDefinition => Some(crate::export::SymbolKind::Function),
+            #[cfg(any(feature = "mod_item", feature = "node_full"))]
+            Self::ModItem => Some(crate::export::SymbolKind::Module),
+            #[cfg(any(feature = "static_item", feature = "node_full"))]
+            Self::StaticItem => Some(crate::export::SymbolKind::Variable),
+            #[cfg(any(feature = "struct_item", feature = "node_full"))]
+            Self::StructItem => Some(crate::export::SymbolKind::Struct),
+            #[cfg(any(feature = "trait_item", feature = "node_full"))]
+            Self::TraitItem => Some(crate::export::SymbolKind::Interface),
+            #[cfg(any(feature = "type_item", feature = "node_full"))]
+            Self::TypeItem => Some(crate::export::SymbolKind::TypeParameter),
+            #[cfg(any(feature = "union_item", feature = "node_full"))]
+            Self::UnionItem => Some(crate::export::SymbolKind::Struct),
+            _ => None,
+        }
+    }
+
+}
@@ -0,0 +1,8 @@
+// @generated by tss-rust-codegen.
+// Run `cargo run -p tss-rust-codegen` to update it.
+
+// The code of the tree-sitter-rust release the `grammar-*` features select
+#[cfg(not(any(feature = "grammar-0-23")))]
+include!("grammar_0_24/meta.rs");
+#[cfg(feature = "grammar-0-23")]
+include!("grammar_0_23/meta.rs");
@@ -0,0 +1,16 @@
+// @generated by tss-rust-codegen.
+// Run `cargo run -p tss-rust-codegen` to update it.
+
+// The code of the tree-sitter-rust release the `grammar-*` features select
+include!("grammar_0_24/nodes.rs");
+
+impl NodeType {
+    /// The tree-sitter-rust release the node types were generated from, selected by the
+    /// `grammar-*` features, or `"custom"` if they came from `TSS_RUST_NODE_TYPES_PATH`.
+    #[cfg(not(any(feature = "grammar-0-23")))]
+    pub const GRAMMAR_VERSION: &'static str = "0.24";
+    /// The tree-sitter-rust release the node types were generated from, selected by the
+    /// `grammar-*` features, or `"custom"` if they came from `TSS_RUST_NODE_TYPES_PATH`.
+    #[cfg(feature = "grammar-0-23")]
+    pub const GRAMMAR_VERSION: &'static str = "0.23";
+}
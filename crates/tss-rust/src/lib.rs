@@ -14,12 +14,15 @@
 //! ```
 //!
 //! ```
+//! # #[cfg(any(feature = "function_item", feature = "node_full"))]
+//! # {
 //! use tree_sitter_symbols_rust::NodeType;
 //! use std::str::FromStr;
 //!
 //! let node_type = NodeType::from_str("function_item")?;
 //! assert_eq!(node_type, NodeType::FunctionItem);
 //! assert_eq!(node_type.to_string(), "function_item");
+//! # }
 //! # Ok::<(), String>(())
 //! ```
 //!
@@ -47,6 +50,28 @@
 //! The `node` feature is a tracking feature automatically enabled when any node type is active.
 //! You don't need to enable it manually - it's used internally for conditional compilation.
 //!
+//! Group features enable a whole category of node types at once:
+//!
+//! - `group_items` - `_declaration_statement` and its subtypes (`function_item`, `struct_item`, ...)
+//! - `group_expressions` - `_expression` and its subtypes, including literals
+//! - `group_types` - `_type` and its subtypes
+//! - `group_patterns` - `_pattern` and its subtypes
+//! - `group_literals` - `_literal`, `_literal_pattern` and their subtypes
+//! - `group_keywords` - keyword tokens such as `fn` and `struct`
+//! - `group_operators` - operator tokens such as `+`, `&&` and `+=`
+//! - `group_punctuation` - delimiters and separators such as `(`, `;` and `::`
+//!
+//! ```toml
+//! tss-rust = { version = "0.2", features = ["group_items", "group_punctuation"] }
+//! ```
+//!
+//! A node feature only enables its own variant, so with just `function_item` the `identifier`,
+//! `parameters` and `block` nodes inside a function have no variant to parse into. Regenerating
+//! this crate with `feature-closure = true` under `[package.metadata.tss]` makes each node
+//! feature also enable every node type reachable through its fields and children, and adds a
+//! `*_shallow` feature per node that enables the node alone. This mode is off in published
+//! releases because it doubles the number of features.
+//!
 //! ## Metadata Features
 //!
 //! Control which metadata from tree-sitter's `NODE_TYPES` JSON is included:
@@ -55,8 +80,20 @@
 //! - `meta_subtypes` - possible subtypes for each node
 //! - `meta_fields` - named fields nodes can have
 //! - `meta_children` - anonymous children nodes can have
-//! - `meta_extra` - extra node markers
+//! - `meta_extra` - extra node markers ([`NodeType::is_extra`]: line and block comments)
 //! - `meta_root` - root node markers
+//! - `meta_docs` - links to the Rust Reference section for each node
+//!   ([`NodeType::reference_url`]); the section, its grammar production and an example are
+//!   also part of each variant's documentation
+//! - `meta_examples` - a minimal source snippet containing each node
+//!   ([`NodeType::example`]), checked by a test that parses every snippet
+//! - `meta_highlights` - the [`Highlight`] captures `HIGHLIGHTS_QUERY` gives each node wherever it
+//!   appears ([`NodeType::highlight_captures`], [`NodeType::highlight`]), so tokens can be
+//!   coloured from a table without running the query
+//! - `meta_tokens` - token categories ([`TokenCategory`]: keyword, operator, punctuation,
+//!   literal, comment, identifier), delimiter and assignment predicates, and binary operator
+//!   precedence and [`Associativity`], and paired delimiters
+//!   ([`NodeType::matching_delimiter`], matched over a token stream by [`delimiters`])
 //!
 //! Enable all metadata:
 //!
@@ -73,6 +110,39 @@
 //! The `meta` feature is a tracking feature automatically enabled when any metadata feature is active.
 //! You don't need to enable it manually - it's used internally for conditional compilation.
 //!
+//! ## Query Features
+//!
+//! - `query` - a typed query builder ([`query::Query`]) validated against the field and children
+//!   metadata (enables the `meta_*` features it needs)
+//! - `tree-sitter` - compile built queries into a `tree_sitter::Query` for tree-sitter-rust, and
+//!   match the delimiters of a parsed tree with [`delimiters::tree_delimiters`]
+//! - `symbols` - the definitions and references tree-sitter-rust's tags query finds in a parsed
+//!   tree, as typed [`symbols::Symbol`]s, a nested [`outline::outline`] of its items, and
+//!   [`export`] writers for Universal Ctags tags files and LSP symbol JSON (enables `tree-sitter`
+//!   and the node types they report)
+//! - `sexp` - an indented S-expression printer for parsed trees ([`sexp::pretty`]) with field
+//!   names, `NodeType` variant names, ranges and source text, for snapshot tests and bug reports
+//!
+//! ```toml
+//! tss-rust = { version = "0.2", features = ["node_full", "query", "tree-sitter"] }
+//! ```
+//!
+//! ## Grammar Version Features
+//!
+//! The node types come from tree-sitter-rust 0.24 by default. `grammar-0-23` uses the code
+//! generated from tree-sitter-rust 0.23 instead, for crates that cannot move to the newer release
+//! yet, and `grammar-0-24` selects 0.24 explicitly. Enabling more than one is a compile error, and
+//! [`NodeType::GRAMMAR_VERSION`] records the version that was used.
+//!
+//! With the `tree-sitter` feature, queries, symbols and the other parsing features use the
+//! `language()` of the same release. tree-sitter-rust 0.23 is an ABI 14 grammar, so with
+//! `grammar-0-23` this crate also builds against `tree-sitter` 0.24, while 0.24 needs `tree-sitter`
+//! 0.25.
+//!
+//! ```toml
+//! tss-rust = { version = "0.2", features = ["node_full", "grammar-0-23"] }
+//! ```
+//!
 //! ## Complete Feature Matrix
 //!
 //! ```toml
@@ -98,17 +168,24 @@
 //!
 //! # How It Works
 //!
-//! At build time, `tss-rust` reads the `NODE_TYPES` constant from the `tree-sitter-rust` crate
-//! and generates:
+//! The `tss-rust-codegen` tool in this crate's repository reads the `NODE_TYPES` constant of each
+//! supported `tree-sitter-rust` release and generates:
 //!
 //! - A `NodeType` enum with variants for all 280+ node types
 //! - `FromStr` implementation for parsing node type strings
 //! - `Display` implementation for converting back to strings
+//! - `NodeType::ALL`, listing every enabled variant
+//! - `variant_name()` and `feature_name()`, with `from_variant_name()` and `from_feature_name()`
+//!   going the other way, and `feature_for_variant()` and `feature_for_kind()`, which work with
+//!   every feature off, so tools can name the Cargo feature a node type needs
+//! - `NodeType::NAMES` and `NodeType::DEPRECATED`, the variant and feature of every node kind and
+//!   the deprecated variants, as data (see [`names`])
 //! - Documentation linking to Rust language reference where applicable
 //! - Feature-gated compilation so you only pay for what you use
 //!
-//! All generation happens at compile time with zero runtime dependencies. The generated code
-//! is feature-gated, so with no features enabled, the crate compiles to almost nothing.
+//! The generated code is committed under `src/generated/`, so building this crate needs no build
+//! script and no dependencies. It is feature-gated, so with no features enabled, the crate
+//! compiles to almost nothing.
 //!
 //! # Design Philosophy
 //!
@@ -118,14 +195,60 @@
 //! **Minimal Overhead**: With no default features, you explicitly choose what to include. Add
 //! only the node types you actually use in your tree-sitter traversals.
 //!
-//! **Build-Time Generation**: By generating at build time rather than using macros, we get
-//! better IDE support, faster compile times, and the ability to inspect generated code.
+//! **Ahead-of-Time Generation**: By committing generated code rather than using macros or a
+//! build script, we get better IDE support, faster compile times, and the ability to inspect
+//! generated code.
 
 #![allow(clippy::negative_feature_names)]
 #![allow(clippy::redundant_feature_names)]
 
-// The generated code is included from the build script output
-include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+#[cfg(all(feature = "grammar-0-23", feature = "grammar-0-24"))]
+compile_error!(
+    "the `grammar-0-23` and `grammar-0-24` features select different tree-sitter-rust versions, \
+     enable at most one"
+);
+
+// The generated code for the selected grammar, written by tss-rust-codegen
+include!("generated/nodes.rs");
+
+/// The tree-sitter-rust language to parse with: the release the node types were generated from,
+/// 0.23 with the `grammar-0-23` feature and 0.24 otherwise.
+#[cfg(feature = "tree-sitter")]
+#[must_use]
+pub fn language() -> tree_sitter::Language {
+    runtime::LANGUAGE.into()
+}
+
+// The tree-sitter-rust crate of the selected grammar, for its language and queries
+#[cfg(all(feature = "tree-sitter", not(feature = "grammar-0-23")))]
+use tree_sitter_rust as runtime;
+#[cfg(all(feature = "tree-sitter", feature = "grammar-0-23"))]
+use tree_sitter_rust_0_23 as runtime;
+
+#[cfg(feature = "meta")]
+pub mod meta;
+pub mod names;
+#[cfg(feature = "meta_fields")]
+pub use meta::FieldName;
+#[cfg(feature = "meta_highlights")]
+pub use meta::Highlight;
+#[cfg(feature = "meta_tokens")]
+pub use meta::{Associativity, TokenCategory};
+
+#[cfg(all(feature = "query", any(feature = "node", feature = "node_full")))]
+pub mod query;
+
+#[cfg(feature = "meta_tokens")]
+pub mod delimiters;
+
+#[cfg(feature = "symbols")]
+pub mod export;
+#[cfg(feature = "symbols")]
+pub mod outline;
+#[cfg(feature = "sexp")]
+pub mod sexp;
+#[cfg(feature = "symbols")]
+pub mod symbols;
 
 #[cfg(any(feature = "function_item", feature = "node_full"))]
 #[cfg(test)]
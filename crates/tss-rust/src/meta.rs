@@ -0,0 +1,141 @@
+//! Grammar metadata from tree-sitter-rust's `NODE_TYPES` and, for highlights, its
+//! `HIGHLIGHTS_QUERY`.
+//!
+//! Each category is gated on its own feature (`meta_named`, `meta_fields`, `meta_children`,
+//! `meta_subtypes`, `meta_tokens`, `meta_docs`, `meta_examples`, `meta_highlights`) and exposed
+//! as methods on [`NodeType`](crate::NodeType), for example
+//! [`NodeType::fields`](crate::NodeType::fields).
+//!
+//! Types referenced by fields, children and subtypes are recorded by their grammar kind as a
+//! [`TypeRef`] rather than as a `NodeType` variant, so the metadata is complete even when only a
+//! handful of node type features are enabled.
+
+/// A reference to a node type by its grammar kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeRef {
+    /// The kind string, e.g. `"identifier"`, `"_expression"` or `"("`.
+    pub kind: &'static str,
+    /// Whether the referenced node is named.
+    pub named: bool,
+}
+
+#[cfg(feature = "meta_subtypes")]
+impl TypeRef {
+    /// Whether a node of the given kind can appear where this type is expected.
+    ///
+    /// This is true for an exact match, or when this type is a supertype (such as
+    /// `_expression`) that has the given kind among its transitive subtypes.
+    #[must_use]
+    pub fn accepts(&self, kind: &str, named: bool) -> bool {
+        if self.kind == kind && self.named == named {
+            return true;
+        }
+        self.named
+            && subtypes_of(self.kind)
+                .iter()
+                .any(|subtype| subtype.accepts(kind, named))
+    }
+}
+
+/// A named field of a node, e.g. the `name` field of `function_item`.
+#[cfg(feature = "meta_fields")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field {
+    /// The field name.
+    pub name: FieldName,
+    /// Whether the field can hold more than one node.
+    pub multiple: bool,
+    /// Whether the field is always present.
+    pub required: bool,
+    /// The node types the field can hold.
+    pub types: &'static [TypeRef],
+}
+
+/// The children a node can have outside of its named fields.
+#[cfg(feature = "meta_children")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Children {
+    /// Whether there can be more than one child.
+    pub multiple: bool,
+    /// Whether at least one child is always present.
+    pub required: bool,
+    /// The node types the children can be.
+    pub types: &'static [TypeRef],
+}
+
+#[cfg(feature = "meta_fields")]
+impl crate::NodeType {
+    /// Look up one of this node's fields by name.
+    #[must_use]
+    pub fn field(self, name: FieldName) -> Option<&'static Field> {
+        self.fields().iter().find(|field| field.name == name)
+    }
+}
+
+/// The lexical category of a node, as returned by [`NodeType::category`](crate::NodeType::category).
+#[cfg(feature = "meta_tokens")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenCategory {
+    /// Keywords, including weak and reserved ones (`fn`, `union`, `self`, `mut`).
+    Keyword,
+    /// Operators (`+`, `&&`, `+=`, `..`, `?`).
+    Operator,
+    /// Delimiters and separators (`(`, `}`, `,`, `;`, `::`, `->`).
+    Punctuation,
+    /// Literals and their parts (`integer_literal`, `string_content`, `escape_sequence`).
+    Literal,
+    /// Comments and comment markers (`line_comment`, `//`, `/*`).
+    Comment,
+    /// Identifiers (`identifier`, `type_identifier`, `field_identifier`).
+    Identifier,
+    /// Everything else, including structural nodes such as `function_item`.
+    Other,
+}
+
+/// How a binary operator groups with others of the same precedence.
+#[cfg(feature = "meta_tokens")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a = b = c` is `a = (b = c)`.
+    Right,
+    /// `a == b == c` is an error: chains need parentheses.
+    None,
+}
+
+#[cfg(feature = "meta_tokens")]
+impl crate::NodeType {
+    /// Whether this is a keyword.
+    #[must_use]
+    pub const fn is_keyword(self) -> bool {
+        matches!(self.category(), TokenCategory::Keyword)
+    }
+
+    /// Whether this is an operator.
+    #[must_use]
+    pub const fn is_operator(self) -> bool {
+        matches!(self.category(), TokenCategory::Operator)
+    }
+
+    /// Whether this is punctuation (a delimiter or separator).
+    #[must_use]
+    pub const fn is_punctuation(self) -> bool {
+        matches!(self.category(), TokenCategory::Punctuation)
+    }
+
+    /// Whether this is a literal or part of one.
+    #[must_use]
+    pub const fn is_literal(self) -> bool {
+        matches!(self.category(), TokenCategory::Literal)
+    }
+
+    /// Whether this is a comment or comment marker.
+    #[must_use]
+    pub const fn is_comment(self) -> bool {
+        matches!(self.category(), TokenCategory::Comment)
+    }
+}
+
+// The generated metadata tables for the selected grammar, written by tss-rust-codegen
+include!("generated/meta.rs");
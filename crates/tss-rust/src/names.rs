@@ -0,0 +1,35 @@
+//! The names the generated code gives each node kind, as data.
+//!
+//! [`NodeType::NAMES`](crate::NodeType::NAMES) lists the variant and Cargo feature of every node
+//! kind, and [`NodeType::DEPRECATED`](crate::NodeType::DEPRECATED) the deprecated variants kept
+//! for kinds earlier grammar releases had. Both follow the `[package.metadata.tss.naming]` and
+//! `[package.metadata.tss.deprecated]` tables the code was generated with, and are there whichever
+//! features are enabled, so tools can name node kinds the way this crate does.
+
+/// A node kind and the names generated for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindNames {
+    /// The kind string, e.g. `function_item` or `::`.
+    pub kind: &'static str,
+    /// Whether the node is named.
+    pub named: bool,
+    /// The `NodeType` variant, e.g. `FunctionItem`.
+    pub variant: &'static str,
+    /// The Cargo feature gating the variant, e.g. `function_item`.
+    pub feature: &'static str,
+}
+
+/// A kind an earlier grammar release had, with its deprecated variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedKind {
+    /// The old kind string.
+    pub kind: &'static str,
+    /// Whether the old kind was named.
+    pub named: bool,
+    /// The old variant, e.g. `MacroInvocation`.
+    pub variant: &'static str,
+    /// The variant of the kind that replaces it, or `None` if it was dropped.
+    pub replacement: Option<&'static str>,
+    /// The deprecation note, e.g. `renamed to macro_call in grammar 0.24; use NodeType::MacroCall`.
+    pub note: &'static str,
+}
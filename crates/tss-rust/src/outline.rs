@@ -0,0 +1,123 @@
+//! A nested outline of the items in a tree-sitter-rust syntax tree, for editor sidebars.
+//!
+//! [`outline`] walks the tree matching node kinds as [`NodeType`]s rather than running a query.
+//! Modules, `impl` blocks and traits contain the items declared in them, enums contain their
+//! variants, and structs and unions contain their named fields. Function bodies are not
+//! descended into.
+//!
+//! ```
+//! use tree_sitter_symbols_rust::outline::outline;
+//! use tree_sitter_symbols_rust::NodeType;
+//!
+//! let source = "mod shapes { impl Circle { pub fn area(&self) -> f64 { 0.0 } } }";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_symbols_rust::language()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let items = outline(&tree, source.as_bytes());
+//! let (shapes, circle) = (&items[0], &items[0].children[0]);
+//! assert_eq!((shapes.kind, shapes.name.as_str()), (NodeType::ModItem, "shapes"));
+//! assert_eq!(circle.name, "impl Circle");
+//! let area = &circle.children[0];
+//! assert_eq!((area.kind, area.name.as_str()), (NodeType::FunctionItem, "area"));
+//! assert_eq!(area.visibility.as_deref(), Some("pub"));
+//! ```
+
+use crate::NodeType;
+use std::str::FromStr;
+
+/// An item in the outline, with the items declared inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineNode {
+    /// The item's node type, e.g. [`NodeType::ImplItem`] or [`NodeType::EnumVariant`].
+    pub kind: NodeType,
+    /// The item's name, or `impl Type` / `impl Trait for Type` for `impl` blocks.
+    pub name: String,
+    /// The visibility modifier as written, e.g. `pub` or `pub(crate)`.
+    pub visibility: Option<String>,
+    /// The whole item, including its body.
+    pub range: tree_sitter::Range,
+    /// The name identifier, or the implemented type for `impl` blocks.
+    pub selection_range: tree_sitter::Range,
+    /// The items declared inside this one, in source order.
+    pub children: Vec<OutlineNode>,
+}
+
+/// The outline of a tree-sitter-rust syntax tree of `source`.
+#[must_use]
+pub fn outline(tree: &tree_sitter::Tree, source: &[u8]) -> Vec<OutlineNode> {
+    items(tree.root_node(), source)
+}
+
+/// The outline nodes for the named children of `parent`.
+fn items(parent: tree_sitter::Node<'_>, source: &[u8]) -> Vec<OutlineNode> {
+    let mut cursor = parent.walk();
+    parent
+        .named_children(&mut cursor)
+        .filter_map(|node| item(node, source))
+        .collect()
+}
+
+fn item(node: tree_sitter::Node<'_>, source: &[u8]) -> Option<OutlineNode> {
+    let kind = NodeType::from_str(node.kind()).ok()?;
+    let (name, selection) = match kind {
+        NodeType::ImplItem => {
+            let ty = node.child_by_field_name("type")?;
+            let name = match node.child_by_field_name("trait") {
+                Some(tr) => format!("impl {} for {}", text(tr, source), text(ty, source)),
+                None => format!("impl {}", text(ty, source)),
+            };
+            (name, ty)
+        }
+        NodeType::AssociatedType
+        | NodeType::ConstItem
+        | NodeType::EnumItem
+        | NodeType::EnumVariant
+        | NodeType::FieldDeclaration
+        | NodeType::FunctionItem
+        | NodeType::FunctionSignatureItem
+        | NodeType::MacroDefinition
+        | NodeType::ModItem
+        | NodeType::StaticItem
+        | NodeType::StructItem
+        | NodeType::TraitItem
+        | NodeType::TypeItem
+        | NodeType::UnionItem => {
+            let name = node.child_by_field_name("name")?;
+            (text(name, source), name)
+        }
+        _ => return None,
+    };
+
+    let children = match kind {
+        NodeType::EnumItem
+        | NodeType::ImplItem
+        | NodeType::ModItem
+        | NodeType::StructItem
+        | NodeType::TraitItem
+        | NodeType::UnionItem => node
+            .child_by_field_name("body")
+            .map(|body| items(body, source))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    let mut cursor = node.walk();
+    let visibility = node
+        .named_children(&mut cursor)
+        .find(|child| NodeType::from_str(child.kind()) == Ok(NodeType::VisibilityModifier))
+        .map(|modifier| text(modifier, source));
+
+    Some(OutlineNode {
+        kind,
+        name,
+        visibility,
+        range: node.range(),
+        selection_range: selection.range(),
+        children,
+    })
+}
+
+fn text(node: tree_sitter::Node<'_>, source: &[u8]) -> String {
+    String::from_utf8_lossy(&source[node.byte_range()]).into_owned()
+}
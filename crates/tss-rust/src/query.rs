@@ -0,0 +1,319 @@
+//! Type-safe construction of tree-sitter query patterns.
+//!
+//! Hand-written S-expression queries such as `(function_item name: (identifier) @name)` only
+//! fail when they are compiled at runtime. [`Query`] builds the same pattern from [`NodeType`]
+//! and [`FieldName`] values and checks every field and child against the grammar metadata as
+//! the pattern is put together.
+//!
+//! ```
+//! # #[cfg(feature = "node_full")]
+//! # {
+//! use tree_sitter_symbols_rust::{FieldName, NodeType};
+//! use tree_sitter_symbols_rust::query::Query;
+//!
+//! let query = Query::node(NodeType::FunctionItem)
+//!     .field(FieldName::Name, Query::node(NodeType::Identifier).capture("name"));
+//! assert_eq!(query.to_source()?, "(function_item name: (identifier) @name)");
+//!
+//! // `struct_item` has no `parameters` field, so rendering fails
+//! let invalid = Query::node(NodeType::StructItem)
+//!     .field(FieldName::Parameters, Query::node(NodeType::Parameters));
+//! assert!(invalid.to_source().is_err());
+//! # }
+//! # Ok::<(), tree_sitter_symbols_rust::query::QueryError>(())
+//! ```
+//!
+//! With the `tree-sitter` feature enabled, [`Query::compile`] turns the pattern into a
+//! `tree_sitter::Query` for the tree-sitter-rust language.
+
+use crate::{FieldName, NodeType};
+use std::fmt;
+
+/// A single query pattern, built from typed node and field names.
+///
+/// Validation happens as the pattern is constructed: invalid fields or children are recorded
+/// and reported by [`Query::to_source`] (and [`Query::compile`]), so patterns can be chained
+/// without handling a `Result` at every step.
+#[derive(Debug)]
+pub struct Query {
+    node: Option<NodeType>,
+    fields: Vec<(FieldName, Query)>,
+    negated_fields: Vec<FieldName>,
+    children: Vec<Query>,
+    captures: Vec<String>,
+    errors: Vec<QueryError>,
+}
+
+/// An error found while building or compiling a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// The node has no field with this name.
+    UnknownField {
+        /// The node the field was used on.
+        node: NodeType,
+        /// The field that does not exist on the node.
+        field: FieldName,
+    },
+    /// The field exists, but cannot hold this node type.
+    InvalidFieldType {
+        /// The node the field was used on.
+        node: NodeType,
+        /// The field being matched.
+        field: FieldName,
+        /// The node type the field cannot hold.
+        child: NodeType,
+    },
+    /// The node can never have this node type as a child.
+    InvalidChild {
+        /// The parent node.
+        node: NodeType,
+        /// The node type that cannot appear under the parent.
+        child: NodeType,
+    },
+    /// Fields, children or negated fields were added to an unnamed node, which is matched by its
+    /// text alone, e.g. `"fn"`.
+    AnonymousNode(NodeType),
+    /// The capture name is empty or contains characters tree-sitter does not accept.
+    InvalidCapture(String),
+    /// tree-sitter rejected the rendered query.
+    #[cfg(feature = "tree-sitter")]
+    Compile {
+        /// The byte offset of the error in the rendered source.
+        offset: usize,
+        /// The message reported by tree-sitter.
+        message: String,
+    },
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownField { node, field } => {
+                write!(f, "`{node}` has no field `{field}`")
+            }
+            Self::InvalidFieldType { node, field, child } => {
+                write!(f, "field `{field}` of `{node}` cannot hold `{child}`")
+            }
+            Self::InvalidChild { node, child } => {
+                write!(f, "`{node}` cannot have `{child}` as a child")
+            }
+            Self::AnonymousNode(node) => {
+                let kind = node.to_string();
+                write!(f, "unnamed node `{kind:?}` cannot have fields or children")
+            }
+            Self::InvalidCapture(name) => write!(f, "invalid capture name `@{name}`"),
+            #[cfg(feature = "tree-sitter")]
+            Self::Compile { offset, message } => {
+                write!(f, "failed to compile query at byte {offset}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl Query {
+    /// A pattern matching nodes of the given type.
+    #[must_use]
+    pub fn node(node: NodeType) -> Self {
+        Self::new(Some(node))
+    }
+
+    /// A pattern matching any named node, written `(_)`.
+    #[must_use]
+    pub fn wildcard() -> Self {
+        Self::new(None)
+    }
+
+    fn new(node: Option<NodeType>) -> Self {
+        Self {
+            node,
+            fields: Vec::new(),
+            negated_fields: Vec::new(),
+            children: Vec::new(),
+            captures: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Match `child` in the named field `name` of this node.
+    #[must_use]
+    pub fn field(mut self, name: FieldName, mut child: Query) -> Self {
+        self.errors.append(&mut child.errors);
+        if let Some(node) = self.named_node() {
+            match node.field(name) {
+                None => self
+                    .errors
+                    .push(QueryError::UnknownField { node, field: name }),
+                Some(field) => {
+                    if let Some(kind) = child.node {
+                        if !accepts(field.types, kind) {
+                            self.errors.push(QueryError::InvalidFieldType {
+                                node,
+                                field: name,
+                                child: kind,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        self.fields.push((name, child));
+        self
+    }
+
+    /// Match `child` anywhere among this node's children, written `(parent (child))`.
+    ///
+    /// As in tree-sitter, this includes children held in fields. Only named children are
+    /// validated, since the grammar metadata does not list the anonymous tokens a node contains.
+    #[must_use]
+    pub fn child(mut self, mut child: Query) -> Self {
+        self.errors.append(&mut child.errors);
+        let named_child = child.node.filter(|kind| kind.is_named());
+        if let (Some(node), Some(kind)) = (self.named_node(), named_child) {
+            let in_children = node
+                .children()
+                .is_some_and(|children| accepts(children.types, kind));
+            let in_fields = node.fields().iter().any(|field| accepts(field.types, kind));
+            if !in_children && !in_fields {
+                self.errors
+                    .push(QueryError::InvalidChild { node, child: kind });
+            }
+        }
+        self.children.push(child);
+        self
+    }
+
+    /// Only match nodes that do not have the field `name`, written `!name`.
+    #[must_use]
+    pub fn without_field(mut self, name: FieldName) -> Self {
+        if let Some(node) = self.named_node() {
+            if node.field(name).is_none() {
+                self.errors
+                    .push(QueryError::UnknownField { node, field: name });
+            }
+        }
+        self.negated_fields.push(name);
+        self
+    }
+
+    /// The node type of this pattern if it can have fields and children, recording an error if it
+    /// is an unnamed node.
+    fn named_node(&mut self) -> Option<NodeType> {
+        let node = self.node?;
+        if node.is_named() {
+            Some(node)
+        } else {
+            if !self.errors.contains(&QueryError::AnonymousNode(node)) {
+                self.errors.push(QueryError::AnonymousNode(node));
+            }
+            None
+        }
+    }
+
+    /// Capture the matched node as `@name`.
+    #[must_use]
+    pub fn capture(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !valid {
+            self.errors.push(QueryError::InvalidCapture(name.clone()));
+        }
+        self.captures.push(name);
+        self
+    }
+
+    /// The errors found so far while building this pattern (including nested patterns).
+    #[must_use]
+    pub fn errors(&self) -> &[QueryError] {
+        &self.errors
+    }
+
+    /// Render the pattern as tree-sitter query source.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error found while building the pattern.
+    pub fn to_source(&self) -> Result<String, QueryError> {
+        if let Some(err) = self.errors.first() {
+            return Err(err.clone());
+        }
+        let mut out = String::new();
+        self.render(&mut out);
+        Ok(out)
+    }
+
+    /// Render the pattern and compile it for the tree-sitter-rust language.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error found while building the pattern, or the error reported by
+    /// tree-sitter if it rejects the rendered source.
+    #[cfg(feature = "tree-sitter")]
+    pub fn compile(&self) -> Result<tree_sitter::Query, QueryError> {
+        let source = self.to_source()?;
+        tree_sitter::Query::new(&crate::language(), &source).map_err(|err| QueryError::Compile {
+            offset: err.offset,
+            message: err.message,
+        })
+    }
+
+    fn render(&self, out: &mut String) {
+        match self.node {
+            Some(node) if !node.is_named() => {
+                out.push('"');
+                for c in node.to_string().chars() {
+                    if matches!(c, '"' | '\\') {
+                        out.push('\\');
+                    }
+                    out.push(c);
+                }
+                out.push('"');
+            }
+            node => {
+                out.push('(');
+                match node {
+                    Some(node) => out.push_str(&node.to_string()),
+                    None => out.push('_'),
+                }
+                for (name, child) in &self.fields {
+                    out.push(' ');
+                    out.push_str(name.as_str());
+                    out.push_str(": ");
+                    child.render(out);
+                }
+                for child in &self.children {
+                    out.push(' ');
+                    child.render(out);
+                }
+                for name in &self.negated_fields {
+                    out.push_str(" !");
+                    out.push_str(name.as_str());
+                }
+                out.push(')');
+            }
+        }
+        for capture in &self.captures {
+            out.push_str(" @");
+            out.push_str(capture);
+        }
+    }
+}
+
+fn accepts(types: &[crate::meta::TypeRef], node: NodeType) -> bool {
+    let kind = node.to_string();
+    let named = node.is_named();
+    types.iter().any(|t| t.accepts(&kind, named))
+}
+
+impl fmt::Display for Query {
+    /// Render the pattern as query source, including any invalid parts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.render(&mut out);
+        f.write_str(&out)
+    }
+}
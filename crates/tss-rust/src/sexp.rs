@@ -0,0 +1,173 @@
+//! An indented S-expression printer for tree-sitter-rust syntax trees.
+//!
+//! [`pretty`] writes one node per line, labelled with its grammar kind or [`NodeType`] variant
+//! name and the [`FieldName`](crate::FieldName) it fills in its parent, optionally with its range
+//! and, for nodes printed without children, its source text. Unnamed tokens and extras (comments)
+//! can be left out using the [`is_named`](NodeType::is_named) and
+//! [`is_extra`](NodeType::is_extra) metadata, which makes the output stable enough for snapshot
+//! tests and readable in bug reports.
+//!
+//! ```
+//! use tree_sitter_symbols_rust::sexp::{pretty, Options};
+//!
+//! let source = "fn f() {} // done";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_symbols_rust::language()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let options = Options {
+//!     text: true,
+//!     ..Options::default()
+//! };
+//! assert_eq!(
+//!     pretty(tree.root_node(), source.as_bytes(), &options),
+//!     "(source_file
+//!   (function_item
+//!     name: (identifier \"f\")
+//!     parameters: (parameters \"()\")
+//!     body: (block \"{}\"))
+//!   (line_comment \"// done\"))"
+//! );
+//! ```
+//!
+//! Kinds that have no `NodeType` variant, such as `ERROR` or the kinds of node type features that
+//! are not enabled, are printed as they are, and whether they are named or extras is read from the
+//! node itself.
+
+use crate::{FieldName, NodeType};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// What to include in the output of [`pretty`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// Label nodes with their `NodeType` variant, e.g. `FunctionItem` or `FnKwToken`, instead of
+    /// their grammar kind.
+    pub variant_names: bool,
+    /// Include unnamed tokens such as `fn` and `(`, which are quoted when labelled by kind.
+    pub unnamed: bool,
+    /// Include extras: line and block comments.
+    pub extras: bool,
+    /// Follow each label with the node's range, as `[row, column] - [row, column]`.
+    pub ranges: bool,
+    /// Follow the label of each node printed without children with its source text, as a quoted
+    /// string.
+    pub text: bool,
+}
+
+impl Default for Options {
+    /// Named nodes and extras by kind, like `tree_sitter::Node::to_sexp` with field names.
+    fn default() -> Self {
+        Self {
+            variant_names: false,
+            unnamed: false,
+            extras: true,
+            ranges: false,
+            text: false,
+        }
+    }
+}
+
+/// `node` and the nodes under it as an indented S-expression, without a trailing newline.
+#[must_use]
+pub fn pretty(node: tree_sitter::Node<'_>, source: &[u8], options: &Options) -> String {
+    let mut out = String::new();
+    write_node(&mut out, node, None, 0, source, options);
+    out
+}
+
+/// The `NodeType` of a node, if its kind has an enabled variant.
+fn node_type(node: tree_sitter::Node<'_>) -> Option<NodeType> {
+    // `FromStr` only parses named kinds
+    static UNNAMED: OnceLock<HashMap<String, NodeType>> = OnceLock::new();
+    if node.is_named() {
+        return NodeType::from_str(node.kind()).ok();
+    }
+    UNNAMED
+        .get_or_init(|| {
+            NodeType::ALL
+                .iter()
+                .filter(|node_type| !node_type.is_named())
+                .map(|node_type| (node_type.to_string(), *node_type))
+                .collect()
+        })
+        .get(node.kind())
+        .copied()
+}
+
+fn is_shown(node: tree_sitter::Node<'_>, options: &Options) -> bool {
+    let kind = node_type(node);
+    let named = kind.map_or_else(|| node.is_named(), NodeType::is_named);
+    let extra = kind.map_or_else(|| node.is_extra(), NodeType::is_extra);
+    (options.unnamed || named || node.is_missing()) && (options.extras || !extra)
+}
+
+fn label(node: tree_sitter::Node<'_>, options: &Options) -> String {
+    let kind = match node_type(node) {
+        Some(node_type) if options.variant_names => node_type.variant_name().to_string(),
+        _ if node.is_named() => node.kind().to_string(),
+        _ => format!("{:?}", node.kind()),
+    };
+    if node.is_missing() {
+        format!("MISSING {kind}")
+    } else {
+        kind
+    }
+}
+
+fn write_node(
+    out: &mut String,
+    node: tree_sitter::Node<'_>,
+    field: Option<&str>,
+    depth: usize,
+    source: &[u8],
+    options: &Options,
+) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+    if let Some(field) = field {
+        match FieldName::from_str(field) {
+            Ok(field) => out.push_str(field.as_str()),
+            Err(_) => out.push_str(field),
+        }
+        out.push_str(": ");
+    }
+    out.push('(');
+    out.push_str(&label(node, options));
+    if options.ranges {
+        let (start, end) = (node.start_position(), node.end_position());
+        let _ = write!(
+            out,
+            " [{}, {}] - [{}, {}]",
+            start.row, start.column, end.row, end.column
+        );
+    }
+
+    let mut cursor = node.walk();
+    let mut children = Vec::new();
+    if cursor.goto_first_child() {
+        loop {
+            let child = cursor.node();
+            if is_shown(child, options) {
+                children.push((child, cursor.field_name()));
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    if options.text && children.is_empty() && !node.is_missing() {
+        let text = String::from_utf8_lossy(&source[node.byte_range()]);
+        let _ = write!(out, " {text:?}");
+    }
+    for (child, field) in children {
+        out.push('\n');
+        write_node(out, child, field, depth + 1, source, options);
+    }
+    out.push(')');
+}
@@ -0,0 +1,284 @@
+//! Symbol definitions and references from tree-sitter-rust's `TAGS_QUERY`.
+//!
+//! [`symbols`] runs the tags query that ships with tree-sitter-rust over a parsed tree and returns
+//! a [`Symbol`] for every function, method, struct, enum, union, type alias, trait, module and
+//! macro definition, every `impl` and every call or macro invocation. `TAGS_QUERY` has no
+//! patterns for constants or calls through a path, so `const` and `static` items are tagged as
+//! `definition.constant` and calls such as `Vec::new()` as `reference.call` in addition. An `impl`
+//! is named after its type, without generics or path (`Wrap` for `impl<T> a::Wrap<T>`), and the
+//! trait it implements, which `TAGS_QUERY` names trait impls after, is kept in
+//! [`Symbol::implements`].
+//!
+//! ```
+//! use tree_sitter_symbols_rust::symbols::{symbols, Role};
+//! use tree_sitter_symbols_rust::NodeType;
+//!
+//! let source = "struct Point;\nimpl Point {\n    fn new() -> Self { Point }\n}\n";
+//! let mut parser = tree_sitter::Parser::new();
+//! parser.set_language(&tree_sitter_symbols_rust::language()).unwrap();
+//! let tree = parser.parse(source, None).unwrap();
+//!
+//! let found = symbols(&tree, source.as_bytes())?;
+//! let new = found.iter().find(|symbol| symbol.name == "new").unwrap();
+//! assert_eq!((new.kind, new.role), (NodeType::FunctionItem, Role::Definition));
+//! assert_eq!(new.syntax, "method");
+//! assert_eq!(found[new.parent.unwrap()].kind, NodeType::ImplItem);
+//! # Ok::<(), tree_sitter_symbols_rust::symbols::SymbolsError>(())
+//! ```
+
+use crate::NodeType;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use streaming_iterator::StreamingIterator;
+
+/// Patterns for the symbols `TAGS_QUERY` leaves out.
+const EXTRA_TAGS: &str = "
+(const_item
+    name: (identifier) @name) @definition.constant
+
+(static_item
+    name: (identifier) @name) @definition.constant
+
+(call_expression
+    function: (scoped_identifier
+        name: (identifier) @name)) @reference.call
+
+(impl_item
+    type: (type_identifier) @name) @reference.implementation
+
+(impl_item
+    type: (scoped_type_identifier
+        name: (type_identifier) @name)) @reference.implementation
+
+(impl_item
+    type: (generic_type
+        type: [
+            (type_identifier) @name
+            (scoped_type_identifier
+                name: (type_identifier) @name)
+        ])) @reference.implementation
+";
+
+/// Whether a symbol is defined or used where it appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// The symbol is defined here, e.g. a `function_item`.
+    Definition,
+    /// The symbol is used here, e.g. a `call_expression`, or implemented by an `impl_item`.
+    Reference,
+}
+
+/// A definition or reference found by the tags query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// The node the tag is on, e.g. [`NodeType::FunctionItem`] or [`NodeType::CallExpression`].
+    pub kind: NodeType,
+    /// Whether it is a definition or a reference.
+    pub role: Role,
+    /// The tag's syntax type, e.g. `"function"`, `"method"`, `"class"` or `"call"`.
+    pub syntax: String,
+    /// The symbol's name.
+    pub name: String,
+    /// The whole node, e.g. the function including its body.
+    pub range: tree_sitter::Range,
+    /// Just the name.
+    pub name_range: tree_sitter::Range,
+    /// The parameters and return type of a function definition, e.g. `(x: i32) -> i32`, with
+    /// line breaks and indentation collapsed to single spaces.
+    pub signature: Option<String>,
+    /// The index of the innermost definition or `impl` containing this symbol.
+    pub parent: Option<usize>,
+    /// The trait an `impl` implements, named like its type, e.g. `Display` for
+    /// `impl fmt::Display for Point`.
+    pub implements: Option<String>,
+}
+
+impl Symbol {
+    /// Whether other symbols can have this one as their parent.
+    fn is_container(&self) -> bool {
+        self.role == Role::Definition || self.kind == NodeType::ImplItem
+    }
+}
+
+/// An error finding symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolsError {
+    /// tree-sitter rejected the tags query, which means the `tree-sitter` in use cannot load the
+    /// tree-sitter-rust language (tree-sitter-rust 0.24 needs `tree-sitter` 0.25).
+    Compile {
+        /// The byte offset of the error in the query source.
+        offset: usize,
+        /// The message reported by tree-sitter.
+        message: String,
+    },
+}
+
+impl fmt::Display for SymbolsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compile { offset, message } => {
+                write!(
+                    f,
+                    "failed to compile the tags query at byte {offset}: {message}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SymbolsError {}
+
+fn query() -> Result<&'static tree_sitter::Query, SymbolsError> {
+    static QUERY: OnceLock<Result<tree_sitter::Query, SymbolsError>> = OnceLock::new();
+    QUERY
+        .get_or_init(|| {
+            let source = format!("{}\n{EXTRA_TAGS}", crate::runtime::TAGS_QUERY);
+            tree_sitter::Query::new(&crate::language(), &source).map_err(|err| {
+                SymbolsError::Compile {
+                    offset: err.offset,
+                    message: err.message,
+                }
+            })
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// The symbols in a tree-sitter-rust syntax tree of `source`, in source order.
+///
+/// A node tagged by more than one pattern (a method is also a function) is reported once, with
+/// the tag of the pattern that comes first in the query. The query tags every function in a
+/// `declaration_list` as a method, so functions directly inside a `mod` are reported as functions.
+///
+/// # Errors
+///
+/// Returns an error if the tags query does not compile for the tree-sitter-rust language, which
+/// means the `tree-sitter` in use comes from an incompatible release.
+pub fn symbols(tree: &tree_sitter::Tree, source: &[u8]) -> Result<Vec<Symbol>, SymbolsError> {
+    let query = query()?;
+    let names = query.capture_names();
+    let Some(name_index) = query.capture_index_for_name("name") else {
+        return Ok(Vec::new());
+    };
+
+    // The first pattern to tag each node
+    let mut tagged: HashMap<usize, (usize, Symbol)> = HashMap::new();
+    let mut cursor = tree_sitter::QueryCursor::new();
+    let mut matches = cursor.matches(query, tree.root_node(), source);
+    while let Some(found) = matches.next() {
+        let mut name = None;
+        let mut tag = None;
+        for capture in found.captures {
+            if capture.index == name_index {
+                name = Some(capture.node);
+            } else if let Some((role, syntax)) = parse_tag(names[capture.index as usize]) {
+                tag = Some((capture.node, role, syntax));
+            }
+        }
+        let (Some(name), Some((node, role, syntax))) = (name, tag) else {
+            continue;
+        };
+        let Ok(kind) = NodeType::from_str(node.kind()) else {
+            continue;
+        };
+        // `TAGS_QUERY` names trait impls after the trait; they are named after the type instead
+        let implements = node.child_by_field_name("trait");
+        if implements == Some(name) {
+            continue;
+        }
+        if tagged
+            .get(&node.id())
+            .is_some_and(|(pattern, _)| *pattern <= found.pattern_index)
+        {
+            continue;
+        }
+        let symbol = Symbol {
+            kind,
+            role,
+            syntax: syntax.to_string(),
+            name: String::from_utf8_lossy(&source[name.byte_range()]).into_owned(),
+            range: node.range(),
+            name_range: name.range(),
+            signature: (role == Role::Definition)
+                .then(|| signature(node, source))
+                .flatten(),
+            parent: None,
+            implements: implements
+                .and_then(type_name)
+                .map(|name| String::from_utf8_lossy(&source[name.byte_range()]).into_owned()),
+        };
+        tagged.insert(node.id(), (found.pattern_index, symbol));
+    }
+
+    let mut symbols: Vec<Symbol> = tagged.into_values().map(|(_, symbol)| symbol).collect();
+    symbols.sort_by_key(|symbol| {
+        (
+            symbol.range.start_byte,
+            std::cmp::Reverse(symbol.range.end_byte),
+        )
+    });
+
+    // The containers enclosing the current symbol, innermost last
+    let mut open: Vec<usize> = Vec::new();
+    for i in 0..symbols.len() {
+        let range = symbols[i].range;
+        while open
+            .last()
+            .is_some_and(|&parent| symbols[parent].range.end_byte < range.end_byte)
+        {
+            open.pop();
+        }
+        symbols[i].parent = open.last().copied();
+        if symbols[i].syntax == "method"
+            && open
+                .last()
+                .is_some_and(|&parent| symbols[parent].kind == NodeType::ModItem)
+        {
+            symbols[i].syntax = "function".to_string();
+        }
+        if symbols[i].is_container() {
+            open.push(i);
+        }
+    }
+    Ok(symbols)
+}
+
+/// The `type_identifier` naming a type, without its generic arguments or path.
+fn type_name(node: tree_sitter::Node<'_>) -> Option<tree_sitter::Node<'_>> {
+    match node.kind() {
+        "type_identifier" => Some(node),
+        "generic_type" => type_name(node.child_by_field_name("type")?),
+        "scoped_type_identifier" => node.child_by_field_name("name"),
+        _ => None,
+    }
+}
+
+/// The parameters and return type of a `function_item` or `function_signature_item`.
+fn signature(node: tree_sitter::Node<'_>, source: &[u8]) -> Option<String> {
+    let parameters = node.child_by_field_name("parameters")?;
+    let end = node
+        .child_by_field_name("return_type")
+        .unwrap_or(parameters)
+        .end_byte();
+    let text = String::from_utf8_lossy(&source[parameters.start_byte()..end]);
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some(
+        collapsed
+            .replace("( ", "(")
+            .replace(", )", ")")
+            .replace(" )", ")"),
+    )
+}
+
+/// The role and syntax type of a capture such as `definition.function`.
+fn parse_tag(capture: &str) -> Option<(Role, &str)> {
+    if let Some(syntax) = capture.strip_prefix("definition.") {
+        Some((Role::Definition, syntax))
+    } else {
+        capture
+            .strip_prefix("reference.")
+            .map(|syntax| (Role::Reference, syntax))
+    }
+}
@@ -13,6 +13,19 @@ fn enum_exists() {
     println!("{}", std::any::type_name::<NodeType>());
 }
 
+// The grammar-* features pick the tree-sitter-rust release the enum is generated from
+#[test]
+fn grammar_version() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    let expected = if cfg!(feature = "grammar-0-23") {
+        "0.23"
+    } else {
+        "0.24"
+    };
+    assert_eq!(NodeType::GRAMMAR_VERSION, expected);
+}
+
 #[cfg(any(
     feature = "function_item",
     feature = "struct_item",
@@ -105,3 +118,130 @@ fn roundtrip() {
     let displayed = parsed.to_string();
     assert_eq!(original, displayed);
 }
+
+// Kinds, variants and features map to each other at runtime
+#[cfg(any(feature = "path_sep_token", feature = "node_full"))]
+#[test]
+fn variant_and_feature_names() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    let path_sep = NodeType::PathSepToken;
+    assert_eq!(path_sep.to_string(), "::");
+    assert_eq!(path_sep.variant_name(), "PathSepToken");
+    assert_eq!(path_sep.feature_name(), "path_sep_token");
+    assert_eq!(NodeType::from_variant_name("PathSepToken"), Some(path_sep));
+    assert_eq!(
+        NodeType::from_feature_name("path_sep_token"),
+        Some(path_sep)
+    );
+    assert_eq!(NodeType::from_variant_name("path_sep_token"), None);
+    assert_eq!(NodeType::from_feature_name("::"), None);
+}
+
+// Every kind and variant names its feature, enabled or not
+#[test]
+fn features_are_named_whether_or_not_enabled() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    assert_eq!(
+        NodeType::feature_for_variant("FunctionItem"),
+        Some("function_item")
+    );
+    assert_eq!(NodeType::feature_for_variant("function_item"), None);
+    assert_eq!(
+        NodeType::feature_for_kind("::", false),
+        Some("path_sep_token")
+    );
+    // `block` is both a named node and an unnamed token
+    assert_eq!(NodeType::feature_for_kind("block", true), Some("block"));
+    assert_eq!(
+        NodeType::feature_for_kind("block", false),
+        Some("block_token")
+    );
+    assert_eq!(NodeType::feature_for_kind("::", true), None);
+}
+
+// The name tables agree with the feature lookups
+#[test]
+fn name_tables_list_every_kind() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    for names in NodeType::NAMES {
+        assert_eq!(
+            NodeType::feature_for_variant(names.variant),
+            Some(names.feature)
+        );
+        assert_eq!(
+            NodeType::feature_for_kind(names.kind, names.named),
+            Some(names.feature)
+        );
+    }
+    assert!(NodeType::NAMES
+        .iter()
+        .any(|names| (names.kind, names.named, names.variant) == ("::", false, "PathSepToken")));
+}
+
+// Parsing a kind whose feature is off says which feature to enable
+#[cfg(all(
+    feature = "node",
+    not(any(feature = "struct_item", feature = "node_full"))
+))]
+#[test]
+fn parsing_a_disabled_kind_names_its_feature() {
+    use std::str::FromStr;
+    use tree_sitter_symbols_rust::NodeType;
+
+    assert_eq!(
+        NodeType::from_str("struct_item"),
+        Err("Node type struct_item needs the `struct_item` feature".to_string())
+    );
+    assert_eq!(
+        NodeType::from_str("struct_itme"),
+        Err("Unknown node type: struct_itme".to_string())
+    );
+}
+
+// Group presets enable every node type in their category
+#[cfg(all(feature = "group_items", feature = "group_punctuation"))]
+#[test]
+fn group_features_enable_their_members() {
+    use std::str::FromStr;
+    use tree_sitter_symbols_rust::NodeType;
+
+    for item in [
+        NodeType::FunctionItem,
+        NodeType::StructItem,
+        NodeType::ImplItem,
+        NodeType::UseDeclaration,
+    ] {
+        assert_eq!(NodeType::from_str(&item.to_string()), Ok(item));
+    }
+    assert_eq!(NodeType::LParenToken.to_string(), "(");
+    assert_eq!(NodeType::SemicolonToken.to_string(), ";");
+}
+
+// Node kinds link to the Rust Reference section describing them
+#[cfg(all(feature = "meta_docs", feature = "node_full"))]
+#[test]
+fn reference_urls() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    let base = "https://doc.rust-lang.org/reference/";
+    for (node, path) in [
+        (NodeType::FunctionItem, "items/functions.html"),
+        (NodeType::ImplItem, "items/implementations.html"),
+        (NodeType::MatchExpression, "expressions/match-expr.html"),
+        (NodeType::ClosureExpression, "expressions/closure-expr.html"),
+        (NodeType::WhereClause, "items/generics.html#where-clauses"),
+        (NodeType::Lifetime, "tokens.html#lifetimes-and-loop-labels"),
+        (NodeType::FnKwToken, "keywords.html"),
+        (NodeType::PlusToken, "tokens.html#punctuation"),
+    ] {
+        assert_eq!(
+            node.reference_url().and_then(|url| url.strip_prefix(base)),
+            Some(path),
+            "{node}"
+        );
+    }
+    assert_eq!(NodeType::GenBlock.reference_url(), None);
+}
@@ -0,0 +1,137 @@
+//! Integration tests for paired delimiters.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(feature = "meta_tokens", feature = "node_full"))]
+
+use tree_sitter_symbols_rust::delimiters::{match_delimiters, Pair};
+use tree_sitter_symbols_rust::NodeType;
+
+#[test]
+fn pairs_delimiters_both_ways() {
+    let pairs = [
+        (NodeType::LParenToken, NodeType::RParenToken),
+        (NodeType::LBracketToken, NodeType::RBracketToken),
+        (NodeType::LBraceToken, NodeType::RBraceToken),
+        (
+            NodeType::BlockCommentStartToken,
+            NodeType::BlockCommentEndToken,
+        ),
+    ];
+    for (open, close) in pairs {
+        assert!(
+            open.is_open_delimiter() && !open.is_close_delimiter(),
+            "{open}"
+        );
+        assert!(
+            close.is_close_delimiter() && !close.is_open_delimiter(),
+            "{close}"
+        );
+        assert_eq!(open.matching_delimiter(), Some(close));
+        assert_eq!(close.matching_delimiter(), Some(open));
+        assert_eq!(NodeType::from_delimiter(&open.to_string()), Some(open));
+    }
+    assert_eq!(NodeType::LtToken.matching_delimiter(), None);
+    assert_eq!(NodeType::Block.matching_delimiter(), None);
+    assert_eq!(NodeType::from_delimiter("block"), None);
+}
+
+#[test]
+fn delimiters_open_or_close() {
+    for node_type in NodeType::ALL {
+        assert_eq!(
+            node_type.is_open_delimiter() || node_type.is_close_delimiter(),
+            node_type.is_delimiter(),
+            "{node_type}"
+        );
+    }
+}
+
+#[test]
+fn matches_nested_delimiters_with_depth() {
+    // `{ f(a[0]) }`
+    let tokens = [
+        (NodeType::LBraceToken, 0),
+        (NodeType::Identifier, 2),
+        (NodeType::LParenToken, 3),
+        (NodeType::LBracketToken, 5),
+        (NodeType::RBracketToken, 7),
+        (NodeType::RParenToken, 8),
+        (NodeType::RBraceToken, 10),
+    ];
+    let matches = match_delimiters(tokens);
+    assert_eq!(
+        matches.pairs,
+        [
+            Pair {
+                open: 0,
+                close: 10,
+                depth: 0
+            },
+            Pair {
+                open: 3,
+                close: 8,
+                depth: 1
+            },
+            Pair {
+                open: 5,
+                close: 7,
+                depth: 2
+            },
+        ]
+    );
+    assert!(matches.unmatched.is_empty());
+}
+
+#[test]
+fn reports_unmatched_delimiters() {
+    // `) ( [ ) {`
+    let tokens = [
+        (NodeType::RParenToken, 0),
+        (NodeType::LParenToken, 1),
+        (NodeType::LBracketToken, 2),
+        (NodeType::RParenToken, 3),
+        (NodeType::LBraceToken, 4),
+    ];
+    let matches = match_delimiters(tokens);
+    assert_eq!(
+        matches.pairs,
+        [Pair {
+            open: 1,
+            close: 3,
+            depth: 0
+        }]
+    );
+    assert_eq!(matches.unmatched, [0, 2, 4]);
+}
+
+#[cfg(feature = "tree-sitter")]
+#[test]
+fn matches_delimiters_in_a_tree() {
+    use tree_sitter_symbols_rust::delimiters::tree_delimiters;
+
+    let source = "fn f() { g([1]) /* x */ }";
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+
+    let matches = tree_delimiters(&tree);
+    let spans: Vec<(&str, usize, usize)> = matches
+        .pairs
+        .iter()
+        .map(|pair| (pair.open.kind(), pair.open.start_byte(), pair.depth))
+        .collect();
+    assert_eq!(
+        spans,
+        [
+            ("(", 4, 0),
+            ("{", 7, 0),
+            ("(", 10, 1),
+            ("[", 11, 2),
+            ("/*", 16, 1)
+        ]
+    );
+    assert!(matches.unmatched.is_empty());
+}
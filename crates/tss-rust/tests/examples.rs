@@ -0,0 +1,78 @@
+//! Every node type's example snippet parses and contains that node.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(
+    feature = "meta_examples",
+    feature = "meta_named",
+    feature = "meta_subtypes",
+    feature = "node_full",
+    feature = "tree-sitter"
+))]
+
+use tree_sitter_symbols_rust::NodeType;
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+/// The kind and namedness of every node in `tree`.
+fn kinds(tree: &tree_sitter::Tree) -> Vec<(String, bool)> {
+    let mut kinds = Vec::new();
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        kinds.push((node.kind().to_string(), node.is_named()));
+        if cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    kinds
+}
+
+#[test]
+fn every_node_type_has_an_example() {
+    let missing: Vec<NodeType> = NodeType::ALL
+        .iter()
+        .copied()
+        .filter(|node| node.example().is_none())
+        .collect();
+    assert!(missing.is_empty(), "no example for {missing:?}");
+}
+
+#[test]
+fn examples_parse_and_contain_their_node() {
+    let mut failures = Vec::new();
+    for &node in NodeType::ALL {
+        let Some(example) = node.example() else {
+            continue;
+        };
+        let tree = parse(example);
+        if tree.root_node().has_error() {
+            failures.push(format!("{node:?}: {example:?} does not parse"));
+            continue;
+        }
+        // Supertypes never appear in a tree, so look for one of their subtypes instead
+        let subtypes = node.subtypes();
+        let found = kinds(&tree).iter().any(|(kind, named)| {
+            if subtypes.is_empty() {
+                *kind == node.to_string() && *named == node.is_named()
+            } else {
+                subtypes.iter().any(|subtype| subtype.accepts(kind, *named))
+            }
+        });
+        if !found {
+            failures.push(format!("{node:?}: {example:?} has no `{node}` node"));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,108 @@
+//! Universal Ctags and LSP symbol JSON writers.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(feature = "symbols")]
+
+use tree_sitter_symbols_rust::export::{
+    ctags_entries, ctags_file, document_symbols, symbol_information, SymbolKind,
+};
+use tree_sitter_symbols_rust::outline::outline;
+use tree_sitter_symbols_rust::symbols::symbols;
+use tree_sitter_symbols_rust::NodeType;
+
+const SOURCE: &str = "mod shapes {
+    pub struct Circle { radius: f64 }
+    impl Circle {
+        pub fn area(&self,
+                    scale: f64) -> f64 { 0.0 }
+    }
+    const PI: f64 = 3.14;
+}
+fn main() { shapes::Circle::area(); }
+";
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+#[test]
+fn generated_kinds() {
+    assert_eq!(NodeType::StructItem.ctags_kind(), Some("struct"));
+    assert_eq!(NodeType::ImplItem.ctags_kind(), Some("implementation"));
+    assert_eq!(
+        NodeType::EnumVariant.lsp_symbol_kind(),
+        Some(SymbolKind::EnumMember)
+    );
+    assert_eq!(NodeType::CallExpression.ctags_kind(), None);
+    assert_eq!(NodeType::CallExpression.lsp_symbol_kind(), None);
+    assert_eq!(SymbolKind::Function.number(), 12);
+}
+
+#[test]
+fn ctags() {
+    let tree = parse(SOURCE);
+    let entries = ctags_entries("src/lib.rs", &symbols(&tree, SOURCE.as_bytes()).unwrap());
+    assert_eq!(
+        entries,
+        [
+            "shapes\tsrc/lib.rs\t1;\"\tkind:module\tline:1",
+            "Circle\tsrc/lib.rs\t2;\"\tkind:struct\tline:2\tscope:module:shapes",
+            "Circle\tsrc/lib.rs\t3;\"\tkind:implementation\tline:3\tscope:module:shapes",
+            "area\tsrc/lib.rs\t4;\"\tkind:method\tline:4\tscope:implementation:shapes::Circle\tsignature:(&self, scale: f64) -> f64",
+            "PI\tsrc/lib.rs\t7;\"\tkind:variable\tline:7\tscope:module:shapes",
+            "main\tsrc/lib.rs\t9;\"\tkind:function\tline:9\tsignature:()",
+        ]
+    );
+
+    let file = ctags_file(entries);
+    let lines: Vec<_> = file.lines().collect();
+    assert!(lines[..3].iter().all(|line| line.starts_with("!_TAG_")));
+    assert!(lines[3].starts_with("Circle\t"));
+    assert!(lines[8].starts_with("shapes\t"));
+}
+
+#[test]
+fn document_symbols_nest() {
+    let tree = parse(SOURCE);
+    let json = document_symbols(&outline(&tree, SOURCE.as_bytes()), SOURCE.as_bytes());
+    assert!(json.starts_with(concat!(
+        r#"[{"name":"shapes","kind":2,"#,
+        r#""range":{"start":{"line":0,"character":0},"end":{"line":7,"character":1}},"#,
+        r#""selectionRange":{"start":{"line":0,"character":4},"end":{"line":0,"character":10}},"#,
+        r#""children":[{"name":"Circle","kind":23,"#,
+    )));
+    // `area` is a method and the struct field a field
+    assert!(json.contains(r#"{"name":"area","kind":6,"#));
+    assert!(json.contains(r#"{"name":"radius","kind":8,"#));
+    assert!(json.contains(r#"{"name":"main","kind":12,"#));
+    assert!(json.ends_with("\"children\":[]}]"));
+}
+
+#[test]
+fn symbol_information_is_flat() {
+    let source = "/* é */ struct S;\nimpl S { fn new() { g(); } }\n";
+    let tree = parse(source);
+    let json = symbol_information(
+        "file:///a.rs",
+        &symbols(&tree, source.as_bytes()).unwrap(),
+        source.as_bytes(),
+    );
+    // `é` is two bytes but one UTF-16 code unit, and the call to `g` is left out
+    assert_eq!(
+        json,
+        concat!(
+            r#"[{"name":"S","kind":23,"location":{"uri":"file:///a.rs","range":"#,
+            r#"{"start":{"line":0,"character":8},"end":{"line":0,"character":17}}}},"#,
+            r#"{"name":"S","kind":19,"location":{"uri":"file:///a.rs","range":"#,
+            r#"{"start":{"line":1,"character":0},"end":{"line":1,"character":28}}}},"#,
+            r#"{"name":"new","kind":6,"location":{"uri":"file:///a.rs","range":"#,
+            r#"{"start":{"line":1,"character":9},"end":{"line":1,"character":26}}},"#,
+            r#""containerName":"S"}]"#,
+        )
+    );
+}
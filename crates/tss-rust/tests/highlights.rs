@@ -0,0 +1,120 @@
+//! Default highlight captures derived from `HIGHLIGHTS_QUERY`.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(feature = "meta_highlights", feature = "node_full"))]
+
+use std::str::FromStr;
+use tree_sitter_symbols_rust::{Highlight, NodeType};
+
+#[test]
+fn unconditional_captures() {
+    assert_eq!(NodeType::TypeIdentifier.highlight(), Some(Highlight::Type));
+    assert_eq!(
+        NodeType::PrimitiveType.highlight_captures(),
+        &["type.builtin"]
+    );
+    assert_eq!(NodeType::FnKwToken.highlight(), Some(Highlight::Keyword));
+    assert_eq!(
+        NodeType::LParenToken.highlight(),
+        Some(Highlight::PunctuationBracket)
+    );
+    assert_eq!(NodeType::CrateKw.highlight(), Some(Highlight::Keyword));
+}
+
+#[test]
+fn contextual_captures_are_left_out() {
+    // Identifiers are highlighted by where they appear and what they look like
+    assert_eq!(NodeType::Identifier.highlight_captures(), &[] as &[&str]);
+    // `<` is a bracket only in type arguments and parameters
+    assert_eq!(NodeType::LtToken.highlight(), None);
+    assert_eq!(NodeType::FunctionItem.highlight(), None);
+}
+
+#[test]
+fn capture_names_round_trip() {
+    assert!(Highlight::ALL.contains(&Highlight::FunctionMacro));
+    for highlight in Highlight::ALL {
+        assert_eq!(Highlight::from_str(highlight.as_str()), Ok(*highlight));
+        assert_eq!(highlight.to_string(), highlight.as_str());
+    }
+    assert_eq!(Highlight::FunctionMacro.as_str(), "function.macro");
+    assert!(Highlight::from_str("nonsense").is_err());
+}
+
+// Every default is what the query engine captures the node as, wherever it appears (checked
+// against tree-sitter-rust 0.24's query, so not for the 0.23 tables)
+#[cfg(all(
+    feature = "meta_named",
+    feature = "tree-sitter",
+    not(feature = "grammar-0-23")
+))]
+#[test]
+fn defaults_agree_with_the_query_engine() {
+    use std::collections::HashMap;
+    use streaming_iterator::StreamingIterator;
+
+    let source = r#"
+        use std::fmt::{self, Display};
+
+        /// Adds two numbers.
+        pub async fn add<'a, T: Clone>(a: &'a T, b: i32) -> Result<i32, String> {
+            let x = [1, 2][0] + b; // a comment
+            if x > 0 && true { return Ok(x); }
+            println!("{}\n", x);
+            Err(String::from("no"))
+        }
+
+        #[derive(Debug)]
+        struct Point { x: f64, y: f64 }
+    "#;
+    let language: tree_sitter::Language = tree_sitter_rust::LANGUAGE.into();
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&language).unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    let query = tree_sitter::Query::new(&language, tree_sitter_rust::HIGHLIGHTS_QUERY).unwrap();
+
+    let mut by_node: HashMap<usize, Vec<&str>> = HashMap::new();
+    let mut cursor = tree_sitter::QueryCursor::new();
+    let mut captures = cursor.captures(&query, tree.root_node(), source.as_bytes());
+    while let Some((query_match, index)) = captures.next() {
+        let capture = query_match.captures[*index];
+        by_node
+            .entry(capture.node.id())
+            .or_default()
+            .push(query.capture_names()[capture.index as usize]);
+    }
+
+    let mut checked = 0;
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        let kind = if node.is_named() {
+            NodeType::from_str(node.kind()).ok()
+        } else {
+            NodeType::ALL
+                .iter()
+                .copied()
+                .find(|node_type| node_type.to_string() == node.kind() && !node_type.is_named())
+        };
+        if let Some(highlight) = kind.and_then(NodeType::highlight) {
+            let names = by_node.get(&node.id()).cloned().unwrap_or_default();
+            assert!(
+                names.contains(&highlight.as_str()),
+                "{} at {:?} is captured as {names:?}, not @{highlight}",
+                node.kind(),
+                node.start_position()
+            );
+            checked += 1;
+        }
+        if cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    assert!(checked > 30, "only {checked} nodes had a default highlight");
+}
@@ -0,0 +1,117 @@
+//! Nested outlines of a syntax tree's items.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(feature = "symbols")]
+
+use tree_sitter_symbols_rust::outline::{outline, OutlineNode};
+use tree_sitter_symbols_rust::NodeType;
+
+const SOURCE: &str = r"
+pub(crate) mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+        center: (f64, f64),
+    }
+
+    enum Kind {
+        Round,
+        Square(f64),
+    }
+
+    trait Area {
+        type Unit;
+        fn area(&self) -> f64;
+    }
+
+    impl Area for Circle {
+        type Unit = f64;
+        fn area(&self) -> f64 {
+            fn square(x: f64) -> f64 { x * x }
+            square(self.radius)
+        }
+    }
+
+    macro_rules! circle {
+        () => {};
+    }
+}
+
+const LIMIT: u8 = 3;
+";
+
+fn parse(source: &str) -> Vec<OutlineNode> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    outline(&tree, source.as_bytes())
+}
+
+/// Each node as an indented `kind name` line.
+fn render(nodes: &[OutlineNode], depth: usize, lines: &mut Vec<String>) {
+    for node in nodes {
+        let visibility = node
+            .visibility
+            .as_ref()
+            .map_or_else(String::new, |vis| format!(" ({vis})"));
+        lines.push(format!(
+            "{}{} {}{visibility}",
+            "  ".repeat(depth),
+            node.kind,
+            node.name
+        ));
+        render(&node.children, depth + 1, lines);
+    }
+}
+
+#[test]
+fn items_nest_inside_their_containers() {
+    let mut lines = Vec::new();
+    render(&parse(SOURCE), 0, &mut lines);
+    assert_eq!(
+        lines,
+        [
+            "mod_item shapes (pub(crate))",
+            "  struct_item Circle (pub)",
+            "    field_declaration radius (pub)",
+            "    field_declaration center",
+            "  enum_item Kind",
+            "    enum_variant Round",
+            "    enum_variant Square",
+            "  trait_item Area",
+            "    associated_type Unit",
+            "    function_signature_item area",
+            "  impl_item impl Area for Circle",
+            "    type_item Unit",
+            "    function_item area",
+            "  macro_definition circle",
+            "const_item LIMIT",
+        ]
+    );
+}
+
+#[test]
+fn ranges_cover_the_item_and_select_the_name() {
+    let items = parse(SOURCE);
+    let span = |range: tree_sitter::Range| &SOURCE[range.start_byte..range.end_byte];
+
+    let shapes = &items[0];
+    assert!(span(shapes.range).starts_with("pub(crate) mod shapes {"));
+    assert!(span(shapes.range).ends_with('}'));
+    assert_eq!(span(shapes.selection_range), "shapes");
+
+    let circle_impl = &shapes.children[3];
+    assert_eq!(circle_impl.kind, NodeType::ImplItem);
+    assert_eq!(span(circle_impl.selection_range), "Circle");
+    assert_eq!(circle_impl.range.start_point.row, 17);
+}
+
+#[test]
+fn inherent_impls_are_named_by_type() {
+    let items = parse("impl<T> Wrapper<T> { fn get(&self) {} }");
+    assert_eq!(items[0].name, "impl Wrapper<T>");
+    assert_eq!(items[0].children[0].name, "get");
+    assert!(parse("").is_empty());
+}
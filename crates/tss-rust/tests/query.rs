@@ -0,0 +1,124 @@
+//! Integration tests for the typed query builder.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(feature = "query", feature = "node_full"))]
+
+use tree_sitter_symbols_rust::query::{Query, QueryError};
+use tree_sitter_symbols_rust::{FieldName, NodeType};
+
+#[test]
+fn renders_fields_and_captures() {
+    let query = Query::node(NodeType::FunctionItem)
+        .field(
+            FieldName::Name,
+            Query::node(NodeType::Identifier).capture("name"),
+        )
+        .capture("definition.function");
+    assert_eq!(
+        query.to_source().unwrap(),
+        "(function_item name: (identifier) @name) @definition.function"
+    );
+}
+
+#[test]
+fn renders_children_wildcards_and_negations() {
+    let query = Query::node(NodeType::ImplItem)
+        .field(FieldName::Type, Query::wildcard())
+        .child(Query::node(NodeType::DeclarationList))
+        .without_field(FieldName::Trait);
+    assert_eq!(
+        query.to_source().unwrap(),
+        "(impl_item type: (_) (declaration_list) !trait)"
+    );
+}
+
+#[test]
+fn renders_unnamed_nodes_as_strings() {
+    let query = Query::node(NodeType::Arguments).child(Query::node(NodeType::LParenToken));
+    assert_eq!(query.to_source().unwrap(), r#"(arguments "(")"#);
+}
+
+#[test]
+fn accepts_subtypes_of_supertype_fields() {
+    // `return_type` holds `_type`, which has `primitive_type` among its subtypes
+    let query = Query::node(NodeType::FunctionItem)
+        .field(FieldName::ReturnType, Query::node(NodeType::PrimitiveType));
+    assert!(query.errors().is_empty());
+}
+
+#[test]
+fn rejects_unknown_field() {
+    let query = Query::node(NodeType::StructItem)
+        .field(FieldName::Parameters, Query::node(NodeType::Parameters));
+    assert_eq!(
+        query.to_source(),
+        Err(QueryError::UnknownField {
+            node: NodeType::StructItem,
+            field: FieldName::Parameters,
+        })
+    );
+}
+
+#[test]
+fn rejects_invalid_field_type() {
+    let query = Query::node(NodeType::FunctionItem)
+        .field(FieldName::Name, Query::node(NodeType::TypeIdentifier));
+    assert_eq!(
+        query.to_source(),
+        Err(QueryError::InvalidFieldType {
+            node: NodeType::FunctionItem,
+            field: FieldName::Name,
+            child: NodeType::TypeIdentifier,
+        })
+    );
+}
+
+#[test]
+fn rejects_invalid_child() {
+    let query = Query::node(NodeType::Identifier).child(Query::node(NodeType::Block));
+    assert_eq!(
+        query.to_source(),
+        Err(QueryError::InvalidChild {
+            node: NodeType::Identifier,
+            child: NodeType::Block,
+        })
+    );
+}
+
+#[test]
+fn rejects_fields_and_children_of_unnamed_nodes() {
+    let query = Query::node(NodeType::FnKwToken)
+        .field(FieldName::Name, Query::node(NodeType::Identifier))
+        .child(Query::node(NodeType::Identifier))
+        .without_field(FieldName::Body);
+    assert_eq!(
+        query.errors(),
+        [QueryError::AnonymousNode(NodeType::FnKwToken)]
+    );
+    assert_eq!(
+        query.to_source().unwrap_err().to_string(),
+        r#"unnamed node `"fn"` cannot have fields or children"#
+    );
+}
+
+#[test]
+fn nested_errors_propagate() {
+    let inner = Query::node(NodeType::Parameters).capture("");
+    let query = Query::node(NodeType::FunctionItem).field(FieldName::Parameters, inner);
+    assert_eq!(
+        query.to_source(),
+        Err(QueryError::InvalidCapture(String::new()))
+    );
+}
+
+#[cfg(feature = "tree-sitter")]
+#[test]
+fn compiles_with_tree_sitter() {
+    let query = Query::node(NodeType::FunctionItem).field(
+        FieldName::Name,
+        Query::node(NodeType::Identifier).capture("name"),
+    );
+    let compiled = query.compile().unwrap();
+    assert_eq!(compiled.capture_names(), ["name"]);
+}
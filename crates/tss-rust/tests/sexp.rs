@@ -0,0 +1,105 @@
+//! Indented S-expressions of parsed trees.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(feature = "sexp", feature = "node_full"))]
+
+use tree_sitter_symbols_rust::sexp::{pretty, Options};
+
+fn parse(source: &str) -> tree_sitter::Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    parser.parse(source, None).unwrap()
+}
+
+fn print(source: &str, options: &Options) -> String {
+    pretty(parse(source).root_node(), source.as_bytes(), options)
+}
+
+#[test]
+fn variant_names_tokens_and_ranges() {
+    let options = Options {
+        variant_names: true,
+        unnamed: true,
+        ranges: true,
+        ..Options::default()
+    };
+    assert_eq!(
+        print("let x = 1; /* c */", &options),
+        "\
+(SourceFile [0, 0] - [0, 18]
+  (LetDeclaration [0, 0] - [0, 10]
+    (LetKwToken [0, 0] - [0, 3])
+    pattern: (Identifier [0, 4] - [0, 5])
+    (EqToken [0, 6] - [0, 7])
+    value: (IntegerLiteral [0, 8] - [0, 9])
+    (SemicolonToken [0, 9] - [0, 10]))
+  (BlockComment [0, 11] - [0, 18]
+    (BlockCommentStartToken [0, 11] - [0, 13])
+    (BlockCommentEndToken [0, 16] - [0, 18])))"
+    );
+
+    // Unnamed tokens are quoted when labelled by kind
+    let options = Options {
+        unnamed: true,
+        ..Options::default()
+    };
+    assert!(print("fn f() {}", &options).contains("\n    (\"fn\")\n"));
+}
+
+#[test]
+fn extras_left_out_with_text() {
+    let options = Options {
+        extras: false,
+        text: true,
+        ..Options::default()
+    };
+    assert_eq!(
+        print("// a\nfn f() -> u8 { 1 }", &options),
+        "\
+(source_file
+  (function_item
+    name: (identifier \"f\")
+    parameters: (parameters \"()\")
+    return_type: (primitive_type \"u8\")
+    body: (block
+      (integer_literal \"1\"))))"
+    );
+}
+
+#[test]
+fn errors_and_missing_nodes() {
+    assert_eq!(
+        print("let x = ;", &Options::default()),
+        "\
+(source_file
+  (let_declaration
+    pattern: (identifier)
+    (ERROR)))"
+    );
+    let options = Options {
+        text: true,
+        ..Options::default()
+    };
+    assert_eq!(
+        print("let x = 1", &options),
+        "\
+(source_file
+  (let_declaration
+    pattern: (identifier \"x\")
+    value: (integer_literal \"1\")
+    (MISSING \";\")))"
+    );
+}
+
+#[test]
+fn extras_metadata() {
+    use tree_sitter_symbols_rust::NodeType;
+
+    assert!(NodeType::LineComment.is_extra());
+    assert!(NodeType::BlockComment.is_extra());
+    assert!(!NodeType::DocComment.is_extra());
+    assert!(!NodeType::Identifier.is_extra());
+}
@@ -0,0 +1,172 @@
+//! Symbols found by tree-sitter-rust's tags query.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(feature = "symbols")]
+
+use tree_sitter_symbols_rust::symbols::{symbols, Role, Symbol};
+use tree_sitter_symbols_rust::NodeType;
+
+const SOURCE: &str = r#"
+const LIMIT: usize = 10;
+
+pub struct Point {
+    x: f64,
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+impl Point {
+    pub fn new() -> Self {
+        helper();
+        Point { x: 0.0 }
+    }
+}
+
+mod inner {
+    static NAME: &str = "inner";
+
+    macro_rules! twice {
+        ($e:expr) => { $e; $e };
+    }
+
+    fn run() {
+        println!("{}", NAME);
+    }
+}
+"#;
+
+fn parse(source: &str) -> Vec<Symbol> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_symbols_rust::language())
+        .unwrap();
+    let tree = parser.parse(source, None).unwrap();
+    symbols(&tree, source.as_bytes()).unwrap()
+}
+
+/// `(role, syntax, name, parent name)` for each symbol.
+fn summary(symbols: &[Symbol]) -> Vec<(Role, &str, &str, Option<&str>)> {
+    symbols
+        .iter()
+        .map(|symbol| {
+            (
+                symbol.role,
+                symbol.syntax.as_str(),
+                symbol.name.as_str(),
+                symbol.parent.map(|parent| symbols[parent].name.as_str()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn definitions_and_references_in_source_order() {
+    use Role::{Definition, Reference};
+
+    let symbols = parse(SOURCE);
+    assert_eq!(
+        summary(&symbols),
+        [
+            (Definition, "constant", "LIMIT", None),
+            (Definition, "class", "Point", None),
+            (Definition, "interface", "Shape", None),
+            (Reference, "implementation", "Point", None),
+            (Definition, "method", "new", Some("Point")),
+            (Reference, "call", "helper", Some("new")),
+            (Definition, "module", "inner", None),
+            (Definition, "constant", "NAME", Some("inner")),
+            (Definition, "macro", "twice", Some("inner")),
+            (Definition, "function", "run", Some("inner")),
+            (Reference, "call", "println", Some("run")),
+        ]
+    );
+}
+
+#[test]
+fn symbols_carry_node_types_and_ranges() {
+    let symbols = parse(SOURCE);
+    let kinds: Vec<NodeType> = symbols.iter().map(|symbol| symbol.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            NodeType::ConstItem,
+            NodeType::StructItem,
+            NodeType::TraitItem,
+            NodeType::ImplItem,
+            NodeType::FunctionItem,
+            NodeType::CallExpression,
+            NodeType::ModItem,
+            NodeType::StaticItem,
+            NodeType::MacroDefinition,
+            NodeType::FunctionItem,
+            NodeType::MacroInvocation,
+        ]
+    );
+
+    let new = &symbols[4];
+    assert_eq!(
+        &SOURCE[new.name_range.start_byte..new.name_range.end_byte],
+        "new"
+    );
+    assert!(SOURCE[new.range.start_byte..new.range.end_byte].starts_with("pub fn new()"));
+    assert_eq!(new.range.start_point.row, 12);
+}
+
+#[test]
+fn calls_through_a_path() {
+    use Role::{Definition, Reference};
+
+    let symbols =
+        parse("fn run(\n    n: u8,\n) {\n    Vec::<u8>::new();\n    std::mem::drop(n);\n}\n");
+    assert_eq!(
+        summary(&symbols),
+        [
+            (Definition, "function", "run", None),
+            (Reference, "call", "new", Some("run")),
+            (Reference, "call", "drop", Some("run")),
+        ]
+    );
+    assert_eq!(symbols[0].signature.as_deref(), Some("(n: u8)"));
+    assert_eq!(symbols[1].signature, None);
+}
+
+#[test]
+fn impls_are_named_after_their_type() {
+    use Role::{Definition, Reference};
+
+    let symbols = parse(
+        "impl Display for Other {\n    fn fmt() {}\n}\n\
+         impl<T> Wrap<T> {\n    fn new() {}\n}\n\
+         impl fmt::Debug for inner::Thing {}\n\
+         impl<T> From<T> for inner::Wrap<T> {}\n",
+    );
+    assert_eq!(
+        summary(&symbols),
+        [
+            (Reference, "implementation", "Other", None),
+            (Definition, "method", "fmt", Some("Other")),
+            (Reference, "implementation", "Wrap", None),
+            (Definition, "method", "new", Some("Wrap")),
+            (Reference, "implementation", "Thing", None),
+            (Reference, "implementation", "Wrap", None),
+        ]
+    );
+    let implements: Vec<_> = symbols
+        .iter()
+        .filter(|symbol| symbol.kind == NodeType::ImplItem)
+        .map(|symbol| symbol.implements.as_deref())
+        .collect();
+    assert_eq!(
+        implements,
+        [Some("Display"), None, Some("Debug"), Some("From")]
+    );
+}
+
+#[test]
+fn no_symbols_in_empty_source() {
+    assert!(parse("").is_empty());
+    assert!(parse("// just a comment\n").is_empty());
+}
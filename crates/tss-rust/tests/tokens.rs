@@ -0,0 +1,80 @@
+//! Integration tests for token classification.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+#![cfg(all(feature = "meta_tokens", feature = "node_full"))]
+
+use tree_sitter_symbols_rust::{Associativity, NodeType, TokenCategory};
+
+#[test]
+fn categorises_tokens_and_nodes() {
+    assert_eq!(NodeType::FnKwToken.category(), TokenCategory::Keyword);
+    assert_eq!(NodeType::UnionToken.category(), TokenCategory::Keyword);
+    assert_eq!(NodeType::SelfKw.category(), TokenCategory::Keyword);
+    assert_eq!(
+        NodeType::PlusAssignToken.category(),
+        TokenCategory::Operator
+    );
+    assert_eq!(
+        NodeType::PathSepToken.category(),
+        TokenCategory::Punctuation
+    );
+    assert_eq!(NodeType::StringLiteral.category(), TokenCategory::Literal);
+    assert_eq!(NodeType::EscapeSequence.category(), TokenCategory::Literal);
+    assert_eq!(NodeType::LineComment.category(), TokenCategory::Comment);
+    assert_eq!(
+        NodeType::LineCommentToken.category(),
+        TokenCategory::Comment
+    );
+    assert_eq!(
+        NodeType::TypeIdentifier.category(),
+        TokenCategory::Identifier
+    );
+    assert_eq!(NodeType::FunctionItem.category(), TokenCategory::Other);
+
+    assert!(NodeType::StructKwToken.is_keyword());
+    assert!(NodeType::AndAndToken.is_operator());
+    assert!(NodeType::SemicolonToken.is_punctuation());
+    assert!(NodeType::IntegerLiteral.is_literal());
+    assert!(NodeType::BlockComment.is_comment());
+    assert!(!NodeType::Identifier.is_keyword());
+}
+
+#[test]
+fn recognises_delimiters_and_assignments() {
+    for delimiter in [
+        NodeType::LParenToken,
+        NodeType::RParenToken,
+        NodeType::LBracketToken,
+        NodeType::RBraceToken,
+    ] {
+        assert!(delimiter.is_delimiter(), "{delimiter}");
+    }
+    assert!(!NodeType::CommaToken.is_delimiter());
+    assert!(!NodeType::LtToken.is_delimiter());
+
+    assert!(NodeType::EqToken.is_assignment_operator());
+    assert!(NodeType::ShlAssignToken.is_assignment_operator());
+    assert!(!NodeType::EqEqToken.is_assignment_operator());
+}
+
+#[test]
+fn orders_binary_operators() {
+    let precedence = |node: NodeType| node.binary_precedence().unwrap();
+    assert!(precedence(NodeType::StarToken) > precedence(NodeType::PlusToken));
+    assert!(precedence(NodeType::PlusToken) > precedence(NodeType::ShlToken));
+    assert!(precedence(NodeType::EqEqToken) > precedence(NodeType::AndAndToken));
+    assert!(precedence(NodeType::OrOrToken) > precedence(NodeType::EqToken));
+    assert_eq!(NodeType::CommaToken.binary_precedence(), None);
+
+    assert_eq!(
+        NodeType::MinusToken.associativity(),
+        Some(Associativity::Left)
+    );
+    assert_eq!(
+        NodeType::PlusAssignToken.associativity(),
+        Some(Associativity::Right)
+    );
+    assert_eq!(NodeType::LtToken.associativity(), Some(Associativity::None));
+    assert_eq!(NodeType::Identifier.associativity(), None);
+}
@@ -0,0 +1,331 @@
+//! Comparing two versions of a grammar's `node-types.json`.
+//!
+//! [`diff`] reports the changes that affect code generated from the grammar: node kinds that
+//! were added or removed, fields that were added, removed or now hold different types, fields
+//! whose cardinality changed, and subtypes that joined or left a supertype. Every node is named
+//! by its kind and by the `NodeType` variant and Cargo feature generated for it (see
+//! [`naming`](crate::naming)), so the report can go straight into upgrade notes.
+//!
+//! ```
+//! use tree_sitter_symbols_schema::diff::{diff, Change};
+//! use tree_sitter_symbols_schema::grammar::Grammar;
+//! use tree_sitter_symbols_schema::naming::Overrides;
+//!
+//! let old = Grammar::from_json(r#"[{"type": "block", "named": true}]"#)?;
+//! let new = Grammar::from_json(r#"[
+//!     {"type": "block", "named": true},
+//!     {"type": "gen_block", "named": true}
+//! ]"#)?;
+//!
+//! let changes = diff(&old, &new, &Overrides::default())?;
+//! assert!(matches!(&changes[0], Change::NodeAdded(node) if node.variant == "GenBlock"));
+//! assert_eq!(changes[0].to_string(), "+ node gen_block (GenBlock, feature gen_block)");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::grammar::Grammar;
+use crate::naming::{find_kind, resolve_names, Names, NamingError, Overrides};
+use crate::schema::Field;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// How many nodes a field can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cardinality {
+    /// Whether the field can hold more than one node.
+    pub multiple: bool,
+    /// Whether the field is always present.
+    pub required: bool,
+}
+
+impl From<&Field> for Cardinality {
+    fn from(field: &Field) -> Self {
+        Self {
+            multiple: field.multiple,
+            required: field.required,
+        }
+    }
+}
+
+impl fmt::Display for Cardinality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match (self.multiple, self.required) {
+            (false, false) => "optional",
+            (false, true) => "required",
+            (true, false) => "zero or more",
+            (true, true) => "one or more",
+        })
+    }
+}
+
+/// One difference between two grammars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// A node kind only in the new grammar.
+    NodeAdded(Names),
+    /// A node kind only in the old grammar.
+    NodeRemoved(Names),
+    /// A field only the new grammar gives `node`.
+    FieldAdded {
+        /// The node with the field.
+        node: Names,
+        /// The field name.
+        field: String,
+    },
+    /// A field only the old grammar gives `node`.
+    FieldRemoved {
+        /// The node that had the field.
+        node: Names,
+        /// The field name.
+        field: String,
+    },
+    /// A field that can hold different node kinds.
+    FieldTypesChanged {
+        /// The node with the field.
+        node: Names,
+        /// The field name.
+        field: String,
+        /// Kinds the field can now hold, shown quoted if unnamed.
+        added: Vec<String>,
+        /// Kinds the field can no longer hold, shown quoted if unnamed.
+        removed: Vec<String>,
+    },
+    /// A field whose cardinality changed, e.g. from optional to required.
+    FieldCardinalityChanged {
+        /// The node with the field.
+        node: Names,
+        /// The field name.
+        field: String,
+        /// The cardinality in the old grammar.
+        old: Cardinality,
+        /// The cardinality in the new grammar.
+        new: Cardinality,
+    },
+    /// A node that became a direct subtype of `supertype`.
+    SubtypeAdded {
+        /// The supertype, e.g. `_expression`.
+        supertype: Names,
+        /// The new subtype.
+        subtype: Names,
+    },
+    /// A node that is no longer a direct subtype of `supertype`.
+    SubtypeRemoved {
+        /// The supertype, e.g. `_expression`.
+        supertype: Names,
+        /// The former subtype.
+        subtype: Names,
+    },
+}
+
+impl Change {
+    /// Whether code written against the old grammar can stop compiling or matching: a node,
+    /// field or field type was removed, a subtype left its supertype, or a field's cardinality
+    /// changed.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::NodeAdded(_) | Self::FieldAdded { .. } | Self::SubtypeAdded { .. } => false,
+            Self::FieldTypesChanged { removed, .. } => !removed.is_empty(),
+            Self::NodeRemoved(_)
+            | Self::FieldRemoved { .. }
+            | Self::FieldCardinalityChanged { .. }
+            | Self::SubtypeRemoved { .. } => true,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = |node: &Names| format!("{}, feature {}", node.variant, node.feature);
+        match self {
+            Self::NodeAdded(node) => write!(f, "+ node {node} ({})", names(node)),
+            Self::NodeRemoved(node) => write!(f, "- node {node} ({})", names(node)),
+            Self::FieldAdded { node, field } => {
+                write!(f, "+ field {node}.{field} ({})", node.variant)
+            }
+            Self::FieldRemoved { node, field } => {
+                write!(f, "- field {node}.{field} ({})", node.variant)
+            }
+            Self::FieldTypesChanged {
+                node,
+                field,
+                added,
+                removed,
+            } => {
+                let types: Vec<String> = added
+                    .iter()
+                    .map(|kind| format!("+{kind}"))
+                    .chain(removed.iter().map(|kind| format!("-{kind}")))
+                    .collect();
+                write!(
+                    f,
+                    "~ field {node}.{field} ({}) types: {}",
+                    node.variant,
+                    types.join(", ")
+                )
+            }
+            Self::FieldCardinalityChanged {
+                node,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "~ field {node}.{field} ({}): {old} -> {new}",
+                node.variant
+            ),
+            Self::SubtypeAdded { supertype, subtype } => {
+                write!(f, "+ subtype {supertype} > {subtype} ({})", subtype.variant)
+            }
+            Self::SubtypeRemoved { supertype, subtype } => {
+                write!(f, "- subtype {supertype} > {subtype} ({})", subtype.variant)
+            }
+        }
+    }
+}
+
+/// The nodes of a grammar with their generated names, keyed by kind and namedness.
+///
+/// Overrides of kinds the grammar does not have are left out, as they may be for another release.
+fn nodes(
+    grammar: &Grammar,
+    overrides: &Overrides,
+) -> Result<HashMap<(String, bool), Names>, NamingError> {
+    let applicable = Overrides(
+        overrides
+            .0
+            .iter()
+            .filter(|(key, _)| find_kind(grammar.node_types(), key).is_some())
+            .map(|(key, names)| (key.clone(), names.clone()))
+            .collect(),
+    );
+    Ok(resolve_names(grammar.node_types(), &applicable)?
+        .into_iter()
+        .map(|names| ((names.kind.clone(), names.named), names))
+        .collect())
+}
+
+fn display_kind(kind: &str, named: bool) -> String {
+    if named {
+        kind.to_string()
+    } else {
+        format!("{kind:?}")
+    }
+}
+
+/// The changes from `old` to `new`.
+///
+/// Node additions and removals come first, then field changes and subtype changes, each group
+/// sorted by node kind. Names in the report come from the grammar the node appears in (the new
+/// one when it is in both), resolved with `overrides` like generated code (see
+/// [`resolve_names`]).
+///
+/// # Errors
+///
+/// Returns an error if either grammar's names clash without an override to resolve them, or an
+/// override is not a valid identifier.
+pub fn diff(
+    old: &Grammar,
+    new: &Grammar,
+    overrides: &Overrides,
+) -> Result<Vec<Change>, NamingError> {
+    let old_nodes = nodes(old, overrides)?;
+    let new_nodes = nodes(new, overrides)?;
+    let mut changes = Vec::new();
+    let mut field_changes = Vec::new();
+    let mut subtype_changes = Vec::new();
+
+    let keys: BTreeSet<&(String, bool)> = old_nodes.keys().chain(new_nodes.keys()).collect();
+    for key in keys {
+        let (kind, named) = (key.0.as_str(), key.1);
+        let (old_type, new_type) = match (old.get(kind, named), new.get(kind, named)) {
+            (None, Some(_)) => {
+                changes.push(Change::NodeAdded(new_nodes[key].clone()));
+                continue;
+            }
+            (Some(_), None) => {
+                changes.push(Change::NodeRemoved(old_nodes[key].clone()));
+                continue;
+            }
+            (Some(old_type), Some(new_type)) => (old_type, new_type),
+            (None, None) => continue,
+        };
+        let node = &new_nodes[key];
+
+        let empty = HashMap::new();
+        let old_fields = old_type.fields.as_ref().unwrap_or(&empty);
+        let new_fields = new_type.fields.as_ref().unwrap_or(&empty);
+        let field_names: BTreeSet<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+        for field in field_names {
+            match (old_fields.get(field), new_fields.get(field)) {
+                (None, Some(_)) => field_changes.push(Change::FieldAdded {
+                    node: node.clone(),
+                    field: field.clone(),
+                }),
+                (Some(_), None) => field_changes.push(Change::FieldRemoved {
+                    node: node.clone(),
+                    field: field.clone(),
+                }),
+                (Some(old_field), Some(new_field)) => {
+                    let types = |field: &Field| -> BTreeSet<String> {
+                        field
+                            .types
+                            .iter()
+                            .map(|t| display_kind(&t.field_type_name, t.named))
+                            .collect()
+                    };
+                    let (old_types, new_types) = (types(old_field), types(new_field));
+                    if old_types != new_types {
+                        field_changes.push(Change::FieldTypesChanged {
+                            node: node.clone(),
+                            field: field.clone(),
+                            added: new_types.difference(&old_types).cloned().collect(),
+                            removed: old_types.difference(&new_types).cloned().collect(),
+                        });
+                    }
+                    let (old_card, new_card) = (old_field.into(), new_field.into());
+                    if old_card != new_card {
+                        field_changes.push(Change::FieldCardinalityChanged {
+                            node: node.clone(),
+                            field: field.clone(),
+                            old: old_card,
+                            new: new_card,
+                        });
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        let subtypes = |grammar: &Grammar| -> BTreeSet<(String, bool)> {
+            grammar
+                .get(kind, named)
+                .and_then(|node_type| node_type.subtypes.as_ref())
+                .into_iter()
+                .flatten()
+                .map(|subtype| (subtype.subchild_type_name.clone(), subtype.named))
+                .collect()
+        };
+        let (old_subtypes, new_subtypes) = (subtypes(old), subtypes(new));
+        for subtype in new_subtypes.difference(&old_subtypes) {
+            if let Some(subtype) = new_nodes.get(subtype) {
+                subtype_changes.push(Change::SubtypeAdded {
+                    supertype: node.clone(),
+                    subtype: subtype.clone(),
+                });
+            }
+        }
+        for subtype in old_subtypes.difference(&new_subtypes) {
+            if let Some(subtype) = new_nodes.get(subtype).or_else(|| old_nodes.get(subtype)) {
+                subtype_changes.push(Change::SubtypeRemoved {
+                    supertype: node.clone(),
+                    subtype: subtype.clone(),
+                });
+            }
+        }
+    }
+
+    changes.extend(field_changes);
+    changes.extend(subtype_changes);
+    Ok(changes)
+}
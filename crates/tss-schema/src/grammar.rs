@@ -0,0 +1,184 @@
+//! An indexed view of a grammar's node types.
+
+use crate::schema::{marks_extras, Field, NodeType};
+use std::collections::{BTreeSet, HashMap};
+
+/// The node types of a grammar, indexed by kind.
+///
+/// Named and unnamed nodes live in separate namespaces: tree-sitter-rust has both a named
+/// `block` node and an unnamed `block` token (the macro fragment specifier), so lookups take the
+/// `named` flag alongside the kind.
+pub struct Grammar {
+    node_types: Vec<NodeType>,
+    index: HashMap<(String, bool), usize>,
+    marks_extras: bool,
+}
+
+impl Grammar {
+    /// Parse a grammar from the contents of its `node-types.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserialisation error if the JSON does not match the schema.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let node_types: Vec<NodeType> = serde_json::from_str(json)?;
+        Ok(Self::new(node_types))
+    }
+
+    /// Index an already deserialised list of node types.
+    #[must_use]
+    pub fn new(node_types: Vec<NodeType>) -> Self {
+        let index = node_types
+            .iter()
+            .enumerate()
+            .map(|(i, node_type)| ((node_type.node_type_name.clone(), node_type.named), i))
+            .collect();
+        Self {
+            marks_extras: marks_extras(&node_types),
+            node_types,
+            index,
+        }
+    }
+
+    /// All node types, in the order they appear in `node-types.json`.
+    #[must_use]
+    pub fn node_types(&self) -> &[NodeType] {
+        &self.node_types
+    }
+
+    /// Look up a node type by kind.
+    #[must_use]
+    pub fn get(&self, kind: &str, named: bool) -> Option<&NodeType> {
+        self.index
+            .get(&(kind.to_string(), named))
+            .map(|&i| &self.node_types[i])
+    }
+
+    /// Whether `kind` is an extra, such as a comment, which can appear anywhere. Grammars whose
+    /// `node-types.json` does not mark extras fall back to [`FALLBACK_EXTRAS`].
+    ///
+    /// [`FALLBACK_EXTRAS`]: crate::schema::FALLBACK_EXTRAS
+    #[must_use]
+    pub fn is_extra(&self, kind: &str, named: bool) -> bool {
+        self.get(kind, named)
+            .is_some_and(|node_type| node_type.is_extra(self.marks_extras))
+    }
+
+    /// Whether the named node `kind` is a supertype (it has subtypes).
+    #[must_use]
+    pub fn is_supertype(&self, kind: &str) -> bool {
+        self.get(kind, true)
+            .is_some_and(|node_type| node_type.subtypes.is_some())
+    }
+
+    /// Look up a field of the named node `kind`.
+    #[must_use]
+    pub fn field(&self, kind: &str, field: &str) -> Option<&Field> {
+        self.get(kind, true)?.fields.as_ref()?.get(field)
+    }
+
+    /// Every field name used by any node in the grammar.
+    #[must_use]
+    pub fn field_names(&self) -> BTreeSet<&str> {
+        self.node_types
+            .iter()
+            .filter_map(|node_type| node_type.fields.as_ref())
+            .flat_map(|fields| fields.keys().map(String::as_str))
+            .collect()
+    }
+
+    /// Whether `kind` can appear where `expected` is required.
+    ///
+    /// This is true for an exact match, or when `expected` is a supertype with `kind` among its
+    /// transitive subtypes.
+    #[must_use]
+    pub fn accepts(&self, expected: (&str, bool), kind: &str, named: bool) -> bool {
+        if expected == (kind, named) {
+            return true;
+        }
+        if !expected.1 {
+            return false;
+        }
+        let Some(subtypes) = self
+            .get(expected.0, true)
+            .and_then(|node_type| node_type.subtypes.as_ref())
+        else {
+            return false;
+        };
+        subtypes
+            .iter()
+            .any(|subtype| self.accepts((&subtype.subchild_type_name, subtype.named), kind, named))
+    }
+
+    /// The subtypes of the supertype `kind`, transitively, in `node-types.json` order.
+    ///
+    /// Nested supertypes (such as `_literal` under `_expression`) are included along with their
+    /// own subtypes.
+    #[must_use]
+    pub fn subtypes(&self, kind: &str) -> Vec<&NodeType> {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![(kind.to_string(), true)];
+        while let Some(key) = stack.pop() {
+            let Some(subtypes) = self
+                .index
+                .get(&key)
+                .and_then(|&i| self.node_types[i].subtypes.as_ref())
+            else {
+                continue;
+            };
+            for subtype in subtypes {
+                let key = (subtype.subchild_type_name.clone(), subtype.named);
+                if let Some(&i) = self.index.get(&key) {
+                    if seen.insert(i) {
+                        stack.push(key);
+                    }
+                }
+            }
+        }
+        seen.into_iter().map(|i| &self.node_types[i]).collect()
+    }
+
+    /// The node types reachable from `kind` through fields, children and subtypes, transitively,
+    /// in `node-types.json` order (excluding `kind` itself).
+    ///
+    /// Supertypes such as `_expression` never appear in a parse tree, so reaching one also reaches
+    /// all of its subtypes. This is everything a typed traversal starting at `kind` can meet.
+    #[must_use]
+    pub fn reachable(&self, kind: &str, named: bool) -> Vec<&NodeType> {
+        let Some(&start) = self.index.get(&(kind.to_string(), named)) else {
+            return Vec::new();
+        };
+        let mut seen = BTreeSet::new();
+        let mut stack = self.direct(start);
+        while let Some(i) = stack.pop() {
+            if i != start && seen.insert(i) {
+                stack.extend(self.direct(i));
+            }
+        }
+        seen.into_iter().map(|i| &self.node_types[i]).collect()
+    }
+
+    /// Indices of the node types directly listed in the fields, children or subtypes of the
+    /// node type at `i`.
+    fn direct(&self, i: usize) -> Vec<usize> {
+        let node_type = &self.node_types[i];
+        let fields = node_type.fields.iter().flat_map(|fields| {
+            fields
+                .values()
+                .flat_map(|field| field.types.iter().map(|t| (&t.field_type_name, t.named)))
+        });
+        let children = node_type
+            .children
+            .iter()
+            .flat_map(|children| children.types.iter().map(|t| (&t.child_type_name, t.named)));
+        let subtypes = node_type
+            .subtypes
+            .iter()
+            .flat_map(|subtypes| subtypes.iter().map(|t| (&t.subchild_type_name, t.named)));
+        fields
+            .chain(children)
+            .chain(subtypes)
+            .filter_map(|(kind, named)| self.index.get(&(kind.clone(), named)).copied())
+            .collect()
+    }
+}
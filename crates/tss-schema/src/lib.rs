@@ -0,0 +1,40 @@
+//! Schema and query tooling shared by the `tss-*` crates.
+//!
+//! Every tree-sitter grammar ships a `node-types.json` describing its node kinds, their fields,
+//! children and supertypes. This crate deserialises that file ([`schema`]), indexes it for lookups
+//! ([`grammar::Grammar`]), and parses tree-sitter query source ([`query`]) so that queries can be
+//! checked against the grammar they target ([`validate`]).
+//!
+//! ```
+//! use tree_sitter_symbols_schema::grammar::Grammar;
+//! use tree_sitter_symbols_schema::{query, validate};
+//!
+//! let grammar = Grammar::from_json(r#"[
+//!     {"type": "identifier", "named": true},
+//!     {"type": "function_item", "named": true, "fields": {
+//!         "name": {"multiple": false, "required": true,
+//!                  "types": [{"type": "identifier", "named": true}]}
+//!     }}
+//! ]"#)?;
+//!
+//! let patterns = query::parse("(function_item nmae: (identifier) @name)")?;
+//! let diagnostics = validate::validate(&grammar, &patterns);
+//! assert_eq!(diagnostics[0].message, "`function_item` has no field `nmae`");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`diff`] compares two versions of a grammar, for example before bumping a language crate.
+//!
+//! The language crates' generators (such as `tss-rust-codegen`) use this schema to generate their
+//! `NodeType` enums, named as described in [`naming`], and `tss-macros` uses it to check `query!`
+//! invocations at compile time.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+pub mod diff;
+pub mod grammar;
+pub mod naming;
+pub mod query;
+pub mod schema;
+pub mod validate;
@@ -0,0 +1,534 @@
+//! How node kinds are named in generated code.
+//!
+//! Every node kind maps to a `NodeType` variant and a Cargo feature. Keywords and operators get
+//! readable aliases (`"::"` is `PathSep`, `"fn"` is `FnKw`), and unnamed nodes get a `Token`
+//! suffix on their variant and a `_token` suffix on their feature.
+//!
+//! [`resolve_names`] names every kind of a grammar, taking [`Overrides`] for particular kinds and
+//! failing on any clash the overrides do not resolve, so variant names never change silently
+//! when a grammar is upgraded. Kinds that a grammar upgrade renamed or dropped can be kept as
+//! deprecated aliases of their replacements with [`resolve_aliases`].
+//!
+//! ```
+//! use tree_sitter_symbols_schema::naming::{feature_name, to_pascal_case};
+//!
+//! assert_eq!(to_pascal_case("function_item"), "FunctionItem");
+//! assert_eq!(to_pascal_case("::"), "PathSep");
+//! assert_eq!(feature_name("::", false), "path_sep_token");
+//! ```
+
+use crate::schema::NodeType;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Convert a node kind to `PascalCase`, aliasing keywords and operators to readable names.
+#[allow(clippy::too_many_lines)]
+#[must_use]
+pub fn to_pascal_case(s: &str) -> String {
+    // Handle special characters and operators
+    match s {
+        "macro_rules!" => return "MacroRulesBang".to_string(),
+        "\"" => return "DoubleQuote".to_string(),
+        "'" => return "SingleQuote".to_string(),
+        "/*" => return "BlockCommentStart".to_string(),
+        "*/" => return "BlockCommentEnd".to_string(),
+        "//" => return "LineComment".to_string(),
+        "/=" => return "DivAssign".to_string(),
+        ":" => return "Colon".to_string(),
+        "::" => return "PathSep".to_string(),
+        ";" => return "Semicolon".to_string(),
+        "," => return "Comma".to_string(),
+        "." => return "Dot".to_string(),
+        "!" => return "Bang".to_string(),
+        "!=" => return "NotEq".to_string(),
+        "=" => return "Eq".to_string(),
+        "==" => return "EqEq".to_string(),
+        "=>" => return "FatArrow".to_string(),
+        ">" => return "Gt".to_string(),
+        ">=" => return "Gte".to_string(),
+        "<" => return "Lt".to_string(),
+        "<=" => return "Lte".to_string(),
+        "<<" => return "Shl".to_string(),
+        ">>" => return "Shr".to_string(),
+        "<<=" => return "ShlAssign".to_string(),
+        ">>=" => return "ShrAssign".to_string(),
+        "&" => return "And".to_string(),
+        "&&" => return "AndAnd".to_string(),
+        "&=" => return "AndAssign".to_string(),
+        "|" => return "Or".to_string(),
+        "||" => return "OrOr".to_string(),
+        "|=" => return "OrAssign".to_string(),
+        "^" => return "Xor".to_string(),
+        "^=" => return "XorAssign".to_string(),
+        "+" => return "Plus".to_string(),
+        "+=" => return "PlusAssign".to_string(),
+        "-" => return "Minus".to_string(),
+        "-=" => return "MinusAssign".to_string(),
+        "*" => return "Star".to_string(),
+        "*=" => return "StarAssign".to_string(),
+        "/" => return "Slash".to_string(),
+        "%" => return "Percent".to_string(),
+        "%=" => return "PercentAssign".to_string(),
+        "(" => return "LParen".to_string(),
+        ")" => return "RParen".to_string(),
+        "[" => return "LBracket".to_string(),
+        "]" => return "RBracket".to_string(),
+        "{" => return "LBrace".to_string(),
+        "}" => return "RBrace".to_string(),
+        "@" => return "At".to_string(),
+        "#" => return "Pound".to_string(),
+        "?" => return "Question".to_string(),
+        "$" => return "Dollar".to_string(),
+        "_" => return "Underscore".to_string(),
+        ".." => return "DotDot".to_string(),
+        "..=" => return "DotDotEq".to_string(),
+        "..." => return "DotDotDot".to_string(),
+        "->" => return "RArrow".to_string(),
+        "as" => return "AsKw".to_string(),
+        "async" => return "AsyncKw".to_string(),
+        "await" => return "AwaitKw".to_string(),
+        "break" => return "BreakKw".to_string(),
+        "const" => return "ConstKw".to_string(),
+        "continue" => return "ContinueKw".to_string(),
+        "crate" => return "CrateKw".to_string(),
+        "dyn" => return "DynKw".to_string(),
+        "else" => return "ElseKw".to_string(),
+        "enum" => return "EnumKw".to_string(),
+        "extern" => return "ExternKw".to_string(),
+        "false" => return "FalseKw".to_string(),
+        "fn" => return "FnKw".to_string(),
+        "for" => return "ForKw".to_string(),
+        "if" => return "IfKw".to_string(),
+        "impl" => return "ImplKw".to_string(),
+        "in" => return "InKw".to_string(),
+        "let" => return "LetKw".to_string(),
+        "loop" => return "LoopKw".to_string(),
+        "match" => return "MatchKw".to_string(),
+        "mod" => return "ModKw".to_string(),
+        "move" => return "MoveKw".to_string(),
+        "mut" => return "MutKw".to_string(),
+        "pub" => return "PubKw".to_string(),
+        "ref" => return "RefKw".to_string(),
+        "return" => return "ReturnKw".to_string(),
+        "self" => return "SelfKw".to_string(),
+        "Self" => return "SelfType".to_string(),
+        "static" => return "StaticKw".to_string(),
+        "struct" => return "StructKw".to_string(),
+        "super" => return "SuperKw".to_string(),
+        "trait" => return "TraitKw".to_string(),
+        "true" => return "TrueKw".to_string(),
+        "type" => return "TypeKw".to_string(),
+        "unsafe" => return "UnsafeKw".to_string(),
+        "use" => return "UseKw".to_string(),
+        "where" => return "WhereKw".to_string(),
+        "while" => return "WhileKw".to_string(),
+        _ => {}
+    }
+
+    // Regular snake_case to PascalCase
+    snake_to_pascal(s)
+}
+
+/// Convert a `snake_case` identifier to `PascalCase` without any keyword or operator aliasing.
+#[must_use]
+pub fn snake_to_pascal(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Canonical feature name from the original node type and the named flag.
+/// Uses the alias produced by `to_pascal_case`, converts to `snake_case`,
+/// and appends `_token` for unnamed nodes.
+#[must_use]
+pub fn feature_name(original: &str, named: bool) -> String {
+    let alias = to_pascal_case(original);
+    let mut feat = alias_to_snake(&alias);
+    if !named {
+        feat.push_str("_token");
+    }
+    feat
+}
+
+/// Convert `PascalCase` (alias) to `snake_case` feature name.
+/// Examples:
+///   "`DotDot`" -> "`dot_dot`"
+///   "`MacroRulesBang`" -> "`macro_rules_bang`"
+#[must_use]
+pub fn alias_to_snake(alias: &str) -> String {
+    let mut s = String::with_capacity(alias.len() + 4);
+    for (i, ch) in alias.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                s.push('_');
+            }
+            for low in ch.to_lowercase() {
+                s.push(low);
+            }
+        } else {
+            s.push(ch);
+        }
+    }
+    s
+}
+
+/// Names chosen for particular node kinds instead of the derived ones.
+///
+/// Keys are node kinds: a bare kind picks the named node of that kind if there is one and the
+/// unnamed node otherwise, and a quoted kind (`"\"block\""`) picks the unnamed node. In TOML:
+///
+/// ```toml
+/// "::" = { variant = "ColonColonToken", feature = "colon_colon_token" }
+/// '"block"' = { variant = "BlockKwToken" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Overrides(pub BTreeMap<String, Override>);
+
+/// The names chosen for one node kind; either can be left to the default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Override {
+    /// The full `NodeType` variant, including any `Token` suffix.
+    pub variant: Option<String>,
+    /// The full Cargo feature, including any `_token` suffix.
+    pub feature: Option<String>,
+}
+
+/// A node kind and the names generated for it, displayed as the kind (quoted if unnamed).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Names {
+    /// The kind string, e.g. `function_item` or `::`.
+    pub kind: String,
+    /// Whether the node is named.
+    pub named: bool,
+    /// The `NodeType` variant, e.g. `FunctionItem`.
+    pub variant: String,
+    /// The Cargo feature gating the variant, e.g. `function_item`.
+    pub feature: String,
+}
+
+impl fmt::Display for Names {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&display_kind(&self.kind, self.named))
+    }
+}
+
+/// Why a grammar's names could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamingError {
+    /// Several kinds would get the same variant; the kinds are shown quoted if unnamed.
+    VariantCollision(String, Vec<String>),
+    /// Several kinds would get the same feature; the kinds are shown quoted if unnamed.
+    FeatureCollision(String, Vec<String>),
+    /// An override or a deprecation's replacement names a kind the grammar does not have.
+    UnknownKind(String),
+    /// An override's or deprecation's variant is not a `PascalCase` identifier.
+    InvalidVariant(String),
+    /// An override's feature is not a `snake_case` identifier.
+    InvalidFeature(String),
+    /// A deprecated kind is still in the grammar, so it needs no alias.
+    StillPresent(String),
+    /// A deprecated unnamed kind has no replacement, so nothing could be generated for it.
+    NoReplacement(String),
+}
+
+impl fmt::Display for NamingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VariantCollision(variant, kinds) => write!(
+                f,
+                "variant {variant} is given to {}; give all but one of them a variant override",
+                kinds.join(", ")
+            ),
+            Self::FeatureCollision(feature, kinds) => write!(
+                f,
+                "feature {feature} is given to {}; give all but one of them a feature override",
+                kinds.join(", ")
+            ),
+            Self::UnknownKind(kind) => write!(f, "unknown kind {kind}"),
+            Self::StillPresent(kind) => {
+                write!(f, "deprecated kind {kind} is still in the grammar")
+            }
+            Self::NoReplacement(kind) => write!(
+                f,
+                "deprecated unnamed kind {kind} needs a replacement, as it does not parse"
+            ),
+            Self::InvalidVariant(variant) => {
+                write!(
+                    f,
+                    "variant override {variant:?} is not a PascalCase identifier"
+                )
+            }
+            Self::InvalidFeature(feature) => {
+                write!(
+                    f,
+                    "feature override {feature:?} is not a snake_case identifier"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for NamingError {}
+
+/// Split a configured kind into the kind and whether it is quoted (picking the unnamed node).
+fn unquote(key: &str) -> (&str, bool) {
+    match key
+        .strip_prefix('"')
+        .and_then(|kind| kind.strip_suffix('"'))
+        .filter(|kind| !kind.is_empty())
+    {
+        Some(kind) => (kind, true),
+        None => (key, false),
+    }
+}
+
+/// The node a configured kind picks: a quoted kind the unnamed node, a bare kind the named node
+/// if there is one and the unnamed node otherwise.
+pub(crate) fn find_kind<'a>(node_types: &[NodeType], key: &'a str) -> Option<(&'a str, bool)> {
+    let has = |kind: &str, named: bool| {
+        node_types
+            .iter()
+            .any(|node_type| node_type.node_type_name == kind && node_type.named == named)
+    };
+    let (kind, quoted) = unquote(key);
+    let named = !quoted && has(kind, true);
+    (named || has(kind, false)).then_some((kind, named))
+}
+
+/// Whether `variant` is a `PascalCase` identifier.
+fn is_variant(variant: &str) -> bool {
+    let mut chars = variant.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase()) && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+fn display_kind(kind: &str, named: bool) -> String {
+    if named {
+        kind.to_string()
+    } else {
+        format!("{kind:?}")
+    }
+}
+
+/// The variant and feature of every node kind, in `node-types.json` order, with `overrides`
+/// applied over the names from [`to_pascal_case`] and [`feature_name`].
+///
+/// # Errors
+///
+/// Returns an error if an override names an unknown kind or an invalid identifier, or if two
+/// kinds end up with the same variant or feature. Clashes are never numbered, so they must be
+/// resolved with an override.
+pub fn resolve_names(
+    node_types: &[NodeType],
+    overrides: &Overrides,
+) -> Result<Vec<Names>, NamingError> {
+    let mut chosen: HashMap<(&str, bool), &Override> = HashMap::new();
+    for (key, names) in &overrides.0 {
+        let target =
+            find_kind(node_types, key).ok_or_else(|| NamingError::UnknownKind(key.clone()))?;
+        if let Some(variant) = &names.variant {
+            if !is_variant(variant) {
+                return Err(NamingError::InvalidVariant(variant.clone()));
+            }
+        }
+        if let Some(feature) = &names.feature {
+            let mut chars = feature.chars();
+            let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+                && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !valid {
+                return Err(NamingError::InvalidFeature(feature.clone()));
+            }
+        }
+        chosen.insert(target, names);
+    }
+
+    let names: Vec<Names> = node_types
+        .iter()
+        .map(|node_type| {
+            let kind = &node_type.node_type_name;
+            let chosen = chosen.get(&(kind.as_str(), node_type.named));
+            let variant = chosen
+                .and_then(|names| names.variant.clone())
+                .unwrap_or_else(|| {
+                    let mut variant = to_pascal_case(kind);
+                    if !node_type.named {
+                        variant.push_str("Token");
+                    }
+                    variant
+                });
+            let feature = chosen
+                .and_then(|names| names.feature.clone())
+                .unwrap_or_else(|| feature_name(kind, node_type.named));
+            Names {
+                kind: kind.clone(),
+                named: node_type.named,
+                variant,
+                feature,
+            }
+        })
+        .collect();
+
+    let mut by_variant: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut by_feature: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for names in &names {
+        let kind = display_kind(&names.kind, names.named);
+        by_variant
+            .entry(&names.variant)
+            .or_default()
+            .push(kind.clone());
+        by_feature.entry(&names.feature).or_default().push(kind);
+    }
+    if let Some((variant, kinds)) = by_variant.into_iter().find(|(_, kinds)| kinds.len() > 1) {
+        return Err(NamingError::VariantCollision(variant.to_string(), kinds));
+    }
+    if let Some((feature, kinds)) = by_feature.into_iter().find(|(_, kinds)| kinds.len() > 1) {
+        return Err(NamingError::FeatureCollision(feature.to_string(), kinds));
+    }
+    Ok(names)
+}
+
+/// Kinds that earlier grammar releases had, keyed like [`Overrides`] (quoted if unnamed). In TOML:
+///
+/// ```toml
+/// "macro_invocation" = { replacement = "macro_call", since = "0.24" }
+/// '"crate"' = { replacement = "crate", variant = "CrateToken", note = "now a named node" }
+/// "old_item" = { since = "0.24", note = "parsed as an identifier" }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Deprecations(pub BTreeMap<String, Deprecation>);
+
+/// What became of a kind the grammar no longer has.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Deprecation {
+    /// The kind that replaces it, picked like an [`Overrides`] key, or none if it was dropped.
+    pub replacement: Option<String>,
+    /// The variant the old kind had, if it was not the derived one.
+    pub variant: Option<String>,
+    /// The grammar release that renamed or dropped it.
+    pub since: Option<String>,
+    /// Further guidance, appended to the deprecation note.
+    pub note: Option<String>,
+}
+
+/// A deprecated variant name standing for a current node kind, or a kind that was dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alias {
+    /// The old kind string.
+    pub kind: String,
+    /// Whether the old kind was named.
+    pub named: bool,
+    /// The old variant, e.g. `MacroInvocation`.
+    pub variant: String,
+    /// The names of the node that replaces it, or none if it was dropped.
+    pub replacement: Option<Names>,
+    /// The deprecation note, e.g. `renamed to macro_call in grammar 0.24; use NodeType::MacroCall`
+    /// or `dropped in grammar 0.24`.
+    pub note: String,
+}
+
+/// The aliases for `deprecations`, given the current `names` from [`resolve_names`].
+///
+/// # Errors
+///
+/// Returns an error if a deprecated kind is still in the grammar, a replacement is unknown, an
+/// unnamed kind has no replacement (only named kinds can say they were dropped, through
+/// `FromStr`), an old variant is not a `PascalCase` identifier, or an old variant clashes with a
+/// current one or another alias.
+pub fn resolve_aliases(
+    node_types: &[NodeType],
+    names: &[Names],
+    deprecations: &Deprecations,
+) -> Result<Vec<Alias>, NamingError> {
+    let mut variants: HashMap<String, String> = names
+        .iter()
+        .map(|names| {
+            (
+                names.variant.clone(),
+                display_kind(&names.kind, names.named),
+            )
+        })
+        .collect();
+    let mut aliases = Vec::new();
+    for (key, deprecation) in &deprecations.0 {
+        if find_kind(node_types, key).is_some() {
+            return Err(NamingError::StillPresent(key.clone()));
+        }
+        let (kind, quoted) = unquote(key);
+        let replacement = match &deprecation.replacement {
+            Some(replacement) => {
+                let (new_kind, new_named) = find_kind(node_types, replacement)
+                    .ok_or_else(|| NamingError::UnknownKind(replacement.clone()))?;
+                let names = names
+                    .iter()
+                    .find(|names| names.kind == new_kind && names.named == new_named)
+                    .ok_or_else(|| NamingError::UnknownKind(replacement.clone()))?;
+                Some(names.clone())
+            }
+            None if quoted => return Err(NamingError::NoReplacement(key.clone())),
+            None => None,
+        };
+
+        let variant = deprecation.variant.clone().unwrap_or_else(|| {
+            let mut variant = to_pascal_case(kind);
+            if quoted {
+                variant.push_str("Token");
+            }
+            variant
+        });
+        if !is_variant(&variant) {
+            return Err(NamingError::InvalidVariant(variant));
+        }
+        // A dropped kind only keeps its `FromStr` arm, so its variant cannot clash
+        if replacement.is_some() {
+            if let Some(other) = variants.get(variant.as_str()) {
+                return Err(NamingError::VariantCollision(
+                    variant,
+                    vec![other.clone(), display_kind(kind, !quoted)],
+                ));
+            }
+            variants.insert(variant.clone(), display_kind(kind, !quoted));
+        }
+
+        let since = deprecation
+            .since
+            .as_ref()
+            .map_or_else(String::new, |since| format!(" in grammar {since}"));
+        let extra = deprecation
+            .note
+            .as_ref()
+            .map_or_else(String::new, |note| format!(" ({note})"));
+        let note = match &replacement {
+            Some(replacement) => {
+                let action = if kind == replacement.kind {
+                    "became"
+                } else {
+                    "renamed to"
+                };
+                format!(
+                    "{action} {replacement}{since}; use NodeType::{}{extra}",
+                    replacement.variant
+                )
+            }
+            None => format!("dropped{since}{extra}"),
+        };
+
+        aliases.push(Alias {
+            kind: kind.to_string(),
+            named: !quoted,
+            variant,
+            replacement,
+            note,
+        });
+    }
+    Ok(aliases)
+}
@@ -0,0 +1,506 @@
+//! A parser for tree-sitter query source (the `.scm` S-expression syntax).
+//!
+//! The parser only builds a syntax tree with byte spans; it knows nothing about any particular
+//! grammar. See [`crate::validate`] for checking the parsed patterns against a [`Grammar`].
+//!
+//! [`Grammar`]: crate::grammar::Grammar
+
+use std::fmt;
+
+/// A byte range into the query source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Byte offset of the start of the range.
+    pub start: usize,
+    /// Byte offset one past the end of the range.
+    pub end: usize,
+}
+
+/// A name in the query source along with where it was written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ident {
+    /// The name, with any string escapes resolved.
+    pub name: String,
+    /// Where the name was written.
+    pub span: Span,
+}
+
+/// A single pattern, with its quantifier and captures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// What the pattern matches.
+    pub kind: PatternKind,
+    /// The quantifier following the pattern, if any.
+    pub quantifier: Option<Quantifier>,
+    /// The captures following the pattern, without the leading `@`.
+    pub captures: Vec<Ident>,
+    /// The whole pattern, including quantifier and captures.
+    pub span: Span,
+}
+
+/// The different kinds of pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternKind {
+    /// A named node, `(kind child...)`, or a named wildcard `(_ child...)` when `kind` is `None`.
+    Named {
+        /// The node kind, or `None` for `(_)`.
+        kind: Option<Ident>,
+        /// The supertype in `(supertype/kind)` syntax.
+        supertype: Option<Ident>,
+        /// The contents of the node pattern.
+        children: Vec<Child>,
+    },
+    /// An anonymous node written as a string, `"fn"`.
+    Anonymous(Ident),
+    /// A bare wildcard `_`, matching any named or anonymous node.
+    Wildcard,
+    /// `(ERROR)` or `(MISSING kind)`, which do not correspond to grammar node types.
+    Special(Ident),
+    /// An alternation, `[a b c]`.
+    Alternation(Vec<Pattern>),
+    /// A group of sibling patterns, `((a) (b))`, possibly with predicates.
+    Group(Vec<Child>),
+}
+
+/// An item inside a node pattern or group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Child {
+    /// A child pattern.
+    Pattern(Pattern),
+    /// A child pattern in a named field, `name: pattern`.
+    Field {
+        /// The field name.
+        name: Ident,
+        /// The pattern the field must match.
+        pattern: Pattern,
+    },
+    /// A negated field, `!name`.
+    NegatedField(Ident),
+    /// An anchor, `.`.
+    Anchor(Span),
+    /// A predicate or directive, `(#eq? @a "b")`.
+    Predicate(Predicate),
+}
+
+/// A quantifier on a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    /// `?`
+    ZeroOrOne,
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+}
+
+/// A predicate or directive such as `#eq?` or `#set!`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Predicate {
+    /// The predicate name without the leading `#`, e.g. `eq?`.
+    pub name: Ident,
+    /// The predicate arguments.
+    pub args: Vec<PredicateArg>,
+    /// The whole predicate, including parentheses.
+    pub span: Span,
+}
+
+/// An argument to a predicate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PredicateArg {
+    /// A capture reference, `@name`.
+    Capture(Ident),
+    /// A string literal.
+    String(Ident),
+    /// A bare identifier.
+    Identifier(Ident),
+}
+
+/// A syntax error in query source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub message: String,
+    /// Where it went wrong.
+    pub span: Span,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.span.start)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Pattern {
+    /// Every capture in this pattern and its children, in source order.
+    #[must_use]
+    pub fn all_captures(&self) -> Vec<&Ident> {
+        let mut captures = Vec::new();
+        self.collect_captures(&mut captures);
+        captures.sort_by_key(|capture| capture.span.start);
+        captures
+    }
+
+    fn collect_captures<'a>(&'a self, out: &mut Vec<&'a Ident>) {
+        out.extend(&self.captures);
+        match &self.kind {
+            PatternKind::Named { children, .. } | PatternKind::Group(children) => {
+                for child in children {
+                    match child {
+                        Child::Pattern(pattern) | Child::Field { pattern, .. } => {
+                            pattern.collect_captures(out);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            PatternKind::Alternation(patterns) => {
+                for pattern in patterns {
+                    pattern.collect_captures(out);
+                }
+            }
+            PatternKind::Anonymous(_) | PatternKind::Wildcard | PatternKind::Special(_) => {}
+        }
+    }
+}
+
+/// Parse query source into its top-level patterns.
+///
+/// # Errors
+///
+/// Returns the first syntax error in the source.
+pub fn parse(source: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut parser = Parser { source, pos: 0 };
+    let mut patterns = Vec::new();
+    loop {
+        parser.skip_trivia();
+        if parser.at_end() {
+            return Ok(patterns);
+        }
+        patterns.push(parser.pattern()?);
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn at_end(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.pos..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn error<T>(&self, message: impl Into<String>, start: usize) -> Result<T, ParseError> {
+        Err(ParseError {
+            message: message.into(),
+            span: Span {
+                start,
+                end: self.pos.max(start),
+            },
+        })
+    }
+
+    fn skip_trivia(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == ';' {
+                while let Some(c) = self.bump() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Characters allowed in predicate names and their bare arguments, e.g. `not-eq?`.
+    fn is_word_char(c: char) -> bool {
+        c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '?' | '!')
+    }
+
+    fn name_while(&mut self, what: &str, accept: fn(char) -> bool) -> Result<Ident, ParseError> {
+        let start = self.pos;
+        while self.peek().is_some_and(accept) {
+            self.bump();
+        }
+        if self.pos == start {
+            return self.error(format!("expected {what}"), start);
+        }
+        Ok(Ident {
+            name: self.source[start..self.pos].to_string(),
+            span: Span {
+                start,
+                end: self.pos,
+            },
+        })
+    }
+
+    /// A node kind or field name.
+    fn ident(&mut self) -> Result<Ident, ParseError> {
+        self.name_while("an identifier", |c| c.is_alphanumeric() || c == '_')
+    }
+
+    fn string(&mut self) -> Result<Ident, ParseError> {
+        let start = self.pos;
+        self.bump(); // opening quote
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                None => return self.error("unterminated string", start),
+                Some('"') => break,
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some('0') => value.push('\0'),
+                    Some(c) => value.push(c),
+                    None => return self.error("unterminated string", start),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+        Ok(Ident {
+            name: value,
+            span: Span {
+                start,
+                end: self.pos,
+            },
+        })
+    }
+
+    fn capture_name(&mut self) -> Result<Ident, ParseError> {
+        let at = self.pos;
+        self.bump(); // `@`
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        if self.pos == start {
+            return self.error("expected a capture name after `@`", at);
+        }
+        Ok(Ident {
+            name: self.source[start..self.pos].to_string(),
+            span: Span {
+                start: at,
+                end: self.pos,
+            },
+        })
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, ParseError> {
+        self.skip_trivia();
+        let start = self.pos;
+        let kind = match self.peek() {
+            Some('(') => self.parenthesized()?,
+            Some('[') => self.alternation()?,
+            Some('"') => PatternKind::Anonymous(self.string()?),
+            Some('_')
+                if !self
+                    .peek_nth(1)
+                    .is_some_and(|c| c.is_alphanumeric() || c == '_') =>
+            {
+                self.bump();
+                PatternKind::Wildcard
+            }
+            Some(c) => return self.error(format!("unexpected `{c}`"), start),
+            None => return self.error("unexpected end of query", start),
+        };
+        let quantifier = match self.peek() {
+            Some('?') => Some(Quantifier::ZeroOrOne),
+            Some('*') => Some(Quantifier::ZeroOrMore),
+            Some('+') => Some(Quantifier::OneOrMore),
+            _ => None,
+        };
+        if quantifier.is_some() {
+            self.bump();
+        }
+        let mut captures = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.peek() == Some('@') {
+                captures.push(self.capture_name()?);
+            } else {
+                break;
+            }
+        }
+        let end = captures.last().map_or(self.pos, |capture| capture.span.end);
+        Ok(Pattern {
+            kind,
+            quantifier,
+            captures,
+            span: Span { start, end },
+        })
+    }
+
+    fn alternation(&mut self) -> Result<PatternKind, ParseError> {
+        let start = self.pos;
+        self.bump(); // `[`
+        let mut patterns = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                Some(']') => {
+                    self.bump();
+                    return Ok(PatternKind::Alternation(patterns));
+                }
+                None => return self.error("unclosed `[`", start),
+                _ => patterns.push(self.pattern()?),
+            }
+        }
+    }
+
+    fn parenthesized(&mut self) -> Result<PatternKind, ParseError> {
+        let start = self.pos;
+        self.bump(); // `(`
+        self.skip_trivia();
+        match self.peek() {
+            Some(c) if c == '_' || c.is_alphanumeric() => {
+                let name = self.ident()?;
+                if name.name == "ERROR" || name.name == "MISSING" {
+                    return self.special(name, start);
+                }
+                let mut supertype = None;
+                let mut kind = if name.name == "_" { None } else { Some(name) };
+                if self.peek() == Some('/') {
+                    self.bump();
+                    supertype = kind.take();
+                    kind = Some(self.ident()?);
+                }
+                let children = self.children(start)?;
+                Ok(PatternKind::Named {
+                    kind,
+                    supertype,
+                    children,
+                })
+            }
+            _ => Ok(PatternKind::Group(self.children(start)?)),
+        }
+    }
+
+    fn special(&mut self, name: Ident, start: usize) -> Result<PatternKind, ParseError> {
+        // `(MISSING)`, `(MISSING identifier)` or `(MISSING ";")`
+        self.skip_trivia();
+        match self.peek() {
+            Some('"') => {
+                self.string()?;
+            }
+            Some(c) if c != ')' && c != '(' => {
+                self.ident()?;
+            }
+            _ => {}
+        }
+        self.skip_trivia();
+        if self.bump() != Some(')') {
+            return self.error(format!("expected `)` to close `({}`", name.name), start);
+        }
+        Ok(PatternKind::Special(name))
+    }
+
+    fn children(&mut self, start: usize) -> Result<Vec<Child>, ParseError> {
+        let mut children = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => return self.error("unclosed `(`", start),
+                Some(')') => {
+                    self.bump();
+                    return Ok(children);
+                }
+                Some('.') => {
+                    let at = self.pos;
+                    self.bump();
+                    children.push(Child::Anchor(Span {
+                        start: at,
+                        end: self.pos,
+                    }));
+                }
+                Some('!') => {
+                    self.bump();
+                    children.push(Child::NegatedField(self.ident()?));
+                }
+                Some('(') if self.peek_after_paren() == Some('#') => {
+                    children.push(Child::Predicate(self.predicate()?));
+                }
+                Some(c) if c.is_alphanumeric() || (c == '_' && self.is_field_ahead()) => {
+                    let name = self.ident()?;
+                    self.skip_trivia();
+                    if self.bump() != Some(':') {
+                        return self.error("expected `:` after field name", name.span.start);
+                    }
+                    let pattern = self.pattern()?;
+                    children.push(Child::Field { name, pattern });
+                }
+                Some(_) => children.push(Child::Pattern(self.pattern()?)),
+            }
+        }
+    }
+
+    fn peek_after_paren(&self) -> Option<char> {
+        self.source[self.pos + 1..]
+            .chars()
+            .find(|c| !c.is_whitespace())
+    }
+
+    fn is_field_ahead(&self) -> bool {
+        let rest = &self.source[self.pos..];
+        let name_len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        name_len > 1 && rest[name_len..].trim_start().starts_with(':')
+    }
+
+    fn predicate(&mut self) -> Result<Predicate, ParseError> {
+        let start = self.pos;
+        self.bump(); // `(`
+        self.skip_trivia();
+        self.bump(); // `#`
+        let name = self.name_while("a predicate name after `#`", Self::is_word_char)?;
+        let mut args = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => return self.error("unclosed predicate", start),
+                Some(')') => {
+                    self.bump();
+                    break;
+                }
+                Some('@') => args.push(PredicateArg::Capture(self.capture_name()?)),
+                Some('"') => args.push(PredicateArg::String(self.string()?)),
+                Some(_) => args.push(PredicateArg::Identifier(
+                    self.name_while("a predicate argument", Self::is_word_char)?,
+                )),
+            }
+        }
+        Ok(Predicate {
+            name,
+            args,
+            span: Span {
+                start,
+                end: self.pos,
+            },
+        })
+    }
+}
@@ -0,0 +1,106 @@
+//! Serde structs for a tree-sitter grammar's `node-types.json`.
+//!
+//! This schema was generated using [genson-cli](https://docs.rs/genson-cli) as JSON schema then
+//! Rust (serde) structs generated through the [app.quicktype.io](https://app.quicktype.io/?l=rust)
+//! web app.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The named extras of grammars whose `node-types.json` does not mark extras, such as
+/// tree-sitter-rust 0.23: the comments listed in `extras` in its `grammar.js`.
+pub const FALLBACK_EXTRAS: &[&str] = &["block_comment", "line_comment"];
+
+/// Whether any node in `node_types` has the `extra` flag, which `node-types.json` only carries
+/// from tree-sitter 0.24 on.
+#[must_use]
+pub fn marks_extras(node_types: &[NodeType]) -> bool {
+    node_types.iter().any(|node_type| node_type.extra.is_some())
+}
+
+/// One entry of `node-types.json`: a node kind and what it can contain.
+#[derive(Serialize, Deserialize)]
+pub struct NodeType {
+    /// The children the node can have outside of its named fields.
+    pub children: Option<Children>,
+    /// Whether the node is an extra (can appear anywhere, e.g. comments).
+    pub extra: Option<bool>,
+    /// The named fields the node can have.
+    pub fields: Option<HashMap<String, Field>>,
+    /// Whether the node is named (unnamed nodes are literal tokens).
+    pub named: bool,
+    /// Whether the node is the root of every parse tree.
+    pub root: Option<bool>,
+    /// The subtypes of a supertype node (such as `_expression`).
+    pub subtypes: Option<Vec<Subtype>>,
+    /// The node kind, e.g. `function_item` or `::`.
+    #[allow(clippy::struct_field_names)]
+    #[serde(rename = "type")]
+    pub node_type_name: String,
+}
+
+impl NodeType {
+    /// Whether the node is an extra, which can appear anywhere in the tree. `marked` is whether
+    /// the grammar marks extras (see [`marks_extras`]): if it does the `extra` flag decides, and
+    /// if not the named [`FALLBACK_EXTRAS`] are extras.
+    #[must_use]
+    pub fn is_extra(&self, marked: bool) -> bool {
+        if marked {
+            self.extra == Some(true)
+        } else {
+            self.named && FALLBACK_EXTRAS.contains(&self.node_type_name.as_str())
+        }
+    }
+}
+
+/// The children a node can have outside of its named fields.
+#[derive(Serialize, Deserialize)]
+pub struct Children {
+    /// Whether there can be more than one child.
+    pub multiple: bool,
+    /// Whether at least one child is always present.
+    pub required: bool,
+    /// The node types the children can be.
+    pub types: Vec<ChildrenType>,
+}
+
+/// A node type that can appear as a child.
+#[derive(Serialize, Deserialize)]
+pub struct ChildrenType {
+    /// Whether the child node is named.
+    pub named: bool,
+    /// The child node kind.
+    #[serde(rename = "type")]
+    pub child_type_name: String,
+}
+
+/// A named field of a node.
+#[derive(Serialize, Deserialize)]
+pub struct Field {
+    /// Whether the field can hold more than one node.
+    pub multiple: bool,
+    /// Whether the field is always present.
+    pub required: bool,
+    /// The node types the field can hold.
+    pub types: Vec<FieldType>,
+}
+
+/// A node type that can appear in a field.
+#[derive(Serialize, Deserialize)]
+pub struct FieldType {
+    /// Whether the field node is named.
+    pub named: bool,
+    /// The field node kind.
+    #[serde(rename = "type")]
+    pub field_type_name: String,
+}
+
+/// A subtype of a supertype node.
+#[derive(Serialize, Deserialize)]
+pub struct Subtype {
+    /// Whether the subtype node is named.
+    pub named: bool,
+    /// The subtype node kind.
+    #[serde(rename = "type")]
+    pub subchild_type_name: String,
+}
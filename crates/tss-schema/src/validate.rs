@@ -0,0 +1,325 @@
+//! Checking parsed query patterns against a grammar.
+//!
+//! [`check`] is the entry point for query files: it parses the source, validates every pattern
+//! and returns diagnostics that can be located by line and column with
+//! [`Diagnostic::location`].
+
+use crate::grammar::Grammar;
+use crate::query::{self, Child, Ident, Pattern, PatternKind, PredicateArg, Span};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A problem found in a query pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// What is wrong.
+    pub message: String,
+    /// Where in the query source it is.
+    pub span: Span,
+}
+
+/// A one-based line and column in query source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column number, counted in characters.
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl Diagnostic {
+    /// The line and column where this diagnostic starts in `source`.
+    #[must_use]
+    pub fn location(&self, source: &str) -> Location {
+        let before = &source[..self.span.start.min(source.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Parse and validate query source, reporting a syntax error as a single diagnostic.
+#[must_use]
+pub fn check(grammar: &Grammar, source: &str) -> Vec<Diagnostic> {
+    match query::parse(source) {
+        Ok(patterns) => validate(grammar, &patterns),
+        Err(err) => vec![Diagnostic {
+            message: err.message,
+            span: err.span,
+        }],
+    }
+}
+
+/// Check `patterns` against `grammar`.
+///
+/// This reports node kinds, anonymous nodes, supertypes, field names and negated fields that
+/// do not exist, supertype/subtype pairs that are unrelated, nodes that cannot appear under
+/// their parent (in a field or as a child) and predicates that reference undefined captures.
+#[must_use]
+pub fn validate(grammar: &Grammar, patterns: &[Pattern]) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        grammar,
+        diagnostics: Vec::new(),
+        captures: HashSet::new(),
+    };
+    for pattern in patterns {
+        checker.captures = pattern
+            .all_captures()
+            .into_iter()
+            .map(|capture| capture.name.clone())
+            .collect();
+        checker.pattern(pattern, Context::Root);
+    }
+    checker.diagnostics
+}
+
+/// Where a pattern sits relative to its parent node.
+#[derive(Clone, Copy)]
+enum Context<'a> {
+    /// A top-level pattern, or inside a wildcard or unknown parent.
+    Root,
+    /// A direct child of the named node `parent`.
+    Child { parent: &'a str },
+    /// The contents of the field `field` of `parent`.
+    Field { parent: &'a str, field: &'a str },
+}
+
+struct Checker<'g> {
+    grammar: &'g Grammar,
+    diagnostics: Vec<Diagnostic>,
+    captures: HashSet<String>,
+}
+
+impl Checker<'_> {
+    fn report(&mut self, message: String, span: Span) {
+        self.diagnostics.push(Diagnostic { message, span });
+    }
+
+    fn pattern(&mut self, pattern: &Pattern, context: Context) {
+        match &pattern.kind {
+            PatternKind::Named {
+                kind,
+                supertype,
+                children,
+            } => {
+                let known = kind.as_ref().filter(|kind| self.node_kind(kind));
+                if let Some(supertype) = supertype {
+                    self.supertype(supertype, known);
+                }
+                if let Some(kind) = known {
+                    self.placement(context, kind, true);
+                }
+                let parent =
+                    known.map_or(Context::Root, |kind| Context::Child { parent: &kind.name });
+                self.children(parent, children);
+            }
+            PatternKind::Anonymous(token) => {
+                if self.grammar.get(&token.name, false).is_none() {
+                    self.report(
+                        format!("unknown anonymous node {:?}", token.name),
+                        token.span,
+                    );
+                } else {
+                    self.placement(context, token, false);
+                }
+            }
+            PatternKind::Alternation(patterns) => {
+                for pattern in patterns {
+                    self.pattern(pattern, context);
+                }
+            }
+            PatternKind::Group(children) => self.children(context, children),
+            PatternKind::Wildcard | PatternKind::Special(_) => {}
+        }
+    }
+
+    /// Report an unknown named node kind, returning whether it exists.
+    fn node_kind(&mut self, kind: &Ident) -> bool {
+        if self.grammar.get(&kind.name, true).is_some() {
+            return true;
+        }
+        self.report(format!("unknown node kind `{}`", kind.name), kind.span);
+        false
+    }
+
+    /// Check `(supertype/kind)` syntax: the supertype exists and `kind` is one of its subtypes.
+    fn supertype(&mut self, supertype: &Ident, kind: Option<&Ident>) {
+        if !self.grammar.is_supertype(&supertype.name) {
+            self.report(
+                format!("unknown supertype `{}`", supertype.name),
+                supertype.span,
+            );
+        } else if let Some(kind) = kind {
+            if !self
+                .grammar
+                .accepts((&supertype.name, true), &kind.name, true)
+            {
+                self.report(
+                    format!("`{}` is not a subtype of `{}`", kind.name, supertype.name),
+                    kind.span,
+                );
+            }
+        }
+    }
+
+    /// Whether a node of `kind` (or, for a supertype, any of its subtypes) fits one of `types`.
+    fn fits<'t>(
+        &self,
+        mut types: impl Iterator<Item = (&'t str, bool)>,
+        kind: &str,
+        named: bool,
+    ) -> bool {
+        let expand = named && self.grammar.is_supertype(kind);
+        types.any(|expected| {
+            self.grammar.accepts(expected, kind, named)
+                || (expand && self.subtypes_fit(expected, kind))
+        })
+    }
+
+    fn subtypes_fit(&self, expected: (&str, bool), supertype: &str) -> bool {
+        let Some(subtypes) = self
+            .grammar
+            .get(supertype, true)
+            .and_then(|node_type| node_type.subtypes.as_ref())
+        else {
+            return false;
+        };
+        subtypes.iter().any(|subtype| {
+            let kind = subtype.subchild_type_name.as_str();
+            self.grammar.accepts(expected, kind, subtype.named)
+                || (subtype.named
+                    && self.grammar.is_supertype(kind)
+                    && self.subtypes_fit(expected, kind))
+        })
+    }
+
+    /// Check that a node of `kind` can appear in `context`.
+    fn placement(&mut self, context: Context, kind: &Ident, named: bool) {
+        if self.grammar.is_extra(&kind.name, named) {
+            // Extras such as comments can appear anywhere
+            return;
+        }
+        match context {
+            Context::Root => {}
+            Context::Field { parent, field } => {
+                let Some(types) = self.grammar.field(parent, field).map(|field| &field.types)
+                else {
+                    return;
+                };
+                let types = types.iter().map(|t| (t.field_type_name.as_str(), t.named));
+                if !self.fits(types, &kind.name, named) {
+                    let shown = display_kind(&kind.name, named);
+                    self.report(
+                        format!("field `{field}` of `{parent}` cannot hold {shown}"),
+                        kind.span,
+                    );
+                }
+            }
+            Context::Child { parent } => {
+                // The grammar metadata only lists the named children a node can have
+                if !named {
+                    return;
+                }
+                let Some(node_type) = self.grammar.get(parent, true) else {
+                    return;
+                };
+                let children = node_type.children.iter().flat_map(|children| {
+                    children
+                        .types
+                        .iter()
+                        .map(|t| (t.child_type_name.as_str(), t.named))
+                });
+                let fields = node_type.fields.iter().flat_map(|fields| {
+                    fields.values().flat_map(|field| {
+                        field
+                            .types
+                            .iter()
+                            .map(|t| (t.field_type_name.as_str(), t.named))
+                    })
+                });
+                if !self.fits(children.chain(fields), &kind.name, named) {
+                    let shown = display_kind(&kind.name, named);
+                    self.report(
+                        format!("`{parent}` cannot have {shown} as a child"),
+                        kind.span,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Report a field that `parent` (or, for wildcards, any node) does not have, returning
+    /// whether the field exists.
+    fn field_name(&mut self, parent: Option<&str>, name: &Ident) -> bool {
+        let exists = match parent {
+            Some(parent) => self.grammar.field(parent, &name.name).is_some(),
+            None => self.grammar.field_names().contains(name.name.as_str()),
+        };
+        if !exists {
+            let message = match parent {
+                Some(parent) => format!("`{parent}` has no field `{}`", name.name),
+                None => format!("unknown field `{}`", name.name),
+            };
+            self.report(message, name.span);
+        }
+        exists
+    }
+
+    fn children(&mut self, context: Context, children: &[Child]) {
+        let parent = match context {
+            Context::Child { parent } => Some(parent),
+            Context::Root | Context::Field { .. } => None,
+        };
+        for child in children {
+            match child {
+                Child::Pattern(pattern) => self.pattern(pattern, context),
+                Child::Field { name, pattern } => {
+                    let field_context = match parent {
+                        Some(parent) if self.field_name(Some(parent), name) => Context::Field {
+                            parent,
+                            field: &name.name,
+                        },
+                        Some(_) => Context::Root,
+                        None => {
+                            self.field_name(None, name);
+                            Context::Root
+                        }
+                    };
+                    self.pattern(pattern, field_context);
+                }
+                Child::NegatedField(name) => {
+                    self.field_name(parent, name);
+                }
+                Child::Predicate(predicate) => {
+                    for arg in &predicate.args {
+                        if let PredicateArg::Capture(capture) = arg {
+                            if !self.captures.contains(&capture.name) {
+                                self.report(
+                                    format!("undefined capture `@{}`", capture.name),
+                                    capture.span,
+                                );
+                            }
+                        }
+                    }
+                }
+                Child::Anchor(_) => {}
+            }
+        }
+    }
+}
+
+fn display_kind(kind: &str, named: bool) -> String {
+    if named {
+        format!("`{kind}`")
+    } else {
+        format!("{kind:?}")
+    }
+}
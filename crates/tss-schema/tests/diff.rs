@@ -0,0 +1,171 @@
+//! Integration tests for comparing grammar versions.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_schema::diff::{diff, Cardinality, Change};
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::{NamingError, Override, Overrides};
+
+const OLD: &str = r#"[
+    {"type": "_expression", "named": true, "subtypes": [
+        {"type": "identifier", "named": true},
+        {"type": "yield_expression", "named": true}
+    ]},
+    {"type": "function_item", "named": true, "fields": {
+        "body": {"multiple": false, "required": false,
+                 "types": [{"type": "block", "named": true}]},
+        "name": {"multiple": false, "required": true,
+                 "types": [{"type": "identifier", "named": true}]},
+        "qualifier": {"multiple": false, "required": false,
+                      "types": [{"type": "identifier", "named": true}]}
+    }},
+    {"type": "block", "named": true},
+    {"type": "identifier", "named": true},
+    {"type": "yield_expression", "named": true},
+    {"type": "yield", "named": false}
+]"#;
+
+const NEW: &str = r#"[
+    {"type": "_expression", "named": true, "subtypes": [
+        {"type": "gen_block", "named": true},
+        {"type": "identifier", "named": true}
+    ]},
+    {"type": "function_item", "named": true, "fields": {
+        "body": {"multiple": false, "required": true,
+                 "types": [{"type": "block", "named": true}]},
+        "name": {"multiple": false, "required": true,
+                 "types": [{"type": "identifier", "named": true},
+                           {"type": "metavariable", "named": true}]},
+        "return_type": {"multiple": false, "required": false,
+                        "types": [{"type": "identifier", "named": true}]}
+    }},
+    {"type": "block", "named": true},
+    {"type": "gen_block", "named": true},
+    {"type": "identifier", "named": true},
+    {"type": "metavariable", "named": true},
+    {"type": "gen", "named": false}
+]"#;
+
+fn changes() -> Vec<Change> {
+    diff(
+        &Grammar::from_json(OLD).unwrap(),
+        &Grammar::from_json(NEW).unwrap(),
+        &Overrides::default(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn identical_grammars_have_no_changes() {
+    let grammar = Grammar::from_json(tree_sitter_rust::NODE_TYPES).unwrap();
+    assert!(diff(&grammar, &grammar, &Overrides::default())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn reports_changes_with_generated_names() {
+    let lines: Vec<String> = changes().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        [
+            "+ node \"gen\" (GenToken, feature gen_token)",
+            "+ node gen_block (GenBlock, feature gen_block)",
+            "+ node metavariable (Metavariable, feature metavariable)",
+            "- node \"yield\" (YieldToken, feature yield_token)",
+            "- node yield_expression (YieldExpression, feature yield_expression)",
+            "~ field function_item.body (FunctionItem): optional -> required",
+            "~ field function_item.name (FunctionItem) types: +metavariable",
+            "- field function_item.qualifier (FunctionItem)",
+            "+ field function_item.return_type (FunctionItem)",
+            "+ subtype _expression > gen_block (GenBlock)",
+            "- subtype _expression > yield_expression (YieldExpression)",
+        ]
+    );
+}
+
+#[test]
+fn names_nodes_with_overrides() {
+    let (old, new) = (
+        Grammar::from_json(OLD).unwrap(),
+        Grammar::from_json(NEW).unwrap(),
+    );
+    // `yield` is only in the old grammar and `gen` only in the new one
+    let overrides = Overrides(
+        [
+            ("\"gen\"", "GenKwToken"),
+            ("yield", "YieldKwToken"),
+            ("metavariable", "MacroVariable"),
+        ]
+        .into_iter()
+        .map(|(kind, variant)| {
+            let names = Override {
+                variant: Some(variant.to_string()),
+                feature: None,
+            };
+            (kind.to_string(), names)
+        })
+        .collect(),
+    );
+    let lines: Vec<String> = diff(&old, &new, &overrides)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .take(4)
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "+ node \"gen\" (GenKwToken, feature gen_token)",
+            "+ node gen_block (GenBlock, feature gen_block)",
+            "+ node metavariable (MacroVariable, feature metavariable)",
+            "- node \"yield\" (YieldKwToken, feature yield_token)",
+        ]
+    );
+
+    // Clashes are not numbered, as generated code would not compile
+    let clashing = Overrides(
+        [(
+            "block".to_string(),
+            Override {
+                variant: Some("Identifier".to_string()),
+                feature: None,
+            },
+        )]
+        .into_iter()
+        .collect(),
+    );
+    assert!(matches!(
+        diff(&old, &new, &clashing),
+        Err(NamingError::VariantCollision(variant, _)) if variant == "Identifier"
+    ));
+}
+
+#[test]
+fn classifies_breaking_changes() {
+    let breaking: Vec<String> = changes()
+        .iter()
+        .filter(|change| change.is_breaking())
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        breaking,
+        [
+            "- node \"yield\" (YieldToken, feature yield_token)",
+            "- node yield_expression (YieldExpression, feature yield_expression)",
+            "~ field function_item.body (FunctionItem): optional -> required",
+            "- field function_item.qualifier (FunctionItem)",
+            "- subtype _expression > yield_expression (YieldExpression)",
+        ]
+    );
+}
+
+#[test]
+fn describes_cardinality() {
+    let cardinality = |multiple, required| Cardinality { multiple, required }.to_string();
+    assert_eq!(cardinality(false, false), "optional");
+    assert_eq!(cardinality(false, true), "required");
+    assert_eq!(cardinality(true, false), "zero or more");
+    assert_eq!(cardinality(true, true), "one or more");
+}
@@ -0,0 +1,64 @@
+//! Integration tests for grammar lookups.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_schema::grammar::Grammar;
+
+fn reachable(kind: &str, named: bool) -> Vec<(String, bool)> {
+    let grammar = Grammar::from_json(tree_sitter_rust::NODE_TYPES).unwrap();
+    grammar
+        .reachable(kind, named)
+        .into_iter()
+        .map(|node_type| (node_type.node_type_name.clone(), node_type.named))
+        .collect()
+}
+
+#[test]
+fn leaves_reach_nothing() {
+    assert!(reachable("identifier", true).is_empty());
+    assert!(reachable("fn", false).is_empty());
+    assert!(reachable("not_a_kind", true).is_empty());
+}
+
+#[test]
+fn reachable_follows_fields_children_and_subtypes() {
+    let from_function = reachable("function_item", true);
+    let has = |kind: &str, named: bool| from_function.contains(&(kind.to_string(), named));
+    // Direct fields and children
+    assert!(has("identifier", true));
+    assert!(has("parameters", true));
+    assert!(has("block", true));
+    assert!(has("visibility_modifier", true));
+    // Through `block` to `_expression` and on to its concrete subtypes
+    assert!(has("_expression", true));
+    assert!(has("call_expression", true));
+    // Anonymous tokens held in fields, such as binary operators
+    assert!(has("+", false));
+    // Nested functions are reachable through `block`, but a node never lists itself
+    assert!(!has("function_item", true));
+}
+
+#[test]
+fn supertypes_reach_their_subtypes() {
+    let from_literal = reachable("_literal", true);
+    assert!(from_literal.contains(&("string_literal".to_string(), true)));
+    assert!(from_literal.contains(&("escape_sequence".to_string(), true)));
+    assert!(!from_literal.contains(&("_literal".to_string(), true)));
+}
+
+#[test]
+fn subtypes_include_nested_supertypes() {
+    let grammar = Grammar::from_json(tree_sitter_rust::NODE_TYPES).unwrap();
+    let kinds: Vec<&str> = grammar
+        .subtypes("_expression")
+        .into_iter()
+        .map(|node_type| node_type.node_type_name.as_str())
+        .collect();
+    assert!(kinds.contains(&"call_expression"));
+    assert!(kinds.contains(&"_literal"));
+    assert!(kinds.contains(&"string_literal"));
+    // Subtypes only, not what their fields hold
+    assert!(!kinds.contains(&"arguments"));
+    assert!(grammar.subtypes("function_item").is_empty());
+}
@@ -0,0 +1,201 @@
+//! Resolving variant and feature names with overrides, and aliases for deprecated kinds.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::naming::{
+    resolve_aliases, resolve_names, Deprecation, Deprecations, NamingError, Override, Overrides,
+};
+
+fn grammar(json: &str) -> Grammar {
+    Grammar::from_json(json).unwrap()
+}
+
+fn overrides(entries: &[(&str, Option<&str>, Option<&str>)]) -> Overrides {
+    Overrides(
+        entries
+            .iter()
+            .map(|(kind, variant, feature)| {
+                let names = Override {
+                    variant: variant.map(str::to_string),
+                    feature: feature.map(str::to_string),
+                };
+                ((*kind).to_string(), names)
+            })
+            .collect(),
+    )
+}
+
+fn deprecations(entries: &[(&str, Option<&str>, Option<&str>)]) -> Deprecations {
+    Deprecations(
+        entries
+            .iter()
+            .map(|(kind, replacement, since)| {
+                let deprecation = Deprecation {
+                    replacement: replacement.map(str::to_string),
+                    since: since.map(str::to_string),
+                    ..Deprecation::default()
+                };
+                ((*kind).to_string(), deprecation)
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn derives_names_without_overrides() {
+    let rust = grammar(tree_sitter_rust::NODE_TYPES);
+    let names = resolve_names(rust.node_types(), &Overrides::default()).unwrap();
+    let path_sep = names.iter().find(|names| names.kind == "::").unwrap();
+    assert_eq!(path_sep.variant, "PathSepToken");
+    assert_eq!(path_sep.feature, "path_sep_token");
+}
+
+#[test]
+fn collisions_fail_until_resolved() {
+    // `foo_bar` and `fooBar` both derive the variant FooBar
+    let clashing =
+        grammar(r#"[{"type": "foo_bar", "named": true}, {"type": "fooBar", "named": true}]"#);
+    let err = resolve_names(clashing.node_types(), &Overrides::default()).unwrap_err();
+    assert_eq!(
+        err,
+        NamingError::VariantCollision(
+            "FooBar".to_string(),
+            vec!["foo_bar".into(), "fooBar".into()]
+        )
+    );
+
+    let fixed = overrides(&[("fooBar", Some("FooBarCamel"), Some("foo_bar_camel"))]);
+    let names = resolve_names(clashing.node_types(), &fixed).unwrap();
+    assert_eq!(names[1].variant, "FooBarCamel");
+    assert_eq!(names[1].feature, "foo_bar_camel");
+
+    // Renaming only the variant still leaves the features clashing
+    let variant_only = overrides(&[("fooBar", Some("FooBarCamel"), None)]);
+    assert!(matches!(
+        resolve_names(clashing.node_types(), &variant_only),
+        Err(NamingError::FeatureCollision(feature, _)) if feature == "foo_bar"
+    ));
+}
+
+#[test]
+fn quoted_kinds_pick_unnamed_nodes() {
+    let both = grammar(r#"[{"type": "block", "named": true}, {"type": "block", "named": false}]"#);
+    let renamed = overrides(&[
+        ("block", Some("Body"), None),
+        ("\"block\"", Some("BlockKw"), Some("block_kw")),
+    ]);
+    let names = resolve_names(both.node_types(), &renamed).unwrap();
+    assert_eq!(
+        (names[0].variant.as_str(), names[0].feature.as_str()),
+        ("Body", "block")
+    );
+    assert_eq!(
+        (names[1].variant.as_str(), names[1].feature.as_str()),
+        ("BlockKw", "block_kw")
+    );
+}
+
+#[test]
+fn rejects_unknown_kinds_and_invalid_names() {
+    let one = grammar(r#"[{"type": "block", "named": true}]"#);
+    let check = |entries| resolve_names(one.node_types(), &overrides(entries)).unwrap_err();
+    assert_eq!(
+        check(&[("blok", Some("Blok"), None)]),
+        NamingError::UnknownKind("blok".to_string())
+    );
+    assert_eq!(
+        check(&[("\"block\"", Some("Blok"), None)]),
+        NamingError::UnknownKind("\"block\"".to_string())
+    );
+    assert_eq!(
+        check(&[("block", Some("block"), None)]),
+        NamingError::InvalidVariant("block".to_string())
+    );
+    assert_eq!(
+        check(&[("block", None, Some("Block-Feature"))]),
+        NamingError::InvalidFeature("Block-Feature".to_string())
+    );
+}
+
+#[test]
+fn aliases_stand_for_replacements() {
+    let rust = grammar(tree_sitter_rust::NODE_TYPES);
+    let names = resolve_names(rust.node_types(), &Overrides::default()).unwrap();
+    let old = deprecations(&[
+        ("function_definition", Some("function_item"), Some("0.20")),
+        ("\"crate_kw\"", Some("crate"), None),
+    ]);
+    let aliases = resolve_aliases(rust.node_types(), &names, &old).unwrap();
+
+    let crate_kw = &aliases[0];
+    assert_eq!(
+        (crate_kw.kind.as_str(), crate_kw.named),
+        ("crate_kw", false)
+    );
+    assert_eq!(crate_kw.variant, "CrateKwToken");
+    assert_eq!(crate_kw.replacement.as_ref().unwrap().variant, "CrateKw");
+    assert_eq!(crate_kw.note, "renamed to crate; use NodeType::CrateKw");
+
+    let function = &aliases[1];
+    assert_eq!(function.variant, "FunctionDefinition");
+    assert_eq!(
+        function.replacement.as_ref().unwrap().variant,
+        "FunctionItem"
+    );
+    assert_eq!(
+        function.note,
+        "renamed to function_item in grammar 0.20; use NodeType::FunctionItem"
+    );
+}
+
+#[test]
+fn dropped_kinds_keep_a_note() {
+    let one = grammar(r#"[{"type": "block", "named": true}]"#);
+    let names = resolve_names(one.node_types(), &Overrides::default()).unwrap();
+    let mut old = deprecations(&[("old_item", None, Some("0.24"))]);
+    old.0.get_mut("old_item").unwrap().note = Some("parsed as a block".to_string());
+    let aliases = resolve_aliases(one.node_types(), &names, &old).unwrap();
+    assert_eq!(aliases[0].replacement, None);
+    assert_eq!(
+        aliases[0].note,
+        "dropped in grammar 0.24 (parsed as a block)"
+    );
+
+    // A dropped kind's variant is not generated, so it may match a current one
+    let reused = deprecations(&[("Block", None, None)]);
+    assert!(resolve_aliases(one.node_types(), &names, &reused).is_ok());
+    // Unnamed kinds do not parse, so a dropped one would leave nothing behind
+    assert_eq!(
+        resolve_aliases(
+            one.node_types(),
+            &names,
+            &deprecations(&[("\"old\"", None, None)])
+        ),
+        Err(NamingError::NoReplacement("\"old\"".to_string()))
+    );
+}
+
+#[test]
+fn rejects_invalid_deprecations() {
+    let one = grammar(r#"[{"type": "block", "named": true}]"#);
+    let names = resolve_names(one.node_types(), &Overrides::default()).unwrap();
+    let check = |entries| resolve_aliases(one.node_types(), &names, &deprecations(entries));
+    assert_eq!(
+        check(&[("block", Some("block"), None)]),
+        Err(NamingError::StillPresent("block".to_string()))
+    );
+    assert_eq!(
+        check(&[("body", Some("blok"), None)]),
+        Err(NamingError::UnknownKind("blok".to_string()))
+    );
+    // An old `Block` kind would derive the variant of the current `block`
+    assert_eq!(
+        check(&[("Block", Some("block"), None)]),
+        Err(NamingError::VariantCollision(
+            "Block".to_string(),
+            vec!["block".into(), "Block".into()]
+        ))
+    );
+}
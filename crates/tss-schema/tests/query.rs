@@ -0,0 +1,89 @@
+//! Integration tests for the query parser.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_schema::query::{parse, Child, PatternKind, PredicateArg, Quantifier};
+
+#[test]
+fn parses_fields_captures_and_negations() {
+    let patterns = parse("(impl_item type: (type_identifier) @name !trait) @impl").unwrap();
+    assert_eq!(patterns.len(), 1);
+    let pattern = &patterns[0];
+    assert_eq!(pattern.captures[0].name, "impl");
+    let PatternKind::Named {
+        kind: Some(kind),
+        children,
+        ..
+    } = &pattern.kind
+    else {
+        panic!("expected a named node pattern");
+    };
+    assert_eq!(kind.name, "impl_item");
+    assert!(matches!(&children[0], Child::Field { name, .. } if name.name == "type"));
+    assert!(matches!(&children[1], Child::NegatedField(name) if name.name == "trait"));
+}
+
+#[test]
+fn spans_point_into_the_source() {
+    let source = "(function_item name: (identifier) @name)";
+    let patterns = parse(source).unwrap();
+    let captures = patterns[0].all_captures();
+    let span = captures[0].span;
+    assert_eq!(&source[span.start..span.end], "@name");
+}
+
+#[test]
+fn parses_alternations_groups_and_predicates() {
+    let source = r#"
+        ; a comment
+        ["(" ")"] @punctuation.bracket
+        ((identifier) @constant
+         (#match? @constant "^[A-Z]"))
+        (attribute_item)* @attrs
+        (_expression/identifier) @expr
+        (_)
+        _
+    "#;
+    let patterns = parse(source).unwrap();
+    assert_eq!(patterns.len(), 6);
+    assert!(matches!(&patterns[0].kind, PatternKind::Alternation(items) if items.len() == 2));
+    let PatternKind::Group(children) = &patterns[1].kind else {
+        panic!("expected a group");
+    };
+    let Child::Predicate(predicate) = &children[1] else {
+        panic!("expected a predicate");
+    };
+    assert_eq!(predicate.name.name, "match?");
+    assert!(matches!(&predicate.args[0], PredicateArg::Capture(c) if c.name == "constant"));
+    assert!(matches!(&predicate.args[1], PredicateArg::String(s) if s.name == "^[A-Z]"));
+    assert_eq!(patterns[2].quantifier, Some(Quantifier::ZeroOrMore));
+    assert!(matches!(
+        &patterns[3].kind,
+        PatternKind::Named { supertype: Some(s), kind: Some(k), .. }
+            if s.name == "_expression" && k.name == "identifier"
+    ));
+    assert!(matches!(
+        &patterns[4].kind,
+        PatternKind::Named { kind: None, .. }
+    ));
+    assert!(matches!(&patterns[5].kind, PatternKind::Wildcard));
+}
+
+#[test]
+fn reports_unclosed_patterns() {
+    let err = parse("(function_item name: (identifier)").unwrap_err();
+    assert_eq!(err.message, "unclosed `(`");
+    assert_eq!(err.span.start, 0);
+}
+
+#[test]
+fn parses_bundled_queries() {
+    for source in [
+        tree_sitter_rust::HIGHLIGHTS_QUERY,
+        tree_sitter_rust::TAGS_QUERY,
+        tree_sitter_rust::INJECTIONS_QUERY,
+    ] {
+        assert!(!parse(source).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+//! Integration tests for checking queries against a grammar.
+
+#![allow(clippy::negative_feature_names)]
+#![allow(clippy::redundant_feature_names)]
+
+use tree_sitter_symbols_schema::grammar::Grammar;
+use tree_sitter_symbols_schema::query::parse;
+use tree_sitter_symbols_schema::validate::{check, validate, Location};
+
+fn messages(source: &str) -> Vec<String> {
+    let grammar = Grammar::from_json(tree_sitter_rust::NODE_TYPES).unwrap();
+    let patterns = parse(source).unwrap();
+    validate(&grammar, &patterns)
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect()
+}
+
+#[test]
+fn bundled_queries_are_valid() {
+    assert_eq!(
+        messages(tree_sitter_rust::HIGHLIGHTS_QUERY),
+        Vec::<String>::new()
+    );
+    assert_eq!(messages(tree_sitter_rust::TAGS_QUERY), Vec::<String>::new());
+}
+
+#[test]
+fn reports_unknown_kinds() {
+    assert_eq!(
+        messages(r#"(function_itme) "fnn""#),
+        [
+            "unknown node kind `function_itme`",
+            r#"unknown anonymous node "fnn""#
+        ]
+    );
+}
+
+#[test]
+fn reports_unknown_fields_and_negations() {
+    assert_eq!(
+        messages("(function_item nmae: (identifier) !trait)"),
+        [
+            "`function_item` has no field `nmae`",
+            "`function_item` has no field `trait`"
+        ]
+    );
+    assert_eq!(messages("(_ nmae: (_))"), ["unknown field `nmae`"]);
+}
+
+#[test]
+fn reports_unknown_supertypes_and_captures() {
+    assert_eq!(
+        messages("((identifier/identifier) @a (#eq? @b \"x\"))"),
+        ["unknown supertype `identifier`", "undefined capture `@b`"]
+    );
+}
+
+#[test]
+fn reports_nodes_that_cannot_appear_in_a_field() {
+    assert_eq!(
+        messages("(function_item name: (type_identifier))"),
+        ["field `name` of `function_item` cannot hold `type_identifier`"]
+    );
+    assert_eq!(
+        messages(r#"(binary_expression operator: "fn")"#),
+        [r#"field `operator` of `binary_expression` cannot hold "fn""#]
+    );
+    assert_eq!(
+        messages(r#"(binary_expression operator: ["+" "-"] left: (_expression))"#),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn reports_children_that_cannot_appear_under_a_parent() {
+    assert_eq!(
+        messages("(identifier (block))"),
+        ["`identifier` cannot have `block` as a child"]
+    );
+    assert_eq!(
+        messages("(function_item [(block) (struct_item)])"),
+        ["`function_item` cannot have `struct_item` as a child"]
+    );
+    // Field types count as children, and anonymous children are not checked
+    assert_eq!(
+        messages(r#"(function_item (identifier) (block) "fn")"#),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn extras_can_appear_anywhere() {
+    assert_eq!(
+        messages("(function_item (line_comment) name: (block_comment))"),
+        Vec::<String>::new()
+    );
+
+    // tree-sitter-rust 0.23's node-types.json does not mark its comments as extras
+    let unmarked = tree_sitter_rust::NODE_TYPES.replace("\"extra\": true,", "");
+    let grammar = Grammar::from_json(&unmarked).unwrap();
+    assert!(grammar.is_extra("line_comment", true));
+    assert!(!grammar.is_extra("identifier", true));
+    let patterns = parse("(function_item (line_comment) name: (block_comment))").unwrap();
+    assert!(validate(&grammar, &patterns).is_empty());
+}
+
+#[test]
+fn reports_kinds_outside_their_supertype() {
+    assert_eq!(
+        messages("(_expression/type_identifier)"),
+        ["`type_identifier` is not a subtype of `_expression`"]
+    );
+    assert_eq!(messages("(_literal/string_literal)"), Vec::<String>::new());
+}
+
+#[test]
+fn check_reports_syntax_errors_and_locations() {
+    let grammar = Grammar::from_json(tree_sitter_rust::NODE_TYPES).unwrap();
+    let source = "; functions\n(function_item\n  nmae: (identifier))\n";
+    let diagnostics = check(&grammar, source);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].location(source),
+        Location { line: 3, column: 3 }
+    );
+
+    let diagnostics = check(&grammar, "(function_item");
+    assert_eq!(diagnostics.len(), 1);
+}
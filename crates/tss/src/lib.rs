@@ -132,7 +132,7 @@
 //! enabled features. It adds zero runtime overhead - the re-exports are resolved at compile
 //! time and generate identical code to using the language crates directly.
 //!
-//! Each language crate (`tss-rust`, etc.) generates node type enums at build time from the
+//! Each language crate (`tss-rust`, etc.) ships node type enums generated ahead of time from the
 //! corresponding `tree-sitter-*` crate's `NODE_TYPES` constant. See the individual language
 //! crate documentation for details on code generation and available metadata features.
 //!